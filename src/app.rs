@@ -2,34 +2,129 @@
 
 use eframe::egui;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc;
 use std::sync::Arc;
 use tokio::runtime::Handle;
 use tokio::sync::RwLock;
-use opcua::types::{NodeId, DataValue};
+use opcua::types::{NodeId, DataValue, StatusCode};
 
-use crate::config::bookmarks::Bookmarks;
-use crate::network::diagnostics::DiagnosticStep;
-use crate::opcua::browser::BrowsedNode;
-use crate::opcua::client::{ClientConfig, OpcUaClient};
+use crate::config::bookmarks::{Bookmarks, WatchlistEntry};
+use crate::config::settings::{DialogKind, SideTab, WindowLayout};
+use crate::config::startup_checks;
+use crate::network::diagnostics::{DiagnosticResult, DiagnosticStep, StepId};
+use crate::opcua::browser::{ArgumentInfo, BrowseFailure, BrowsedNode, CollectedVariables, NodeClass, FOLDER_VARIABLES_MAX_BROWSES};
+use crate::opcua::client::{ClientConfig, EndpointCheck, EndpointMismatch, EndpointSelection, OpcUaClient, SharedSessionStats};
+use crate::opcua::service_timing::SharedServiceTimings;
+use crate::opcua::crawler::CRAWL_CHUNK_SIZE;
+use crate::opcua::network_activity::{self, ActivityIndicator};
+use crate::opcua::data_types::DataTypeCache;
+use crate::opcua::server_profile::ServerProfile;
+use crate::utils::renderer::{FrameTimeProbe, RendererInfo};
+use crate::opcua::node_attributes::{AttributeFetcher, NodeAttributes};
+use crate::opcua::node_details::NodeDetails;
+use crate::opcua::node_names::NodeNameCache;
+use crate::opcua::simulation::{self, DemoAddressSpace, Simulation};
+use crate::opcua::subscription::{TimestampsMode, TrendStyle};
 use crate::opcua::subscription_manager::{SubscriptionManager, SubscriptionAction};
 use crate::ui::connection::ConnectionPanel;
 use crate::ui::error_panel::{ErrorPanel, ErrorSeverity};
 use crate::ui::monitor::{MonitorPanel, MonitorAction};
-use crate::ui::trending::TrendingPanel;
+use crate::ui::events_panel::EventsAction;
+use crate::ui::trending::{DetachedSeries, TrendingPanel, TrendingAction};
 use crate::ui::crawler_panel::{CrawlerPanel, CrawlerAction};
-use crate::ui::certificates_panel::CertificatesPanel;
+use crate::ui::certificates_panel::{CertAction, CertificatesPanel};
+use crate::ui::server_diagnostics_panel::{ServerDiagnosticsPanel, ServerDiagnosticsAction};
+use crate::ui::tag_validation_panel::{TagValidationPanel, TagValidationAction};
+use crate::ui::favorites::FavoritesAction;
+use crate::ui::timestamp_format::TimestampFormatPicker;
+use crate::ui::goto_bar::{GotoBar, GotoAction};
+use crate::ui::reachability_panel::{ReachabilityPanel, ReachabilityAction};
 use crate::ui::tree_view::TreeView;
-use crate::ui::properties::PropertiesPanel;
+use crate::ui::audit_panel::{AuditAction, AuditPanel};
+use crate::ui::settings_panel::SettingsAction;
+use crate::ui::history_dialog::{HistoryDialog, HistoryRequest};
+use crate::utils::audit::{AuditEvent, AuditLog};
+use crate::ui::properties::{MethodArgumentsRead, PropertiesPanel, ValueRead};
 use crate::utils::i18n::{self, T, Language};
 
 
+const CRAWL_TASK: &str = "crawl";
+
+
+const CONNECT_TASK: &str = "connect";
+
+
+const DIAGNOSE_TASK: &str = "diagnose";
+
+
+const BROWSE_TASK: &str = "browse";
+
+
+const MAX_CRAWL_NODES_PER_FRAME: usize = 5 * CRAWL_CHUNK_SIZE;
+
+
+const COPY_STRUCTURE_MAX_DEPTH: usize = 8;
+
+
+const COPY_STRUCTURE_MAX_BROWSES: usize = 2000;
+
+
+const ADD_VARIABLES_MAX_DEPTH: usize = 10;
+
+
+const MAX_IMPORT_WARNINGS: usize = 20;
+
+
+const RECONNECT_BASE_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+
+const RECONNECT_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
+
+
+const LAYOUT_SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+
+fn reconnect_delay(attempt: u32) -> std::time::Duration {
+    RECONNECT_BASE_DELAY
+        .saturating_mul(1u32 << attempt.saturating_sub(1).min(16))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+
+macro_rules! audit {
+    ($app:expr, $event:ident, $($arg:tt)*) => {
+        $app.record_audit($crate::utils::audit::AuditEvent::$event, format!($($arg)*))
+    };
+}
+
+
+fn watchlist_node(node_id: NodeId, display_name: String) -> BrowsedNode {
+    BrowsedNode {
+        node_id,
+        browse_name: display_name.clone(),
+        display_name,
+        node_class: crate::opcua::browser::NodeClass::Variable,
+        type_definition: None,
+        has_children: false,
+        crawl_root: None,
+        parent_id: None,
+    }
+}
+
+
+fn status_only(status: StatusCode) -> DataValue {
+    DataValue { status: Some(status), ..DataValue::null() }
+}
+
+
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum AppStatus {
     #[default]
     Idle,
     Busy {
+        task_id: &'static str,
         task_name: String,
         start_time: std::time::Instant,
     },
@@ -38,22 +133,125 @@ pub enum AppStatus {
 
 pub struct ActiveTask {
     
+    pub id: &'static str,
+    
     pub name: String,
     
     pub handle: tokio::task::JoinHandle<()>,
     
     pub cancel_token: tokio_util::sync::CancellationToken,
+    
+    pub start_time: std::time::Instant,
+}
+
+impl ActiveTask {
+    
+    pub fn new(id: &'static str, name: &str, handle: tokio::task::JoinHandle<()>, cancel_token: tokio_util::sync::CancellationToken) -> Self {
+        Self { id, name: name.to_string(), handle, cancel_token, start_time: std::time::Instant::now() }
+    }
+}
+
+
+#[derive(Default)]
+pub struct TaskSlots {
+    
+    active: Option<ActiveTask>,
+    
+    crawl: Option<ActiveTask>,
+}
+
+impl TaskSlots {
+    
+    pub fn start(&mut self, task: ActiveTask) {
+        self.active = Some(task);
+    }
+
+    
+    pub fn start_crawl(&mut self, task: ActiveTask) {
+        if let Some(previous) = self.crawl.replace(task) {
+            previous.cancel_token.cancel();
+        }
+    }
+
+    
+    pub fn active(&self) -> Option<&ActiveTask> {
+        self.active.as_ref()
+    }
+
+    
+    pub fn take_active(&mut self) -> Option<ActiveTask> {
+        self.active.take()
+    }
+
+    
+    pub fn stop_crawl(&mut self) -> Option<ActiveTask> {
+        let task = self.crawl.take()?;
+        task.cancel_token.cancel();
+        Some(task)
+    }
+
+    
+    pub fn reap_finished(&mut self) -> bool {
+        if self.crawl.as_ref().is_some_and(|task| task.handle.is_finished()) {
+            self.crawl = None;
+        }
+        let finished = self.active.as_ref().is_some_and(|task| task.handle.is_finished());
+        if finished {
+            self.active = None;
+        }
+        finished
+    }
+
+    
+    pub fn status(&self) -> AppStatus {
+        match self.active.as_ref().or(self.crawl.as_ref()) {
+            Some(task) => AppStatus::Busy { task_id: task.id, task_name: task.name.clone(), start_time: task.start_time },
+            None => AppStatus::Idle,
+        }
+    }
+}
+
+
+struct ScreenshotRequest {
+    
+    rect: Option<egui::Rect>,
+    
+    save_to: Option<std::path::PathBuf>,
 }
 
 
 #[derive(Debug)]
 pub enum BackendMessage {
     
-    SessionEstablished { endpoint: String },
+    SessionEstablished { endpoint: String, generation: u64, stats: SharedSessionStats, timings: SharedServiceTimings, endpoint_check: EndpointCheck },
+    
+    ConnectFailed { error: String, generation: u64 },
     
-    SessionClosed,
+    EndpointFallback { used: String, reason: String, generation: u64 },
     
-    BrowseResult(NodeId, Result<Vec<BrowsedNode>, String>),
+    SessionClosed { user_initiated: bool },
+    
+    BrowseResult(NodeId, Result<Vec<BrowsedNode>, BrowseFailure>),
+    
+    NodeNamesResolved(Vec<(NodeId, Option<String>)>),
+    
+    DataTypesResolved(Vec<(NodeId, Option<NodeId>)>),
+    
+    EngineeringUnitsRead(Vec<(NodeId, Option<String>)>),
+    
+    NodeAttributesRead(u64, Result<NodeAttributes, String>),
+    
+    ReadResult(NodeId, DataValue),
+    
+    MethodArgumentsRead(NodeId, Result<(Vec<ArgumentInfo>, Vec<ArgumentInfo>), String>),
+    
+    HistoryRead(NodeId, String, Result<Vec<(f64, f64)>, String>),
+    
+    RevealResult(NodeId, Result<(Vec<NodeId>, BrowsedNode), String>),
+    
+    SubtreeLoaded(BrowsedNode),
+    
+    FolderVariables(String, Result<CollectedVariables, String>),
     
     Error(String),
     
@@ -61,27 +259,111 @@ pub enum BackendMessage {
     
     DataChange(u32, DataValue),
     
+    InitialValues(Vec<(u32, DataValue)>),
+    
     SubscriptionCreated(u32),
     
-    MonitoredItemsAdded(Vec<(NodeId, u32, u32)>),
+    AdditionalSubscriptionCreated(u32),
+    
+    AdditionalSubscriptionFailed(String),
+    
+    SubscriptionsModified(Result<crate::opcua::subscription::SubscriptionConfig, String>),
+    
+    MonitoredItemsAdded(crate::opcua::subscription::AddItemsOutcome),
+    
+    MonitoredItemModified(NodeId, Result<crate::opcua::subscription::ModifiedItem, String>),
     
-    CrawlResult(Result<Vec<BrowsedNode>, String>),
+    EventSubscriptionCreated(Result<(u32, NodeId), String>),
+    
+    Event(crate::opcua::events::EventData),
+    
+    CrawlResultChunk(Vec<BrowsedNode>),
+    
+    CrawlProgress { nodes: usize, depth: usize, current: String },
+    
+    CrawlResultDone { total: usize, duration: std::time::Duration, truncated: bool, cancelled: bool, conflicts: Vec<crate::opcua::crawler::CrawlConflict> },
+    
+    CrawlFailed(String),
+    
+    CrawlNamespaces(Vec<String>),
+    
+    CrawlStreamFinished(Result<(std::path::PathBuf, usize), String>),
     
     DiagnosticStep(DiagnosticStep),
     
+    ServerDiagnosticsRead(Result<crate::opcua::server_diagnostics::ServerDiagnostics, String>),
+    
+    ServerProfileExported(Result<std::path::PathBuf, String>),
+    
+    NodeDetailsExported(Result<std::path::PathBuf, String>),
+    
+    ViewsListed(Vec<BrowsedNode>),
+    
+    TagValidationResult(Result<Vec<crate::opcua::tag_validation::TagCheck>, String>),
+    
+    PathResolved(String, Result<NodeId, String>),
+    
+    QuickAddResolved(NodeId, Result<BrowsedNode, String>),
+    
+    ReachabilitySample(crate::network::reachability::ProbeSample),
+    
     DiagnosticComplete(crate::network::diagnostics::DiagnosticResult),
 }
 
 
 
+#[derive(Debug, Clone, Default)]
+pub struct ConnectGeneration {
+    current: Arc<AtomicU64>,
+}
+
+impl ConnectGeneration {
+    
+    pub fn advance(&self) -> u64 {
+        self.current.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    
+    pub fn is_current(&self, generation: u64) -> bool {
+        self.current.load(Ordering::SeqCst) == generation
+    }
+}
+
+
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum ConnectionState {
     #[default]
     Disconnected,
     Connected { endpoint: String },
+    Lost { endpoint: String, connected_for: Option<std::time::Duration> },
+    Reconnecting { attempt: u32 },
     Error(String),
 }
 
+impl ConnectionState {
+    
+    fn after_session_closed(&self, user_initiated: bool, connected_for: Option<std::time::Duration>) -> Self {
+        match self {
+            _ if user_initiated => ConnectionState::Disconnected,
+            ConnectionState::Connected { endpoint } => ConnectionState::Lost { endpoint: endpoint.clone(), connected_for },
+            ConnectionState::Lost { .. } => self.clone(),
+            _ => ConnectionState::Disconnected,
+        }
+    }
+
+    
+    fn loss_message(&self, lang: Language) -> Option<String> {
+        let ConnectionState::Lost { endpoint, connected_for } = self else {
+            return None;
+        };
+        Some(match connected_for {
+            Some(duration) => i18n::t_fmt(T::ConnectionLostAfter, lang, &[endpoint, &crate::ui::connection::format_duration(*duration)]),
+            None => i18n::t(T::ServerDisconnected, lang).to_string(),
+        })
+    }
+}
+
 
 
 
@@ -119,9 +401,15 @@ pub struct DiagnosticApp {
 
     
     node_cache: HashMap<NodeId, Vec<BrowsedNode>>,
+    
+    browse_errors: HashMap<NodeId, BrowseFailure>,
 
     
     root_nodes: Vec<BrowsedNode>,
+    
+    server_views: Vec<BrowsedNode>,
+    
+    selected_view: Option<NodeId>,
 
     
     selected_node: Option<BrowsedNode>,
@@ -130,10 +418,18 @@ pub struct DiagnosticApp {
     status: AppStatus,
 
     
-    active_task: Option<ActiveTask>,
+    tasks: TaskSlots,
 
     
     show_about: bool,
+    
+    renderer: RendererInfo,
+    
+    frame_probe: FrameTimeProbe,
+    
+    measured_fps: Option<f32>,
+    
+    renderer_banner_dismissed: bool,
 
     
     
@@ -151,6 +447,68 @@ pub struct DiagnosticApp {
     
     
     show_trending: bool,
+    
+    
+    show_events: bool,
+    
+    
+    events_panel: crate::ui::events_panel::EventsPanel,
+    
+    
+    event_log: crate::opcua::events::EventLog,
+    
+    
+    event_notifier: Option<NodeId>,
+    
+    
+    restore_event_notifier: Option<NodeId>,
+    
+    
+    event_subscription_id: Option<u32>,
+    
+    
+    settings: crate::config::settings::Settings,
+    
+    
+    active_bookmark: Option<usize>,
+    
+    
+    node_names: NodeNameCache,
+    
+    data_types: DataTypeCache,
+    
+    data_type_labels: HashMap<NodeId, String>,
+    
+    attribute_fetcher: AttributeFetcher,
+    
+    node_attributes: Option<NodeAttributes>,
+    
+    value_reads: HashMap<NodeId, ValueRead>,
+    
+    method_arguments: HashMap<NodeId, MethodArgumentsRead>,
+    
+    
+    pending_reveal: Option<(Vec<NodeId>, NodeId)>,
+    
+    
+    copy_structure_confirm: Option<(BrowsedNode, usize)>,
+    
+    add_variables_prompt: Option<(BrowsedNode, bool, usize)>,
+    
+    add_variables_confirm: Option<Vec<BrowsedNode>>,
+    
+    confirm_discard_detached: bool,
+    
+    
+    pending_structure_copy: Option<BrowsedNode>,
+
+    
+    panel_rects: Vec<egui::Rect>,
+
+    
+    activity_indicator: ActivityIndicator,
+    
+    timestamp_picker: TimestampFormatPicker,
 
     
 
@@ -169,6 +527,38 @@ pub struct DiagnosticApp {
     show_certificates: bool,
 
     
+    server_diagnostics_panel: ServerDiagnosticsPanel,
+    
+    
+    show_server_diagnostics: bool,
+    
+    tag_validation_panel: TagValidationPanel,
+    
+    show_tag_validation: bool,
+    
+    audit_log: AuditLog,
+    
+    audit_panel: AuditPanel,
+    
+    history_dialog: HistoryDialog,
+    
+    show_audit_log: bool,
+
+    
+    show_settings: bool,
+    
+    goto_bar: GotoBar,
+
+    
+    reachability_panel: ReachabilityPanel,
+    
+    
+    show_reachability: bool,
+    
+    
+    reachability_cancel: Option<tokio_util::sync::CancellationToken>,
+
+    
     current_lang: Language,
 
     
@@ -181,6 +571,30 @@ pub struct DiagnosticApp {
 
     
     last_connection_check: std::time::Instant,
+
+    
+    connect_generation: ConnectGeneration,
+
+    
+    simulation: Option<Simulation>,
+
+    
+    session_stats: Option<SharedSessionStats>,
+
+    
+    service_timings: Option<SharedServiceTimings>,
+
+    
+    endpoint_check: Option<EndpointCheck>,
+
+    
+    last_connect_config: Option<ClientConfig>,
+
+    
+    reconnect_at: Option<std::time::Instant>,
+
+    
+    pending_layout: Option<(WindowLayout, std::time::Instant)>,
 }
 
 
@@ -202,13 +616,31 @@ pub enum TaskMessage {
 
 impl DiagnosticApp {
     
-    pub fn new(_cc: &eframe::CreationContext<'_>, runtime: Handle) -> Self {
+    pub fn new(_cc: &eframe::CreationContext<'_>, runtime: Handle, renderer: RendererInfo) -> Self {
         // Create channels for communication
         let (task_tx, _task_rx) = std::sync::mpsc::channel::<TaskMessage>();
         let (backend_tx, backend_rx) = std::sync::mpsc::channel::<BackendMessage>();
 
-        // Load bookmarks
-        let bookmarks = Bookmarks::load().unwrap_or_default();
+        // Load bookmarks and check the folders we write to
+        let pki_dir = crate::opcua::certificates::CertificateManager::new()
+            .map(|m| m.pki_directory().to_path_buf())
+            .unwrap_or_else(|_| std::path::PathBuf::from("./pki"));
+        let log_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+        let startup = startup_checks::run(&Bookmarks::bookmarks_path(), &pki_dir, &log_dir);
+        let bookmarks = startup.bookmarks;
+        let settings = crate::config::settings::Settings::load();
+        let layout = settings.layout;
+        let mut error_panel = ErrorPanel::default();
+        for issue in &startup.issues {
+            error_panel.add_error(issue.message(layout.language), ErrorSeverity::Warning);
+        }
+        let subscription_manager = SubscriptionManager {
+            read_initial_values: settings.read_initial_value_on_add,
+            monitoring_options: settings.monitoring_options(),
+            subscription_config: settings.subscription_config(),
+            ..SubscriptionManager::new()
+        };
+        let audit_log = AuditLog::new(settings.audit_log_enabled, log_dir);
 
         Self {
             runtime,
@@ -218,169 +650,602 @@ impl DiagnosticApp {
             connection_state: ConnectionState::default(),
             bookmarks,
             connection_panel: ConnectionPanel::default(),
-            show_connection_panel: true,
-            status_message: i18n::t(T::ReadyNotConnected, Language::default()).to_string(),
+            show_connection_panel: layout.show_connection_panel,
+            status_message: i18n::t(T::ReadyNotConnected, layout.language).to_string(),
             opcua_client: Arc::new(RwLock::new(None)),
             node_cache: HashMap::new(),
+            browse_errors: HashMap::new(),
             root_nodes: Vec::new(),
+            server_views: Vec::new(),
+            selected_view: None,
             selected_node: None,
             status: AppStatus::Idle,
-            active_task: None,
+            tasks: TaskSlots::default(),
             show_about: false,
+            renderer,
+            frame_probe: FrameTimeProbe::default(),
+            measured_fps: None,
+            renderer_banner_dismissed: false,
             // Phase 4
             // Phase 4
-            subscription_manager: SubscriptionManager::new(),
-            monitor_panel: MonitorPanel,
+            subscription_manager,
+            monitor_panel: MonitorPanel::default(),
             trending_panel: TrendingPanel::default(),
-            show_watchlist: true,
-            show_trending: true,
+            show_watchlist: layout.show_watchlist,
+            show_trending: layout.show_trending,
+            show_events: false,
+            events_panel: Default::default(),
+            event_log: Default::default(),
+            event_notifier: None,
+            restore_event_notifier: None,
+            event_subscription_id: None,
+            settings,
+            active_bookmark: None,
+            node_names: NodeNameCache::default(),
+            data_types: DataTypeCache::default(),
+            data_type_labels: HashMap::new(),
+            attribute_fetcher: AttributeFetcher::default(),
+            node_attributes: None,
+            value_reads: HashMap::new(),
+            method_arguments: HashMap::new(),
+            pending_reveal: None,
+            copy_structure_confirm: None,
+            add_variables_prompt: None,
+            add_variables_confirm: None,
+            confirm_discard_detached: false,
+            pending_structure_copy: None,
+            panel_rects: Vec::new(),
+            activity_indicator: ActivityIndicator::default(),
+            timestamp_picker: TimestampFormatPicker::default(),
             // Phase 5
             crawler_panel: CrawlerPanel::default(),
-            show_crawler: false,
+            show_crawler: layout.show_crawler,
             // Phase 6
             certificates_panel: CertificatesPanel::default(),
-            show_certificates: false,
+            show_certificates: layout.show_certificates,
+            server_diagnostics_panel: ServerDiagnosticsPanel::default(),
+            show_server_diagnostics: false,
+            tag_validation_panel: TagValidationPanel::default(),
+            show_tag_validation: false,
+            audit_log,
+            audit_panel: AuditPanel::default(),
+            history_dialog: HistoryDialog::default(),
+            show_audit_log: false,
+            show_settings: false,
+            goto_bar: GotoBar::default(),
+            reachability_panel: ReachabilityPanel::default(),
+            show_reachability: false,
+            reachability_cancel: None,
             // i18n
-            current_lang: Language::default(),
+            current_lang: layout.language,
             // Error handling
-            error_panel: ErrorPanel::default(),
-            show_errors: false,
+            error_panel,
+            show_errors: layout.show_errors,
             last_connection_check: std::time::Instant::now(),
+            connect_generation: ConnectGeneration::default(),
+            simulation: None,
+            session_stats: None,
+            service_timings: None,
+            endpoint_check: None,
+            last_connect_config: None,
+            reconnect_at: None,
+            pending_layout: None,
         }
 
     }
 
     /// Process messages from background tasks
     fn process_backend_messages(&mut self) {
-        while let Ok(msg) = self.backend_rx.try_recv() {
+        let mut crawl_nodes_this_frame = 0;
+        while crawl_nodes_this_frame < MAX_CRAWL_NODES_PER_FRAME {
+            let Ok(msg) = self.backend_rx.try_recv() else { break };
             match msg {
-                BackendMessage::SessionEstablished { endpoint } => {
+                BackendMessage::SessionEstablished { endpoint, generation, stats, timings, endpoint_check } => {
+                    if !self.connect_generation.is_current(generation) {
+                        tracing::warn!("Ignoring stale session for {} (attempt {})", endpoint, generation);
+                        continue;
+                    }
                     self.connection_state = ConnectionState::Connected { endpoint: endpoint.clone() };
-                    self.status_message = i18n::t(T::ConnectedTo, self.current_lang).replace("{}", &endpoint);
+                    audit!(self, Connect, "{} [{}]", endpoint, endpoint_check.negotiated.security_label());
+                    self.session_stats = Some(stats);
+                    self.service_timings = Some(timings);
+                    self.error_panel.acknowledge_errors();
+                    self.status_message = i18n::t_fmt(T::ConnectedTo, self.current_lang, &[&endpoint]);
                     self.connection_panel.set_connecting(false);
+                    self.report_endpoint_mismatch(&endpoint_check);
+                    self.endpoint_check = Some(endpoint_check);
                     
                     // Auto-hide connection panel on successful connection
-                    self.show_connection_panel = false;
+                    if !self.settings.pin_connection_panel {
+                        self.show_connection_panel = false;
+                    }
                     
                     // Reset state
                     self.root_nodes.clear();
                     self.node_cache.clear();
+                    self.browse_errors.clear();
                     self.selected_node = None;
                     self.subscription_manager.clear();
+                    self.subscription_manager.subscription_config = self.settings.subscription_config();
+                    self.event_notifier = None;
+                    self.event_subscription_id = None;
+                    self.node_names.clear();
+                    self.clear_data_types();
+                    self.pending_reveal = None;
+                    self.value_reads.clear();
+                    self.method_arguments.clear();
+                    self.server_diagnostics_panel.reset();
+                    self.server_views.clear();
+                    self.selected_view = None;
 
                     // Auto-browse root on connect
                     self.browse_node(NodeId::from(opcua::types::ObjectId::RootFolder));
+                    self.load_server_views();
+                    self.load_bookmark_watchlist();
+                    self.restore_watchlist();
+                    if let Some(notifier) = self.restore_event_notifier.take() {
+                        self.subscribe_events(notifier);
+                    }
                 }
-                BackendMessage::SessionClosed => {
-                    self.connection_state = ConnectionState::Disconnected;
+                BackendMessage::SessionClosed { user_initiated } => {
+                    audit!(self, Disconnect, "{}", if user_initiated { "user initiated" } else { "connection lost" });
+                    let was_lost = matches!(self.connection_state, ConnectionState::Lost { .. });
+                    let connected_for = self.session_stats.as_ref()
+                        .and_then(|stats| stats.lock().ok().map(|s| s.connected_since.elapsed()));
+                    self.connection_state = self.connection_state.after_session_closed(user_initiated, connected_for);
+                    let reconnect = !was_lost
+                        && self.settings.auto_reconnect
+                        && self.last_connect_config.is_some()
+                        && matches!(self.connection_state, ConnectionState::Lost { .. });
+                    if reconnect {
+                        self.subscription_manager.remember_for_restore();
+                        self.restore_event_notifier = self.event_notifier.clone();
+                    } else if user_initiated {
+                        self.reconnect_at = None;
+                        self.subscription_manager.restore_items.clear();
+                        self.restore_event_notifier = None;
+                    }
+                    self.session_stats = None;
+                    self.service_timings = None;
+                    self.endpoint_check = None;
+                    network_activity::reset();
+                    self.activity_indicator.reset();
                     self.status_message = i18n::t(T::Disconnected, self.current_lang).to_string();
                     self.connection_panel.set_connecting(false);
                     self.root_nodes.clear();
                     self.node_cache.clear();
+                    self.browse_errors.clear();
                     self.selected_node = None;
                     self.subscription_manager.clear();
+                    self.event_notifier = None;
+                    self.event_subscription_id = None;
+                    self.node_names.clear();
+                    self.clear_data_types();
+                    self.pending_reveal = None;
+                    self.value_reads.clear();
+                    self.method_arguments.clear();
+                    self.server_diagnostics_panel.reset();
+                    self.server_views.clear();
+                    self.selected_view = None;
+                    self.confirm_discard_detached = !self.trending_panel.detached_series().is_empty();
                     
                     // Show connection panel again so user can reconnect
                     self.show_connection_panel = true;
                     
-                    // Notify user about disconnection
-                    self.error_panel.add_error(
-                        i18n::t(T::ServerDisconnected, self.current_lang),
-                        ErrorSeverity::Warning
-                    );
+                    // Only alarm on a real loss, once
+                    if let Some(message) = self.connection_state.loss_message(self.current_lang).filter(|_| !was_lost) {
+                        self.status_message = message.clone();
+                        self.error_panel.add_error(message, ErrorSeverity::Error);
+                    }
+                    if reconnect {
+                        self.schedule_reconnect(1);
+                    }
                 }
                 BackendMessage::BrowseResult(parent_id, result) => {
                     match result {
                         Ok(nodes) => {
-                            if parent_id == opcua::types::ObjectId::RootFolder {
+                            let variables: Vec<NodeId> = nodes.iter()
+                                .filter(|node| node.node_class == crate::opcua::browser::NodeClass::Variable)
+                                .map(|node| node.node_id.clone())
+                                .collect();
+                            self.resolve_data_types(&variables);
+                            self.browse_errors.remove(&parent_id);
+                            if parent_id == self.tree_root() {
                                 self.root_nodes = nodes;
                             } else {
                                 self.node_cache.insert(parent_id, nodes);
                             }
                         }
-                        Err(e) => {
-                            self.status_message = format!("Browse error: {}", e);
+                        Err(failure) => {
+                            self.status_message = match &failure {
+                                BrowseFailure::AccessDenied => i18n::t_fmt(T::BrowseAccessDenied, self.current_lang, &[&parent_id.to_string()]),
+                                BrowseFailure::Error(e) => i18n::t_fmt(T::BrowseError, self.current_lang, &[e]),
+                            };
+                            self.browse_errors.insert(parent_id, failure);
                         }
                     }
                 }
-                BackendMessage::Error(e) => {
-                    self.connection_state = ConnectionState::Error(e.clone());
-                    self.status_message = format!("Error: {}", e);
-                    self.connection_panel.set_connecting(false);
-                    self.subscription_manager.creating_subscription = false;
-                    
-                    // Add error notification
-                    self.error_panel.add_error(&e, ErrorSeverity::Error);
-                }
-                BackendMessage::StatusMessage(msg) => {
-                    self.status_message = msg;
-                }
-                BackendMessage::DataChange(item_id, value) => {
-                    self.subscription_manager.handle_data_change(item_id, value);
+                BackendMessage::ViewsListed(views) => {
+                    self.server_views = views;
                 }
-                BackendMessage::SubscriptionCreated(id) => {
-                    self.subscription_manager.subscription_state.subscription_id = Some(id);
-                    self.subscription_manager.creating_subscription = false;
-                    
-                    // Add any pending items
-                    self.subscription_manager.spawn_add_items_task(
-                        &self.runtime,
-                        self.opcua_client.clone(),
-                        self.backend_tx.clone()
-                    );
+                BackendMessage::SubtreeLoaded(node) => {
+                    self.pending_structure_copy = Some(node);
                 }
-                BackendMessage::MonitoredItemsAdded(pairs) => {
-                    self.subscription_manager.handle_monitored_items_added(pairs);
+                BackendMessage::FolderVariables(folder, result) => self.handle_folder_variables(folder, result),
+                BackendMessage::NodeNamesResolved(names) => {
+                    for (node_id, name) in names {
+                        self.node_names.resolve(node_id, name);
+                    }
+                    self.data_type_labels = self.data_types.labels(&self.node_names);
                 }
-                BackendMessage::CrawlResult(result) => {
-                    self.crawler_panel.is_crawling = false;
+                BackendMessage::NodeAttributesRead(generation, result) => {
+                    if !self.attribute_fetcher.is_current(generation) {
+                        continue;
+                    }
                     match result {
-                        Ok(nodes) => {
-                            self.crawler_panel.results = nodes;
-                            self.crawler_panel.status = i18n::t(T::CrawlComplete, self.current_lang).replace("{}", &self.crawler_panel.results.len().to_string());
-                        }
-                        Err(e) => {
-                            self.crawler_panel.status = i18n::t(T::CrawlFailed, self.current_lang).replace("{}", &e);
+                        Ok(attributes) => {
+                            let selected = self.selected_node.as_ref().map(|node| node.node_id.clone());
+                            if let (Some(node_id), Some(data_type)) = (selected, attributes.data_type.clone()) {
+                                if crate::opcua::data_types::builtin_type_name(&data_type).is_none() {
+                                    self.resolve_node_names(std::slice::from_ref(&data_type));
+                                }
+                                self.data_types.resolve(node_id, Some(data_type));
+                                self.data_type_labels = self.data_types.labels(&self.node_names);
+                            }
+                            self.node_attributes = Some(attributes);
                         }
+                        Err(e) => tracing::debug!("Attribute read failed: {}", e),
                     }
                 }
-                BackendMessage::DiagnosticStep(step) => {
-                    self.connection_panel.add_diagnostic_step(step);
+                BackendMessage::ReadResult(node_id, value) => {
+                    if let Some(read) = self.value_reads.get_mut(&node_id) {
+                        *read = ValueRead::Done(value);
+                    }
                 }
-                BackendMessage::DiagnosticComplete(result) => {
-                    self.connection_panel.set_diagnostic_result(result);
-                    // Clear the active task since diagnostic is done
-                    if let Some(task) = &self.active_task {
-                        if task.name == i18n::t(T::Diagnose, self.current_lang) {
-                            self.active_task = None;
-                            self.status = AppStatus::Idle;
+                BackendMessage::MethodArgumentsRead(node_id, result) => {
+                    let read = match result {
+                        Ok((inputs, outputs)) => {
+                            let custom_types: Vec<NodeId> = inputs.iter().chain(&outputs)
+                                .map(|argument| argument.data_type.clone())
+                                .filter(|data_type| crate::opcua::data_types::builtin_type_name(data_type).is_none())
+                                .collect();
+                            self.resolve_node_names(&custom_types);
+                            MethodArgumentsRead::Done { inputs, outputs }
+                        }
+                        Err(e) => MethodArgumentsRead::Failed(e),
+                    };
+                    if let Some(entry) = self.method_arguments.get_mut(&node_id) {
+                        *entry = read;
+                    }
+                }
+                BackendMessage::HistoryRead(node_id, display_name, result) => match result {
+                    Ok(points) if points.is_empty() => {
+                        self.status_message = i18n::t_fmt(T::HistoryEmpty, self.current_lang, &[&display_name]);
+                    }
+                    Ok(points) => {
+                        self.status_message = i18n::t_fmt(T::HistoryLoaded, self.current_lang, &[&points.len().to_string(), &display_name]);
+                        let mut series = DetachedSeries::from_history(node_id.clone(), display_name, points);
+                        if let Some(item) = self.subscription_manager.monitored_items.get(&node_id) {
+                            series.unit = item.unit.clone();
+                            if let Some(color) = item.trend_color {
+                                series.color = color;
+                            }
+                        }
+                        self.trending_panel.add_history(series);
+                        self.show_trending = true;
+                    }
+                    Err(e) => {
+                        self.status_message.clear();
+                        self.error_panel.add_error_with_details(
+                            i18n::t_fmt(T::HistoryReadFailed, self.current_lang, &[&display_name]),
+                            e,
+                            ErrorSeverity::Warning,
+                        );
+                    }
+                },
+                BackendMessage::EngineeringUnitsRead(units) => {
+                    for (node_id, unit) in units {
+                        if let Some(item) = self.subscription_manager.monitored_items.get_mut(&node_id) {
+                            item.unit = unit;
+                        }
+                    }
+                }
+                BackendMessage::DataTypesResolved(types) => {
+                    for (node_id, data_type) in types {
+                        self.data_types.resolve(node_id, data_type);
+                    }
+                    self.resolve_node_names(&self.data_types.custom_types());
+                    self.data_type_labels = self.data_types.labels(&self.node_names);
+                }
+                BackendMessage::RevealResult(node_id, result) => {
+                    match result {
+                        Ok((path, node)) => {
+                            self.selected_node = Some(node);
+                            self.pending_reveal = Some((path, node_id));
+                            self.goto_bar.set_error(None);
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to locate {}: {}", node_id, e);
+                            self.status_message = i18n::t_fmt(T::NavigateFailed, self.current_lang, &[&node_id.to_string()]);
+                            self.goto_bar.set_error(Some(format!("{}: {}", self.status_message, e)));
+                        }
+                    }
+                }
+                BackendMessage::QuickAddResolved(node_id, result) => {
+                    let result = result.and_then(|node| match node.node_class {
+                        NodeClass::Variable => Ok(node),
+                        _ => Err(i18n::t(T::NotAVariable, self.current_lang).to_string()),
+                    });
+                    match result {
+                        Ok(node) => self.add_to_watchlist(&node),
+                        Err(e) => {
+                            tracing::warn!("Failed to add {} to the watchlist: {}", node_id, e);
+                            self.error_panel.add_error(
+                                i18n::t_fmt(T::QuickAddFailed, self.current_lang, &[&node_id.to_string(), &e]),
+                                ErrorSeverity::Warning,
+                            );
+                        }
+                    }
+                }
+                BackendMessage::PathResolved(path, result) => {
+                    match result {
+                        Ok(node_id) => self.navigate_to_node(node_id),
+                        Err(e) => {
+                            tracing::warn!("Failed to translate path {}: {}", path, e);
+                            self.goto_bar.set_error(Some(
+                                i18n::t_fmt(T::PathNotFound, self.current_lang, &[&path]) + ": " + &e,
+                            ));
+                        }
+                    }
+                }
+                BackendMessage::EndpointFallback { used, reason, generation } => {
+                    if !self.connect_generation.is_current(generation) {
+                        continue;
+                    }
+                    tracing::warn!("Connected through fallback endpoint {} ({})", used, reason);
+                    self.error_panel.add_error_with_details(
+                        i18n::t_fmt(T::ConnectedViaFallback, self.current_lang, &[&used]),
+                        reason,
+                        ErrorSeverity::Warning,
+                    );
+                }
+                BackendMessage::ConnectFailed { error, generation } => {
+                    if !self.connect_generation.is_current(generation) {
+                        tracing::warn!("Ignoring stale connection failure (attempt {}): {}", generation, error);
+                        continue;
+                    }
+                    self.connection_panel.set_connecting(false);
+                    if let ConnectionState::Reconnecting { attempt } = self.connection_state {
+                        if !self.settings.reconnect_exhausted(attempt) {
+                            tracing::warn!("Reconnect attempt {} failed: {}", attempt, error);
+                            self.schedule_reconnect(attempt + 1);
+                            continue;
+                        }
+                        self.subscription_manager.restore_items.clear();
+                        self.restore_event_notifier = None;
+                        let error = i18n::t_fmt(T::ReconnectGaveUp, self.current_lang, &[&attempt.to_string(), &error]);
+                        self.connection_state = ConnectionState::Error(error.clone());
+                        self.status_message = error.clone();
+                        self.error_panel.add_error(error, ErrorSeverity::Error);
+                        continue;
+                    }
+                    self.connection_state = ConnectionState::Error(error.clone());
+                    self.status_message = i18n::t_fmt(T::ErrorPrefix, self.current_lang, &[&error]);
+                    self.error_panel.add_error(&error, ErrorSeverity::Error);
+                }
+                BackendMessage::Error(e) => {
+                    self.connection_state = ConnectionState::Error(e.clone());
+                    self.status_message = i18n::t_fmt(T::ErrorPrefix, self.current_lang, &[&e]);
+                    self.connection_panel.set_connecting(false);
+                    self.subscription_manager.creating_subscription = false;
+                    
+                    // Add error notification
+                    self.error_panel.add_error(&e, ErrorSeverity::Error);
+                }
+                BackendMessage::StatusMessage(msg) => {
+                    self.status_message = msg;
+                }
+                BackendMessage::DataChange(item_id, value) => {
+                    if let Some(name) = self.subscription_manager.handle_data_change(item_id, value, self.settings.ui_update_interval()) {
+                        self.error_panel.add_error(
+                            i18n::t_fmt(T::QueueOverflowWarning, self.current_lang, &[&name]),
+                            ErrorSeverity::Warning,
+                        );
+                    }
+                }
+                BackendMessage::InitialValues(values) => {
+                    self.subscription_manager.handle_initial_values(values, self.settings.ui_update_interval());
+                }
+                BackendMessage::SubscriptionCreated(id) => {
+                    self.subscription_manager.subscription_state.subscription_id = Some(id);
+                    self.subscription_manager.creating_subscription = false;
+                    self.subscription_manager.applied_config = Some(self.subscription_manager.subscription_config);
+                    
+                    // Add any pending items
+                    self.subscription_manager.spawn_add_items_task(
+                        &self.runtime,
+                        self.opcua_client.clone(),
+                        self.backend_tx.clone()
+                    );
+                }
+                BackendMessage::AdditionalSubscriptionCreated(id) => {
+                    let moved = self.subscription_manager.handle_additional_subscription_created(id);
+                    self.subscription_manager.spawn_add_items_to_subscription(
+                        id,
+                        moved,
+                        &self.runtime,
+                        self.opcua_client.clone(),
+                        self.backend_tx.clone()
+                    );
+                }
+                BackendMessage::AdditionalSubscriptionFailed(e) => {
+                    self.subscription_manager.handle_additional_subscription_failed();
+                    self.error_panel.add_error(i18n::t_fmt(T::AdditionalSubscriptionFailed, self.current_lang, &[&e]), ErrorSeverity::Error);
+                }
+                BackendMessage::SubscriptionsModified(result) => match result {
+                    Ok(config) => {
+                        self.subscription_manager.applied_config = Some(config);
+                        self.status_message = i18n::t(T::SubscriptionUpdated, self.current_lang).to_string();
+                    }
+                    Err(e) => {
+                        self.error_panel.add_error(i18n::t_fmt(T::SubscriptionUpdateFailed, self.current_lang, &[&e]), ErrorSeverity::Error);
+                    }
+                },
+                BackendMessage::MonitoredItemsAdded(outcome) => {
+                    self.apply_items_added(outcome);
+                }
+                BackendMessage::EventSubscriptionCreated(result) => self.handle_event_subscription_created(result),
+                BackendMessage::Event(event) => self.event_log.push(event),
+                BackendMessage::MonitoredItemModified(node_id, result) => match result {
+                    Ok(modified) => {
+                        if modified.filter_rejected {
+                            self.warn_filter_rejected(&node_id);
                         }
+                        self.subscription_manager.handle_monitored_item_modified(&node_id, modified);
+                    }
+                    Err(e) => {
+                        self.error_panel.add_error(i18n::t_fmt(T::MonitoredItemModifyFailed, self.current_lang, &[&node_id.to_string(), &e]), ErrorSeverity::Error);
+                    }
+                },
+                BackendMessage::CrawlResultChunk(nodes) => {
+                    crawl_nodes_this_frame += self.crawler_panel.append_chunk(nodes);
+                }
+                BackendMessage::CrawlProgress { nodes, depth, current } => {
+                    self.crawler_panel.update_progress(crate::opcua::crawler::CrawlProgress { nodes, depth, current }, self.current_lang);
+                }
+                BackendMessage::CrawlResultDone { total, duration, truncated, cancelled, conflicts } => {
+                    self.crawler_panel.finish(total, duration, truncated, cancelled, conflicts, self.current_lang);
+                }
+                BackendMessage::CrawlFailed(e) => {
+                    self.crawler_panel.fail(&e, self.current_lang);
+                }
+                BackendMessage::CrawlNamespaces(namespaces) => {
+                    self.crawler_panel.namespaces = namespaces;
+                }
+                BackendMessage::CrawlStreamFinished(result) => match result {
+                    Ok((path, count)) => {
+                        audit!(self, Export, "crawl JSON stream: {}", path.display());
+                        self.status_message = i18n::t_fmt(T::CrawlStreamed, self.current_lang, &[&count.to_string(), &path.display().to_string()]);
+                    }
+                    Err(e) => {
+                        self.error_panel.add_error(i18n::t_fmt(T::CrawlStreamFailed, self.current_lang, &[&e]), ErrorSeverity::Error);
+                    }
+                },
+                BackendMessage::TagValidationResult(result) => {
+                    self.tag_validation_panel.set_result(result);
+                }
+                BackendMessage::ServerProfileExported(result) => match result {
+                    Ok(path) => {
+                        audit!(self, Export, "server profile: {}", path.display());
+                        self.status_message = i18n::t_fmt(T::ServerProfileExported, self.current_lang, &[&path.display().to_string()]);
+                    }
+                    Err(e) => {
+                        self.error_panel.add_error(format!("{}: {}", i18n::t(T::ExportServerProfile, self.current_lang), e), ErrorSeverity::Error);
+                    }
+                },
+                BackendMessage::NodeDetailsExported(result) => match result {
+                    Ok(path) => {
+                        audit!(self, Export, "node details: {}", path.display());
+                        self.status_message = i18n::t_fmt(T::NodeDetailsExported, self.current_lang, &[&path.display().to_string()]);
+                    }
+                    Err(e) => {
+                        self.error_panel.add_error(format!("{}: {}", i18n::t(T::ExportNodeDetails, self.current_lang), e), ErrorSeverity::Error);
+                    }
+                },
+                BackendMessage::ServerDiagnosticsRead(result) => {
+                    self.server_diagnostics_panel.set_result(result);
+                }
+                BackendMessage::ReachabilitySample(sample) => {
+                    self.reachability_panel.record(sample);
+                }
+                BackendMessage::DiagnosticStep(step) => {
+                    self.connection_panel.add_diagnostic_step(step);
+                }
+                BackendMessage::DiagnosticComplete(result) => {
+                    self.connection_panel.set_diagnostic_result(result);
+                    // Clear the active task since diagnostic is done
+                    if self.tasks.active().is_some_and(|task| task.id == DIAGNOSE_TASK) {
+                        self.tasks.take_active();
+                        self.status = self.tasks.status();
                     }
                 }
             }
         }
 
+        self.subscription_manager.flush_pending_updates(self.settings.ui_update_interval());
+
         // Check if active task has finished naturally or panicked
-        if let Some(task) = &self.active_task {
-            if task.handle.is_finished() {
-                // If it finished but we didn't get a specific success/fail message affecting state,
-                
-                self.connection_panel.set_connecting(false);
-                
-                self.active_task = None;
-                self.status = AppStatus::Idle;
-            }
+        if self.tasks.reap_finished() {
+            // If it finished but we didn't get a specific success/fail message affecting state,
+            
+            self.connection_panel.set_connecting(false);
         }
+        self.status = self.tasks.status();
 
         
         if self.last_connection_check.elapsed().as_secs() >= 2 {
             self.last_connection_check = std::time::Instant::now();
             self.check_connection_health();
         }
+        self.poll_reconnect();
+    }
+
+    
+    fn schedule_reconnect(&mut self, attempt: u32) {
+        let delay = reconnect_delay(attempt);
+        self.connection_state = ConnectionState::Reconnecting { attempt };
+        self.reconnect_at = Some(std::time::Instant::now() + delay);
+        self.status_message = i18n::t_fmt(
+            T::ReconnectScheduled,
+            self.current_lang,
+            &[&delay.as_secs().to_string(), &attempt.to_string(), &self.settings.reconnect_attempts_label()],
+        );
+        tracing::info!("Reconnecting in {:?} (attempt {}/{})", delay, attempt, self.settings.reconnect_attempts_label());
+    }
+
+    
+    fn poll_reconnect(&mut self) {
+        if !self.reconnect_at.is_some_and(|at| at <= std::time::Instant::now()) || self.is_connecting() {
+            return;
+        }
+        self.reconnect_at = None;
+        if !matches!(self.connection_state, ConnectionState::Reconnecting { .. }) {
+            return;
+        }
+        if let Some(config) = self.last_connect_config.clone() {
+            self.connect(config);
+        }
+    }
+
+    
+    fn stop_reconnecting(&mut self) {
+        self.reconnect_at = None;
+        self.subscription_manager.restore_items.clear();
+        self.restore_event_notifier = None;
+        if matches!(self.connection_state, ConnectionState::Reconnecting { .. }) {
+            let endpoint = self.last_connect_config.as_ref().map(|config| config.endpoint_url.clone()).unwrap_or_default();
+            self.connection_state = ConnectionState::Lost { endpoint, connected_for: None };
+        }
+    }
+
+    
+    fn restore_watchlist(&mut self) {
+        let items = self.subscription_manager.take_restore_items();
+        if items.is_empty() {
+            return;
+        }
+        let nodes: Vec<BrowsedNode> = items.into_iter()
+            .map(|(node_id, display_name)| watchlist_node(node_id, display_name))
+            .collect();
+        self.add_many_to_watchlist(&nodes);
+        self.status_message = i18n::t_fmt(T::WatchlistRestored, self.current_lang, &[&nodes.len().to_string()]);
     }
 
     
     fn check_connection_health(&mut self) {
+        if self.is_simulating() {
+            return;
+        }
         if let ConnectionState::Connected { .. } = &self.connection_state {
             let client_handle = self.opcua_client.clone();
             let tx = self.backend_tx.clone();
@@ -390,150 +1255,1262 @@ impl DiagnosticApp {
                 if let Some(client) = guard.as_ref() {
                     if !client.is_connected() {
                         
-                        let _ = tx.send(BackendMessage::SessionClosed);
+                        let _ = tx.send(BackendMessage::SessionClosed { user_initiated: false });
                     }
                 } else {
                     
-                    let _ = tx.send(BackendMessage::SessionClosed);
+                    let _ = tx.send(BackendMessage::SessionClosed { user_initiated: false });
                 }
             });
         }
     }
 
     
-    pub fn set_busy(&mut self, task_name: &str, handle: tokio::task::JoinHandle<()>, cancel_token: tokio_util::sync::CancellationToken) {
-        self.status = AppStatus::Busy {
-            task_name: task_name.to_string(),
-            start_time: std::time::Instant::now(),
+    pub fn set_busy(&mut self, task_id: &'static str, task_name: &str, handle: tokio::task::JoinHandle<()>, cancel_token: tokio_util::sync::CancellationToken) {
+        self.tasks.start(ActiveTask::new(task_id, task_name, handle, cancel_token));
+        self.status = self.tasks.status();
+    }
+
+    
+    pub fn set_busy_simple(&mut self, task_id: &'static str, task_name: &str, handle: tokio::task::JoinHandle<()>) {
+        let cancel_token = tokio_util::sync::CancellationToken::new();
+        self.set_busy(task_id, task_name, handle, cancel_token);
+    }
+
+    
+    pub fn cancel_task(&mut self) {
+        let Some(task) = self.tasks.take_active() else {
+            self.stop_crawl();
+            return;
+        };
+        if task.id == CONNECT_TASK {
+            self.connect_generation.advance();
+        }
+        
+        task.cancel_token.cancel();
+        task.handle.abort();
+        self.stop_reconnecting();
+        self.status = self.tasks.status();
+        self.status_message = i18n::t_fmt(T::TaskCancelled, self.current_lang, &[&task.name]);
+        
+        self.connection_panel.reset_diagnostic();
+        self.connection_panel.set_connecting(false);
+    }
+
+    
+    fn stop_crawl(&mut self) {
+        if let Some(task) = self.tasks.stop_crawl() {
+            self.status = self.tasks.status();
+            self.status_message = i18n::t_fmt(T::TaskCancelled, self.current_lang, &[&task.name]);
+        }
+    }
+
+    
+    #[allow(dead_code)]
+    pub fn runtime(&self) -> &Handle {
+        &self.runtime
+    }
+
+    
+    #[allow(dead_code)]
+    pub fn backend_sender(&self) -> mpsc::Sender<BackendMessage> {
+        self.backend_tx.clone()
+    }
+
+    
+    #[allow(dead_code)]
+    pub fn opcua_client(&self) -> Arc<RwLock<Option<OpcUaClient>>> {
+        self.opcua_client.clone()
+    }
+
+    
+    pub fn is_connected(&self) -> bool {
+        matches!(self.connection_state, ConnectionState::Connected { .. })
+    }
+
+    
+    pub fn is_simulating(&self) -> bool {
+        self.simulation.is_some()
+    }
+
+    
+    pub fn start_simulation(&mut self) {
+        if self.is_connected() || self.is_connecting() {
+            return;
+        }
+
+        self.stop_reconnecting();
+        let space = DemoAddressSpace::build();
+        self.subscription_manager.clear();
+        self.subscription_manager.subscription_state.subscription_id = Some(simulation::DEMO_SUBSCRIPTION_ID);
+        self.root_nodes = space.root_nodes.clone();
+        self.node_cache = space.node_cache.clone();
+        self.selected_node = None;
+        self.active_bookmark = None;
+
+        let cancel = tokio_util::sync::CancellationToken::new();
+        self.runtime.spawn(simulation::run_simulation(
+            space.variables.clone(),
+            self.backend_tx.clone(),
+            cancel.clone(),
+        ));
+        let variables: Vec<NodeId> = space.variables.iter().map(|v| v.node_id.clone()).collect();
+        self.simulation = Some(Simulation { space, cancel, started: std::time::Instant::now() });
+        self.clear_data_types();
+        self.resolve_data_types(&variables);
+
+        self.connection_state = ConnectionState::Connected { endpoint: simulation::DEMO_ENDPOINT.to_string() };
+        self.status_message = i18n::t(T::DemoModeActive, self.current_lang).to_string();
+        if !self.settings.pin_connection_panel {
+            self.show_connection_panel = false;
+        }
+        tracing::info!("Demo simulation started");
+    }
+
+    
+    fn report_endpoint_mismatch(&mut self, check: &EndpointCheck) {
+        let mismatches = check.mismatches();
+        if mismatches.is_empty() {
+            return;
+        }
+        let fields: Vec<&str> = mismatches.iter()
+            .map(|mismatch| match mismatch {
+                EndpointMismatch::Url => i18n::t(T::EndpointUrlField, self.current_lang),
+                EndpointMismatch::SecurityPolicy => i18n::t(T::SecurityPolicyField, self.current_lang),
+                EndpointMismatch::SecurityMode => i18n::t(T::SecurityModeField, self.current_lang),
+            })
+            .collect();
+        let details = format!(
+            "{}: {} [{}]\n{}: {} [{}]",
+            i18n::t(T::RequestedEndpoint, self.current_lang),
+            check.requested.endpoint_url,
+            check.requested.security_label(),
+            i18n::t(T::NegotiatedEndpoint, self.current_lang),
+            check.negotiated.endpoint_url,
+            check.negotiated.security_label(),
+        );
+        tracing::warn!("Negotiated endpoint differs from the requested one: {}", details.replace('\n', "; "));
+        self.error_panel.add_error_with_details(
+            i18n::t_fmt(T::EndpointMismatch, self.current_lang, &[&fields.join(", ")]),
+            details,
+            ErrorSeverity::Warning,
+        );
+    }
+
+    
+    pub fn stop_simulation(&mut self) {
+        if let Some(sim) = self.simulation.take() {
+            sim.cancel.cancel();
+            self.root_nodes.clear();
+            self.node_cache.clear();
+            self.browse_errors.clear();
+            self.selected_node = None;
+            self.subscription_manager.clear();
+            self.clear_data_types();
+            self.value_reads.clear();
+            self.method_arguments.clear();
+            self.crawler_panel.results.clear();
+            self.confirm_discard_detached = !self.trending_panel.detached_series().is_empty();
+            self.connection_state = ConnectionState::Disconnected;
+            self.status_message = i18n::t(T::DemoStopped, self.current_lang).to_string();
+            self.show_connection_panel = true;
+        }
+    }
+
+    
+    pub fn is_connecting(&self) -> bool {
+        self.connection_panel.is_connecting()
+            || self.tasks.active().is_some_and(|task| task.id == CONNECT_TASK)
+    }
+
+    
+    pub fn connect(&mut self, config: ClientConfig) {
+        if self.is_connecting() {
+            tracing::warn!("Connect requested while a connection attempt is already running");
+            return;
+        }
+        self.stop_simulation();
+        if let Err(e) = crate::network::precheck::parse_endpoint_url(&config.endpoint_url) {
+            self.status_message = format!("{}: {}", i18n::t(T::ConnectionError, self.current_lang), e);
+            self.connection_state = ConnectionState::Error(e);
+            return;
+        }
+        self.last_connect_config = Some(config.clone());
+        self.active_bookmark = self.connection_panel.loaded_bookmark()
+            .filter(|&idx| self.bookmarks.servers.get(idx).is_some_and(|b| b.endpoint_url == config.endpoint_url));
+        self.status_message = i18n::t(T::Connecting, self.current_lang).to_string();
+        self.connection_panel.set_connecting(true);
+        
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let endpoint = config.endpoint_url.clone();
+        let generations = self.connect_generation.clone();
+        let generation = generations.advance();
+        let lang = self.current_lang;
+        let fallbacks = if self.settings.endpoint_fallback {
+            self.connection_panel.fallback_configs(&config)
+        } else {
+            Vec::new()
+        };
+        let requested = EndpointSelection::from_config(&config);
+
+        let handle = self.runtime.spawn(async move {
+            let _ = tx.send(BackendMessage::StatusMessage(i18n::t(T::EstablishingConnection, lang).to_string()));
+
+            let mut outcome = Err(String::new());
+            for (index, attempt) in std::iter::once(config).chain(fallbacks).enumerate() {
+                if !generations.is_current(generation) {
+                    break;
+                }
+                let label = format!("{} [{} / {:?}]", attempt.endpoint_url, attempt.security_policy_string(), attempt.security_mode);
+                if index > 0 {
+                    let _ = tx.send(BackendMessage::StatusMessage(
+                        i18n::t_fmt(T::TryingFallbackEndpoint, lang, &[&label]),
+                    ));
+                }
+                match OpcUaClient::connect(attempt).await {
+                    Ok(client) => {
+                        if index > 0 {
+                            let reason = outcome.err().unwrap_or_default();
+                            let _ = tx.send(BackendMessage::EndpointFallback { used: label, reason, generation });
+                        }
+                        outcome = Ok(client);
+                        break;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Connection to {} failed: {}", label, e);
+                        if index == 0 {
+                            outcome = Err(e.to_string());
+                        }
+                    }
+                }
+            }
+
+            match outcome {
+                Ok(client) => {
+                    let stats = client.stats();
+                    let timings = client.service_timings();
+                    let endpoint_check = EndpointCheck {
+                        requested,
+                        negotiated: EndpointSelection::from_description(&client.negotiated_endpoint()),
+                    };
+                    {
+                        let mut guard = client_handle.write().await;
+                        if generations.is_current(generation) {
+                            if let Some(previous) = guard.replace(client) {
+                                previous.disconnect().await;
+                            }
+                        } else {
+                            drop(guard);
+                            tracing::warn!("Connection attempt {} superseded, closing its session", generation);
+                            client.disconnect().await;
+                        }
+                    }
+                    let _ = tx.send(BackendMessage::SessionEstablished { endpoint, generation, stats, timings, endpoint_check });
+                }
+                Err(e) => {
+                    let _ = tx.send(BackendMessage::ConnectFailed { error: i18n::t_fmt(T::ConnectionFailed, lang, &[&e]), generation });
+                }
+            }
+        });
+
+        self.set_busy_simple(CONNECT_TASK, i18n::t(T::Connecting, self.current_lang), handle);
+    }
+
+    
+    pub fn disconnect(&mut self) {
+        if self.is_simulating() {
+            self.stop_simulation();
+            return;
+        }
+        self.connect_generation.advance();
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+
+        self.runtime.spawn(async move {
+            let mut guard = client_handle.write().await;
+            if let Some(client) = guard.take() {
+                client.disconnect().await;
+            }
+            let _ = tx.send(BackendMessage::SessionClosed { user_initiated: true });
+        });
+    }
+
+    
+    fn browse_node(&mut self, node_id: NodeId) {
+        if self.is_simulating() {
+            return;
+        }
+        audit!(self, Browse, "{}", node_id);
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let request_id = node_id.clone();
+        let view = self.selected_view.clone();
+
+        let handle = self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            if let Some(client) = guard.as_ref() {
+                let session = client.session();
+                match crate::opcua::browser::browse_node_with_retry(session, &node_id, view.as_ref()).await {
+                    Ok(nodes) => {
+                        let _ = tx.send(BackendMessage::BrowseResult(request_id, Ok(nodes)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(BackendMessage::BrowseResult(request_id, Err(BrowseFailure::from_error(&e))));
+                    }
+                }
+            }
+        });
+
+        self.set_busy_simple(BROWSE_TASK, i18n::t(T::Properties, self.current_lang), handle);
+    }
+
+    
+    fn tree_root(&self) -> NodeId {
+        self.selected_view
+            .clone()
+            .unwrap_or_else(|| NodeId::from(opcua::types::ObjectId::RootFolder))
+    }
+
+    
+    fn load_server_views(&mut self) {
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            if let Some(client) = guard.as_ref() {
+                match crate::opcua::browser::list_views(client.session()).await {
+                    Ok(views) => {
+                        let _ = tx.send(BackendMessage::ViewsListed(views));
+                    }
+                    Err(e) => {
+                        tracing::debug!("Could not list server views: {}", e);
+                    }
+                }
+            }
+        });
+    }
+
+    
+    pub fn select_view(&mut self, view: Option<NodeId>) {
+        self.selected_view = view;
+        self.root_nodes.clear();
+        self.node_cache.clear();
+        self.browse_errors.clear();
+        self.selected_node = None;
+        self.pending_reveal = None;
+        self.browse_node(self.tree_root());
+    }
+
+    
+    pub fn resolve_node_names(&mut self, node_ids: &[NodeId]) {
+        let unknown = self.node_names.take_unknown(node_ids);
+        if unknown.is_empty() {
+            return;
+        }
+        if self.is_simulating() {
+            let _ = self.backend_tx.send(BackendMessage::NodeNamesResolved(
+                unknown.into_iter().map(|id| (id, None)).collect(),
+            ));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let names = match guard.as_ref() {
+                Some(client) => crate::opcua::browser::read_display_names(client.session(), &unknown)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::debug!("Name lookup failed: {}", e);
+                        vec![None; unknown.len()]
+                    }),
+                None => vec![None; unknown.len()],
+            };
+            let _ = tx.send(BackendMessage::NodeNamesResolved(unknown.into_iter().zip(names).collect()));
+        });
+    }
+
+    
+    pub fn resolve_data_types(&mut self, node_ids: &[NodeId]) {
+        let unknown = self.data_types.take_unknown(node_ids);
+        if unknown.is_empty() {
+            return;
+        }
+        if let Some(sim) = &self.simulation {
+            let _ = self.backend_tx.send(BackendMessage::DataTypesResolved(
+                unknown.into_iter().map(|id| { let data_type = sim.space.data_type_of(&id); (id, data_type) }).collect(),
+            ));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let types = match guard.as_ref() {
+                Some(client) => crate::opcua::data_types::read_data_types(client.session(), &unknown)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::debug!("DataType lookup failed: {}", e);
+                        vec![None; unknown.len()]
+                    }),
+                None => vec![None; unknown.len()],
+            };
+            let _ = tx.send(BackendMessage::DataTypesResolved(unknown.into_iter().zip(types).collect()));
+        });
+    }
+
+    
+    fn read_engineering_units(&mut self, node_ids: Vec<NodeId>) {
+        if node_ids.is_empty() || self.is_simulating() {
+            return;
+        }
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let Some(client) = guard.as_ref() else { return };
+            match crate::opcua::engineering_units::read_engineering_units(client.session(), &node_ids).await {
+                Ok(units) => {
+                    let _ = tx.send(BackendMessage::EngineeringUnitsRead(node_ids.into_iter().zip(units).collect()));
+                }
+                Err(e) => tracing::debug!("EngineeringUnits lookup failed: {}", e),
+            }
+        });
+    }
+
+    
+    fn poll_node_attributes(&mut self, ctx: &egui::Context) {
+        let now = std::time::Instant::now();
+        let selected = self.selected_node.as_ref().map(|node| &node.node_id);
+        if self.attribute_fetcher.observe_selection(selected, now) {
+            self.node_attributes = None;
+        }
+        if let Some(remaining) = self.attribute_fetcher.remaining(now) {
+            ctx.request_repaint_after(remaining);
+        }
+        let Some((generation, node_id)) = self.attribute_fetcher.poll(now) else { return };
+        if let Some(sim) = &self.simulation {
+            let attributes = NodeAttributes { data_type: sim.space.data_type_of(&node_id), ..Default::default() };
+            let _ = self.backend_tx.send(BackendMessage::NodeAttributesRead(generation, Ok(attributes)));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let result = match guard.as_ref() {
+                Some(client) => client.read_node_attributes(&node_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::NodeAttributesRead(generation, result));
+        });
+    }
+
+    
+    fn poll_value_read(&mut self) {
+        let Some(node) = self.selected_node.as_ref().filter(|node| node.node_class == NodeClass::Variable) else { return };
+        if !self.is_connected()
+            || self.value_reads.contains_key(&node.node_id)
+            || self.subscription_manager.monitored_items.contains_key(&node.node_id)
+        {
+            return;
+        }
+        self.read_node_value(node.node_id.clone());
+    }
+
+    
+    fn read_node_value(&mut self, node_id: NodeId) {
+        self.value_reads.insert(node_id.clone(), ValueRead::Pending);
+        if let Some(sim) = &self.simulation {
+            let value = sim.space.read_value(&node_id, sim.started.elapsed().as_secs_f64())
+                .unwrap_or_else(|| status_only(StatusCode::BadAttributeIdInvalid));
+            let _ = self.backend_tx.send(BackendMessage::ReadResult(node_id, value));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let value = match guard.as_ref() {
+                Some(client) => client.read_node_value(&node_id).await.unwrap_or_else(|e| {
+                    tracing::debug!("Value read of {} failed: {:#}", node_id, e);
+                    status_only(e.downcast_ref::<StatusCode>().copied().unwrap_or(StatusCode::BadCommunicationError))
+                }),
+                None => status_only(StatusCode::BadNotConnected),
+            };
+            let _ = tx.send(BackendMessage::ReadResult(node_id, value));
+        });
+    }
+
+    
+    fn poll_method_arguments(&mut self) {
+        let Some(node) = self.selected_node.as_ref().filter(|node| node.node_class == NodeClass::Method) else { return };
+        if !self.is_connected() || self.method_arguments.contains_key(&node.node_id) {
+            return;
+        }
+        let node_id = node.node_id.clone();
+        self.method_arguments.insert(node_id.clone(), MethodArgumentsRead::Pending);
+        if self.is_simulating() {
+            let _ = self.backend_tx.send(BackendMessage::MethodArgumentsRead(node_id, Ok((Vec::new(), Vec::new()))));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let result = match guard.as_ref() {
+                Some(client) => crate::opcua::browser::read_method_arguments(client.session(), &node_id)
+                    .await
+                    .map_err(|e| format!("{:#}", e)),
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::MethodArgumentsRead(node_id, result));
+        });
+    }
+
+    
+    fn read_history(&mut self, request: HistoryRequest) {
+        let HistoryRequest { node, start, end, max_values } = request;
+        self.status_message = i18n::t_fmt(T::ReadingHistory, self.current_lang, &[&node.display_name]);
+        if let Some(sim) = &self.simulation {
+            let started = chrono::Utc::now().timestamp_millis() as f64 / 1000.0 - sim.started.elapsed().as_secs_f64();
+            let from = start.timestamp_millis() as f64 / 1000.0;
+            let to = end.timestamp_millis() as f64 / 1000.0;
+            let samples = ((to - from) as usize).min(max_values as usize).max(1);
+            let points = sim.space.read_history(&node.node_id, from - started, to - started, samples)
+                .map(|points| points.into_iter().map(|(t, value)| (t + started, value)).collect())
+                .unwrap_or_default();
+            let _ = self.backend_tx.send(BackendMessage::HistoryRead(node.node_id, node.display_name, Ok(points)));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let result = match guard.as_ref() {
+                Some(client) => client.read_raw_history(&node.node_id, start, end, max_values)
+                    .await
+                    .map_err(|e| format!("{:#}", e)),
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::HistoryRead(node.node_id, node.display_name, result));
+        });
+    }
+
+    fn open_side_tabs(&self) -> Vec<SideTab> {
+        SideTab::ALL
+            .into_iter()
+            .filter(|tab| match tab {
+                SideTab::Properties => self.is_connected(),
+                SideTab::Crawler => self.show_crawler,
+                SideTab::Certificates => self.show_certificates,
+                SideTab::Errors => self.show_errors,
+            })
+            .collect()
+    }
+
+    fn side_tab_label(&self, tab: SideTab) -> String {
+        let (icon, key) = match tab {
+            SideTab::Properties => ("ℹ", T::Properties),
+            SideTab::Crawler => ("🕷", T::Crawler),
+            SideTab::Certificates => ("🔐", T::Certificates),
+            SideTab::Errors => ("⚠", T::ErrorPanel),
+        };
+        format!("{} {}", icon, i18n::t(key, self.current_lang))
+    }
+
+    fn show_properties_contents(&mut self, ui: &mut egui::Ui) -> Option<crate::ui::properties::PropertiesAction> {
+        let monitored_data = self.selected_node.as_ref()
+            .and_then(|node| self.subscription_manager.monitored_items.get(&node.node_id));
+        let type_label = self.selected_node.as_ref()
+            .and_then(|node| node.type_definition.as_ref())
+            .map(|type_def| self.node_names.label(type_def));
+        let is_favorite = self.selected_node.as_ref().is_some_and(|node| self.is_favorite(&node.node_id));
+        let data_type_label = self.selected_node.as_ref()
+            .and_then(|node| self.data_type_labels.get(&node.node_id).cloned());
+        let value_read = self.selected_node.as_ref()
+            .and_then(|node| self.value_reads.get(&node.node_id));
+        let panel = PropertiesPanel::new(
+            &self.selected_node,
+            monitored_data,
+            type_label,
+            is_favorite,
+            self.node_attributes.as_ref(),
+            data_type_label,
+            value_read,
+        );
+        let method_arguments = self.selected_node.as_ref()
+            .and_then(|node| self.method_arguments.get(&node.node_id));
+        let argument_type_labels = match method_arguments {
+            Some(MethodArgumentsRead::Done { inputs, outputs }) => inputs.iter().chain(outputs)
+                .map(|argument| {
+                    let label = crate::opcua::data_types::builtin_type_name(&argument.data_type)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| self.node_names.label(&argument.data_type));
+                    (argument.data_type.clone(), label)
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        let panel = panel.with_method_arguments(method_arguments, argument_type_labels);
+        panel.show(ui, &self.settings.timestamp_format, self.current_lang)
+    }
+
+    fn show_crawler_contents(&mut self, ui: &mut egui::Ui) -> Option<CrawlerAction> {
+        let connected = self.is_connected();
+        self.crawler_panel.show(ui, connected, self.selected_node.as_ref().map(|node| &node.node_id), self.current_lang)
+    }
+
+    fn show_certificates_contents(&mut self, ui: &mut egui::Ui) {
+        if let Some(action) = self.certificates_panel.show(ui, self.current_lang) {
+            match action {
+                CertAction::ExportList => self.export_certificate_list(),
+                action => self.certificates_panel.handle_action(&action),
+            }
+        }
+    }
+
+    
+    fn export_certificate_list(&mut self) {
+        let Some(path) = self.file_dialog(DialogKind::Export)
+            .set_file_name("certificates.csv")
+            .add_filter("CSV", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+        self.remember_directory(DialogKind::Export, &path);
+        if self.certificates_panel.export_list(&path, &self.export_context()) {
+            audit!(self, Export, "certificate list CSV: {}", path.display());
+        }
+    }
+
+    fn clear_data_types(&mut self) {
+        self.data_types.clear();
+        self.data_type_labels.clear();
+    }
+
+    
+    pub fn start_reachability_monitor(&mut self, host: String, port: u16, interval: std::time::Duration) {
+        self.stop_reachability_monitor();
+        let cancel = tokio_util::sync::CancellationToken::new();
+        self.runtime.spawn(crate::network::reachability::run_reachability_monitor(
+            host,
+            port,
+            interval,
+            self.backend_tx.clone(),
+            cancel.clone(),
+        ));
+        self.reachability_cancel = Some(cancel);
+    }
+
+    
+    pub fn stop_reachability_monitor(&mut self) {
+        if let Some(cancel) = self.reachability_cancel.take() {
+            cancel.cancel();
+        }
+    }
+
+    
+    pub fn refresh_server_diagnostics(&mut self) {
+        self.server_diagnostics_panel.begin_refresh();
+        if self.is_simulating() {
+            self.server_diagnostics_panel.set_result(Err(i18n::t(T::DemoModeActive, self.current_lang).to_string()));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let result = match guard.as_ref() {
+                Some(client) => crate::opcua::server_diagnostics::read_server_diagnostics(client.session())
+                    .await
+                    .map_err(|e| e.to_string()),
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::ServerDiagnosticsRead(result));
+        });
+    }
+
+    
+    fn save_settings(&mut self) {
+        if let Err(e) = self.settings.save() {
+            self.error_panel.add_error(i18n::t_fmt(T::SettingsSaveFailed, self.current_lang, &[&e.to_string()]), ErrorSeverity::Warning);
+        }
+    }
+
+    
+    fn current_layout(&self, ctx: &egui::Context) -> WindowLayout {
+        let saved = self.settings.layout;
+        let (inner, outer) = ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.minimized == Some(true) || viewport.maximized == Some(true) || viewport.fullscreen == Some(true) {
+                (None, None)
+            } else {
+                (viewport.inner_rect, viewport.outer_rect)
+            }
+        });
+        WindowLayout {
+            show_connection_panel: self.show_connection_panel,
+            show_watchlist: self.show_watchlist,
+            show_trending: self.show_trending,
+            show_crawler: self.show_crawler,
+            show_certificates: self.show_certificates,
+            show_errors: self.show_errors,
+            language: self.current_lang,
+            window_size: inner.map(|rect| [rect.width(), rect.height()]).or(saved.window_size),
+            window_position: outer.map(|rect| [rect.min.x, rect.min.y]).or(saved.window_position),
+        }
+    }
+
+    
+    fn track_layout(&mut self, ctx: &egui::Context) {
+        let layout = self.current_layout(ctx);
+        if layout == self.settings.layout {
+            self.pending_layout = None;
+            return;
+        }
+        let now = std::time::Instant::now();
+        match self.pending_layout {
+            Some((pending, since)) if pending == layout => {
+                let elapsed = now.duration_since(since);
+                if elapsed >= LAYOUT_SAVE_DELAY {
+                    self.settings.layout = layout;
+                    self.pending_layout = None;
+                    self.save_settings();
+                } else {
+                    ctx.request_repaint_after(LAYOUT_SAVE_DELAY - elapsed);
+                }
+            }
+            _ => {
+                self.pending_layout = Some((layout, now));
+                ctx.request_repaint_after(LAYOUT_SAVE_DELAY);
+            }
+        }
+    }
+
+    
+    fn file_dialog(&self, kind: DialogKind) -> rfd::FileDialog {
+        match self.settings.last_directory(kind) {
+            Some(dir) => rfd::FileDialog::new().set_directory(dir),
+            None => rfd::FileDialog::new(),
+        }
+    }
+
+    
+    fn remember_directory(&mut self, kind: DialogKind, path: &std::path::Path) {
+        if self.settings.remember_directory(kind, path) {
+            self.save_settings();
+        }
+    }
+
+    
+    fn renderer_description(&self) -> String {
+        match self.measured_fps {
+            Some(fps) if fps.is_finite() => format!("{} · {:.0} fps", self.renderer.summary(), fps),
+            _ => self.renderer.summary(),
+        }
+    }
+
+    fn show_renderer_banner(&mut self, ctx: &egui::Context) {
+        if self.renderer_banner_dismissed || !self.renderer.is_degraded(self.measured_fps) {
+            return;
+        }
+        let lang = self.current_lang;
+        egui::TopBottomPanel::top("renderer_banner").show(ctx, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 165, 0),
+                    format!("⚠ {}", i18n::t_fmt(T::RendererDegraded, lang, &[&self.renderer_description()])),
+                );
+                if self.renderer.suggest_mesa() {
+                    ui.label(i18n::t(T::MesaSuggestion, lang));
+                    if ui.button(format!("🌐 {}", i18n::t(T::OpenMesaDownload, lang))).clicked() {
+                        ctx.open_url(egui::OpenUrl::new_tab(crate::utils::renderer::MESA_DOWNLOAD_URL));
+                    }
+                    if ui.button(format!("📋 {}", i18n::t(T::CopyMesaInstructions, lang))).clicked() {
+                        ctx.copy_text(i18n::t_fmt(T::MesaInstructions, lang, &[crate::utils::renderer::MESA_DOWNLOAD_URL]));
+                    }
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("✖").on_hover_text(i18n::t(T::Close, lang)).clicked() {
+                        self.renderer_banner_dismissed = true;
+                    }
+                });
+            });
+        });
+    }
+
+    
+    pub fn export_server_profile(&mut self) {
+        let Some(path) = self.file_dialog(DialogKind::Export)
+            .set_file_name("server_profile.md")
+            .add_filter("Markdown", &["md"])
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        self.remember_directory(DialogKind::Export, &path);
+
+        let client_renderer = self.renderer_description();
+        if let Some(sim) = &self.simulation {
+            let profile = ServerProfile {
+                client_renderer: Some(client_renderer),
+                ..ServerProfile::simulated(sim.space.namespaces())
+            };
+            let result = crate::export::ExportEngine::export_server_profile(&profile, &path, &self.export_context())
+                .map(|_| path)
+                .map_err(|e| e.to_string());
+            let _ = self.backend_tx.send(BackendMessage::ServerProfileExported(result));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        let context = self.export_context();
+        self.runtime.spawn(async move {
+            let session = client_handle.read().await.as_ref().map(|client| client.session());
+            let result = match session {
+                Some(session) => match crate::opcua::server_profile::read_server_profile(session).await {
+                    Ok(profile) => crate::export::ExportEngine::export_server_profile(&ServerProfile { client_renderer: Some(client_renderer), ..profile }, &path, &context)
+                        .map(|_| path)
+                        .map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                },
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::ServerProfileExported(result));
+        });
+    }
+
+    
+    pub fn export_node_details(&mut self, node: BrowsedNode) {
+        let file_name: String = node.display_name
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        let Some(path) = self.file_dialog(DialogKind::Export)
+            .set_file_name(format!("node_{}.json", file_name))
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        self.remember_directory(DialogKind::Export, &path);
+
+        let monitored = self.subscription_manager.monitored_items.get(&node.node_id).cloned();
+        let data_type_name = self.data_type_labels.get(&node.node_id).cloned();
+        let context = self.export_context();
+        if self.is_simulating() {
+            let details = NodeDetails {
+                data_type_name,
+                ..NodeDetails::unreadable(&node, i18n::t(T::DemoModeActive, self.current_lang))
+            }
+            .with_monitored(monitored.as_ref());
+            let result = crate::export::ExportEngine::export_node_details(&details, &path, &context)
+                .map(|_| path)
+                .map_err(|e| e.to_string());
+            let _ = self.backend_tx.send(BackendMessage::NodeDetailsExported(result));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let session = client_handle.read().await.as_ref().map(|client| client.session());
+            let details = match session {
+                Some(session) => crate::opcua::node_details::read_node_details(session, &node).await,
+                None => NodeDetails::unreadable(&node, i18n::t(T::NotConnected, lang)),
+            };
+            let details = NodeDetails { data_type_name, ..details }.with_monitored(monitored.as_ref());
+            let result = crate::export::ExportEngine::export_node_details(&details, &path, &context)
+                .map(|_| path)
+                .map_err(|e| e.to_string());
+            let _ = tx.send(BackendMessage::NodeDetailsExported(result));
+        });
+    }
+
+    
+    pub fn validate_tag_list(&mut self) {
+        let Some(path) = self.file_dialog(DialogKind::TagList)
+            .add_filter("Tag list", &["txt", "csv"])
+            .pick_file()
+        else {
+            return;
+        };
+        self.remember_directory(DialogKind::TagList, &path);
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.error_panel.add_error(format!("{}: {}", path.display(), e), ErrorSeverity::Error);
+                return;
+            }
+        };
+
+        let import = crate::export::parse_tag_list(&content);
+        self.show_tag_validation = true;
+        self.tag_validation_panel.begin(import.invalid);
+        if self.is_simulating() {
+            self.tag_validation_panel.set_result(Err(i18n::t(T::DemoModeActive, self.current_lang).to_string()));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        let lang = self.current_lang;
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let result = match guard.as_ref() {
+                Some(client) => crate::opcua::tag_validation::validate_tags(client.session(), &import.entries)
+                    .await
+                    .map_err(|e| e.to_string()),
+                None => Err(i18n::t(T::NotConnected, lang).to_string()),
+            };
+            let _ = tx.send(BackendMessage::TagValidationResult(result));
+        });
+    }
+
+    
+    pub fn add_validated_tags_to_watchlist(&mut self) {
+        let nodes: Vec<BrowsedNode> = self.tag_validation_panel
+            .passing()
+            .map(|check| watchlist_node(check.node_id.clone(), check.name.clone()))
+            .collect();
+        self.add_many_to_watchlist(&nodes);
+        self.show_watchlist = true;
+    }
+
+    
+    pub fn request_copy_structure(&mut self, node: BrowsedNode) {
+        let outline = crate::ui::tree_view::subtree_outline(
+            &node,
+            &self.node_cache,
+            COPY_STRUCTURE_MAX_DEPTH,
+            crate::ui::tree_view::OUTLINE_MAX_BYTES,
+        );
+        if outline.missing.is_empty() || self.is_simulating() {
+            self.pending_structure_copy = Some(node);
+        } else {
+            self.copy_structure_confirm = Some((node, outline.missing.len()));
+        }
+    }
+
+    
+    fn browse_subtree_then_copy(&mut self, node: BrowsedNode) {
+        let missing = crate::ui::tree_view::subtree_outline(
+            &node,
+            &self.node_cache,
+            COPY_STRUCTURE_MAX_DEPTH,
+            crate::ui::tree_view::OUTLINE_MAX_BYTES,
+        ).missing;
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+
+        let handle = self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let Some(client) = guard.as_ref() else { return };
+            let session = client.session();
+
+            let mut queue: std::collections::VecDeque<(NodeId, usize)> = missing.into();
+            let mut browses = 0;
+            while let Some((node_id, depth)) = queue.pop_front() {
+                if browses >= COPY_STRUCTURE_MAX_BROWSES {
+                    tracing::warn!("Stopped loading subtree after {} browse calls", browses);
+                    break;
+                }
+                browses += 1;
+                match crate::opcua::browser::browse_node(session.clone(), &node_id).await {
+                    Ok(children) => {
+                        for child in children.iter().filter(|c| c.has_children && depth + 1 < COPY_STRUCTURE_MAX_DEPTH) {
+                            queue.push_back((child.node_id.clone(), depth + 1));
+                        }
+                        let _ = tx.send(BackendMessage::BrowseResult(node_id, Ok(children)));
+                    }
+                    Err(e) => tracing::warn!("Failed to browse {} while copying structure: {}", node_id, e),
+                }
+            }
+            let _ = tx.send(BackendMessage::SubtreeLoaded(node));
+        });
+
+        self.set_busy_simple(BROWSE_TASK, i18n::t(T::CopyStructure, self.current_lang), handle);
+    }
+
+    
+    fn collect_folder_variables(&mut self, folder: BrowsedNode, max_depth: usize) {
+        let tx = self.backend_tx.clone();
+        let name = folder.display_name.clone();
+        let root = folder.node_id;
+        let handle = if self.is_simulating() {
+            let cache = self.node_cache.clone();
+            self.runtime.spawn(async move {
+                let result = crate::opcua::browser::collect_variables(&root, max_depth, |node_id| {
+                    let children = cache.get(&node_id).cloned().ok_or_else(|| anyhow::anyhow!("{} is not loaded", node_id));
+                    std::future::ready(children)
+                })
+                .await;
+                let _ = tx.send(BackendMessage::FolderVariables(name, result.map_err(|e| format!("{:#}", e))));
+            })
+        } else {
+            let client_handle = self.opcua_client.clone();
+            self.runtime.spawn(async move {
+                let guard = client_handle.read().await;
+                let Some(client) = guard.as_ref() else { return };
+                let result = crate::opcua::browser::browse_variables(client.session(), &root, max_depth).await;
+                let _ = tx.send(BackendMessage::FolderVariables(name, result.map_err(|e| format!("{:#}", e))));
+            })
+        };
+        self.set_busy_simple(BROWSE_TASK, i18n::t(T::AddAllVariables, self.current_lang), handle);
+    }
+
+    
+    fn handle_folder_variables(&mut self, folder: String, result: Result<CollectedVariables, String>) {
+        let nodes = match result {
+            Ok(collected) => {
+                if collected.truncated {
+                    self.error_panel.add_error(
+                        i18n::t_fmt(T::FolderVariablesTruncated, self.current_lang, &[&folder, &FOLDER_VARIABLES_MAX_BROWSES.to_string()]),
+                        ErrorSeverity::Warning,
+                    );
+                }
+                collected.variables
+            }
+            Err(e) => {
+                self.error_panel.add_error(i18n::t_fmt(T::CollectVariablesFailed, self.current_lang, &[&folder, &e]), ErrorSeverity::Error);
+                return;
+            }
+        };
+        if nodes.is_empty() {
+            self.status_message = i18n::t_fmt(T::NoVariablesFound, self.current_lang, &[&folder]);
+            return;
+        }
+        let (_, dropped) = self.subscription_manager.within_limit(&nodes, self.settings.max_watchlist_items);
+        if dropped > 0 {
+            self.add_variables_confirm = Some(nodes);
+        } else {
+            self.add_folder_variables(nodes);
+        }
+    }
+
+    
+    fn add_folder_variables(&mut self, nodes: Vec<BrowsedNode>) {
+        let (accepted, _) = self.subscription_manager.within_limit(&nodes, self.settings.max_watchlist_items);
+        self.status_message = i18n::t_fmt(T::FolderVariablesAdded, self.current_lang, &[&accepted.len().to_string()]);
+        self.add_many_to_watchlist(&accepted);
+        self.show_watchlist = true;
+    }
+
+    
+    fn copy_structure_to_clipboard(&mut self, ctx: &egui::Context, node: &BrowsedNode) {
+        let outline = crate::ui::tree_view::subtree_outline(
+            node,
+            &self.node_cache,
+            COPY_STRUCTURE_MAX_DEPTH,
+            crate::ui::tree_view::OUTLINE_MAX_BYTES,
+        );
+        ctx.copy_text(outline.text);
+
+        let mut message = i18n::t_fmt(T::StructureCopied, self.current_lang, &[&outline.nodes.to_string()]);
+        if outline.truncated {
+            message = format!("{} {}", message, i18n::t(T::StructureTruncated, self.current_lang));
+        }
+        self.error_panel.add_error(message, ErrorSeverity::Info);
+    }
+
+    
+    fn request_screenshot(&mut self, ctx: &egui::Context, rect: Option<egui::Rect>, save: bool) {
+        let save_to = if save {
+            let file_name = format!("screenshot_{}.png", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+            match self.file_dialog(DialogKind::Screenshot).set_file_name(file_name).add_filter("PNG", &["png"]).save_file() {
+                Some(path) => {
+                    self.remember_directory(DialogKind::Screenshot, &path);
+                    Some(path)
+                }
+                None => return,
+            }
+        } else {
+            None
         };
-        self.active_task = Some(ActiveTask {
-            name: task_name.to_string(),
-            handle,
-            cancel_token,
+        ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(ScreenshotRequest { rect, save_to })));
+    }
+
+    
+    fn handle_screenshots(&mut self, ctx: &egui::Context) {
+        let shots: Vec<(Arc<egui::ColorImage>, egui::UserData)> = ctx.input(|i| {
+            i.raw.events.iter().filter_map(|event| match event {
+                egui::Event::Screenshot { image, user_data, .. } => Some((image.clone(), user_data.clone())),
+                _ => None,
+            }).collect()
         });
+        for (image, user_data) in shots {
+            let Some(request) = user_data.data.as_ref().and_then(|data| data.downcast_ref::<ScreenshotRequest>()) else {
+                continue;
+            };
+            let cropped = crate::utils::clipboard::crop_screenshot(&image, request.rect, ctx.pixels_per_point());
+            let result = match &request.save_to {
+                Some(path) => crate::utils::clipboard::save_png(&cropped, path)
+                    .map(|_| {
+                        audit!(self, Export, "screenshot: {}", path.display());
+                        i18n::t_fmt(T::ScreenshotSaved, self.current_lang, &[&path.display().to_string()])
+                    }),
+                None => crate::utils::clipboard::copy_image(&cropped)
+                    .map(|_| i18n::t(T::ScreenshotCopied, self.current_lang).to_string()),
+            };
+            match result {
+                Ok(message) => self.error_panel.add_error(message, ErrorSeverity::Info),
+                Err(e) => self.error_panel.add_error(format!("{:#}", e), ErrorSeverity::Error),
+            }
+        }
     }
 
     
-    pub fn set_busy_simple(&mut self, task_name: &str, handle: tokio::task::JoinHandle<()>) {
-        let cancel_token = tokio_util::sync::CancellationToken::new();
-        self.set_busy(task_name, handle, cancel_token);
+    fn record_audit(&mut self, event: AuditEvent, details: String) {
+        let endpoint = self.connected_endpoint().map(str::to_string);
+        self.audit_log.record(event, endpoint.as_deref(), details);
     }
 
     
-    pub fn cancel_task(&mut self) {
-        if let Some(task) = self.active_task.take() {
-            
-            task.cancel_token.cancel();
-            
-            task.handle.abort();
-            self.status = AppStatus::Idle;
-            self.status_message = i18n::t(T::TaskCancelled, self.current_lang).replace("{}", &task.name);
-            
-            self.connection_panel.reset_diagnostic();
-            self.connection_panel.set_connecting(false);
+    fn connected_endpoint(&self) -> Option<&str> {
+        match &self.connection_state {
+            ConnectionState::Connected { endpoint } => Some(endpoint),
+            _ => None,
         }
     }
 
     
-    #[allow(dead_code)]
-    pub fn runtime(&self) -> &Handle {
-        &self.runtime
+    fn is_favorite(&self, node_id: &NodeId) -> bool {
+        self.connected_endpoint()
+            .is_some_and(|endpoint| self.bookmarks.is_favorite(endpoint, &node_id.to_string()))
     }
 
     
-    #[allow(dead_code)]
-    pub fn backend_sender(&self) -> mpsc::Sender<BackendMessage> {
-        self.backend_tx.clone()
+    fn favorite_ids(&self) -> Vec<NodeId> {
+        self.connected_endpoint()
+            .map(|endpoint| self.bookmarks.favorites_for(endpoint))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|f| f.node_id.parse().ok())
+            .collect()
     }
 
     
-    #[allow(dead_code)]
-    pub fn opcua_client(&self) -> Arc<RwLock<Option<OpcUaClient>>> {
-        self.opcua_client.clone()
+    fn toggle_favorite(&mut self, node: &BrowsedNode) {
+        self.update_favorites(WatchlistEntry {
+            node_id: node.node_id.to_string(),
+            display_name: node.display_name.clone(),
+            trend_style: None,
+        });
     }
 
     
-    pub fn is_connected(&self) -> bool {
-        matches!(self.connection_state, ConnectionState::Connected { .. })
+    fn remove_favorite(&mut self, entry: WatchlistEntry) {
+        self.update_favorites(entry);
+    }
+
+    fn update_favorites(&mut self, entry: WatchlistEntry) {
+        let Some(endpoint) = self.connected_endpoint().map(str::to_string) else {
+            return;
+        };
+        let name = entry.display_name.clone();
+        let added = self.bookmarks.toggle_favorite(&endpoint, entry);
+        let key = if added { T::FavoriteAdded } else { T::FavoriteRemoved };
+        self.status_message = i18n::t_fmt(key, self.current_lang, &[&name]);
+        if let Err(e) = self.bookmarks.save() {
+            self.error_panel.add_error(e.to_string(), ErrorSeverity::Error);
+        }
     }
 
     
-    pub fn connect(&mut self, config: ClientConfig) {
-        if let Err(e) = crate::network::precheck::parse_endpoint_url(&config.endpoint_url) {
-            self.status_message = format!("{}: {}", i18n::t(T::ConnectionError, self.current_lang), e);
-            self.connection_state = ConnectionState::Error(e);
+    pub fn navigate_to_node(&mut self, node_id: NodeId) {
+        if self.is_simulating() {
+            let _ = self.backend_tx.send(BackendMessage::RevealResult(node_id, Err(i18n::t(T::NotAvailableInDemo, self.current_lang).to_string())));
             return;
         }
-        self.status_message = i18n::t(T::Connecting, self.current_lang).to_string();
-        self.connection_panel.set_connecting(true);
-        
         let tx = self.backend_tx.clone();
         let client_handle = self.opcua_client.clone();
-        let endpoint = config.endpoint_url.clone();
 
         let handle = self.runtime.spawn(async move {
-            let _ = tx.send(BackendMessage::StatusMessage(i18n::t(T::EstablishingConnection, Language::default()).to_string()));
+            let guard = client_handle.read().await;
+            let Some(client) = guard.as_ref() else { return };
+            let session = client.session();
 
-            match OpcUaClient::connect(config).await {
-                Ok(client) => {
-                    
-                    {
-                        let mut guard = client_handle.write().await;
-                        *guard = Some(client);
+            let result = async {
+                let path = crate::opcua::browser::find_path_from_root(session.clone(), &node_id).await?;
+                let mut target = None;
+                for parent in &path {
+                    let children = crate::opcua::browser::browse_node(session.clone(), parent).await?;
+                    if let Some(node) = children.iter().find(|n| n.node_id == node_id) {
+                        target = Some(node.clone());
                     }
-                    let _ = tx.send(BackendMessage::SessionEstablished { endpoint });
+                    let _ = tx.send(BackendMessage::BrowseResult(parent.clone(), Ok(children)));
                 }
-                Err(e) => {
-                    let _ = tx.send(BackendMessage::Error(format!("Connection failed: {}", e)));
-                }
-            }
+                let target = match target {
+                    Some(node) => node,
+                    None => crate::opcua::browser::read_node(session.clone(), &node_id).await?,
+                };
+                anyhow::Ok((path, target))
+            }.await;
+
+            let _ = tx.send(BackendMessage::RevealResult(node_id, result.map_err(|e| e.to_string())));
         });
 
-        self.set_busy_simple(i18n::t(T::Connecting, self.current_lang), handle);
+        self.set_busy_simple(BROWSE_TASK, i18n::t(T::Properties, self.current_lang), handle);
     }
 
     
-    pub fn disconnect(&mut self) {
-        let tx = self.backend_tx.clone();
-        let client_handle = self.opcua_client.clone();
-
-        self.runtime.spawn(async move {
-            let mut guard = client_handle.write().await;
-            if let Some(client) = guard.take() {
-                client.disconnect().await;
+    pub fn navigate_to_path(&mut self, path: String) {
+        let segments = match crate::opcua::browser::parse_browse_path(&path) {
+            Ok(segments) => segments,
+            Err(e) => {
+                self.goto_bar.set_error(Some(e.to_string()));
+                return;
             }
-            let _ = tx.send(BackendMessage::SessionClosed);
-        });
-    }
+        };
+        if self.is_simulating() {
+            let _ = self.backend_tx.send(BackendMessage::PathResolved(path, Err(i18n::t(T::NotAvailableInDemo, self.current_lang).to_string())));
+            return;
+        }
 
-    
-    fn browse_node(&mut self, node_id: NodeId) {
         let tx = self.backend_tx.clone();
         let client_handle = self.opcua_client.clone();
-        let request_id = node_id.clone();
-
         let handle = self.runtime.spawn(async move {
             let guard = client_handle.read().await;
-            if let Some(client) = guard.as_ref() {
-                let session = client.session();
-                match crate::opcua::browser::browse_node(session, &node_id).await {
-                    Ok(nodes) => {
-                        let _ = tx.send(BackendMessage::BrowseResult(request_id, Ok(nodes)));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(BackendMessage::BrowseResult(request_id, Err(e.to_string())));
-                    }
-                }
-            }
+            let Some(client) = guard.as_ref() else { return };
+            let root = NodeId::from(opcua::types::ObjectId::RootFolder);
+            let result = crate::opcua::browser::translate_path(client.session(), &root, &segments)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(BackendMessage::PathResolved(path, result));
         });
 
-        self.set_busy_simple(i18n::t(T::Properties, self.current_lang), handle);
+        self.set_busy_simple(BROWSE_TASK, i18n::t(T::Properties, self.current_lang), handle);
     }
 
     
     pub fn start_diagnostic(&mut self, input: String) {
         self.connection_panel.start_diagnostic();
-        
+        self.spawn_diagnostic(input, StepId::ValidateInput, DiagnosticResult::new());
+    }
+
+    
+    fn spawn_diagnostic(&mut self, input: String, from: StepId, previous: DiagnosticResult) {
         let tx = self.backend_tx.clone();
         let cancel_token = tokio_util::sync::CancellationToken::new();
         let cancel_token_clone = cancel_token.clone();
@@ -550,8 +2527,10 @@ impl DiagnosticApp {
         });
         
         let handle = self.runtime.spawn(async move {
-            let result = crate::network::diagnostics::run_diagnostic(
+            let result = crate::network::diagnostics::resume_diagnostic(
                 &input,
+                from,
+                previous,
                 progress_tx,
                 cancel_token_clone,
                 lang,
@@ -560,15 +2539,78 @@ impl DiagnosticApp {
             let _ = tx.send(BackendMessage::DiagnosticComplete(result));
         });
         
-        self.set_busy(i18n::t(T::Diagnose, self.current_lang), handle, cancel_token);
+        self.set_busy(DIAGNOSE_TASK, i18n::t(T::Diagnose, self.current_lang), handle, cancel_token);
+    }
+
+    
+    pub fn quick_add_to_watchlist(&mut self, node_id: NodeId) {
+        if self.subscription_manager.monitored_items.contains_key(&node_id) {
+            return;
+        }
+        if let Some(sim) = &self.simulation {
+            let node = sim.space.find_node(&node_id)
+                .ok_or_else(|| crate::opcua::status_codes::translate_status_code(opcua::types::StatusCode::BadNodeIdUnknown));
+            let _ = self.backend_tx.send(BackendMessage::QuickAddResolved(node_id, node));
+            return;
+        }
+
+        let tx = self.backend_tx.clone();
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let Some(client) = guard.as_ref() else { return };
+            let result = crate::opcua::browser::read_node(client.session(), &node_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(BackendMessage::QuickAddResolved(node_id, result));
+        });
     }
 
     
     pub fn add_to_watchlist(&mut self, node: &BrowsedNode) {
-        match self.subscription_manager.request_add_to_watchlist(node) {
+        self.add_many_to_watchlist(std::slice::from_ref(node));
+    }
+
+    
+    pub fn add_many_to_watchlist(&mut self, nodes: &[BrowsedNode]) {
+        let (nodes, dropped) = self.subscription_manager.within_limit(nodes, self.settings.max_watchlist_items);
+        if dropped > 0 {
+            self.error_panel.add_error(
+                i18n::t_fmt(T::WatchlistLimitReached, self.current_lang, &[&self.settings.max_watchlist_items.to_string(), &dropped.to_string()]),
+                ErrorSeverity::Warning,
+            );
+        }
+        if !nodes.is_empty() {
+            audit!(self, WatchlistAdd, "{}", nodes.iter().map(|node| node.node_id.to_string()).collect::<Vec<_>>().join(", "));
+        }
+        match self.subscription_manager.request_add_many_to_watchlist(&nodes) {
             SubscriptionAction::None => {}
+            SubscriptionAction::AddItems(items) if self.is_simulating() => {
+                if let Some(sim) = &self.simulation {
+                    let (known, unknown): (Vec<NodeId>, Vec<NodeId>) = items.into_iter()
+                        .partition(|id| sim.space.handle_for(id).is_some());
+                    let queue_size = self.subscription_manager.monitoring_options.queue_size;
+                    let created = known.into_iter()
+                        .filter_map(|id| sim.space.handle_for(&id).map(|handle| crate::opcua::subscription::CreatedItem {
+                            node_id: id,
+                            monitored_item_id: handle,
+                            client_handle: handle,
+                            revised_sampling_interval: simulation::TICK_INTERVAL_MS as f64,
+                            revised_queue_size: queue_size,
+                        }))
+                        .collect();
+                    let failed = unknown.into_iter().map(|id| (id, opcua::types::StatusCode::BadNodeIdUnknown)).collect();
+                    let _ = self.backend_tx.send(BackendMessage::MonitoredItemsAdded(crate::opcua::subscription::AddItemsOutcome {
+                        subscription_id: simulation::DEMO_SUBSCRIPTION_ID,
+                        created,
+                        failed,
+                        filter_rejected: Vec::new(),
+                    }));
+                }
+            }
             SubscriptionAction::CreateSubscription => {
                 self.subscription_manager.spawn_subscription_task(
+                    false,
                     &self.runtime,
                     self.opcua_client.clone(),
                     self.backend_tx.clone()
@@ -586,7 +2628,275 @@ impl DiagnosticApp {
     }
 
     
+    fn apply_items_added(&mut self, outcome: crate::opcua::subscription::AddItemsOutcome) {
+        if !self.subscription_manager.is_current_outcome(&outcome) {
+            return;
+        }
+        let node_ids: Vec<NodeId> = outcome.created.iter().map(|item| item.node_id.clone()).collect();
+        self.resolve_data_types(&node_ids);
+        let without_unit = node_ids.iter()
+            .filter(|id| self.subscription_manager.monitored_items.get(*id).is_some_and(|item| item.unit.is_none()))
+            .cloned()
+            .collect();
+        self.read_engineering_units(without_unit);
+        let mut over_limit = 0;
+        for (node_id, status) in &outcome.failed {
+            if *status == opcua::types::StatusCode::BadTooManyMonitoredItems {
+                over_limit += 1;
+                continue;
+            }
+            let name = self.subscription_manager.monitored_items.get(node_id)
+                .map(|item| item.display_name.clone())
+                .unwrap_or_else(|| node_id.to_string());
+            self.error_panel.add_error(
+                i18n::t_fmt(T::MonitoredItemFailed, self.current_lang, &[&name, &crate::opcua::status_codes::translate_status_code(*status)]),
+                ErrorSeverity::Warning,
+            );
+        }
+        if over_limit > 0 {
+            self.error_panel.add_error(
+                i18n::t_fmt(T::MonitoredItemLimitReached, self.current_lang, &[&over_limit.to_string()]),
+                ErrorSeverity::Warning,
+            );
+        }
+        for node_id in &outcome.filter_rejected {
+            self.warn_filter_rejected(node_id);
+        }
+        self.subscription_manager.handle_monitored_items_added(outcome);
+    }
+
+    
+    fn warn_filter_rejected(&mut self, node_id: &NodeId) {
+        let name = self.subscription_manager.monitored_items.get(node_id)
+            .map(|item| item.display_name.clone())
+            .unwrap_or_else(|| node_id.to_string());
+        self.error_panel.add_error(i18n::t_fmt(T::DeadbandRejected, self.current_lang, &[&name]), ErrorSeverity::Warning);
+    }
+
+    
+    fn create_additional_subscription(&mut self) {
+        if self.is_simulating() || !self.subscription_manager.request_additional_subscription() {
+            return;
+        }
+        self.subscription_manager.spawn_subscription_task(
+            true,
+            &self.runtime,
+            self.opcua_client.clone(),
+            self.backend_tx.clone()
+        );
+    }
+
+    
+    fn set_publishing_interval(&mut self, interval_ms: u64) {
+        self.subscription_manager.subscription_config.publishing_interval_ms = interval_ms;
+        if self.is_simulating() || self.subscription_manager.subscription_ids().is_empty() {
+            return;
+        }
+        self.status_message = i18n::t_fmt(T::ChangingPublishingInterval, self.current_lang, &[&interval_ms.to_string()]);
+        self.subscription_manager.spawn_modify_subscriptions_task(
+            &self.runtime,
+            self.opcua_client.clone(),
+            self.backend_tx.clone()
+        );
+    }
+
+    
+    fn subscribe_events(&mut self, notifier: NodeId) {
+        if self.is_simulating() {
+            let message = i18n::t_fmt(T::EventSubscriptionFailed, self.current_lang, &[i18n::t(T::NotAvailableInDemo, self.current_lang)]);
+            self.error_panel.add_error(message, ErrorSeverity::Warning);
+            return;
+        }
+        if self.event_notifier.is_some() {
+            return;
+        }
+        self.event_notifier = Some(notifier.clone());
+        let config = self.subscription_manager.subscription_config;
+        let client_handle = self.opcua_client.clone();
+        let tx = self.backend_tx.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            let Some(client) = guard.as_ref() else {
+                return;
+            };
+            let tx_events = tx.clone();
+            let callback = move |fields: Option<Vec<opcua::types::Variant>>, _item: &opcua::client::MonitoredItem| {
+                crate::opcua::network_activity::record_notification();
+                if let Some(fields) = fields {
+                    let event = crate::opcua::events::EventData::from_fields(&fields, opcua::types::DateTime::now());
+                    let _ = tx_events.send(BackendMessage::Event(event));
+                }
+            };
+            let result = match client.create_event_subscription(config, callback).await {
+                Ok(subscription_id) => match client.add_event_monitored_item(subscription_id, &notifier).await {
+                    Ok(_) => Ok((subscription_id, notifier)),
+                    Err(e) => {
+                        let _ = client.delete_subscription(subscription_id).await;
+                        Err(format!("{:#}", e))
+                    }
+                },
+                Err(e) => Err(format!("{:#}", e)),
+            };
+            let _ = tx.send(BackendMessage::EventSubscriptionCreated(result));
+        });
+    }
+
+    
+    fn handle_event_subscription_created(&mut self, result: Result<(u32, NodeId), String>) {
+        match result {
+            Ok((subscription_id, notifier)) if self.event_notifier.as_ref() == Some(&notifier) => {
+                self.event_subscription_id = Some(subscription_id);
+            }
+            Ok((subscription_id, _)) => self.delete_event_subscription(subscription_id),
+            Err(e) => {
+                self.event_notifier = None;
+                self.error_panel.add_error(i18n::t_fmt(T::EventSubscriptionFailed, self.current_lang, &[&e]), ErrorSeverity::Error);
+            }
+        }
+    }
+
+    
+    fn unsubscribe_events(&mut self) {
+        self.event_notifier = None;
+        if let Some(subscription_id) = self.event_subscription_id.take() {
+            self.delete_event_subscription(subscription_id);
+        }
+    }
+
+    fn delete_event_subscription(&self, subscription_id: u32) {
+        let client_handle = self.opcua_client.clone();
+        self.runtime.spawn(async move {
+            let guard = client_handle.read().await;
+            if let Some(client) = guard.as_ref() {
+                let _ = client.delete_subscription(subscription_id).await;
+            }
+        });
+    }
+
+    
+    fn set_item_parameters(&mut self, node_id: NodeId, parameters: crate::opcua::subscription::MonitoredItemConfig) {
+        if self.is_simulating() {
+            self.subscription_manager.set_item_parameters(&node_id, parameters);
+            return;
+        }
+        self.subscription_manager.spawn_modify_item_task(
+            &node_id,
+            parameters,
+            &self.runtime,
+            self.opcua_client.clone(),
+            self.backend_tx.clone()
+        );
+    }
+
+    
+    fn recreate_monitored_items(&mut self, node_ids: Vec<NodeId>) {
+        if node_ids.is_empty() || self.is_simulating() {
+            return;
+        }
+        self.subscription_manager.spawn_add_specific_items_task(
+            node_ids,
+            &self.runtime,
+            self.opcua_client.clone(),
+            self.backend_tx.clone()
+        );
+    }
+
+    
+    fn load_bookmark_watchlist(&mut self) {
+        let Some(entries) = self.active_bookmark
+            .and_then(|idx| self.bookmarks.servers.get(idx))
+            .and_then(|b| b.watchlist.clone())
+        else {
+            return;
+        };
+
+        let mut nodes = Vec::new();
+        let mut styles = Vec::new();
+        for entry in entries {
+            match entry.node_id.parse::<NodeId>() {
+                Ok(node_id) => {
+                    if let Some(style) = entry.trend_style {
+                        styles.push((node_id.clone(), style));
+                    }
+                    nodes.push(watchlist_node(node_id, entry.display_name));
+                }
+                Err(_) => {
+                    self.error_panel.add_error(
+                        i18n::t_fmt(T::MonitoredItemFailed, self.current_lang, &[&entry.display_name, &entry.node_id]),
+                        ErrorSeverity::Warning,
+                    );
+                }
+            }
+        }
+        self.add_many_to_watchlist(&nodes);
+        for (node_id, style) in styles {
+            self.change_trend_style(node_id, Some(style));
+        }
+        self.status_message = i18n::t_fmt(T::WatchlistLoadedFromBookmark, self.current_lang, &[&nodes.len().to_string()]);
+    }
+
+    
+    pub fn import_tag_list(&mut self) {
+        let Some(path) = self.file_dialog(DialogKind::TagList)
+            .add_filter("Tag list", &["txt", "csv"])
+            .pick_file()
+        else {
+            return;
+        };
+        self.remember_directory(DialogKind::TagList, &path);
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.error_panel.add_error(format!("{}: {}", path.display(), e), ErrorSeverity::Error);
+                return;
+            }
+        };
+
+        let import = crate::export::parse_tag_list(&content);
+        for (line, text) in import.invalid.iter().take(MAX_IMPORT_WARNINGS) {
+            self.error_panel.add_error(
+                i18n::t_fmt(T::InvalidTagLine, self.current_lang, &[&line.to_string(), text]),
+                ErrorSeverity::Warning,
+            );
+        }
+
+        let nodes: Vec<BrowsedNode> = import.entries.into_iter()
+            .map(|(node_id, name)| watchlist_node(node_id, name))
+            .collect();
+        self.add_many_to_watchlist(&nodes);
+        self.show_watchlist = true;
+        self.status_message = i18n::t_fmt(T::TagListImported, self.current_lang, &[&nodes.len().to_string(), &import.invalid.len().to_string()]);
+    }
+
+    
+    pub fn save_watchlist_to_bookmark(&mut self) {
+        let Some(bookmark) = self.active_bookmark.and_then(|idx| self.bookmarks.servers.get_mut(idx)) else {
+            return;
+        };
+
+        let mut entries: Vec<WatchlistEntry> = self.subscription_manager.monitored_items.values()
+            .map(|item| WatchlistEntry {
+                node_id: item.node_id.to_string(),
+                display_name: item.display_name.clone(),
+                trend_style: item.trend_style,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+        let count = entries.len();
+        bookmark.watchlist = if entries.is_empty() { None } else { Some(entries) };
+
+        match self.bookmarks.save() {
+            Ok(()) => {
+                self.status_message = i18n::t_fmt(T::WatchlistSavedToBookmark, self.current_lang, &[&count.to_string()]);
+            }
+            Err(e) => self.error_panel.add_error(e.to_string(), ErrorSeverity::Error),
+        }
+    }
+
+    
     pub fn remove_from_watchlist(&mut self, node_id: &NodeId) {
+        audit!(self, WatchlistRemove, "{}", node_id);
         self.subscription_manager.remove_from_watchlist(
             node_id,
             &self.runtime,
@@ -595,7 +2905,27 @@ impl DiagnosticApp {
     }
     
     
+    pub fn detach_from_watchlist(&mut self, node_id: &NodeId) {
+        if let Some(item) = self.subscription_manager.monitored_items.get(node_id) {
+            if self.trending_panel.detach(item) {
+                self.show_trending = true;
+            }
+        }
+        self.remove_from_watchlist(node_id);
+    }
+    
+    
     pub fn toggle_trending(&mut self, node_id: NodeId) {
+        let trended = self.subscription_manager.monitored_items.values().filter(|i| i.show_in_trend).count();
+        let adding = self.subscription_manager.monitored_items.get(&node_id).is_some_and(|i| !i.show_in_trend);
+        if adding && trended >= self.settings.max_trend_series {
+            self.error_panel.add_error(
+                i18n::t_fmt(T::TrendLimitReached, self.current_lang, &[&self.settings.max_trend_series.to_string()]),
+                ErrorSeverity::Warning,
+            );
+            return;
+        }
+
         if let Some(item) = self.subscription_manager.monitored_items.get_mut(&node_id) {
             item.show_in_trend = !item.show_in_trend;
             if item.show_in_trend {
@@ -611,91 +2941,291 @@ impl DiagnosticApp {
         }
     }
 
+    
+    pub fn change_trend_style(&mut self, node_id: NodeId, style: Option<TrendStyle>) {
+        if let Some(item) = self.subscription_manager.monitored_items.get_mut(&node_id) {
+            item.trend_style = style;
+        }
+    }
+
+    
+    pub fn auto_assign_trend_colors(&mut self) {
+        let count = crate::ui::trending::assign_distinct_colors(&mut self.subscription_manager.monitored_items);
+        tracing::info!("Assigned distinct trend colors to {} items", count);
+    }
+
 
     
-    pub fn start_crawl(&mut self, config: crate::opcua::crawler::CrawlConfig) {
+    pub fn start_crawl(&mut self, config: crate::opcua::crawler::CrawlConfig, stream_to: Option<std::path::PathBuf>) {
+         let stream = match stream_to {
+             Some(path) => match crate::export::CrawlJsonStream::create(&path, self.settings.anonymize_exports) {
+                 Ok(stream) => Some((path, stream)),
+                 Err(e) => {
+                     self.error_panel.add_error(i18n::t_fmt(T::CrawlStreamFailed, self.current_lang, &[&format!("{:#}", e)]), ErrorSeverity::Error);
+                     return;
+                 }
+             },
+             None => None,
+         };
+         let manifest_context = self.crawl_export_context(&config);
+         audit!(
+             self,
+             Crawl,
+             "start_nodes={} max_depth={} max_nodes={} include_start_node={}{}",
+             config.start_nodes_label(),
+             config.max_depth,
+             config.max_nodes,
+             config.include_start_node,
+             stream.as_ref().map(|(path, _)| format!(" stream={}", path.display())).unwrap_or_default(),
+         );
+         self.crawler_panel.begin(self.current_lang);
+         if let Some(sim) = &self.simulation {
+             let start = std::time::Instant::now();
+             self.crawler_panel.namespaces = sim.space.namespaces();
+             let nodes = sim.space.crawl(&config);
+             let total = nodes.len();
+             let truncated = total >= config.max_nodes;
+             if let Some((path, mut stream)) = stream {
+                 let result = stream.write_nodes(&nodes)
+                     .and_then(|_| stream.finish_export(&path, &manifest_context));
+                 let _ = self.backend_tx.send(BackendMessage::CrawlStreamFinished(
+                     result.map(|count| (path, count)).map_err(|e| format!("{:#}", e)),
+                 ));
+             }
+             for chunk in nodes.chunks(CRAWL_CHUNK_SIZE) {
+                 let _ = self.backend_tx.send(BackendMessage::CrawlResultChunk(chunk.to_vec()));
+             }
+             let _ = self.backend_tx.send(BackendMessage::CrawlResultDone { total, duration: start.elapsed(), truncated, cancelled: false, conflicts: Vec::new() });
+             return;
+         }
+         let (stream_path, stream) = match stream {
+             Some((path, stream)) => (Some(path), Some(Ok(stream))),
+             None => (None, None),
+         };
+         let stream = std::sync::Arc::new(std::sync::Mutex::new(stream));
          let tx = self.backend_tx.clone();
          let client_handle = self.opcua_client.clone();
+         let lang = self.current_lang;
+         let cancel = tokio_util::sync::CancellationToken::new();
+         let crawl_cancel = cancel.clone();
 
          let handle = self.runtime.spawn(async move {
              let guard = client_handle.read().await;
-             if let Some(client) = guard.as_ref() {
-                 let session = client.session();
-                 let mut crawler = crate::opcua::crawler::Crawler::new(session, config);
-                 match crawler.crawl().await {
-                     Ok(nodes) => {
-                         let _ = tx.send(BackendMessage::CrawlResult(Ok(nodes)));
-                     },
-                     Err(e) => {
-                         let _ = tx.send(BackendMessage::CrawlResult(Err(e.to_string())));
+             let Some(client) = guard.as_ref() else {
+                 let _ = tx.send(BackendMessage::CrawlFailed(i18n::t(T::NotConnected, lang).to_string()));
+                 return;
+             };
+             let session = client.session();
+             match crate::opcua::browser::read_namespace_array(session.clone()).await {
+                 Ok(namespaces) => {
+                     let _ = tx.send(BackendMessage::CrawlNamespaces(namespaces));
+                 }
+                 Err(e) => {
+                     tracing::warn!("Could not read NamespaceArray, exports will omit namespace URIs: {:#}", e);
+                 }
+             }
+             let chunk_tx = tx.clone();
+             let progress_tx = tx.clone();
+             let sink_stream = stream.clone();
+             let mut crawler = crate::opcua::crawler::Crawler::new(session, config, crawl_cancel).with_chunk_sink(
+                 CRAWL_CHUNK_SIZE,
+                 Box::new(move |chunk| {
+                     if let Ok(mut guard) = sink_stream.lock() {
+                         if let Some(Ok(stream)) = guard.as_mut() {
+                             if let Err(e) = stream.write_nodes(&chunk) {
+                                 tracing::warn!("Streaming crawl export stopped: {:#}", e);
+                                 *guard = Some(Err(format!("{:#}", e)));
+                             }
+                         }
                      }
+                     let _ = chunk_tx.send(BackendMessage::CrawlResultChunk(chunk));
+                 }),
+             ).with_progress_sink(Box::new(move |progress| {
+                 let _ = progress_tx.send(BackendMessage::CrawlProgress {
+                     nodes: progress.nodes,
+                     depth: progress.depth,
+                     current: progress.current,
+                 });
+             }));
+             let crawl_result = crawler.crawl_streaming().await;
+             let finished = stream.lock().ok().and_then(|mut guard| guard.take());
+             if let (Some(path), Some(finished)) = (stream_path, finished) {
+                 let result = finished.and_then(|stream| {
+                     stream.finish_export(&path, &manifest_context).map_err(|e| format!("{:#}", e))
+                 });
+                 let _ = tx.send(BackendMessage::CrawlStreamFinished(result.map(|count| (path, count))));
+             }
+             match crawl_result {
+                 Ok(summary) => {
+                     let _ = tx.send(BackendMessage::CrawlResultDone {
+                         total: summary.total,
+                         duration: summary.duration,
+                         truncated: summary.truncated,
+                         cancelled: summary.cancelled,
+                         conflicts: crawler.conflicts().to_vec(),
+                     });
+                 },
+                 Err(e) => {
+                     let _ = tx.send(BackendMessage::CrawlFailed(e.to_string()));
                  }
              }
          });
          
-         self.set_busy_simple("Crawling", handle);
+         self.tasks.start_crawl(ActiveTask::new(CRAWL_TASK, i18n::t(T::Crawler, self.current_lang), handle, cancel));
+         self.status = self.tasks.status();
     }
 
       
-      pub fn export_watchlist_csv(&self) {
-           if let Some(path) = rfd::FileDialog::new()
+      fn export_context(&self) -> crate::export::ExportContext {
+           crate::export::ExportContext {
+               write_manifest: self.settings.write_export_manifest,
+               anonymize: self.settings.anonymize_exports,
+               endpoint: self.connected_endpoint().map(str::to_string),
+               ..Default::default()
+           }
+      }
+
+      
+      fn watchlist_export_context(&self) -> crate::export::ExportContext {
+           let context = self.export_context()
+               .with_option("number_format", format!("{:?}", self.settings.export_number_format))
+               .with_option("timestamp_format", self.settings.export_timestamp_format.label());
+           self.monitor_panel.export_options(context)
+      }
+
+      
+      fn crawl_export_context(&self, config: &crate::opcua::crawler::CrawlConfig) -> crate::export::ExportContext {
+           self.export_context()
+           .with_option("start_node", config.start_nodes_label())
+           .with_option("max_depth", config.max_depth)
+           .with_option("max_nodes", config.max_nodes)
+           .with_option("include_start_node", config.include_start_node)
+      }
+
+      
+      pub fn export_watchlist_csv(&mut self) {
+           if let Some(path) = self.file_dialog(DialogKind::Export)
                 .set_file_name("watchlist.csv")
                 .add_filter("CSV", &["csv"])
                 .save_file() 
             {
-               let items: Vec<_> = self.subscription_manager.monitored_items.values().cloned().collect();
-               if let Err(e) = crate::export::ExportEngine::export_watchlist_to_csv(&items, &path) {
-                  eprintln!("Export failed: {}", e);
+               self.remember_directory(DialogKind::Export, &path);
+               let items = self.monitor_panel.export_items(&self.subscription_manager.monitored_items);
+               let context = self.watchlist_export_context();
+               match crate::export::ExportEngine::export_watchlist_to_csv(&items, &path, self.settings.export_number_format, &self.settings.export_timestamp_format, &context) {
+                  Ok(()) => audit!(self, Export, "watchlist CSV: {}", path.display()),
+                  Err(e) => eprintln!("Export failed: {}", e),
                }
            }
       }
 
       
-      pub fn export_watchlist_json(&self) {
-           if let Some(path) = rfd::FileDialog::new()
+      pub fn export_watchlist_json(&mut self) {
+           if let Some(path) = self.file_dialog(DialogKind::Export)
                 .set_file_name("watchlist.json")
                 .add_filter("JSON", &["json"])
                 .save_file() 
             {
-               let items: Vec<_> = self.subscription_manager.monitored_items.values().cloned().collect();
-               if let Err(e) = crate::export::ExportEngine::export_watchlist_to_json(&items, &path) {
-                  eprintln!("Export failed: {}", e);
+               self.remember_directory(DialogKind::Export, &path);
+               let items = self.monitor_panel.export_items(&self.subscription_manager.monitored_items);
+               let context = self.watchlist_export_context();
+               match crate::export::ExportEngine::export_watchlist_to_json(&items, &path, self.settings.export_number_format, &self.settings.export_timestamp_format, &context) {
+                  Ok(()) => audit!(self, Export, "watchlist JSON: {}", path.display()),
+                  Err(e) => eprintln!("Export failed: {}", e),
                }
            }
       }
 
      
-     pub fn export_crawl_json(&self) {
-          if let Some(path) = rfd::FileDialog::new()
+     pub fn export_crawl_json(&mut self) {
+          if let Some(path) = self.file_dialog(DialogKind::Export)
                 .set_file_name("crawl_result.json")
                 .add_filter("JSON", &["json"])
                 .save_file() 
           {
-              if let Err(e) = crate::export::ExportEngine::export_crawl_result_to_json(&self.crawler_panel.results, &path) {
-                 eprintln!("Export failed: {}", e);
+              self.remember_directory(DialogKind::Export, &path);
+              let context = self.crawl_export_context(&self.crawler_panel.config);
+              match crate::export::ExportEngine::export_crawl_result_to_json(&self.crawler_panel.results, &self.crawler_panel.namespaces, &path, &context) {
+                 Ok(()) => audit!(self, Export, "crawl JSON: {}", path.display()),
+                 Err(e) => eprintln!("Export failed: {}", e),
               }
           }
      }
 
      
-     pub fn export_crawl_csv(&self) {
-          if let Some(path) = rfd::FileDialog::new()
+     pub fn export_crawl_csv(&mut self) {
+          if let Some(path) = self.file_dialog(DialogKind::Export)
                 .set_file_name("crawl_result.csv")
                 .add_filter("CSV", &["csv"])
                 .save_file() 
           {
-              if let Err(e) = crate::export::ExportEngine::export_crawl_result_to_csv(&self.crawler_panel.results, &path) {
-                 eprintln!("Export failed: {}", e);
+              self.remember_directory(DialogKind::Export, &path);
+              let context = self.crawl_export_context(&self.crawler_panel.config);
+              match crate::export::ExportEngine::export_crawl_result_to_csv(&self.crawler_panel.results, &self.crawler_panel.namespaces, &path, &context) {
+                 Ok(()) => audit!(self, Export, "crawl CSV: {}", path.display()),
+                 Err(e) => eprintln!("Export failed: {}", e),
+              }
+          }
+     }
+
+     
+     pub fn export_crawl_conflicts(&mut self) {
+          if let Some(path) = self.file_dialog(DialogKind::Export)
+                .set_file_name("crawl_conflicts.csv")
+                .add_filter("CSV", &["csv"])
+                .save_file()
+          {
+              self.remember_directory(DialogKind::Export, &path);
+              match crate::export::ExportEngine::export_crawl_conflicts_to_csv(&self.crawler_panel.conflicts, &path, &self.export_context()) {
+                  Ok(()) => {
+                      audit!(self, Export, "crawl conflicts CSV: {}", path.display());
+                      self.status_message = i18n::t_fmt(
+                          T::ConflictsExported,
+                          self.current_lang,
+                          &[&self.crawler_panel.conflicts.len().to_string(), &path.display().to_string()],
+                      );
+                  }
+                  Err(e) => {
+                      self.error_panel.add_error(format!("{}: {}", i18n::t(T::ExportConflicts, self.current_lang), e), ErrorSeverity::Error);
+                  }
               }
           }
      }
 
-}
+}
+
+impl eframe::App for DiagnosticApp {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        
+        self.process_backend_messages();
+        let repairs = self.subscription_manager.take_repairs();
+        self.recreate_monitored_items(repairs);
+        self.poll_node_attributes(ctx);
+        self.poll_value_read();
+        self.poll_method_arguments();
+
+        
+        if self.frame_probe.is_running() {
+            self.measured_fps = self.frame_probe.observe(frame.info().cpu_usage, std::time::Instant::now());
+            if let Some(fps) = self.measured_fps {
+                tracing::info!("Renderer {} measured at {:.1} fps", self.renderer.summary(), fps);
+            }
+            ctx.request_repaint();
+        }
+
+        
+        if let Some(node) = self.pending_structure_copy.take() {
+            self.copy_structure_to_clipboard(ctx, &node);
+        }
 
-impl eframe::App for DiagnosticApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         
-        self.process_backend_messages();
+        self.handle_screenshots(ctx);
+        if let Some(save) = ctx.input(|i| i.key_pressed(egui::Key::F12).then_some(i.modifiers.shift)) {
+            let hovered = ctx.pointer_hover_pos()
+                .and_then(|pos| self.panel_rects.iter().copied().find(|rect| rect.contains(pos)));
+            self.request_screenshot(ctx, hovered, save);
+        }
+        self.panel_rects.clear();
 
         
         ctx.request_repaint_after(std::time::Duration::from_millis(100));
@@ -715,17 +3245,146 @@ impl eframe::App for DiagnosticApp {
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
                 ui.menu_button(i18n::t(T::File, self.current_lang), |ui| {
+                    if ui.add_enabled(self.is_connected(), egui::Button::new(format!("📥 {}", i18n::t(T::ImportTagList, self.current_lang)))).clicked() {
+                        self.import_tag_list();
+                        ui.close_menu();
+                    }
+                    if ui.add_enabled(self.is_connected(), egui::Button::new(format!("✅ {}", i18n::t(T::ValidateTagList, self.current_lang)))).clicked() {
+                        self.validate_tag_list();
+                        ui.close_menu();
+                    }
+                    if ui.add_enabled(self.is_connected(), egui::Button::new(format!("🧾 {}", i18n::t(T::ExportServerProfile, self.current_lang)))).clicked() {
+                        self.export_server_profile();
+                        ui.close_menu();
+                    }
+                    if ui.checkbox(&mut self.settings.write_export_manifest, format!("🗒 {}", i18n::t(T::WriteExportManifest, self.current_lang)))
+                        .on_hover_text(i18n::t(T::WriteExportManifestHint, self.current_lang))
+                        .changed()
+                    {
+                        self.save_settings();
+                    }
+                    if ui.checkbox(&mut self.settings.anonymize_exports, format!("🕶 {}", i18n::t(T::AnonymizeExports, self.current_lang)))
+                        .on_hover_text(i18n::t(T::AnonymizeExportsHint, self.current_lang))
+                        .changed()
+                    {
+                        self.save_settings();
+                    }
+                    if ui.add(egui::Button::new(format!("📷 {}", i18n::t(T::SaveScreenshot, self.current_lang))).shortcut_text("Shift+F12"))
+                        .on_hover_text(i18n::t(T::ScreenshotHint, self.current_lang))
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.request_screenshot(ctx, None, true);
+                    }
                     if ui.button(i18n::t(T::Exit, self.current_lang)).clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                     }
                 });
                 ui.menu_button(i18n::t(T::View, self.current_lang), |ui| {
                     ui.checkbox(&mut self.show_connection_panel, i18n::t(T::Connection, self.current_lang));
+                    if ui.checkbox(&mut self.settings.pin_connection_panel, format!("📌 {}", i18n::t(T::PinConnectionPanel, self.current_lang)))
+                        .on_hover_text(i18n::t(T::PinConnectionPanelHint, self.current_lang))
+                        .changed()
+                    {
+                        self.save_settings();
+                    }
+                    ui.menu_button(format!("⏱ {}", i18n::t(T::MaxUiUpdateRate, self.current_lang)), |ui| {
+                        let before = self.settings.max_ui_update_hz;
+                        for hz in [5, 10, 30, 60] {
+                            ui.radio_value(&mut self.settings.max_ui_update_hz, hz, format!("{} Hz", hz));
+                        }
+                        ui.radio_value(&mut self.settings.max_ui_update_hz, 0, i18n::t(T::Unlimited, self.current_lang));
+                        if self.settings.max_ui_update_hz != before {
+                            self.save_settings();
+                        }
+                    })
+                    .response
+                    .on_hover_text(i18n::t(T::MaxUiUpdateRateHint, self.current_lang));
+                    ui.menu_button(format!("〰 {}", i18n::t(T::TrendLineStyle, self.current_lang)), |ui| {
+                        if crate::ui::trending::trend_style_editor(ui, &mut self.settings.trend_style, self.current_lang) {
+                            self.save_settings();
+                        }
+                    })
+                    .response
+                    .on_hover_text(i18n::t(T::TrendLineStyleHint, self.current_lang));
+                    ui.menu_button(format!("🕒 {}", i18n::t(T::TimestampFormatLabel, self.current_lang)), |ui| {
+                        if self.timestamp_picker.show(ui, &mut self.settings.timestamp_format, self.current_lang) {
+                            self.save_settings();
+                        }
+                    });
+                    if ui.checkbox(&mut self.settings.read_initial_value_on_add, format!("⚡ {}", i18n::t(T::ReadInitialValue, self.current_lang)))
+                        .on_hover_text(i18n::t(T::ReadInitialValueHint, self.current_lang))
+                        .changed()
+                    {
+                        self.subscription_manager.read_initial_values = self.settings.read_initial_value_on_add;
+                        self.save_settings();
+                    }
+                    ui.menu_button(format!("🔄 {}", i18n::t(T::AutoReconnect, self.current_lang)), |ui| {
+                        let mut changed = ui.checkbox(&mut self.settings.auto_reconnect, i18n::t(T::AutoReconnect, self.current_lang))
+                            .on_hover_text(i18n::t(T::AutoReconnectHint, self.current_lang))
+                            .changed();
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::t(T::ReconnectMaxAttempts, self.current_lang));
+                            changed |= ui.add_enabled(
+                                self.settings.auto_reconnect,
+                                egui::DragValue::new(&mut self.settings.reconnect_max_attempts)
+                                    .range(0..=100)
+                                    .custom_formatter(|n, _| if n == 0.0 { "∞".to_string() } else { n.to_string() }),
+                            )
+                            .on_hover_text(i18n::t(T::ReconnectMaxAttemptsHint, self.current_lang))
+                            .changed();
+                        });
+                        if changed {
+                            if !self.settings.auto_reconnect {
+                                self.stop_reconnecting();
+                            }
+                            self.save_settings();
+                        }
+                    });
+                    ui.menu_button(format!("📥 {}", i18n::t(T::MonitoredItemQueue, self.current_lang)), |ui| {
+                        let before = self.settings.monitoring_options();
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::t(T::QueueSize, self.current_lang));
+                            ui.add(egui::DragValue::new(&mut self.settings.monitored_item_queue_size).range(1..=1000));
+                        });
+                        ui.checkbox(&mut self.settings.discard_oldest, i18n::t(T::DiscardOldest, self.current_lang))
+                            .on_hover_text(i18n::t(T::DiscardOldestHint, self.current_lang));
+                        ui.separator();
+                        ui.label(i18n::t(T::TimestampsToReturn, self.current_lang))
+                            .on_hover_text(i18n::t(T::TimestampsToReturnHint, self.current_lang));
+                        for mode in TimestampsMode::ALL {
+                            let label = match mode {
+                                TimestampsMode::Source => T::TimestampsSource,
+                                TimestampsMode::Server => T::TimestampsServer,
+                                TimestampsMode::Both => T::TimestampsBoth,
+                                TimestampsMode::Neither => T::TimestampsNeither,
+                            };
+                            ui.radio_value(&mut self.settings.timestamps_to_return, mode, i18n::t(label, self.current_lang));
+                        }
+                        if self.settings.monitoring_options() != before {
+                            self.subscription_manager.monitoring_options = self.settings.monitoring_options();
+                            self.save_settings();
+                        }
+                    })
+                    .response
+                    .on_hover_text(i18n::t(T::MonitoredItemQueueHint, self.current_lang));
                     ui.checkbox(&mut self.show_watchlist, i18n::t(T::Watchlist, self.current_lang));
                     ui.checkbox(&mut self.show_trending, i18n::t(T::Trend, self.current_lang));
+                    ui.checkbox(&mut self.show_events, format!("🔔 {}", i18n::t(T::Events, self.current_lang)));
                     ui.checkbox(&mut self.show_crawler, i18n::t(T::Crawler, self.current_lang));
                     ui.checkbox(&mut self.show_certificates, i18n::t(T::Certificates, self.current_lang));
+                    ui.checkbox(&mut self.show_server_diagnostics, i18n::t(T::ServerDiagnostics, self.current_lang));
+                    ui.checkbox(&mut self.show_reachability, i18n::t(T::ConnectivityMonitor, self.current_lang));
                     ui.checkbox(&mut self.show_errors, i18n::t(T::ErrorPanel, self.current_lang));
+                    ui.checkbox(&mut self.show_audit_log, format!("📜 {}", i18n::t(T::AuditLog, self.current_lang)));
+                    ui.checkbox(&mut self.show_settings, format!("⚙ {}", i18n::t(T::Settings, self.current_lang)));
+                    ui.horizontal(|ui| {
+                        ui.label(format!("🗂 {}", i18n::t(T::NarrowLayoutWidth, self.current_lang)))
+                            .on_hover_text(i18n::t(T::NarrowLayoutWidthHint, self.current_lang));
+                        if ui.add(egui::DragValue::new(&mut self.settings.narrow_layout_width).range(0.0..=4000.0).speed(10.0).suffix(" px")).changed() {
+                            self.save_settings();
+                        }
+                    });
                     
                     ui.separator();
                     ui.label("Language / Idioma");
@@ -746,6 +3405,112 @@ impl eframe::App for DiagnosticApp {
         });
 
         
+        self.show_renderer_banner(ctx);
+
+        
+        if let Some((node, missing)) = self.copy_structure_confirm.clone() {
+            egui::Window::new(i18n::t(T::CopyStructure, self.current_lang))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label(
+                        i18n::t_fmt(T::BrowseMissingLevels, self.current_lang, &[&missing.to_string(), &COPY_STRUCTURE_MAX_DEPTH.to_string()]),
+                    );
+                    ui.horizontal(|ui| {
+                        if ui.button(i18n::t(T::BrowseAndCopy, self.current_lang)).clicked() {
+                            self.copy_structure_confirm = None;
+                            self.browse_subtree_then_copy(node.clone());
+                        }
+                        if ui.button(i18n::t(T::CopyLoadedOnly, self.current_lang)).clicked() {
+                            self.copy_structure_confirm = None;
+                            self.pending_structure_copy = Some(node.clone());
+                        }
+                        if ui.button(i18n::t(T::Cancel, self.current_lang)).clicked() {
+                            self.copy_structure_confirm = None;
+                        }
+                    });
+                });
+        }
+
+        if let Some((node, recursive, depth)) = self.add_variables_prompt.as_mut() {
+            let mut start = None;
+            let mut close = false;
+            egui::Window::new(format!("📊 {}", i18n::t(T::AddAllVariables, self.current_lang)))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label(egui::RichText::new(&node.display_name).strong());
+                    ui.checkbox(recursive, i18n::t(T::IncludeSubfolders, self.current_lang));
+                    ui.horizontal(|ui| {
+                        ui.label(i18n::t(T::MaxDepth, self.current_lang));
+                        ui.add_enabled(*recursive, egui::DragValue::new(depth).range(2..=ADD_VARIABLES_MAX_DEPTH));
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button(i18n::t(T::Add, self.current_lang)).clicked() {
+                            start = Some((node.clone(), if *recursive { *depth } else { 1 }));
+                        }
+                        if ui.button(i18n::t(T::Cancel, self.current_lang)).clicked() {
+                            close = true;
+                        }
+                    });
+                });
+            if let Some((node, max_depth)) = start {
+                self.add_variables_prompt = None;
+                self.collect_folder_variables(node, max_depth);
+            } else if close {
+                self.add_variables_prompt = None;
+            }
+        }
+
+        if let Some(nodes) = &self.add_variables_confirm {
+            let found = nodes.len();
+            let (accepted, _) = self.subscription_manager.within_limit(nodes, self.settings.max_watchlist_items);
+            egui::Window::new(format!("📊 {}", i18n::t(T::AddAllVariables, self.current_lang)))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label(i18n::t_fmt(
+                        T::FolderVariablesOverLimit,
+                        self.current_lang,
+                        &[&found.to_string(), &self.settings.max_watchlist_items.to_string(), &accepted.len().to_string()],
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!accepted.is_empty(), egui::Button::new(i18n::t(T::Add, self.current_lang))).clicked() {
+                            if let Some(nodes) = self.add_variables_confirm.take() {
+                                self.add_folder_variables(nodes);
+                            }
+                        }
+                        if ui.button(i18n::t(T::Cancel, self.current_lang)).clicked() {
+                            self.add_variables_confirm = None;
+                        }
+                    });
+                });
+        }
+
+        if self.confirm_discard_detached {
+            egui::Window::new(i18n::t(T::DiscardCapturedCurves, self.current_lang))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label(
+                        i18n::t_fmt(T::DiscardCapturedCurvesPrompt, self.current_lang, &[&self.trending_panel.detached_series().len().to_string()]),
+                    );
+                    ui.horizontal(|ui| {
+                        if ui.button(i18n::t(T::Discard, self.current_lang)).clicked() {
+                            self.trending_panel.clear_detached();
+                            self.confirm_discard_detached = false;
+                        }
+                        if ui.button(i18n::t(T::Keep, self.current_lang)).clicked() {
+                            self.confirm_discard_detached = false;
+                        }
+                    });
+                });
+        }
+
         if self.show_about {
             egui::Window::new(i18n::t(T::AboutTitle, self.current_lang))
                 .collapsible(false)
@@ -761,6 +3526,11 @@ impl eframe::App for DiagnosticApp {
                         ui.label(i18n::t(T::AboutAuthor, self.current_lang));
                         ui.label(i18n::t(T::AboutCompany, self.current_lang));
                         ui.label(i18n::t(T::AboutYear, self.current_lang));
+                        ui.add_space(10.0);
+                        ui.label(format!("{}: {}", i18n::t(T::Renderer, self.current_lang), self.renderer_description()));
+                        ui.add_space(10.0);
+                        ui.label(egui::RichText::new(format!("🔒 {}", i18n::t(T::ReadOnlyMode, self.current_lang))).strong());
+                        ui.label(egui::RichText::new(i18n::t(T::ReadOnlyModeDetail, self.current_lang)).small().weak());
                         ui.add_space(20.0);
                         if ui.button(i18n::t(T::Close, self.current_lang)).clicked() {
                             self.show_about = false;
@@ -770,6 +3540,8 @@ impl eframe::App for DiagnosticApp {
         }
 
         
+        let activity = self.activity_indicator.observe(network_activity::counters(), std::time::Instant::now());
+        let blink_on = (ctx.input(|i| i.time) * 4.0) as i64 % 2 == 0;
         egui::TopBottomPanel::bottom("status_bar")
             .min_height(24.0)
             .show(ctx, |ui| {
@@ -777,21 +3549,35 @@ impl eframe::App for DiagnosticApp {
                 
                 let (color, text) = match &self.connection_state {
                     ConnectionState::Disconnected => {
-                        if matches!(self.status, AppStatus::Busy { ref task_name, .. } if task_name == i18n::t(T::Connecting, self.current_lang)) {
+                        if matches!(self.status, AppStatus::Busy { task_id: CONNECT_TASK, .. }) {
                             (egui::Color32::from_rgb(255, 255, 0), "🟡")
                         } else {
                             (egui::Color32::from_rgb(100, 100, 100), "⚫")
                         }
                     }
                     ConnectionState::Connected { .. } => (egui::Color32::from_rgb(0, 255, 0), "🟢"),
-                    ConnectionState::Error(_) => (egui::Color32::from_rgb(255, 0, 0), "🔴"),
+                    ConnectionState::Reconnecting { .. } => (egui::Color32::from_rgb(255, 255, 0), "🟡"),
+                    ConnectionState::Lost { .. } | ConnectionState::Error(_) => (egui::Color32::from_rgb(255, 0, 0), "🔴"),
                 };
                 
                 ui.label(egui::RichText::new(text).color(color));
+                let arrow = |active: bool, symbol: &str| {
+                    let color = if active && blink_on {
+                        egui::Color32::from_rgb(0, 255, 0)
+                    } else {
+                        egui::Color32::from_rgb(80, 80, 80)
+                    };
+                    egui::RichText::new(symbol).color(color).monospace()
+                };
+                ui.label(arrow(activity.outbound, "▲")).on_hover_text(i18n::t(T::OutboundRequests, self.current_lang));
+                ui.label(arrow(activity.inbound, "▼")).on_hover_text(i18n::t(T::InboundNotifications, self.current_lang));
+                ui.separator();
+                ui.label(egui::RichText::new(format!("🔒 {}", i18n::t(T::ReadOnly, self.current_lang))).small())
+                    .on_hover_text(format!("{}\n\n{}", i18n::t(T::ReadOnlyMode, self.current_lang), i18n::t(T::ReadOnlyModeDetail, self.current_lang)));
                 ui.separator();
                 
                 
-                if let AppStatus::Busy { task_name, start_time } = &self.status {
+                if let AppStatus::Busy { task_name, start_time, .. } = &self.status {
                     let elapsed = start_time.elapsed().as_secs();
                     ui.spinner();
                     ui.label(format!("{}: {}s", task_name, elapsed));
@@ -802,13 +3588,35 @@ impl eframe::App for DiagnosticApp {
                     ui.separator();
                 }
 
+                if self.is_simulating() {
+                    ui.label(egui::RichText::new(format!("🧪 {}", i18n::t(T::DemoModeActive, self.current_lang)))
+                        .color(egui::Color32::from_rgb(255, 165, 0))
+                        .strong());
+                    ui.separator();
+                }
+
                 ui.label(&self.status_message);
+
+                if let Some(error) = self.error_panel.latest_error() {
+                    let chip = egui::Button::new(
+                        egui::RichText::new(format!("{} {}", ErrorSeverity::Error.icon(), error.summary(40)))
+                            .color(ErrorSeverity::Error.color())
+                            .small(),
+                    );
+                    let hover = format!("{}\n\n{}", error.display_message(), i18n::t(T::OpenErrorPanel, self.current_lang));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add(chip).on_hover_text(hover).clicked() {
+                            self.show_errors = true;
+                            self.error_panel.acknowledge_errors();
+                        }
+                    });
+                }
             });
         });
 
         
         if self.show_connection_panel {
-            egui::SidePanel::left("connection_panel")
+            let shown = egui::SidePanel::left("connection_panel")
                 .resizable(true)
                 .default_width(320.0)
                 .min_width(280.0)
@@ -817,8 +3625,15 @@ impl eframe::App for DiagnosticApp {
                     
                     let runtime = self.runtime.clone();
                     let tx = self.backend_tx.clone();
-                    let is_connected = self.is_connected();
-                    let app_busy = matches!(self.status, AppStatus::Busy { .. });
+                    let connected_endpoint = match &self.connection_state {
+                        ConnectionState::Connected { endpoint } => Some(endpoint.clone()),
+                        _ => None,
+                    };
+                    let app_busy = matches!(self.status, AppStatus::Busy { .. }) || self.is_connecting();
+                    let session_stats = self.session_stats.as_ref()
+                        .and_then(|stats| stats.lock().ok().map(|s| s.clone()));
+                    let service_timings = self.service_timings.as_ref()
+                        .and_then(|timings| timings.lock().ok().map(|t| t.clone()));
                     
                     
                     let (action, _unused_disconnect) = self.connection_panel.show(
@@ -828,8 +3643,14 @@ impl eframe::App for DiagnosticApp {
                         can_cancel,
                         &runtime,
                         tx,
-                        is_connected,
+                        connected_endpoint.as_deref(),
+                        self.settings.pin_connection_panel,
+                        self.settings.endpoint_fallback,
+                        self.settings.auto_reconnect,
                         app_busy,
+                        session_stats.as_ref(),
+                        service_timings.as_ref(),
+                        self.endpoint_check.as_ref(),
                         self.current_lang,
                     );
 
@@ -841,68 +3662,238 @@ impl eframe::App for DiagnosticApp {
                         Some(crate::ui::connection::ConnectionAction::Disconnect) => {
                             self.disconnect();
                         }
+                        Some(crate::ui::connection::ConnectionAction::SetPinned(pinned)) => {
+                            self.settings.pin_connection_panel = pinned;
+                            self.save_settings();
+                        }
+                        Some(crate::ui::connection::ConnectionAction::SetEndpointFallback(enabled)) => {
+                            self.settings.endpoint_fallback = enabled;
+                            self.save_settings();
+                        }
+                        Some(crate::ui::connection::ConnectionAction::SetAutoReconnect(enabled)) => {
+                            self.settings.auto_reconnect = enabled;
+                            if !enabled {
+                                self.stop_reconnecting();
+                            }
+                            self.save_settings();
+                        }
                         Some(crate::ui::connection::ConnectionAction::StartDiagnostic(input)) => {
                             self.start_diagnostic(input);
                         }
+                        Some(crate::ui::connection::ConnectionAction::RetryDiagnosticStep { input, from, previous }) => {
+                            self.spawn_diagnostic(input, from, previous);
+                        }
                         Some(crate::ui::connection::ConnectionAction::CancelDiagnostic) => {
                             self.cancel_task();
                         }
                         None => {}
                     }
                 });
+            self.panel_rects.push(shown.response.rect);
         }
 
         
         let mut properties_action = None;
-        if self.is_connected() {
-            egui::SidePanel::right("properties_panel")
+        if let Some(type_def) = self.selected_node.as_ref().and_then(|node| node.type_definition.clone()) {
+            self.resolve_node_names(&[type_def]);
+        }
+        let mut crawler_action = None;
+        let narrow_layout = self.settings.is_narrow_layout(ctx.screen_rect().width());
+        if narrow_layout {
+            let open_tabs = self.open_side_tabs();
+            if let Some(active) = self.settings.side_tab.resolve(&open_tabs) {
+                let shown = egui::SidePanel::right("tabbed_side_panel")
+                    .resizable(true)
+                    .default_width(320.0)
+                    .min_width(250.0)
+                    .max_width(500.0)
+                    .show(ctx, |ui| {
+                        let mut selected = active;
+                        ui.horizontal_wrapped(|ui| {
+                            for tab in &open_tabs {
+                                ui.selectable_value(&mut selected, *tab, self.side_tab_label(*tab));
+                            }
+                        });
+                        ui.separator();
+                        if selected != self.settings.side_tab {
+                            self.settings.side_tab = selected;
+                            self.save_settings();
+                        }
+                        match selected {
+                            SideTab::Properties => properties_action = self.show_properties_contents(ui),
+                            SideTab::Crawler => crawler_action = self.show_crawler_contents(ui),
+                            SideTab::Certificates => self.show_certificates_contents(ui),
+                            SideTab::Errors => self.error_panel.show_panel(ui, self.current_lang),
+                        }
+                    });
+                self.panel_rects.push(shown.response.rect);
+            }
+        }
+
+        if self.is_connected() && !narrow_layout {
+            let shown = egui::SidePanel::right("properties_panel")
                 .resizable(true)
                 .default_width(300.0)
                 .min_width(200.0)
                 .max_width(500.0)
                 .show(ctx, |ui| {
-                    let monitored_data = self.selected_node.as_ref()
-                        .and_then(|node| self.subscription_manager.monitored_items.get(&node.node_id));
-                    
-                    let panel = PropertiesPanel::new(&self.selected_node, monitored_data);
-                    properties_action = panel.show(ui, self.current_lang);
+                    properties_action = self.show_properties_contents(ui);
                 });
+            self.panel_rects.push(shown.response.rect);
         }
         
         
-        let mut crawler_action = None;
-        if self.show_crawler {
-             egui::SidePanel::right("crawler_panel")
+        if self.show_crawler && !narrow_layout {
+             let shown = egui::SidePanel::right("crawler_panel")
                 .resizable(true)
                 .default_width(320.0)
                 .min_width(250.0)
                 .max_width(500.0)
                 .show(ctx, |ui| {
-                    crawler_action = self.crawler_panel.show(ui, self.is_connected(), self.current_lang);
+                    crawler_action = self.show_crawler_contents(ui);
                 });
+             self.panel_rects.push(shown.response.rect);
         }
 
         
-        if self.show_certificates {
-            egui::SidePanel::right("certificates_panel_view")
+        if self.show_server_diagnostics && self.is_connected() {
+            if self.server_diagnostics_panel.needs_refresh() {
+                self.refresh_server_diagnostics();
+            }
+            let shown = egui::SidePanel::right("server_diagnostics_panel")
                 .resizable(true)
                 .default_width(320.0)
                 .min_width(250.0)
                 .max_width(500.0)
                 .show(ctx, |ui| {
-                    if let Some(action) = self.certificates_panel.show(ui, self.current_lang) {
-                        self.certificates_panel.handle_action(&action);
-                    }
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        if let Some(ServerDiagnosticsAction::Refresh) = self.server_diagnostics_panel.show(ui, self.current_lang) {
+                            self.refresh_server_diagnostics();
+                        }
+                    });
+                });
+            self.panel_rects.push(shown.response.rect);
+        }
+
+        
+        if self.show_settings {
+            let mut open = true;
+            let mut settings_action = None;
+            let outdated = self.subscription_manager.config_outdated();
+            egui::Window::new(format!("⚙ {}", i18n::t(T::Settings, self.current_lang)))
+                .open(&mut open)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    settings_action = crate::ui::settings_panel::show(ui, &mut self.settings, outdated, self.current_lang);
+                });
+            self.show_settings = open;
+            match settings_action {
+                Some(SettingsAction::Changed) => {
+                    self.subscription_manager.subscription_config = self.settings.subscription_config();
+                    self.save_settings();
+                }
+                Some(SettingsAction::ApplyToSubscription) => {
+                    self.subscription_manager.spawn_modify_subscriptions_task(
+                        &self.runtime,
+                        self.opcua_client.clone(),
+                        self.backend_tx.clone()
+                    );
+                }
+                None => {}
+            }
+        }
+
+        
+        if self.show_audit_log {
+            let mut open = true;
+            let mut audit_action = None;
+            egui::Window::new(format!("📜 {}", i18n::t(T::AuditLog, self.current_lang)))
+                .open(&mut open)
+                .default_size([720.0, 420.0])
+                .show(ctx, |ui| {
+                    audit_action = self.audit_panel.show(ui, &self.audit_log, &self.settings.timestamp_format, self.current_lang);
+                });
+            self.show_audit_log = open;
+            if let Some(AuditAction::SetEnabled(enabled)) = audit_action {
+                self.audit_log.set_enabled(enabled);
+                self.settings.audit_log_enabled = enabled;
+                self.save_settings();
+            }
+        }
+
+        
+        if self.show_tag_validation {
+            let mut open = true;
+            let mut validation_action = None;
+            egui::Window::new(i18n::t(T::ValidateTagList, self.current_lang))
+                .open(&mut open)
+                .default_size([720.0, 420.0])
+                .show(ctx, |ui| {
+                    validation_action = self.tag_validation_panel.show(ui, self.current_lang);
+                });
+            self.show_tag_validation = open;
+            match validation_action {
+                Some(TagValidationAction::OpenFile) => self.validate_tag_list(),
+                Some(TagValidationAction::AddPassingToWatchlist) => self.add_validated_tags_to_watchlist(),
+                None => {}
+            }
+        }
+
+        
+        if self.show_reachability {
+            let default_target = match &self.connection_state {
+                ConnectionState::Connected { endpoint } => Some(endpoint.clone()),
+                _ => None,
+            };
+            let mut reachability_action = None;
+            let shown = egui::SidePanel::right("reachability_panel")
+                .resizable(true)
+                .default_width(320.0)
+                .min_width(250.0)
+                .max_width(500.0)
+                .show(ctx, |ui| {
+                    reachability_action = self.reachability_panel.show(ui, default_target.as_deref(), self.current_lang);
                 });
+            self.panel_rects.push(shown.response.rect);
+            match reachability_action {
+                Some(ReachabilityAction::Start { host, port, interval }) => self.start_reachability_monitor(host, port, interval),
+                Some(ReachabilityAction::Stop) => self.stop_reachability_monitor(),
+                None => {}
+            }
+        }
+
+        
+        if self.show_certificates && !narrow_layout {
+            let shown = egui::SidePanel::right("certificates_panel_view")
+                .resizable(true)
+                .default_width(320.0)
+                .min_width(250.0)
+                .max_width(500.0)
+                .show(ctx, |ui| {
+                    self.show_certificates_contents(ui);
+                });
+            self.panel_rects.push(shown.response.rect);
         }
 
         
         
         if let Some(action) = crawler_action {
             match action {
-                CrawlerAction::StartCrawl(config) => self.start_crawl(config),
+                CrawlerAction::StartCrawl(config) => self.start_crawl(config, None),
+                CrawlerAction::StreamToJson(config) => {
+                    if let Some(path) = self.file_dialog(DialogKind::Export)
+                        .set_file_name("crawl_stream.json")
+                        .add_filter("JSON", &["json"])
+                        .save_file()
+                    {
+                        self.remember_directory(DialogKind::Export, &path);
+                        self.start_crawl(config, Some(path));
+                    }
+                }
+                CrawlerAction::StopCrawl => self.stop_crawl(),
                 CrawlerAction::ExportJson => self.export_crawl_json(),
                 CrawlerAction::ExportCsv => self.export_crawl_csv(),
+                CrawlerAction::ExportConflicts => self.export_crawl_conflicts(),
                 CrawlerAction::JumpToNode(node_id) => {
                     
                     
@@ -920,14 +3911,32 @@ impl eframe::App for DiagnosticApp {
                 crate::ui::properties::PropertiesAction::AddToWatchlist(node) => {
                     self.add_to_watchlist(&node);
                 }
+                crate::ui::properties::PropertiesAction::NavigateTo(node_id) => {
+                    self.navigate_to_node(node_id);
+                }
+                crate::ui::properties::PropertiesAction::ToggleFavorite(node) => {
+                    self.toggle_favorite(&node);
+                }
+                crate::ui::properties::PropertiesAction::ExportNodeDetails(node) => {
+                    self.export_node_details(node);
+                }
+                crate::ui::properties::PropertiesAction::RefreshValue(node_id) => {
+                    self.read_node_value(node_id);
+                }
+                crate::ui::properties::PropertiesAction::ReadHistory(node) => {
+                    self.history_dialog.open(node);
+                }
             }
         }
 
+        if let Some(request) = self.history_dialog.show(ctx, self.current_lang) {
+            self.read_history(request);
+        }
+
         
         
-        if self.is_connected() && (self.show_watchlist || self.show_trending)
-           && !self.subscription_manager.monitored_items.is_empty() {
-            egui::TopBottomPanel::bottom("monitor_panel")
+        if self.is_connected() && (self.show_watchlist || self.show_trending || self.show_events) {
+            let shown = egui::TopBottomPanel::bottom("monitor_panel")
                 .resizable(true)
                 .min_height(200.0)
                 .max_height(500.0)
@@ -936,19 +3945,34 @@ impl eframe::App for DiagnosticApp {
                     ui.horizontal(|ui| {
                         ui.selectable_value(&mut self.show_watchlist, true, format!("📊 {}", i18n::t(T::Watchlist, self.current_lang)));
                         ui.selectable_value(&mut self.show_trending, true, format!("📈 {}", i18n::t(T::Trending, self.current_lang)));
+                        ui.selectable_value(&mut self.show_events, true, format!("🔔 {}", i18n::t(T::Events, self.current_lang)));
                     });
                     ui.separator();
 
                     
                     egui::ScrollArea::vertical().show(ui, |ui| {
                         if self.show_watchlist {
-                            if let Some(action) = self.monitor_panel.show(ui, &self.subscription_manager.monitored_items, self.current_lang) {
+                            self.monitor_panel.subscription_config = self.subscription_manager.subscription_config;
+                            if let Some(action) = self.monitor_panel.show(ui, &self.subscription_manager.monitored_items, &self.data_type_labels, self.active_bookmark.is_some(), &mut self.settings, self.current_lang) {
                                 match action {
                                     MonitorAction::Remove(node_id) => self.remove_from_watchlist(&node_id),
+                                    MonitorAction::Detach(node_id) => self.detach_from_watchlist(&node_id),
                                     MonitorAction::ToggleTrend(node_id) => self.toggle_trending(node_id),
                                     MonitorAction::ChangeColor(node_id, rgb) => self.change_trend_color(node_id, rgb),
+                                    MonitorAction::ChangeStyle(node_id, style) => self.change_trend_style(node_id, style),
                                     MonitorAction::ExportCsv => self.export_watchlist_csv(),
                                     MonitorAction::ExportJson => self.export_watchlist_json(),
+                                    MonitorAction::SaveToBookmark => self.save_watchlist_to_bookmark(),
+                                    MonitorAction::ImportTagList => self.import_tag_list(),
+                                    MonitorAction::QuickAdd(node_id) => self.quick_add_to_watchlist(node_id),
+                                    MonitorAction::QuickAddInvalid(error) => self.error_panel.add_error(error, ErrorSeverity::Warning),
+                                    MonitorAction::SetPublishingInterval(interval_ms) => self.set_publishing_interval(interval_ms),
+                                    MonitorAction::SetItemParameters(node_id, parameters) => self.set_item_parameters(node_id, parameters),
+                                    MonitorAction::Recreate(node_id) => {
+                                        let node_ids = self.subscription_manager.request_recreate(&node_id).into_iter().collect();
+                                        self.recreate_monitored_items(node_ids);
+                                    }
+                                    MonitorAction::CreateAdditionalSubscription => self.create_additional_subscription(),
                                 }
                             }
                             if self.show_trending {
@@ -958,15 +3982,35 @@ impl eframe::App for DiagnosticApp {
                         }
                         
                         if self.show_trending {
-                            self.trending_panel.show(ui, &self.subscription_manager.monitored_items);
+                            if let Some(action) = self.trending_panel.show(ui, &self.subscription_manager.monitored_items, self.settings.trend_style, self.current_lang) {
+                                match action {
+                                    TrendingAction::AutoAssignColors => self.auto_assign_trend_colors(),
+                                }
+                            }
+                        }
+
+                        if self.show_events {
+                            if self.show_watchlist || self.show_trending {
+                                ui.add_space(10.0);
+                                ui.separator();
+                            }
+                            let subscribed = self.event_notifier.as_ref();
+                            if let Some(action) = self.events_panel.show(ui, &self.event_log, subscribed, &self.settings.timestamp_format, self.current_lang) {
+                                match action {
+                                    EventsAction::Subscribe(notifier) => self.subscribe_events(notifier),
+                                    EventsAction::Unsubscribe => self.unsubscribe_events(),
+                                    EventsAction::Clear => self.event_log.clear(),
+                                }
+                            }
                         }
                     });
                 });
+            self.panel_rects.push(shown.response.rect);
         }
 
         
-        if self.show_errors {
-            egui::SidePanel::right("error_panel")
+        if self.show_errors && !narrow_layout {
+            let shown = egui::SidePanel::right("error_panel")
                 .resizable(true)
                 .default_width(350.0)
                 .min_width(280.0)
@@ -974,18 +4018,43 @@ impl eframe::App for DiagnosticApp {
                 .show(ctx, |ui| {
                     self.error_panel.show_panel(ui, self.current_lang);
                 });
+            self.panel_rects.push(shown.response.rect);
         }
 
         
-        self.error_panel.show_toasts(ctx);
+        self.error_panel.show_toasts(ctx, self.current_lang);
 
 
         
-        egui::CentralPanel::default().show(ctx, |ui| {
+        let mut start_demo = false;
+        let mut reconnect = false;
+        let mut stop_reconnecting = false;
+        let shown = egui::CentralPanel::default().show(ctx, |ui| {
             
             match &self.connection_state {
                 ConnectionState::Connected { endpoint } => {
-                    ui.label(format!("Connected to: {}", endpoint));
+                    ui.label(i18n::t_fmt(T::ConnectedTo, self.current_lang, &[endpoint]));
+                    if !self.server_views.is_empty() {
+                        if let Some(view) = crate::ui::tree_view::show_view_selector(ui, &self.server_views, self.selected_view.as_ref(), self.current_lang) {
+                            self.select_view(view);
+                        }
+                    }
+                    let goto_action = self.goto_bar.show(ui, true, self.current_lang);
+                    match goto_action {
+                        Some(GotoAction::NodeId(node_id)) => self.navigate_to_node(node_id),
+                        Some(GotoAction::Path(path)) => self.navigate_to_path(path),
+                        None => {}
+                    }
+                    let favorites = self.connected_endpoint()
+                        .map(|endpoint| self.bookmarks.favorites_for(endpoint).to_vec())
+                        .unwrap_or_default();
+                    if !favorites.is_empty() {
+                        match crate::ui::favorites::show_favorites(ui, &favorites, self.current_lang) {
+                            Some(FavoritesAction::Open(node_id)) => self.navigate_to_node(node_id),
+                            Some(FavoritesAction::Remove(entry)) => self.remove_favorite(entry),
+                            None => {}
+                        }
+                    }
                     ui.separator();
                     
                     
@@ -993,7 +4062,14 @@ impl eframe::App for DiagnosticApp {
                         .auto_shrink([false; 2])
                         .show(ui, |ui| {
                          let selected_id = self.selected_node.as_ref().map(|n| n.node_id.clone());
-                         let tree = TreeView::new(&self.node_cache, &selected_id);
+                         let favorite_ids = self.favorite_ids();
+                         let tree = match &self.pending_reveal {
+                             Some((path, target)) => TreeView::new(&self.node_cache, &selected_id).with_reveal(path, target),
+                             None => TreeView::new(&self.node_cache, &selected_id),
+                         }
+                         .with_favorites(&favorite_ids)
+                         .with_data_types(&self.data_type_labels)
+                         .with_browse_errors(&self.browse_errors);
                          let actions = tree.show(ui, &self.root_nodes, self.current_lang);
 
                          for action in actions {
@@ -1004,33 +4080,72 @@ impl eframe::App for DiagnosticApp {
                                  crate::ui::tree_view::TreeViewAction::Expand(node_id) => {
                                      self.browse_node(node_id);
                                  }
+                                 crate::ui::tree_view::TreeViewAction::RetryBrowse(node_id) => {
+                                     self.browse_errors.remove(&node_id);
+                                     self.browse_node(node_id);
+                                 }
                                  crate::ui::tree_view::TreeViewAction::AddToWatchlist(node) => {
                                      self.add_to_watchlist(&node);
                                  }
+                                 crate::ui::tree_view::TreeViewAction::AddAllVariables(node) => {
+                                     self.add_variables_prompt = Some((node, false, 3));
+                                 }
+                                 crate::ui::tree_view::TreeViewAction::Revealed(_) => {
+                                     self.pending_reveal = None;
+                                 }
+                                 crate::ui::tree_view::TreeViewAction::ToggleFavorite(node) => {
+                                     self.toggle_favorite(&node);
+                                 }
+                                 crate::ui::tree_view::TreeViewAction::ExportNodeDetails(node) => {
+                                     self.export_node_details(node);
+                                 }
+                                 crate::ui::tree_view::TreeViewAction::CopyStructure(node) => {
+                                     self.request_copy_structure(node);
+                                 }
                                  crate::ui::tree_view::TreeViewAction::ExportJson(node) => {
                                      
                                      self.show_crawler = true;
-                                     self.crawler_panel.config.start_node = node.node_id.clone();
+                                     self.crawler_panel.config.start_nodes = vec![node.node_id.clone()];
                                      self.crawler_panel.config.max_depth = 10; 
                                      self.crawler_panel.config.max_nodes = 100000;
+                                     self.crawler_panel.config.include_start_node = true;
                                      
                                      
-                                     self.start_crawl(self.crawler_panel.config.clone());
+                                     self.start_crawl(self.crawler_panel.config.clone(), None);
                                  }
                                  crate::ui::tree_view::TreeViewAction::ExportCsv(node) => {
                                       
                                       
                                      self.show_crawler = true;
-                                     self.crawler_panel.config.start_node = node.node_id.clone();
+                                     self.crawler_panel.config.start_nodes = vec![node.node_id.clone()];
                                      self.crawler_panel.config.max_depth = 10;
                                      self.crawler_panel.config.max_nodes = 100000;
-                                     self.start_crawl(self.crawler_panel.config.clone());
+                                     self.crawler_panel.config.include_start_node = true;
+                                     self.start_crawl(self.crawler_panel.config.clone(), None);
                                  }
                              }
                          }
                     });
                 }
-                _ if matches!(self.status, AppStatus::Busy { ref task_name, .. } if task_name == i18n::t(T::Connecting, self.current_lang)) => {
+                ConnectionState::Reconnecting { attempt } => {
+                    let attempts = self.settings.reconnect_attempts_label();
+                    ui.centered_and_justified(|ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.spinner();
+                            ui.add_space(10.0);
+                            ui.colored_label(
+                                egui::Color32::from_rgb(255, 200, 100),
+                                i18n::t_fmt(T::ReconnectingAttempt, self.current_lang, &[&attempt.to_string(), &attempts]),
+                            );
+                            ui.label(&self.status_message);
+                            ui.add_space(20.0);
+                            if ui.button(format!("⏹ {}", i18n::t(T::StopReconnecting, self.current_lang))).clicked() {
+                                stop_reconnecting = true;
+                            }
+                        });
+                    });
+                }
+                _ if matches!(self.status, AppStatus::Busy { task_id: CONNECT_TASK, .. }) => {
                     ui.centered_and_justified(|ui| {
                         ui.vertical_centered(|ui| {
                             ui.spinner();
@@ -1039,6 +4154,20 @@ impl eframe::App for DiagnosticApp {
                         });
                     });
                 }
+                ConnectionState::Lost { .. } => {
+                    let message = self.connection_state.loss_message(self.current_lang).unwrap_or_default();
+                    ui.centered_and_justified(|ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.colored_label(egui::Color32::RED, egui::RichText::new(format!("🔌 {}", i18n::t(T::ConnectionLost, self.current_lang))).size(20.0));
+                            ui.add_space(10.0);
+                            ui.label(message);
+                            ui.add_space(20.0);
+                            if ui.add_enabled(self.last_connect_config.is_some(), egui::Button::new(format!("🔄 {}", i18n::t(T::Reconnect, self.current_lang)))).clicked() {
+                                reconnect = true;
+                            }
+                        });
+                    });
+                }
                 ConnectionState::Error(e) => {
                     ui.centered_and_justified(|ui| {
                         ui.vertical_centered(|ui| {
@@ -1058,6 +4187,13 @@ impl eframe::App for DiagnosticApp {
                             ui.label(i18n::t(T::ConnectStep1, self.current_lang));
                             ui.label(i18n::t(T::ConnectStep2, self.current_lang));
                             ui.label(i18n::t(T::ConnectStep3, self.current_lang));
+                            ui.add_space(20.0);
+                            if ui.button(format!("🧪 {}", i18n::t(T::DemoServer, self.current_lang)))
+                                .on_hover_text(i18n::t(T::DemoServerHint, self.current_lang))
+                                .clicked()
+                            {
+                                start_demo = true;
+                            }
                             ui.add_space(30.0);
                             ui.label(egui::RichText::new(format!("⚠️ {}", i18n::t(T::SafetyMode, self.current_lang))).color(egui::Color32::YELLOW));
                             ui.label(i18n::t(T::ProductionSafe, self.current_lang));
@@ -1066,5 +4202,127 @@ impl eframe::App for DiagnosticApp {
                 }
             }
         });
+        self.panel_rects.push(shown.response.rect);
+
+        if start_demo {
+            self.start_simulation();
+        }
+        if reconnect {
+            if let Some(config) = self.last_connect_config.clone() {
+                self.connect(config);
+            }
+        }
+        if stop_reconnecting {
+            if self.is_connecting() {
+                self.cancel_task();
+            } else {
+                self.stop_reconnecting();
+            }
+        }
+        self.track_layout(ctx);
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        if let Some((layout, _)) = self.pending_layout.take() {
+            self.settings.layout = layout;
+            if let Err(e) = self.settings.save() {
+                tracing::warn!("Failed to save the window layout on exit: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connect_generation_guard() {
+        let generations = ConnectGeneration::default();
+        let first = generations.advance();
+        assert!(generations.is_current(first));
+
+        let second = generations.advance();
+        assert!(!generations.is_current(first));
+        assert!(generations.is_current(second));
+    }
+
+    #[test]
+    fn test_connect_task_is_recognised_in_any_language() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut tasks = TaskSlots::default();
+        let label = i18n::t(T::Connecting, Language::Spanish);
+        tasks.start(ActiveTask::new(CONNECT_TASK, label, runtime.spawn(std::future::pending()), tokio_util::sync::CancellationToken::new()));
+        assert!(tasks.active().is_some_and(|task| task.id == CONNECT_TASK));
+        assert!(matches!(tasks.status(), AppStatus::Busy { task_id: CONNECT_TASK, ref task_name, .. } if task_name == label));
+        tasks.take_active().unwrap().handle.abort();
+    }
+
+    #[test]
+    fn test_browse_started_mid_crawl_leaves_crawl_stoppable() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let crawl_cancel = tokio_util::sync::CancellationToken::new();
+        let crawl_stopped = crawl_cancel.clone();
+        let mut tasks = TaskSlots::default();
+        tasks.start_crawl(ActiveTask::new(
+            CRAWL_TASK,
+            "Crawler",
+            runtime.spawn(async move { crawl_stopped.cancelled().await }),
+            crawl_cancel.clone(),
+        ));
+
+        tasks.start(ActiveTask::new(BROWSE_TASK, "Browse", runtime.spawn(async {}), tokio_util::sync::CancellationToken::new()));
+        while !tasks.active().unwrap().handle.is_finished() {
+            std::thread::yield_now();
+        }
+        assert!(tasks.reap_finished());
+        assert!(matches!(tasks.status(), AppStatus::Busy { task_id: CRAWL_TASK, .. }));
+
+        let crawl = tasks.stop_crawl().unwrap();
+        assert!(crawl_cancel.is_cancelled());
+        runtime.block_on(crawl.handle).unwrap();
+        assert_eq!(tasks.status(), AppStatus::Idle);
+        assert!(tasks.stop_crawl().is_none());
+    }
+
+    #[test]
+    fn test_user_disconnect_is_quiet() {
+        let connected = ConnectionState::Connected { endpoint: "opc.tcp://plc:4840".to_string() };
+        let closed = connected.after_session_closed(true, Some(std::time::Duration::from_secs(90)));
+        assert_eq!(closed, ConnectionState::Disconnected);
+        assert_eq!(closed.loss_message(Language::English), None);
+    }
+
+    #[test]
+    fn test_connection_loss_reports_duration() {
+        let connected = ConnectionState::Connected { endpoint: "opc.tcp://plc:4840".to_string() };
+        let lost = connected.after_session_closed(false, Some(std::time::Duration::from_secs(3725)));
+        assert!(matches!(&lost, ConnectionState::Lost { endpoint, .. } if endpoint == "opc.tcp://plc:4840"));
+        let message = lost.loss_message(Language::English).unwrap();
+        assert!(message.contains("opc.tcp://plc:4840"), "{}", message);
+        assert!(message.contains("1h 02m 05s"), "{}", message);
+
+        assert_eq!(lost.after_session_closed(false, None), lost);
+        assert_eq!(lost.after_session_closed(true, None), ConnectionState::Disconnected);
+        assert_eq!(ConnectionState::Disconnected.after_session_closed(false, None), ConnectionState::Disconnected);
+    }
+
+    #[test]
+    fn test_connect_generation_shared_between_clones() {
+        let generations = ConnectGeneration::default();
+        let task_view = generations.clone();
+        let attempt = task_view.advance();
+
+        generations.advance();
+        assert!(!task_view.is_current(attempt));
+    }
+
+    #[test]
+    fn test_reconnect_delay_backs_off_exponentially() {
+        assert_eq!(reconnect_delay(1), std::time::Duration::from_secs(1));
+        assert_eq!(reconnect_delay(2), std::time::Duration::from_secs(2));
+        assert_eq!(reconnect_delay(4), std::time::Duration::from_secs(8));
+        assert_eq!(reconnect_delay(6), RECONNECT_MAX_DELAY);
+        assert_eq!(reconnect_delay(u32::MAX), RECONNECT_MAX_DELAY);
     }
 }
@@ -2,7 +2,9 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use crate::opcua::subscription::TrendStyle;
 use crate::utils::i18n::{self, T, Language};
 
 
@@ -77,11 +79,27 @@ pub enum AuthMethod {
         username: String,
         password: String,
     },
+    Certificate {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
 }
 
 impl AuthMethod {}
 
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchlistEntry {
+    
+    pub node_id: String,
+    
+    pub display_name: String,
+    
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trend_style: Option<TrendStyle>,
+}
+
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ServerBookmark {
     
@@ -94,20 +112,40 @@ pub struct ServerBookmark {
     pub security_mode: MessageSecurityMode,
     
     pub auth_method: AuthMethod,
+    
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watchlist: Option<Vec<WatchlistEntry>>,
+    
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl ServerBookmark {}
 
 
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Bookmarks {
     
     pub servers: Vec<ServerBookmark>,
+    
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub favorites: BTreeMap<String, Vec<WatchlistEntry>>,
 }
 
 impl Bookmarks {
     
-    fn bookmarks_path() -> PathBuf {
+    pub fn bookmarks_path() -> PathBuf {
         
         std::env::current_exe()
             .unwrap_or_else(|_| PathBuf::from("."))
@@ -119,9 +157,13 @@ impl Bookmarks {
 
     
     pub fn load() -> Result<Self> {
-        let path = Self::bookmarks_path();
+        Self::load_from(&Self::bookmarks_path())
+    }
+
+    
+    pub fn load_from(path: &Path) -> Result<Self> {
         if path.exists() {
-            let content = std::fs::read_to_string(&path)?;
+            let content = std::fs::read_to_string(path)?;
             let bookmarks: Bookmarks = serde_json::from_str(&content)?;
             tracing::info!("Loaded {} bookmarks from {:?}", bookmarks.servers.len(), path);
             Ok(bookmarks)
@@ -156,4 +198,151 @@ impl Bookmarks {
     pub fn is_empty(&self) -> bool {
         self.servers.is_empty()
     }
+
+    
+    pub fn favorites_for(&self, endpoint_url: &str) -> &[WatchlistEntry] {
+        self.favorites.get(endpoint_url).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    
+    pub fn is_favorite(&self, endpoint_url: &str, node_id: &str) -> bool {
+        self.favorites_for(endpoint_url).iter().any(|f| f.node_id == node_id)
+    }
+
+    
+    pub fn toggle_favorite(&mut self, endpoint_url: &str, entry: WatchlistEntry) -> bool {
+        let list = self.favorites.entry(endpoint_url.to_string()).or_default();
+        let added = match list.iter().position(|f| f.node_id == entry.node_id) {
+            Some(index) => {
+                list.remove(index);
+                false
+            }
+            None => {
+                list.push(entry);
+                true
+            }
+        };
+        if list.is_empty() {
+            self.favorites.remove(endpoint_url);
+        }
+        added
+    }
+
+    
+    
+    pub fn grouped(&self, tag_filter: &str) -> Vec<(Option<String>, Vec<usize>)> {
+        let filter = tag_filter.trim().to_lowercase();
+        let mut groups: Vec<(Option<String>, Vec<usize>)> = Vec::new();
+
+        for (i, bookmark) in self.servers.iter().enumerate() {
+            let tags: Vec<Option<&String>> = if bookmark.tags.is_empty() {
+                vec![None]
+            } else {
+                bookmark.tags.iter().map(Some).collect()
+            };
+            for tag in tags {
+                let visible = match tag {
+                    Some(tag) => tag.to_lowercase().contains(&filter),
+                    None => filter.is_empty(),
+                };
+                if !visible {
+                    continue;
+                }
+                let key = tag.map(|t| t.to_lowercase());
+                match groups.iter_mut().find(|(k, _)| k.as_ref().map(|k| k.to_lowercase()) == key) {
+                    Some((_, members)) => members.push(i),
+                    None => groups.push((tag.cloned(), vec![i])),
+                }
+            }
+        }
+
+        for (_, members) in &mut groups {
+            members.sort_by_cached_key(|&i| (self.servers[i].name.to_lowercase(), i));
+        }
+        groups.sort_by_cached_key(|(tag, _)| (tag.is_none(), tag.as_ref().map(|t| t.to_lowercase())));
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmark_without_watchlist_still_loads() {
+        let json = r#"{"servers":[{"name":"Line 1","endpoint_url":"opc.tcp://plc:4840","security_policy":"None","security_mode":"None","auth_method":"Anonymous"}]}"#;
+        let bookmarks: Bookmarks = serde_json::from_str(json).unwrap();
+        assert_eq!(bookmarks.servers[0].watchlist, None);
+
+        let out = serde_json::to_string(&bookmarks).unwrap();
+        assert!(!out.contains("watchlist"));
+        assert!(bookmarks.servers[0].tags.is_empty());
+        assert!(!out.contains("tags"));
+    }
+
+    fn bookmark(name: &str, tags: &[&str]) -> ServerBookmark {
+        ServerBookmark {
+            name: name.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_grouping_is_sorted_and_untagged_last() {
+        let bookmarks = Bookmarks {
+            servers: vec![
+                bookmark("Press 2", &["Plant B"]),
+                bookmark("Loose", &[]),
+                bookmark("press 1", &["plant b", "Presses"]),
+                bookmark("Oven", &["Plant A"]),
+            ],
+            ..Default::default()
+        };
+
+        let groups = bookmarks.grouped("");
+        let tags: Vec<Option<&str>> = groups.iter().map(|(t, _)| t.as_deref()).collect();
+        assert_eq!(tags, vec![Some("Plant A"), Some("Plant B"), Some("Presses"), None]);
+        assert_eq!(groups[1].1, vec![2, 0]);
+        assert_eq!(groups[3].1, vec![1]);
+
+        let filtered = bookmarks.grouped("PRESS");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], (Some("Presses".to_string()), vec![2]));
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(parse_tags(" Plant A, line 1 ,, plant a"), vec!["Plant A".to_string(), "line 1".to_string()]);
+        assert!(parse_tags(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_favorites_are_per_endpoint() {
+        let mut bookmarks = Bookmarks::default();
+        let entry = |id: &str| WatchlistEntry { node_id: id.to_string(), display_name: id.to_string(), trend_style: None };
+
+        assert!(bookmarks.toggle_favorite("opc.tcp://a:4840", entry("ns=2;s=Speed")));
+        assert!(bookmarks.toggle_favorite("opc.tcp://b:4840", entry("ns=2;s=Speed")));
+        assert!(bookmarks.is_favorite("opc.tcp://a:4840", "ns=2;s=Speed"));
+        assert!(!bookmarks.is_favorite("opc.tcp://a:4840", "ns=2;s=Temp"));
+
+        assert!(!bookmarks.toggle_favorite("opc.tcp://a:4840", entry("ns=2;s=Speed")));
+        assert!(bookmarks.favorites_for("opc.tcp://a:4840").is_empty());
+        assert_eq!(bookmarks.favorites.len(), 1);
+
+        let json = serde_json::to_string(&Bookmarks::default()).unwrap();
+        assert!(!json.contains("favorites"));
+    }
+
+    #[test]
+    fn test_watchlist_entry_trend_style_is_optional() {
+        let entry: WatchlistEntry = serde_json::from_str(r#"{"node_id": "ns=2;s=Speed", "display_name": "Speed"}"#).unwrap();
+        assert!(entry.trend_style.is_none());
+        assert!(!serde_json::to_string(&entry).unwrap().contains("trend_style"));
+
+        let styled = WatchlistEntry { trend_style: Some(TrendStyle { width: 3.0, markers: true, ..TrendStyle::default() }), ..entry };
+        let json = serde_json::to_string(&styled).unwrap();
+        assert_eq!(serde_json::from_str::<WatchlistEntry>(&json).unwrap(), styled);
+    }
 }
@@ -2,3 +2,4 @@
 
 pub mod bookmarks;
 pub mod settings;
+pub mod startup_checks;
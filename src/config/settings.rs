@@ -1,26 +1,306 @@
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::export::NumberFormat;
+use crate::opcua::subscription::{MonitoringOptions, SubscriptionConfig, TimestampsMode, TrendStyle, DEFAULT_PUBLISHING_INTERVAL_MS};
+use crate::utils::i18n::Language;
+use crate::utils::timestamps::TimestampFormat;
+
+
+pub const DEFAULT_WINDOW_SIZE: [f32; 2] = [1400.0, 900.0];
+
+
+pub const MIN_WINDOW_SIZE: [f32; 2] = [800.0, 600.0];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DialogKind {
+    
+    Export,
+    
+    TagList,
+    
+    Screenshot,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SideTab {
+    
+    #[default]
+    Properties,
+    
+    Crawler,
+    
+    Certificates,
+    
+    Errors,
+}
+
+impl SideTab {
+    pub const ALL: [SideTab; 4] = [SideTab::Properties, SideTab::Crawler, SideTab::Certificates, SideTab::Errors];
+
+    
+    pub fn resolve(self, open: &[SideTab]) -> Option<SideTab> {
+        if open.contains(&self) {
+            Some(self)
+        } else {
+            open.first().copied()
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowLayout {
+    
+    pub show_connection_panel: bool,
+    
+    pub show_watchlist: bool,
+    
+    pub show_trending: bool,
+    
+    pub show_crawler: bool,
+    
+    pub show_certificates: bool,
+    
+    pub show_errors: bool,
+    
+    pub language: Language,
+    
+    pub window_size: Option<[f32; 2]>,
+    
+    pub window_position: Option<[f32; 2]>,
+}
+
+impl WindowLayout {
+    
+    pub fn inner_size(&self) -> [f32; 2] {
+        self.window_size
+            .filter(|[width, height]| *width >= MIN_WINDOW_SIZE[0] && *height >= MIN_WINDOW_SIZE[1])
+            .unwrap_or(DEFAULT_WINDOW_SIZE)
+    }
+}
+
+impl Default for WindowLayout {
+    fn default() -> Self {
+        Self {
+            show_connection_panel: true,
+            show_watchlist: true,
+            show_trending: true,
+            show_crawler: false,
+            show_certificates: false,
+            show_errors: false,
+            language: Language::default(),
+            window_size: None,
+            window_position: None,
+        }
+    }
+}
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 #[allow(dead_code)]
 pub struct Settings {
     
     pub subscription_interval_ms: u32,
     
+    pub subscription_lifetime_count: u32,
+    
+    pub subscription_keepalive_count: u32,
+    
+    pub subscription_max_notifications: u32,
+    
     pub max_watchlist_items: usize,
     
     pub trending_history_seconds: u32,
     
+    pub max_trend_series: usize,
+    
+    #[serde(default)]
+    pub export_number_format: NumberFormat,
+    
     pub auto_save_bookmarks: bool,
+    
+    #[serde(default)]
+    pub pin_connection_panel: bool,
+    
+    #[serde(default = "default_max_ui_update_hz")]
+    pub max_ui_update_hz: u32,
+    
+    #[serde(default)]
+    pub endpoint_fallback: bool,
+    
+    pub read_initial_value_on_add: bool,
+    
+    pub write_export_manifest: bool,
+    
+    pub anonymize_exports: bool,
+    
+    pub timestamp_format: TimestampFormat,
+    
+    pub export_timestamp_format: TimestampFormat,
+    
+    pub monitored_item_queue_size: u32,
+    
+    pub discard_oldest: bool,
+    
+    pub timestamps_to_return: TimestampsMode,
+    
+    pub last_directories: HashMap<DialogKind, PathBuf>,
+    
+    pub narrow_layout_width: f32,
+    
+    pub side_tab: SideTab,
+    
+    pub trend_style: TrendStyle,
+    
+    pub audit_log_enabled: bool,
+    
+    pub auto_reconnect: bool,
+    
+    pub reconnect_max_attempts: u32,
+    
+    pub layout: WindowLayout,
+}
+
+fn default_max_ui_update_hz() -> u32 {
+    10
+}
+
+impl Settings {
+    
+    fn settings_path() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("settings.json")
+    }
+
+    
+    pub fn load() -> Self {
+        let path = Self::settings_path();
+        if !path.exists() {
+            return Self::default();
+        }
+        match std::fs::read_to_string(&path).map_err(anyhow::Error::from).and_then(|c| Ok(serde_json::from_str(&c)?)) {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable settings file {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    
+    pub fn save(&self) -> Result<()> {
+        let path = Self::settings_path();
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        tracing::info!("Saved settings to {:?}", path);
+        Ok(())
+    }
+
+    
+    pub fn monitoring_options(&self) -> MonitoringOptions {
+        MonitoringOptions {
+            queue_size: self.monitored_item_queue_size,
+            discard_oldest: self.discard_oldest,
+            timestamps: self.timestamps_to_return,
+        }
+    }
+
+    
+    pub fn subscription_config(&self) -> SubscriptionConfig {
+        SubscriptionConfig {
+            publishing_interval_ms: self.subscription_interval_ms.into(),
+            lifetime_count: self.subscription_lifetime_count.max(SubscriptionConfig::min_lifetime_count(self.subscription_keepalive_count)),
+            max_keepalive_count: self.subscription_keepalive_count,
+            max_notifications: self.subscription_max_notifications,
+            ..SubscriptionConfig::default()
+        }
+    }
+
+    
+    pub fn last_directory(&self, kind: DialogKind) -> Option<&Path> {
+        self.last_directories.get(&kind).map(PathBuf::as_path).filter(|dir| dir.is_dir())
+    }
+
+    
+    pub fn remember_directory(&mut self, kind: DialogKind, picked: &Path) -> bool {
+        let Some(dir) = picked.parent().filter(|dir| !dir.as_os_str().is_empty()) else {
+            return false;
+        };
+        if self.last_directories.get(&kind).map(PathBuf::as_path) == Some(dir) {
+            return false;
+        }
+        self.last_directories.insert(kind, dir.to_path_buf());
+        true
+    }
+
+    
+    pub fn is_narrow_layout(&self, window_width: f32) -> bool {
+        window_width < self.narrow_layout_width
+    }
+
+    
+    pub fn reconnect_exhausted(&self, attempt: u32) -> bool {
+        self.reconnect_max_attempts != 0 && attempt >= self.reconnect_max_attempts
+    }
+
+    
+    pub fn reconnect_attempts_label(&self) -> String {
+        match self.reconnect_max_attempts {
+            0 => "∞".to_string(),
+            max => max.to_string(),
+        }
+    }
+
+    
+    pub fn ui_update_interval(&self) -> std::time::Duration {
+        match self.max_ui_update_hz {
+            0 => std::time::Duration::ZERO,
+            hz => std::time::Duration::from_secs(1) / hz,
+        }
+    }
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
-            subscription_interval_ms: 1000,
-            max_watchlist_items: 50,
+            subscription_interval_ms: DEFAULT_PUBLISHING_INTERVAL_MS as u32,
+            subscription_lifetime_count: 90,
+            subscription_keepalive_count: 30,
+            subscription_max_notifications: 0,
+            max_watchlist_items: 1000,
             trending_history_seconds: 300,
+            max_trend_series: 12,
+            export_number_format: NumberFormat::FullPrecision,
             auto_save_bookmarks: true,
+            pin_connection_panel: false,
+            max_ui_update_hz: default_max_ui_update_hz(),
+            endpoint_fallback: false,
+            read_initial_value_on_add: true,
+            write_export_manifest: false,
+            anonymize_exports: false,
+            timestamp_format: TimestampFormat::LocalShort,
+            export_timestamp_format: TimestampFormat::Iso8601Millis,
+            monitored_item_queue_size: 1,
+            discard_oldest: true,
+            timestamps_to_return: TimestampsMode::Both,
+            last_directories: HashMap::new(),
+            narrow_layout_width: 1200.0,
+            side_tab: SideTab::Properties,
+            trend_style: TrendStyle::default(),
+            audit_log_enabled: false,
+            auto_reconnect: true,
+            reconnect_max_attempts: 0,
+            layout: WindowLayout::default(),
         }
     }
 }
@@ -28,11 +308,112 @@ impl Default for Settings {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::test_dir::TestDir;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_default_settings() {
         let s = Settings::default();
-        assert_eq!(s.subscription_interval_ms, 1000);
+        assert_eq!(s.subscription_interval_ms, 500);
         assert_eq!(s.auto_save_bookmarks, true);
+        assert_eq!(s.max_trend_series, 12);
+        assert!(!s.pin_connection_panel);
+        assert!(!s.endpoint_fallback);
+        assert!(!s.write_export_manifest);
+        assert!(!s.audit_log_enabled);
+        assert!(s.auto_reconnect);
+        assert_eq!(s.reconnect_max_attempts, 0);
+        assert_eq!(s.export_timestamp_format, TimestampFormat::Iso8601Millis);
+        assert_eq!(s.ui_update_interval(), std::time::Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_partial_settings_file_keeps_defaults() {
+        let s: Settings = serde_json::from_str(r#"{"pin_connection_panel": true}"#).unwrap();
+        assert!(s.pin_connection_panel);
+        assert_eq!(s.max_trend_series, 12);
+        assert_eq!(s.max_ui_update_hz, 10);
+        assert!(s.read_initial_value_on_add);
+        assert_eq!(s.monitoring_options(), MonitoringOptions::default());
+        assert_eq!(s.subscription_config(), SubscriptionConfig::default());
+        assert!(s.last_directories.is_empty());
+    }
+
+    #[test]
+    fn test_side_tab_falls_back_to_first_open_panel() {
+        let s: Settings = serde_json::from_str(r#"{"side_tab": "Certificates", "narrow_layout_width": 900.0}"#).unwrap();
+        assert_eq!(s.side_tab, SideTab::Certificates);
+        assert!(s.is_narrow_layout(800.0));
+        assert!(!s.is_narrow_layout(900.0));
+
+        assert_eq!(s.side_tab.resolve(&SideTab::ALL), Some(SideTab::Certificates));
+        assert_eq!(s.side_tab.resolve(&[SideTab::Crawler, SideTab::Errors]), Some(SideTab::Crawler));
+        assert_eq!(s.side_tab.resolve(&[]), None);
+        assert_eq!(Settings::default().side_tab, SideTab::Properties);
+    }
+
+    #[test]
+    fn test_last_directory_is_remembered_per_dialog_kind() {
+        let dir = TestDir::new("last_dir");
+        let mut s = Settings::default();
+        assert!(s.remember_directory(DialogKind::Export, &dir.join("watchlist.csv")));
+        assert!(!s.remember_directory(DialogKind::Export, &dir.join("crawl.json")));
+        assert_eq!(s.last_directory(DialogKind::Export), Some(dir.path()));
+        assert_eq!(s.last_directory(DialogKind::TagList), None);
+
+        let restored: Settings = serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap();
+        assert_eq!(restored.last_directory(DialogKind::Export), Some(dir.path()));
+
+        std::fs::remove_dir_all(dir.path()).unwrap();
+        assert_eq!(s.last_directory(DialogKind::Export), None);
+        assert_eq!(s.last_directories.get(&DialogKind::Export).map(|p| p.as_path()), Some(dir.path()));
+    }
+
+    #[test]
+    fn test_lifetime_count_is_at_least_three_keepalives() {
+        let s = Settings::default();
+        assert!(s.subscription_lifetime_count >= 3 * s.subscription_keepalive_count);
+        assert_eq!(s.subscription_config().lifetime_count, 90);
+
+        let s: Settings = serde_json::from_str(r#"{"subscription_lifetime_count": 10, "subscription_keepalive_count": 30}"#).unwrap();
+        let config = s.subscription_config();
+        assert_eq!(config.lifetime_count, 90);
+        assert_eq!(config.max_keepalive_count, 30);
+
+        let s: Settings = serde_json::from_str(r#"{"subscription_lifetime_count": 600, "subscription_keepalive_count": 10}"#).unwrap();
+        assert_eq!(s.subscription_config().lifetime_count, 600);
+    }
+
+    #[test]
+    fn test_reconnect_retries_until_cancelled_unless_limited() {
+        let mut s = Settings::default();
+        assert!(!s.reconnect_exhausted(1_000));
+        assert_eq!(s.reconnect_attempts_label(), "∞");
+
+        s.reconnect_max_attempts = 5;
+        assert!(!s.reconnect_exhausted(4));
+        assert!(s.reconnect_exhausted(5));
+        assert_eq!(s.reconnect_attempts_label(), "5");
+    }
+
+    #[test]
+    fn test_window_layout_round_trips_and_rejects_tiny_sizes() {
+        let s = Settings::default();
+        assert!(s.layout.show_connection_panel && s.layout.show_watchlist && s.layout.show_trending);
+        assert!(!s.layout.show_crawler && !s.layout.show_certificates && !s.layout.show_errors);
+        assert_eq!(s.layout.inner_size(), DEFAULT_WINDOW_SIZE);
+
+        let s: Settings = serde_json::from_str(r#"{"layout": {"show_errors": true, "language": "Spanish", "window_size": [1024.0, 700.0]}}"#).unwrap();
+        assert!(s.layout.show_errors);
+        assert!(s.layout.show_watchlist);
+        assert_eq!(s.layout.language, Language::Spanish);
+        assert_eq!(s.layout.inner_size(), [1024.0, 700.0]);
+        assert_eq!(s.layout.window_position, None);
+
+        let restored: Settings = serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap();
+        assert_eq!(restored.layout, s.layout);
+
+        let tiny = WindowLayout { window_size: Some([200.0, 100.0]), ..WindowLayout::default() };
+        assert_eq!(tiny.inner_size(), DEFAULT_WINDOW_SIZE);
     }
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::bookmarks::Bookmarks;
+use crate::utils::i18n::{self, T, Language};
+
+
+const PROBE_FILE_NAME: &str = ".denginks_write_test";
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartupIssue {
+    
+    CorruptBookmarks { path: PathBuf, backup: Option<PathBuf>, error: String },
+    
+    PkiNotWritable { dir: PathBuf, error: String },
+    
+    LogNotWritable { dir: PathBuf, error: String },
+}
+
+impl StartupIssue {
+    
+    pub fn message(&self, lang: Language) -> String {
+        match self {
+            Self::CorruptBookmarks { path, backup: Some(backup), error } => i18n::t_fmt(
+                T::StartupBookmarksCorrupt,
+                lang,
+                &[&path.display().to_string(), error, &backup.display().to_string()],
+            ),
+            Self::CorruptBookmarks { path, backup: None, error } => i18n::t_fmt(
+                T::StartupBookmarksCorruptNoBackup,
+                lang,
+                &[&path.display().to_string(), error],
+            ),
+            Self::PkiNotWritable { dir, error } => i18n::t_fmt(
+                T::StartupPkiNotWritable,
+                lang,
+                &[&dir.display().to_string(), error],
+            ),
+            Self::LogNotWritable { dir, error } => i18n::t_fmt(
+                T::StartupLogNotWritable,
+                lang,
+                &[&dir.display().to_string(), error],
+            ),
+        }
+    }
+}
+
+
+pub struct StartupReport {
+    
+    pub bookmarks: Bookmarks,
+    
+    pub issues: Vec<StartupIssue>,
+}
+
+
+pub fn run(bookmarks_path: &Path, pki_dir: &Path, log_dir: &Path) -> StartupReport {
+    let (bookmarks, bookmark_issue) = check_bookmarks(bookmarks_path);
+    let mut issues: Vec<StartupIssue> = bookmark_issue.into_iter().collect();
+    if let Err(error) = check_writable(pki_dir) {
+        issues.push(StartupIssue::PkiNotWritable { dir: pki_dir.to_path_buf(), error });
+    }
+    if let Err(error) = check_writable(log_dir) {
+        issues.push(StartupIssue::LogNotWritable { dir: log_dir.to_path_buf(), error });
+    }
+    for issue in &issues {
+        tracing::warn!("Startup check: {:?}", issue);
+    }
+    StartupReport { bookmarks, issues }
+}
+
+
+pub fn check_bookmarks(path: &Path) -> (Bookmarks, Option<StartupIssue>) {
+    match Bookmarks::load_from(path) {
+        Ok(bookmarks) => (bookmarks, None),
+        Err(e) => {
+            let backup = backup_path(path);
+            let backup = match std::fs::copy(path, &backup) {
+                Ok(_) => Some(backup),
+                Err(copy_err) => {
+                    tracing::warn!("Could not back up {:?} to {:?}: {}", path, backup, copy_err);
+                    None
+                }
+            };
+            let issue = StartupIssue::CorruptBookmarks { path: path.to_path_buf(), backup, error: e.to_string() };
+            (Bookmarks::default(), Some(issue))
+        }
+    }
+}
+
+
+pub fn check_writable(dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let probe = dir.join(PROBE_FILE_NAME);
+    std::fs::write(&probe, b"ok").map_err(|e| e.to_string())?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(|n| n.to_os_string()).unwrap_or_default();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    #[test]
+    fn test_corrupt_bookmarks_are_kept_as_backup() {
+        let dir = TestDir::new("startup_corrupt");
+        let path = dir.join("bookmarks.json");
+        std::fs::write(&path, "{ \"servers\": [ broken").unwrap();
+
+        let (bookmarks, issue) = check_bookmarks(&path);
+        assert!(bookmarks.is_empty());
+        let Some(StartupIssue::CorruptBookmarks { backup: Some(backup), .. }) = issue else {
+            panic!("expected a corrupt bookmarks issue, got {:?}", issue);
+        };
+        assert_eq!(backup, dir.join("bookmarks.json.bak"));
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "{ \"servers\": [ broken");
+    }
+
+    #[test]
+    fn test_valid_or_missing_bookmarks_report_nothing() {
+        let dir = TestDir::new("startup_bookmarks_ok");
+        let path = dir.join("bookmarks.json");
+        assert!(check_bookmarks(&path).1.is_none());
+
+        std::fs::write(&path, r#"{"servers": []}"#).unwrap();
+        assert!(check_bookmarks(&path).1.is_none());
+        assert!(!dir.join("bookmarks.json.bak").exists());
+    }
+
+    #[test]
+    fn test_writable_directory_is_created_and_left_clean() {
+        let root = TestDir::new("startup_writable");
+        let dir = root.join("pki");
+        assert_eq!(check_writable(&dir), Ok(()));
+        assert!(dir.is_dir());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_unwritable_directory_is_reported() {
+        let dir = TestDir::new("startup_unwritable");
+        let blocker = dir.join("logs");
+        std::fs::write(&blocker, "a file where a directory should be").unwrap();
+
+        let report = run(&dir.join("bookmarks.json"), &dir, &blocker);
+        assert!(matches!(report.issues.as_slice(), [StartupIssue::LogNotWritable { .. }]));
+        let message = report.issues[0].message(Language::English);
+        assert!(message.contains(&blocker.display().to_string()));
+    }
+}
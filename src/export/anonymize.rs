@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use opcua::types::{Identifier, NodeId};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::opcua::browser::{BrowsedNode, NodeClass};
+use crate::opcua::crawler::CrawlConflict;
+use crate::opcua::node_details::{Field, NodeDetails};
+use crate::opcua::subscription::MonitoredData;
+use crate::opcua::server_profile::ServerProfile;
+
+use super::ExportContext;
+
+
+const TOKEN_HEX_LEN: usize = 8;
+
+
+const STANDARD_NAMESPACE: &str = "http://opcfoundation.org/UA/";
+
+
+pub fn mapping_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!("{}.anonymization.csv", name))
+}
+
+
+fn class_prefix(node_class: NodeClass) -> &'static str {
+    match node_class {
+        NodeClass::Object => "OBJ",
+        NodeClass::Variable => "VAR",
+        NodeClass::Method => "MTH",
+        NodeClass::ObjectType => "OTYPE",
+        NodeClass::VariableType => "VTYPE",
+        NodeClass::ReferenceType => "RTYPE",
+        NodeClass::DataType => "DTYPE",
+        NodeClass::View => "VIEW",
+        NodeClass::Unknown => "NODE",
+    }
+}
+
+
+
+fn class_from_label(label: &str) -> NodeClass {
+    [
+        NodeClass::Object,
+        NodeClass::Variable,
+        NodeClass::Method,
+        NodeClass::ObjectType,
+        NodeClass::VariableType,
+        NodeClass::ReferenceType,
+        NodeClass::DataType,
+        NodeClass::View,
+    ]
+    .into_iter()
+    .find(|node_class| node_class.to_string() == label)
+    .unwrap_or(NodeClass::Unknown)
+}
+
+
+#[derive(Debug, Default)]
+pub struct Anonymizer {
+    
+    mapping: BTreeMap<String, String>,
+    
+    tokens: HashMap<(&'static str, String), String>,
+}
+
+impl Anonymizer {
+    
+    pub fn token(&mut self, prefix: &'static str, original: &str) -> String {
+        if let Some(token) = self.tokens.get(&(prefix, original.to_string())) {
+            return token.clone();
+        }
+        let hex: String = Sha256::digest(original.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+        let token = (TOKEN_HEX_LEN..=hex.len())
+            .step_by(2)
+            .map(|len| format!("{}_{}", prefix, &hex[..len]))
+            .find(|candidate| self.mapping.get(candidate).map_or(true, |known| known == original))
+            .unwrap_or_else(|| format!("{}_{}_{}", prefix, hex, self.mapping.len()));
+        self.mapping.insert(token.clone(), original.to_string());
+        self.tokens.insert((prefix, original.to_string()), token.clone());
+        token
+    }
+
+    
+    pub fn name(&mut self, node_class: NodeClass, name: &str) -> String {
+        if name.is_empty() {
+            return String::new();
+        }
+        self.token(class_prefix(node_class), name)
+    }
+
+    
+    pub fn browse_name(&mut self, node_class: NodeClass, browse_name: &str) -> String {
+        match browse_name.split_once(':') {
+            Some((ns, name)) if ns.parse::<u16>().is_ok() => format!("{}:{}", ns, self.name(node_class, name)),
+            _ => self.name(node_class, browse_name),
+        }
+    }
+
+    
+    pub fn node_id(&mut self, node_id: &NodeId) -> NodeId {
+        match &node_id.identifier {
+            Identifier::String(s) => NodeId::new(node_id.namespace, self.token("ID", s.as_ref())),
+            _ => node_id.clone(),
+        }
+    }
+
+    
+    pub fn node(&mut self, node: &BrowsedNode) -> BrowsedNode {
+        BrowsedNode {
+            node_id: self.node_id(&node.node_id),
+            browse_name: self.browse_name(node.node_class, &node.browse_name),
+            display_name: self.name(node.node_class, &node.display_name),
+            node_class: node.node_class,
+            type_definition: node.type_definition.as_ref().map(|id| self.node_id(id)),
+            has_children: node.has_children,
+            crawl_root: node.crawl_root.as_ref().map(|id| self.node_id(id)),
+            parent_id: node.parent_id.as_ref().map(|id| self.node_id(id)),
+        }
+    }
+
+    
+    pub fn nodes(&mut self, nodes: &[BrowsedNode]) -> Vec<BrowsedNode> {
+        nodes.iter().map(|node| self.node(node)).collect()
+    }
+
+    
+    pub fn node_id_text(&mut self, text: &str) -> String {
+        match text.parse::<NodeId>() {
+            Ok(node_id) => self.node_id(&node_id).to_string(),
+            Err(_) => text.to_string(),
+        }
+    }
+
+    
+    pub fn conflicts(&mut self, conflicts: &[CrawlConflict]) -> Vec<CrawlConflict> {
+        conflicts
+            .iter()
+            .map(|conflict| CrawlConflict { first: self.node(&conflict.first), second: self.node(&conflict.second) })
+            .collect()
+    }
+
+    
+    pub fn node_details(&mut self, details: &NodeDetails) -> NodeDetails {
+        let node_class = class_from_label(&details.node_class);
+        let mut anonymized = details.clone();
+        anonymized.node_id = self.node_id_text(&details.node_id);
+        anonymized.browse_name = self.browse_name(node_class, &details.browse_name);
+        anonymized.display_name = self.name(node_class, &details.display_name);
+        anonymized.type_definition = details.type_definition.as_deref().map(|id| self.node_id_text(id));
+        if let Some(Field::Value(Value::String(data_type))) = anonymized.attributes.get_mut("DataType") {
+            *data_type = self.node_id_text(data_type);
+        }
+        if let Some(Field::Value(Value::String(description))) = anonymized.attributes.get_mut("Description") {
+            if !description.is_empty() {
+                *description = self.token("DESC", description);
+            }
+        }
+        anonymized.forward_references = self.references(&details.forward_references);
+        anonymized.inverse_references = self.references(&details.inverse_references);
+        anonymized
+    }
+
+    
+    fn references(&mut self, field: &Field) -> Field {
+        let Field::Value(Value::Array(references)) = field else {
+            return field.clone();
+        };
+        let references = references
+            .iter()
+            .map(|reference| {
+                let mut reference = reference.clone();
+                let node_class = reference.get("nodeClass").and_then(Value::as_str).map_or(NodeClass::Unknown, class_from_label);
+                if let Some(Value::String(node_id)) = reference.get_mut("nodeId") {
+                    *node_id = self.node_id_text(node_id);
+                }
+                if let Some(Value::String(browse_name)) = reference.get_mut("browseName") {
+                    *browse_name = self.browse_name(node_class, browse_name);
+                }
+                if let Some(Value::String(display_name)) = reference.get_mut("displayName") {
+                    *display_name = self.name(node_class, display_name);
+                }
+                reference
+            })
+            .collect();
+        Field::Value(Value::Array(references))
+    }
+
+    
+    pub fn monitored_items(&mut self, items: &[MonitoredData]) -> Vec<MonitoredData> {
+        items
+            .iter()
+            .map(|item| {
+                let mut item = item.clone();
+                item.display_name = self.name(NodeClass::Variable, &item.display_name);
+                item.node_id = self.node_id(&item.node_id);
+                item
+            })
+            .collect()
+    }
+
+    
+    pub fn namespaces(&mut self, namespaces: &[String]) -> Vec<String> {
+        namespaces
+            .iter()
+            .map(|uri| if uri == STANDARD_NAMESPACE { uri.clone() } else { self.token("NS", uri) })
+            .collect()
+    }
+
+    
+    pub fn endpoint_url(&mut self, url: &str) -> String {
+        let Some(rest) = url.strip_prefix("opc.tcp://") else {
+            return url.to_string();
+        };
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => (host, Some(port)),
+            _ => (authority, None),
+        };
+        if host.is_empty() {
+            return url.to_string();
+        }
+        let host = self.token("HOST", host);
+        match port {
+            Some(port) => format!("opc.tcp://{}:{}{}", host, port, path),
+            None => format!("opc.tcp://{}{}", host, path),
+        }
+    }
+
+    
+    pub fn context(&mut self, context: &ExportContext) -> ExportContext {
+        let options = context
+            .options
+            .iter()
+            .map(|(name, value)| {
+                let ids: Option<Vec<NodeId>> = value.split(" | ").map(|part| part.parse::<NodeId>().ok()).collect();
+                let value = match ids {
+                    Some(ids) => ids.iter().map(|id| self.node_id(id).to_string()).collect::<Vec<_>>().join(" | "),
+                    None => value.clone(),
+                };
+                (name.clone(), value)
+            })
+            .collect();
+        ExportContext {
+            endpoint: context.endpoint.as_deref().map(|url| self.endpoint_url(url)),
+            options,
+            ..context.clone()
+        }
+    }
+
+    
+    pub fn server_profile(&mut self, profile: &ServerProfile) -> ServerProfile {
+        let mut profile = profile.clone();
+        for endpoint in profile.endpoint.iter_mut().chain(profile.discovered_endpoints.iter_mut()) {
+            endpoint.endpoint_url = self.endpoint_url(&endpoint.endpoint_url);
+        }
+        profile.namespaces = self.namespaces(&profile.namespaces);
+        profile
+    }
+
+    
+    pub fn mapping(&self) -> &BTreeMap<String, String> {
+        &self.mapping
+    }
+
+    
+    pub fn write_mapping(&self, export_path: &Path) -> Result<PathBuf> {
+        let path = mapping_path(export_path);
+        let mut wtr = csv::Writer::from_path(&path).context("Failed to create anonymization mapping file")?;
+        wtr.write_record(["token", "original"]).context("Failed to write anonymization mapping header")?;
+        for (token, original) in &self.mapping {
+            wtr.write_record([token, original]).context("Failed to write anonymization mapping")?;
+        }
+        wtr.flush().context("Failed to flush anonymization mapping file")?;
+        Ok(path)
+    }
+}
+
+
+pub fn read_mapping(path: &Path) -> Result<BTreeMap<String, String>> {
+    let mut reader = csv::Reader::from_path(path).context("Failed to open anonymization mapping file")?;
+    reader
+        .records()
+        .map(|record| {
+            let record = record.context("Failed to read anonymization mapping")?;
+            Ok((record.get(0).unwrap_or_default().to_string(), record.get(1).unwrap_or_default().to_string()))
+        })
+        .collect()
+}
+
+
+pub fn restore(text: &str, mapping: &BTreeMap<String, String>) -> String {
+    let mut tokens: Vec<(&String, &String)> = mapping.iter().collect();
+    tokens.sort_by_key(|(token, _)| std::cmp::Reverse(token.len()));
+    tokens
+        .into_iter()
+        .fold(text.to_string(), |text, (token, original)| text.replace(token.as_str(), original))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+    use crate::utils::test_nodes::TestNode;
+
+    fn node(id: &str, name: &str, node_class: NodeClass) -> BrowsedNode {
+        TestNode::new(NodeId::new(3, id), name)
+            .class(node_class)
+            .type_definition(NodeId::new(0, 63u32))
+            .crawl_root(NodeId::new(3, "AcmePlant"))
+            .build()
+    }
+
+    #[test]
+    fn test_tokens_are_deterministic_and_keep_namespace_indices() {
+        let original = node("AcmePlant.Line1.Speed", "Line1Speed", NodeClass::Variable);
+        let first = Anonymizer::default().node(&original);
+        let second = Anonymizer::default().node(&original);
+        assert_eq!(format!("{:?}", first), format!("{:?}", second));
+
+        assert_eq!(first.node_id.namespace, 3);
+        assert!(first.node_id.to_string().starts_with("ns=3;s=ID_"), "{}", first.node_id);
+        assert!(first.display_name.starts_with("VAR_") && first.display_name.len() == 4 + TOKEN_HEX_LEN);
+        assert_eq!(first.browse_name, format!("3:{}", first.display_name));
+        assert_eq!(first.type_definition, Some(NodeId::new(0, 63u32)));
+        assert!(!format!("{:?}", first).contains("Acme"));
+
+        let mut anonymizer = Anonymizer::default();
+        assert_eq!(anonymizer.node_id(&NodeId::new(2, 1001u32)), NodeId::new(2, 1001u32));
+        assert_eq!(anonymizer.namespaces(&[STANDARD_NAMESPACE.to_string()]), vec![STANDARD_NAMESPACE.to_string()]);
+    }
+
+    #[test]
+    fn test_endpoint_hostnames_are_replaced() {
+        let mut anonymizer = Anonymizer::default();
+        let url = anonymizer.endpoint_url("opc.tcp://plc01.acme.local:4840/UA/Server");
+        assert!(url.starts_with("opc.tcp://HOST_") && url.ends_with(":4840/UA/Server"), "{}", url);
+        assert!(!url.contains("acme"));
+        assert_eq!(anonymizer.endpoint_url("opc.tcp://plc01.acme.local:4840/UA/Server"), url);
+        assert_eq!(anonymizer.endpoint_url("not a url"), "not a url");
+
+        let context = ExportContext {
+            endpoint: Some("opc.tcp://plc01.acme.local:4840".into()),
+            ..Default::default()
+        }
+        .with_option("start_node", "ns=3;s=AcmePlant | i=85")
+        .with_option("max_depth", 5);
+        let context = anonymizer.context(&context);
+        assert!(!context.endpoint.as_deref().unwrap().contains("acme"));
+        assert!(context.options["start_node"].starts_with("ns=3;s=ID_") && context.options["start_node"].ends_with(" | i=85"));
+        assert_eq!(context.options["max_depth"], "5");
+    }
+
+    #[test]
+    fn test_colliding_prefixes_get_longer_tokens() {
+        let mut anonymizer = Anonymizer::default();
+        let hex: String = Sha256::digest(b"Speed").iter().map(|b| format!("{:02x}", b)).collect();
+        anonymizer.mapping.insert(format!("VAR_{}", &hex[..TOKEN_HEX_LEN]), "Other".into());
+
+        let token = anonymizer.name(NodeClass::Variable, "Speed");
+        assert_eq!(token, format!("VAR_{}", &hex[..TOKEN_HEX_LEN + 2]));
+        assert_eq!(anonymizer.mapping()[&token], "Speed");
+    }
+
+    #[test]
+    fn test_mapping_file_restores_originals() {
+        let dir = TestDir::new("anonymize");
+        let export_path = dir.join("crawl.csv");
+
+        let originals = vec![
+            node("AcmePlant.Line1", "Line1", NodeClass::Object),
+            node("AcmePlant.Line1.Speed", "Speed", NodeClass::Variable),
+        ];
+        let mut anonymizer = Anonymizer::default();
+        let anonymized = anonymizer.nodes(&originals);
+        let path = anonymizer.write_mapping(&export_path).unwrap();
+        assert_eq!(path, dir.join("crawl.csv.anonymization.csv"));
+
+        let mapping = read_mapping(&path).unwrap();
+        assert_eq!(&mapping, anonymizer.mapping());
+        for (original, anonymized) in originals.iter().zip(&anonymized) {
+            assert_eq!(restore(&anonymized.node_id.to_string(), &mapping), original.node_id.to_string());
+            assert_eq!(restore(&anonymized.browse_name, &mapping), original.browse_name);
+            assert_eq!(restore(&anonymized.display_name, &mapping), original.display_name);
+        }
+    }
+}
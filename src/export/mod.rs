@@ -2,132 +2,531 @@
 
 
 
-use std::path::Path;
+pub mod anonymize;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::fs::File;
+use std::io::{BufWriter, Write};
 use anyhow::{Context, Result};
-use serde::Serialize;
+use opcua::types::{BinaryEncodable, ContextOwned, Variant, VariantTypeId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 
-use crate::opcua::subscription::MonitoredData;
+use crate::opcua::subscription::{format_variant, MonitoredData};
 use crate::opcua::browser::BrowsedNode;
+use crate::opcua::crawler::CrawlConflict;
+use crate::opcua::certificates::CertificateEntry;
+use crate::opcua::node_details::NodeDetails;
+use crate::opcua::server_profile::ServerProfile;
+use crate::utils::timestamps::TimestampFormat;
+use anonymize::Anonymizer;
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberFormat {
+    
+    #[default]
+    FullPrecision,
+    
+    Fixed(u8),
+    
+    Shortest,
+}
+
+impl NumberFormat {
+    
+    fn apply(&self, full: f64, shortest: f64) -> f64 {
+        match self {
+            NumberFormat::FullPrecision => full,
+            NumberFormat::Fixed(decimals) => {
+                let factor = 10f64.powi(*decimals as i32);
+                (full * factor).round() / factor
+            }
+            NumberFormat::Shortest => shortest,
+        }
+    }
+
+    
+    fn format(&self, full: f64, shortest: f64) -> String {
+        if !full.is_finite() {
+            return non_finite_label(full).to_string();
+        }
+        match self {
+            NumberFormat::Fixed(decimals) => format!("{:.*}", *decimals as usize, full),
+            _ => self.apply(full, shortest).to_string(),
+        }
+    }
+}
+
+
+fn non_finite_label(value: f64) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value > 0.0 {
+        "Infinity"
+    } else {
+        "-Infinity"
+    }
+}
+
 
+fn float_parts(variant: &Variant) -> Option<(f64, f64)> {
+    match variant {
+        Variant::Float(v) => Some((*v as f64, v.to_string().parse().unwrap_or(*v as f64))),
+        Variant::Double(v) => Some((*v, *v)),
+        _ => None,
+    }
+}
+
+
+
+pub fn variant_to_json(variant: &Variant, format: NumberFormat) -> Value {
+    if let Some((full, shortest)) = float_parts(variant) {
+        if !full.is_finite() {
+            return Value::String(non_finite_label(full).to_string());
+        }
+        return serde_json::Number::from_f64(format.apply(full, shortest))
+            .map(Value::Number)
+            .unwrap_or(Value::Null);
+    }
+
+    match variant {
+        Variant::Empty => Value::Null,
+        Variant::Boolean(b) => Value::Bool(*b),
+        Variant::SByte(v) => Value::from(*v),
+        Variant::Byte(v) => Value::from(*v),
+        Variant::Int16(v) => Value::from(*v),
+        Variant::UInt16(v) => Value::from(*v),
+        Variant::Int32(v) => Value::from(*v),
+        Variant::UInt32(v) => Value::from(*v),
+        Variant::Int64(v) => Value::from(*v),
+        Variant::UInt64(v) => Value::from(*v),
+        other => Value::String(format_variant(other)),
+    }
+}
+
+
+pub fn variant_to_csv(variant: &Variant, format: NumberFormat) -> String {
+    match float_parts(variant) {
+        Some((full, shortest)) => format.format(full, shortest),
+        None => format_variant(variant),
+    }
+}
+
+
+pub fn variant_type_name(variant: &Variant) -> String {
+    match variant.type_id() {
+        VariantTypeId::Empty => "Empty".to_string(),
+        VariantTypeId::Scalar(t) => format!("{:?}", t),
+        VariantTypeId::Array(t, _) => format!("Array<{:?}>", t),
+    }
+}
+
+
+fn ua_json_body(variant: &Variant) -> Value {
+    if let Some((full, _)) = float_parts(variant) {
+        if !full.is_finite() {
+            return Value::String(non_finite_label(full).to_string());
+        }
+    }
+    match variant {
+        Variant::Int64(v) => Value::String(v.to_string()),
+        Variant::UInt64(v) => Value::String(v.to_string()),
+        Variant::String(s) => s.value().clone().map(Value::String).unwrap_or(Value::Null),
+        Variant::DateTime(dt) => Value::String(dt.as_chrono().to_rfc3339()),
+        Variant::Guid(g) => Value::String(g.to_string()),
+        Variant::ByteString(bs) => Value::String(bs.as_base64()),
+        Variant::NodeId(id) => Value::String(id.to_string()),
+        Variant::ExpandedNodeId(id) => Value::String(id.to_string()),
+        Variant::StatusCode(sc) => Value::from(sc.bits()),
+        Variant::QualifiedName(qn) => {
+            let mut body = serde_json::Map::new();
+            body.insert("Name".into(), Value::String(qn.name.to_string()));
+            if qn.namespace_index != 0 {
+                body.insert("Uri".into(), Value::from(qn.namespace_index));
+            }
+            Value::Object(body)
+        }
+        Variant::LocalizedText(lt) => {
+            let mut body = serde_json::Map::new();
+            if !lt.locale.is_empty() {
+                body.insert("Locale".into(), Value::String(lt.locale.to_string()));
+            }
+            body.insert("Text".into(), Value::String(lt.text.to_string()));
+            Value::Object(body)
+        }
+        other => variant_to_json(other, NumberFormat::FullPrecision),
+    }
+}
+
+
+pub fn variant_to_ua_json(variant: &Variant) -> Value {
+    let (type_id, dimensions) = match variant.type_id() {
+        VariantTypeId::Empty => return Value::Null,
+        VariantTypeId::Scalar(t) => (t, None),
+        VariantTypeId::Array(t, dims) => (t, dims),
+    };
+
+    let mut encoded = serde_json::Map::new();
+    encoded.insert("Type".into(), Value::from(type_id as u32));
+    match variant {
+        Variant::Array(array) => {
+            if let Some(dims) = dimensions.filter(|d| d.len() > 1) {
+                encoded.insert("Dimensions".into(), Value::from(dims.to_vec()));
+            }
+            encoded.insert("Body".into(), Value::Array(array.values.iter().map(ua_json_body).collect()));
+        }
+        scalar => {
+            encoded.insert("Body".into(), ua_json_body(scalar));
+        }
+    }
+    Value::Object(encoded)
+}
+
+
+pub fn variant_to_binary_hex(variant: &Variant) -> String {
+    let ctx = ContextOwned::default();
+    variant
+        .encode_to_vec(&ctx.context())
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
 #[derive(Serialize)]
-struct ExportItem<'a> {
+struct ExportItem<'a, V: Serialize> {
     name: &'a str,
     node_id: String,
-    value: String,
+    value: V,
+    unit: Option<&'a str>,
     status: String,
     timestamp: String,
+    timestamp_origin: Option<&'static str>,
 }
 
-impl<'a> From<&'a MonitoredData> for ExportItem<'a> {
-    fn from(item: &'a MonitoredData) -> Self {
+impl<'a, V: Serialize> ExportItem<'a, V> {
+    fn new(item: &'a MonitoredData, value: V, timestamps: &TimestampFormat) -> Self {
         Self {
             name: &item.display_name,
             node_id: item.node_id.to_string(),
-            value: item.value_string(),
+            value,
+            unit: item.unit.as_deref(),
             status: format!("{:?}", item.status),
-            timestamp: item.timestamp_string(),
+            timestamp: item.display_timestamp().map(|(dt, _)| timestamps.format(dt.as_chrono())).unwrap_or_else(|| "---".to_string()),
+            timestamp_origin: item.display_timestamp().map(|(_, origin)| origin.as_str()),
+        }
+    }
+}
+
+
+pub fn namespaces_side_file(path: &Path) -> std::path::PathBuf {
+    let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!("{}_namespaces.csv", stem))
+}
+
+
+pub const UNPARSEABLE_CERTIFICATE: &str = "unparseable";
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportKind {
+    WatchlistCsv,
+    WatchlistJson,
+    CrawlCsv,
+    CrawlJson,
+    CrawlJsonStream,
+    CrawlConflictsCsv,
+    NodeDetailsJson,
+    ServerProfileJson,
+    ServerProfileMarkdown,
+    CertificatesCsv,
+}
+
+impl ExportKind {
+    
+    pub fn columns(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ExportKind::WatchlistCsv => &[
+                ("name", "string"),
+                ("node_id", "node_id"),
+                ("value", "string"),
+                ("unit", "string"),
+                ("status", "status_code"),
+                ("timestamp", "timestamp"),
+                ("timestamp_origin", "string"),
+            ],
+            ExportKind::WatchlistJson => &[
+                ("name", "string"),
+                ("node_id", "node_id"),
+                ("value", "variant"),
+                ("unit", "string"),
+                ("status", "status_code"),
+                ("timestamp", "timestamp"),
+                ("timestamp_origin", "string"),
+            ],
+            ExportKind::CrawlCsv => &[
+                ("node_id", "node_id"),
+                ("browse_name", "qualified_name"),
+                ("display_name", "string"),
+                ("node_class", "node_class"),
+                ("namespace_uri", "string"),
+            ],
+            ExportKind::CrawlJson => &[
+                ("nodeId", "node_id"),
+                ("displayName", "string"),
+                ("nodeClass", "node_class"),
+            ],
+            ExportKind::CrawlJsonStream => &[
+                ("nodeId", "node_id"),
+                ("browseName", "qualified_name"),
+                ("displayName", "string"),
+                ("nodeClass", "node_class"),
+                ("typeDefinition", "node_id"),
+            ],
+            ExportKind::CrawlConflictsCsv => &[
+                ("node_id", "node_id"),
+                ("differences", "string"),
+                ("first_display_name", "string"),
+                ("first_browse_name", "qualified_name"),
+                ("first_node_class", "node_class"),
+                ("second_display_name", "string"),
+                ("second_browse_name", "qualified_name"),
+                ("second_node_class", "node_class"),
+            ],
+            ExportKind::NodeDetailsJson => &[
+                ("nodeId", "node_id"),
+                ("browseName", "qualified_name"),
+                ("displayName", "string"),
+                ("nodeClass", "node_class"),
+                ("attributes", "object"),
+                ("forwardReferences", "array"),
+                ("inverseReferences", "array"),
+            ],
+            ExportKind::ServerProfileJson | ExportKind::ServerProfileMarkdown => &[
+                ("generated_at", "timestamp"),
+                ("endpoint", "object"),
+                ("discovered_endpoints", "array"),
+                ("namespaces", "array"),
+                ("server_status", "object"),
+                ("build_info", "object"),
+                ("operation_limits", "object"),
+                ("client_renderer", "string"),
+            ],
+            ExportKind::CertificatesCsv => &[
+                ("store", "string"),
+                ("filename", "string"),
+                ("subject", "string"),
+                ("issuer", "string"),
+                ("not_before", "timestamp"),
+                ("not_after", "timestamp"),
+                ("sha256_thumbprint", "string"),
+            ],
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportContext {
+    
+    pub write_manifest: bool,
+    
+    pub anonymize: bool,
+    
+    pub endpoint: Option<String>,
+    
+    pub options: BTreeMap<String, String>,
+}
+
+impl ExportContext {
+    
+    pub fn with_option(mut self, name: &str, value: impl ToString) -> Self {
+        self.options.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestColumn {
+    pub name: &'static str,
+    #[serde(rename = "type")]
+    pub data_type: &'static str,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportManifest {
+    pub manifest_version: u32,
+    pub tool: &'static str,
+    pub tool_version: &'static str,
+    pub exported_at: String,
+    pub endpoint: Option<String>,
+    pub kind: ExportKind,
+    pub file: String,
+    pub columns: Vec<ManifestColumn>,
+    pub row_count: usize,
+    pub options: BTreeMap<String, String>,
+}
+
+impl ExportManifest {
+    
+    pub fn new(kind: ExportKind, path: &Path, row_count: usize, context: &ExportContext) -> Self {
+        Self {
+            manifest_version: 1,
+            tool: env!("CARGO_PKG_NAME"),
+            tool_version: env!("CARGO_PKG_VERSION"),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            endpoint: context.endpoint.clone(),
+            kind,
+            file: path.file_name().map(|s| s.to_string_lossy().to_string()).unwrap_or_default(),
+            columns: kind
+                .columns()
+                .iter()
+                .map(|(name, data_type)| ManifestColumn { name, data_type })
+                .collect(),
+            row_count,
+            options: context.options.clone(),
         }
     }
 }
 
 
+pub fn manifest_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!("{}.manifest.json", name))
+}
+
+
 pub struct ExportEngine;
 
 impl ExportEngine {
     
-    pub fn export_watchlist_to_csv(items: &[MonitoredData], path: &Path) -> Result<()> {
+    pub fn export_watchlist_to_csv(items: &[MonitoredData], path: &Path, format: NumberFormat, timestamps: &TimestampFormat, context: &ExportContext) -> Result<()> {
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized;
+        let items = match anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = anonymizer.monitored_items(items);
+                &anonymized[..]
+            }
+            None => items,
+        };
         let mut wtr = csv::Writer::from_path(path)
             .context("Failed to create CSV writer")?;
 
         for item in items {
-            let export_item = ExportItem::from(item);
+            let value = item.value.as_ref()
+                .map(|v| variant_to_csv(v, format))
+                .unwrap_or_else(|| item.value_string());
+            let export_item = ExportItem::new(item, value, timestamps);
             wtr.serialize(export_item)
                 .context("Failed to serialize item to CSV")?;
         }
 
         wtr.flush().context("Failed to flush CSV writer")?;
-        Ok(())
+        Self::finish_export(ExportKind::WatchlistCsv, path, items.len(), context, anonymizer)
     }
 
     
-    pub fn export_watchlist_to_json(items: &[MonitoredData], path: &Path) -> Result<()> {
-        let export_items: Vec<ExportItem> = items.iter().map(ExportItem::from).collect();
+    pub fn export_watchlist_to_json(items: &[MonitoredData], path: &Path, format: NumberFormat, timestamps: &TimestampFormat, context: &ExportContext) -> Result<()> {
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized;
+        let items = match anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = anonymizer.monitored_items(items);
+                &anonymized[..]
+            }
+            None => items,
+        };
+        let export_items: Vec<ExportItem<Value>> = items
+            .iter()
+            .map(|item| {
+                let value = item.value.as_ref()
+                    .map(|v| variant_to_json(v, format))
+                    .unwrap_or(Value::Null);
+                ExportItem::new(item, value, timestamps)
+            })
+            .collect();
         
         let file = File::create(path).context("Failed to create JSON file")?;
         serde_json::to_writer_pretty(file, &export_items)
             .context("Failed to write JSON data")?;
             
-        Ok(())
+        let row_count = export_items.len();
+        Self::finish_export(ExportKind::WatchlistJson, path, row_count, context, anonymizer)
     }
 
     
     
-    pub fn export_crawl_result_to_json(nodes: &[BrowsedNode], path: &Path) -> Result<()> {
-        use serde_json::{json, Map, Value};
-        use crate::opcua::browser::NodeClass;
-        
-        
+    pub fn export_crawl_result_to_json(nodes: &[BrowsedNode], namespaces: &[String], path: &Path, context: &ExportContext) -> Result<()> {
+        use serde_json::Map;
+
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized;
+        let (nodes, namespaces) = match anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = (anonymizer.nodes(nodes), anonymizer.namespaces(namespaces));
+                (&anonymized.0[..], &anonymized.1[..])
+            }
+            None => (nodes, namespaces),
+        };
         
-        let mut root = Map::new();
+        let root = crawl_tree(nodes);
         
-        for node in nodes {
-            
-            
-            let name = if node.browse_name.contains(':') {
-                
-                node.browse_name.split(':').next_back().unwrap_or(&node.browse_name)
-            } else {
-                &node.browse_name
-            };
-            
-            
-            let node_entry = json!({
-                "nodeId": node.node_id.to_string(),
-                "displayName": node.display_name,
-                "nodeClass": node.node_class.to_string()
-            });
-            
-            
-            
-            match node.node_class {
-                NodeClass::Object | NodeClass::ObjectType | NodeClass::View => {
-                    
-                    let mut obj_map = Map::new();
-                    obj_map.insert("_nodeId".to_string(), Value::String(node.node_id.to_string()));
-                    obj_map.insert("_nodeClass".to_string(), Value::String(node.node_class.to_string()));
-                    root.insert(name.to_string(), Value::Object(obj_map));
-                }
-                NodeClass::Variable => {
-                    
-                    root.insert(name.to_string(), node_entry);
-                }
-                _ => {
-                    
-                    root.insert(name.to_string(), node_entry);
-                }
-            }
+        #[derive(Serialize)]
+        struct CrawlJsonExport {
+            #[serde(rename = "_namespaces", skip_serializing_if = "Map::is_empty")]
+            namespaces: Map<String, Value>,
+            #[serde(flatten)]
+            nodes: Map<String, Value>,
         }
-        
+
+        let row_count = nodes.len();
+        let export = CrawlJsonExport {
+            namespaces: namespaces
+                .iter()
+                .enumerate()
+                .map(|(i, uri)| (i.to_string(), Value::String(uri.clone())))
+                .collect(),
+            nodes: root,
+        };
+
         let file = File::create(path).context("Failed to create JSON file")?;
-        serde_json::to_writer_pretty(file, &Value::Object(root))
+        serde_json::to_writer_pretty(file, &export)
             .context("Failed to write JSON data")?;
 
-        Ok(())
+        Self::finish_export(ExportKind::CrawlJson, path, row_count, context, anonymizer)
     }
 
     
-    pub fn export_crawl_result_to_csv(nodes: &[BrowsedNode], path: &Path) -> Result<()> {
+    pub fn export_crawl_result_to_csv(nodes: &[BrowsedNode], namespaces: &[String], path: &Path, context: &ExportContext) -> Result<()> {
         #[derive(Serialize)]
         struct CrawlNodeExport<'a> {
             node_id: String,
             browse_name: &'a str,
             display_name: &'a str,
             node_class: String,
+            namespace_uri: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start_node: Option<String>,
         }
 
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized;
+        let (nodes, namespaces) = match anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = (anonymizer.nodes(nodes), anonymizer.namespaces(namespaces));
+                (&anonymized.0[..], &anonymized.1[..])
+            }
+            None => (nodes, namespaces),
+        };
+
         let mut wtr = csv::Writer::from_path(path)
             .context("Failed to create CSV writer")?;
 
@@ -137,12 +536,979 @@ impl ExportEngine {
                 browse_name: &node.browse_name,
                 display_name: &node.display_name,
                 node_class: node.node_class.to_string(),
+                namespace_uri: namespaces
+                    .get(node.node_id.namespace as usize)
+                    .map(String::as_str)
+                    .unwrap_or(""),
+                start_node: node.crawl_root.as_ref().map(|id| id.to_string()),
             };
             wtr.serialize(export_node)
                 .context("Failed to serialize node to CSV")?;
         }
 
         wtr.flush().context("Failed to flush CSV writer")?;
+
+        if !namespaces.is_empty() {
+            let mut ns_wtr = csv::Writer::from_path(namespaces_side_file(path))
+                .context("Failed to create namespaces CSV writer")?;
+            ns_wtr.write_record(["index", "uri"]).context("Failed to write namespaces header")?;
+            for (i, uri) in namespaces.iter().enumerate() {
+                ns_wtr.write_record([i.to_string().as_str(), uri])
+                    .context("Failed to write namespace to CSV")?;
+            }
+            ns_wtr.flush().context("Failed to flush namespaces CSV writer")?;
+        }
+        Self::finish_export(ExportKind::CrawlCsv, path, nodes.len(), context, anonymizer)
+    }
+
+    
+    pub fn export_crawl_conflicts_to_csv(conflicts: &[CrawlConflict], path: &Path, context: &ExportContext) -> Result<()> {
+        #[derive(Serialize)]
+        struct ConflictExport<'a> {
+            node_id: String,
+            differences: String,
+            first_display_name: &'a str,
+            first_browse_name: &'a str,
+            first_node_class: String,
+            second_display_name: &'a str,
+            second_browse_name: &'a str,
+            second_node_class: String,
+        }
+
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized = anonymizer.as_mut().map(|anonymizer| anonymizer.conflicts(conflicts));
+        let rows = anonymized.as_deref().unwrap_or(conflicts);
+
+        let mut wtr = csv::Writer::from_path(path)
+            .context("Failed to create CSV writer")?;
+
+        for (original, conflict) in conflicts.iter().zip(rows) {
+            let (first, second) = (&conflict.first, &conflict.second);
+            wtr.serialize(ConflictExport {
+                node_id: first.node_id.to_string(),
+                differences: original.differences().join(";"),
+                first_display_name: &first.display_name,
+                first_browse_name: &first.browse_name,
+                first_node_class: first.node_class.to_string(),
+                second_display_name: &second.display_name,
+                second_browse_name: &second.browse_name,
+                second_node_class: second.node_class.to_string(),
+            })
+            .context("Failed to serialize conflict to CSV")?;
+        }
+
+        wtr.flush().context("Failed to flush CSV writer")?;
+        Self::finish_export(ExportKind::CrawlConflictsCsv, path, conflicts.len(), context, anonymizer)
+    }
+
+    
+    fn finish_export(kind: ExportKind, path: &Path, row_count: usize, context: &ExportContext, anonymizer: Option<Anonymizer>) -> Result<()> {
+        let Some(mut anonymizer) = anonymizer else {
+            return Self::write_manifest(kind, path, row_count, context);
+        };
+        let context = anonymizer.context(context);
+        Self::write_manifest(kind, path, row_count, &context)?;
+        anonymizer.write_mapping(path).map(|_| ())
+    }
+
+    
+    pub fn write_manifest(kind: ExportKind, path: &Path, row_count: usize, context: &ExportContext) -> Result<()> {
+        if !context.write_manifest {
+            return Ok(());
+        }
+        let manifest = ExportManifest::new(kind, path, row_count, context);
+        let file = File::create(manifest_path(path)).context("Failed to create manifest file")?;
+        serde_json::to_writer_pretty(file, &manifest).context("Failed to write manifest")
+    }
+
+    
+    pub fn export_server_profile(profile: &ServerProfile, path: &Path, context: &ExportContext) -> Result<()> {
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized;
+        let profile = match anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = anonymizer.server_profile(profile);
+                &anonymized
+            }
+            None => profile,
+        };
+        let is_markdown = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("md"));
+        let kind = if is_markdown {
+            std::fs::write(path, profile.to_markdown()).context("Failed to write Markdown report")?;
+            ExportKind::ServerProfileMarkdown
+        } else {
+            let file = File::create(path).context("Failed to create JSON file")?;
+            serde_json::to_writer_pretty(file, profile).context("Failed to write JSON data")?;
+            ExportKind::ServerProfileJson
+        };
+        Self::finish_export(kind, path, 1, context, anonymizer)
+    }
+
+    
+    pub fn export_certificates_to_csv(entries: &[CertificateEntry], path: &Path, context: &ExportContext) -> Result<()> {
+        #[derive(Serialize)]
+        struct CertificateExport<'a> {
+            store: &'static str,
+            filename: &'a str,
+            subject: &'a str,
+            issuer: &'a str,
+            not_before: String,
+            not_after: String,
+            sha256_thumbprint: &'a str,
+        }
+
+        let mut wtr = csv::Writer::from_path(path)
+            .context("Failed to create CSV writer")?;
+
+        for entry in entries {
+            let export_cert = match &entry.details {
+                Some(details) => CertificateExport {
+                    store: entry.store.name(),
+                    filename: &entry.info.name,
+                    subject: &details.subject,
+                    issuer: &details.issuer,
+                    not_before: details.not_before.to_rfc3339(),
+                    not_after: details.not_after.to_rfc3339(),
+                    sha256_thumbprint: &details.sha256_thumbprint,
+                },
+                None => CertificateExport {
+                    store: entry.store.name(),
+                    filename: &entry.info.name,
+                    subject: UNPARSEABLE_CERTIFICATE,
+                    issuer: "",
+                    not_before: String::new(),
+                    not_after: String::new(),
+                    sha256_thumbprint: "",
+                },
+            };
+            wtr.serialize(export_cert)
+                .context("Failed to serialize certificate to CSV")?;
+        }
+
+        wtr.flush().context("Failed to flush CSV writer")?;
+        Self::write_manifest(ExportKind::CertificatesCsv, path, entries.len(), context)
+    }
+
+    
+    pub fn export_node_details(details: &NodeDetails, path: &Path, context: &ExportContext) -> Result<()> {
+        let mut anonymizer = context.anonymize.then(Anonymizer::default);
+        let anonymized = anonymizer.as_mut().map(|anonymizer| anonymizer.node_details(details));
+        let file = File::create(path).context("Failed to create JSON file")?;
+        serde_json::to_writer_pretty(BufWriter::new(file), anonymized.as_ref().unwrap_or(details)).context("Failed to write JSON data")?;
+        Self::finish_export(ExportKind::NodeDetailsJson, path, 1, context, anonymizer)
+    }
+}
+
+
+pub struct CrawlJsonStream<W: Write> {
+    
+    writer: W,
+    
+    written: usize,
+    
+    closed: bool,
+    
+    anonymizer: Option<Anonymizer>,
+}
+
+impl CrawlJsonStream<BufWriter<File>> {
+    
+    pub fn create(path: &Path, anonymize: bool) -> Result<Self> {
+        let file = File::create(path).context("Failed to create JSON file")?;
+        let mut stream = Self::new(BufWriter::new(file))?;
+        stream.anonymizer = anonymize.then(Anonymizer::default);
+        Ok(stream)
+    }
+}
+
+impl<W: Write> CrawlJsonStream<W> {
+    
+    pub fn new(mut writer: W) -> Result<Self> {
+        writer.write_all(b"[").context("Failed to write JSON data")?;
+        Ok(Self { writer, written: 0, closed: false, anonymizer: None })
+    }
+
+    
+    pub fn write_nodes(&mut self, nodes: &[BrowsedNode]) -> Result<()> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct StreamedNode<'a> {
+            node_id: String,
+            browse_name: &'a str,
+            display_name: &'a str,
+            node_class: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            type_definition: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start_node: Option<String>,
+        }
+
+        let anonymized;
+        let nodes = match self.anonymizer.as_mut() {
+            Some(anonymizer) => {
+                anonymized = anonymizer.nodes(nodes);
+                &anonymized[..]
+            }
+            None => nodes,
+        };
+
+        for node in nodes {
+            let separator: &[u8] = if self.written == 0 { b"\n  " } else { b",\n  " };
+            self.writer.write_all(separator).context("Failed to write JSON data")?;
+            let streamed = StreamedNode {
+                node_id: node.node_id.to_string(),
+                browse_name: &node.browse_name,
+                display_name: &node.display_name,
+                node_class: node.node_class.to_string(),
+                type_definition: node.type_definition.as_ref().map(|id| id.to_string()),
+                start_node: node.crawl_root.as_ref().map(|id| id.to_string()),
+            };
+            serde_json::to_writer(&mut self.writer, &streamed).context("Failed to write JSON data")?;
+            self.written += 1;
+        }
         Ok(())
     }
+
+    
+    pub fn finish(mut self) -> Result<usize> {
+        self.close()
+    }
+
+    
+    pub fn finish_export(mut self, path: &Path, context: &ExportContext) -> Result<usize> {
+        let count = self.close()?;
+        ExportEngine::finish_export(ExportKind::CrawlJsonStream, path, count, context, self.anonymizer.take())?;
+        Ok(count)
+    }
+
+    fn close(&mut self) -> Result<usize> {
+        if !self.closed {
+            self.closed = true;
+            self.writer.write_all(b"\n]\n").context("Failed to write JSON data")?;
+            self.writer.flush().context("Failed to flush JSON file")?;
+        }
+        Ok(self.written)
+    }
+}
+
+impl<W: Write> Drop for CrawlJsonStream<W> {
+    fn drop(&mut self) {
+        
+        if let Err(e) = self.close() {
+            tracing::warn!("Failed to close streamed crawl export: {:#}", e);
+        }
+    }
+}
+
+
+fn crawl_tree(nodes: &[BrowsedNode]) -> serde_json::Map<String, Value> {
+    use std::collections::HashMap;
+
+    let index: HashMap<&opcua::types::NodeId, usize> = nodes.iter().enumerate().map(|(i, node)| (&node.node_id, i)).collect();
+    let parent_of = |i: usize| nodes[i].parent_id.as_ref().and_then(|id| index.get(id).copied());
+    
+    let on_cycle = |i: usize| {
+        let mut current = parent_of(i);
+        for _ in 0..nodes.len() {
+            match current {
+                Some(parent) if parent == i => return true,
+                Some(parent) => current = parent_of(parent),
+                None => return false,
+            }
+        }
+        false
+    };
+
+    let mut children: HashMap<Option<usize>, Vec<usize>> = HashMap::new();
+    for i in 0..nodes.len() {
+        let parent = if on_cycle(i) { None } else { parent_of(i) };
+        children.entry(parent).or_default().push(i);
+    }
+    crawl_tree_level(nodes, &children, None)
+}
+
+
+fn crawl_tree_level(
+    nodes: &[BrowsedNode],
+    children: &std::collections::HashMap<Option<usize>, Vec<usize>>,
+    parent: Option<usize>,
+) -> serde_json::Map<String, Value> {
+    use crate::opcua::browser::NodeClass;
+
+    let siblings = children.get(&parent).map(Vec::as_slice).unwrap_or_default();
+
+    let name = |node: &BrowsedNode| -> String {
+        match node.browse_name.split_once(':') {
+            Some((ns, name)) if ns.parse::<u16>().is_ok() => name.to_string(),
+            _ => node.browse_name.clone(),
+        }
+    };
+    let mut name_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for i in siblings {
+        *name_counts.entry(name(&nodes[*i])).or_default() += 1;
+    }
+
+    let mut level = serde_json::Map::new();
+    for &i in siblings {
+        let node = &nodes[i];
+        let mut key = name(node);
+        if name_counts[&key] > 1 {
+            key = format!("{} ({})", key, node.node_id);
+        }
+
+        let nested = crawl_tree_level(nodes, children, Some(i));
+        let is_folder = matches!(node.node_class, NodeClass::Object | NodeClass::ObjectType | NodeClass::View);
+        let entry = if is_folder || !nested.is_empty() {
+            
+            let mut folder = serde_json::Map::new();
+            folder.insert("_nodeId".to_string(), Value::String(node.node_id.to_string()));
+            folder.insert("_nodeClass".to_string(), Value::String(node.node_class.to_string()));
+            if let Some(root) = &node.crawl_root {
+                folder.insert("_startNode".to_string(), Value::String(root.to_string()));
+            }
+            folder.extend(nested);
+            Value::Object(folder)
+        } else {
+            let mut leaf = serde_json::json!({
+                "nodeId": node.node_id.to_string(),
+                "displayName": node.display_name,
+                "nodeClass": node.node_class.to_string()
+            });
+            if let Some(root) = &node.crawl_root {
+                leaf["startNode"] = Value::String(root.to_string());
+            }
+            leaf
+        };
+        level.insert(key, entry);
+    }
+    level
+}
+
+
+#[derive(Debug, Default)]
+pub struct TagListImport {
+    
+    pub entries: Vec<(opcua::types::NodeId, String)>,
+    
+    pub invalid: Vec<(usize, String)>,
+}
+
+
+pub fn parse_tag_list(content: &str) -> TagListImport {
+    use opcua::types::NodeId;
+
+    let mut import = TagListImport::default();
+    let mut node_column = None;
+    let mut name_column = None;
+    let mut header_checked = false;
+    let mut seen = std::collections::HashSet::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line
+            .split([',', '\t'])
+            .map(|f| f.trim().trim_matches('"'))
+            .collect();
+
+        if !header_checked {
+            header_checked = true;
+            let header: Vec<String> = fields.iter().map(|f| f.to_lowercase().replace(['_', ' '], "")).collect();
+            if let Some(col) = header.iter().position(|f| f == "nodeid") {
+                node_column = Some(col);
+                name_column = header.iter().position(|f| f == "name" || f == "displayname");
+                continue;
+            }
+        }
+
+        let parsed = match node_column {
+            Some(col) => fields.get(col).and_then(|f| f.parse::<NodeId>().ok()),
+            None => line.parse::<NodeId>().ok()
+                .or_else(|| fields.first().and_then(|f| f.parse::<NodeId>().ok())),
+        };
+
+        match parsed {
+            Some(node_id) => {
+                if !seen.insert(node_id.clone()) {
+                    continue;
+                }
+                let name = name_column
+                    .and_then(|col| fields.get(col))
+                    .filter(|n| !n.is_empty())
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| node_id.to_string());
+                import.entries.push((node_id, name));
+            }
+            None => import.invalid.push((index + 1, line.to_string())),
+        }
+    }
+
+    import
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    #[test]
+    fn test_export_node_details_keeps_unreadable_fields() {
+        use crate::opcua::browser::NodeClass;
+        let node = BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, "Pump.Speed"),
+            browse_name: "2:Speed".to_string(),
+            display_name: "Speed".to_string(),
+            node_class: NodeClass::Variable,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        };
+        let details = NodeDetails::unreadable(&node, "Not connected");
+
+        let dir = TestDir::new("node_details");
+        let path = dir.join("node.json");
+        ExportEngine::export_node_details(&details, &path, &ExportContext::default()).unwrap();
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(written["nodeId"], "ns=2;s=Pump.Speed");
+        assert_eq!(written["attributes"]["DataType"]["error"], "Not connected");
+        assert_eq!(written["inverseReferences"]["error"], "Not connected");
+    }
+
+    #[test]
+    fn test_crawl_json_stream_is_valid_array() {
+        use crate::opcua::browser::NodeClass;
+        let node = |id: &str, class: NodeClass| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, id),
+            browse_name: format!("2:{}", id),
+            display_name: id.to_string(),
+            node_class: class,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        };
+
+        let empty = CrawlJsonStream::new(Vec::new()).unwrap();
+        assert_eq!(empty.finish().unwrap(), 0);
+
+        let mut buffer = Vec::new();
+        let mut stream = CrawlJsonStream::new(&mut buffer).unwrap();
+        stream.write_nodes(&[node("Line1", NodeClass::Object)]).unwrap();
+        stream.write_nodes(&[node("Speed", NodeClass::Variable), node("Temp", NodeClass::Variable)]).unwrap();
+        assert_eq!(stream.finish().unwrap(), 3);
+
+        let parsed: Value = serde_json::from_slice(&buffer).unwrap();
+        let nodes = parsed.as_array().unwrap();
+        assert_eq!(nodes.len(), 3);
+
+        let mut cancelled = Vec::new();
+        {
+            let mut stream = CrawlJsonStream::new(&mut cancelled).unwrap();
+            stream.write_nodes(&[node("Line1", NodeClass::Object)]).unwrap();
+        }
+        let parsed_cancelled: Value = serde_json::from_slice(&cancelled).unwrap();
+        assert_eq!(parsed_cancelled.as_array().unwrap().len(), 1);
+        assert_eq!(nodes[1]["nodeId"], "ns=2;s=Speed");
+        assert_eq!(nodes[2]["nodeClass"], "Variable");
+        assert!(nodes[0].get("typeDefinition").is_none());
+    }
+
+    #[test]
+    fn test_parse_plain_tag_list() {
+        let import = parse_tag_list("# pumps\nns=2;s=Pump.Speed\n\nns=2;i=1001\nnot a node\nns=2;s=Pump.Speed\n");
+        assert_eq!(import.entries.len(), 2);
+        assert_eq!(import.entries[1].0, opcua::types::NodeId::new(2, 1001u32));
+        assert_eq!(import.entries[0].1, "ns=2;s=Pump.Speed");
+        assert_eq!(import.invalid, vec![(5, "not a node".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_exported_watchlist_csv() {
+        let csv = "name,node_id,value,status,timestamp\nSpeed,ns=2;s=Pump.Speed,1.5,Good,---\nBroken,xyz,0,Good,---\n";
+        let import = parse_tag_list(csv);
+        assert_eq!(import.entries, vec![(opcua::types::NodeId::new(2, "Pump.Speed"), "Speed".to_string())]);
+        assert_eq!(import.invalid.len(), 1);
+        assert_eq!(import.invalid[0].0, 3);
+    }
+
+    #[test]
+    fn test_export_certificates_to_csv() {
+        use crate::opcua::certificates::{CertStore, CertificateEntry, CertificateInfo};
+        use opcua::crypto::{X509, X509Data};
+
+        let dir = TestDir::new("cert_export");
+
+        let mut data = X509Data::sample_cert();
+        data.key_size = 1024;
+        data.common_name = "Test Server".to_string();
+        let (cert, _) = X509::cert_and_pkey(&data).unwrap();
+        let der = cert.to_der().unwrap();
+        std::fs::write(dir.join("server.der"), &der).unwrap();
+        std::fs::write(dir.join("broken.der"), b"not a certificate").unwrap();
+
+        let entries: Vec<CertificateEntry> = [(CertStore::Trusted, "server.der"), (CertStore::Rejected, "broken.der")]
+            .into_iter()
+            .map(|(store, name)| CertificateEntry::load(store, CertificateInfo { path: dir.join(name), name: name.to_string() }))
+            .collect();
+        let path = dir.join("inventory.csv");
+        ExportEngine::export_certificates_to_csv(&entries, &path, &ExportContext::default()).unwrap();
+
+        let mut rows = csv::Reader::from_path(&path).unwrap();
+        let headers = rows.headers().unwrap().clone();
+        assert_eq!(headers.iter().collect::<Vec<_>>(), ["store", "filename", "subject", "issuer", "not_before", "not_after", "sha256_thumbprint"]);
+        let records: Vec<csv::StringRecord> = rows.records().map(|r| r.unwrap()).collect();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(&records[0][0], "trusted");
+        assert!(records[0][2].contains("CN=Test Server"));
+        assert_eq!(records[0][2], records[0][3]);
+        assert_eq!(records[0][6].len(), 64);
+        assert_eq!(&records[1][0], "rejected");
+        assert_eq!(&records[1][2], UNPARSEABLE_CERTIFICATE);
+        assert_eq!(&records[1][6], "");
+    }
+
+    #[test]
+    fn test_crawl_csv_records_start_node_for_multi_root_crawls() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("crawl_roots");
+        let node = |id: &str, root: &str| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, id),
+            browse_name: format!("2:{}", id),
+            display_name: id.to_string(),
+            node_class: NodeClass::Variable,
+            type_definition: None,
+            has_children: false,
+            crawl_root: Some(opcua::types::NodeId::new(2, root)),
+            parent_id: None,
+        };
+        let nodes = vec![node("Speed", "Plant"), node("Power", "Energy")];
+
+        let csv_path = dir.join("crawl.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &[], &csv_path, &ExportContext::default()).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        assert!(csv.lines().next().unwrap().ends_with(",namespace_uri,start_node"));
+        assert!(csv.lines().nth(2).unwrap().ends_with(",ns=2;s=Energy"));
+    }
+
+    #[test]
+    fn test_crawl_conflicts_csv_lists_both_variants() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("crawl_conflicts");
+        let node = |display_name: &str, node_class: NodeClass| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, "Line1.Speed"),
+            browse_name: "2:Speed".to_string(),
+            display_name: display_name.to_string(),
+            node_class,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        };
+        let conflicts = vec![CrawlConflict {
+            first: node("Speed", NodeClass::Variable),
+            second: node("Line 1 Speed", NodeClass::Object),
+        }];
+
+        let csv_path = dir.join("conflicts.csv");
+        ExportEngine::export_crawl_conflicts_to_csv(&conflicts, &csv_path, &ExportContext::default()).unwrap();
+        let mut reader = csv::Reader::from_path(&csv_path).unwrap();
+        let records: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][0], "ns=2;s=Line1.Speed");
+        assert_eq!(&records[0][1], "display_name;node_class");
+        assert_eq!(&records[0][2], "Speed");
+        assert_eq!(&records[0][5], "Line 1 Speed");
+        assert_eq!(&records[0][3], &records[0][6]);
+        assert_ne!(&records[0][4], &records[0][7]);
+    }
+
+    #[test]
+    fn test_conflicts_and_node_details_are_anonymized() {
+        use crate::opcua::browser::NodeClass;
+        use crate::opcua::node_details::Field;
+
+        let dir = TestDir::new("anon_details");
+        let node = |display_name: &str, node_class: NodeClass| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, "AcmeLine.Speed"),
+            browse_name: "2:AcmeSpeed".to_string(),
+            display_name: display_name.to_string(),
+            node_class,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        };
+        let context = ExportContext { anonymize: true, write_manifest: true, ..Default::default() };
+
+        let conflicts = vec![CrawlConflict { first: node("Acme Speed", NodeClass::Variable), second: node("Acme Line Speed", NodeClass::Variable) }];
+        let csv_path = dir.join("conflicts.csv");
+        ExportEngine::export_crawl_conflicts_to_csv(&conflicts, &csv_path, &context).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        assert!(!csv.contains("Acme"), "{}", csv);
+        assert!(csv.lines().nth(1).unwrap().starts_with("ns=2;s=ID_"));
+        assert!(csv.contains(",display_name,"));
+        let mapping = anonymize::read_mapping(&anonymize::mapping_path(&csv_path)).unwrap();
+        assert!(anonymize::restore(&csv, &mapping).contains("Acme Line Speed"));
+        assert!(manifest_path(&csv_path).exists());
+
+        let mut details = NodeDetails::unreadable(&node("Acme Speed", NodeClass::Variable), "Not connected");
+        details.attributes.insert("Description", Field::Value(Value::String("Acme pump speed".into())));
+        details.forward_references = Field::Value(serde_json::json!([{
+            "referenceType": "HasComponent",
+            "nodeId": "ns=2;s=AcmeLine.Speed.Setpoint",
+            "browseName": "2:AcmeSetpoint",
+            "displayName": "Acme Setpoint",
+            "nodeClass": "Variable",
+        }]));
+        let json_path = dir.join("details.json");
+        ExportEngine::export_node_details(&details, &json_path, &context).unwrap();
+        let json = std::fs::read_to_string(&json_path).unwrap();
+        assert!(!json.contains("Acme"), "{}", json);
+        let written: Value = serde_json::from_str(&json).unwrap();
+        assert!(written["displayName"].as_str().unwrap().starts_with("VAR_"));
+        assert_eq!(written["forwardReferences"][0]["referenceType"], "HasComponent");
+        assert!(anonymize::mapping_path(&json_path).exists());
+    }
+
+    #[test]
+    fn test_crawl_exports_embed_namespaces() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("crawl_ns");
+        let nodes = vec![BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, "Pump.Speed"),
+            browse_name: "2:Speed".to_string(),
+            display_name: "Speed".to_string(),
+            node_class: NodeClass::Variable,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        }];
+        let namespaces = vec!["http://opcfoundation.org/UA/".to_string(), "urn:local".to_string(), "urn:pumps".to_string()];
+
+        let json_path = dir.join("crawl.json");
+        ExportEngine::export_crawl_result_to_json(&nodes, &namespaces, &json_path, &ExportContext::default()).unwrap();
+        let json = std::fs::read_to_string(&json_path).unwrap();
+        assert!(json.trim_start_matches(['{', '\n', ' ']).starts_with("\"_namespaces\""));
+        let parsed: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["_namespaces"]["2"], "urn:pumps");
+        assert_eq!(parsed["Speed"]["nodeId"], "ns=2;s=Pump.Speed");
+
+        let csv_path = dir.join("crawl.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &namespaces, &csv_path, &ExportContext::default()).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        assert!(csv.lines().next().unwrap().ends_with(",namespace_uri"));
+        assert!(csv.lines().nth(1).unwrap().ends_with(",urn:pumps"));
+        let side = std::fs::read_to_string(namespaces_side_file(&csv_path)).unwrap();
+        assert_eq!(side.lines().nth(3), Some("2,urn:pumps"));
+
+        let bare_path = dir.join("bare.json");
+        ExportEngine::export_crawl_result_to_json(&nodes, &[], &bare_path, &ExportContext::default()).unwrap();
+        assert!(!std::fs::read_to_string(&bare_path).unwrap().contains("_namespaces"));
+    }
+
+    #[test]
+    fn test_anonymized_crawl_export_round_trips_through_mapping() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("crawl_anon");
+        let nodes = vec![BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, "AcmePump.Speed"),
+            browse_name: "2:AcmeSpeed".to_string(),
+            display_name: "Acme Speed".to_string(),
+            node_class: NodeClass::Variable,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        }];
+        let namespaces = vec!["http://opcfoundation.org/UA/".to_string(), "urn:local".to_string(), "urn:acme-plc01:pumps".to_string()];
+        let context = ExportContext {
+            write_manifest: true,
+            anonymize: true,
+            endpoint: Some("opc.tcp://acme-plc01:4840".to_string()),
+            ..Default::default()
+        };
+
+        let csv_path = dir.join("crawl.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &namespaces, &csv_path, &context).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        let manifest = std::fs::read_to_string(manifest_path(&csv_path)).unwrap();
+        let side = std::fs::read_to_string(namespaces_side_file(&csv_path)).unwrap();
+        for text in [&csv, &manifest, &side] {
+            assert!(!text.to_lowercase().contains("acme"), "{}", text);
+        }
+        assert!(csv.lines().nth(1).unwrap().starts_with("ns=2;s=ID_"));
+
+        let mapping = anonymize::read_mapping(&anonymize::mapping_path(&csv_path)).unwrap();
+        let restored = anonymize::restore(&csv, &mapping);
+        assert!(restored.contains("ns=2;s=AcmePump.Speed,2:AcmeSpeed,Acme Speed,Variable,urn:acme-plc01:pumps"), "{}", restored);
+        assert!(anonymize::restore(&manifest, &mapping).contains("opc.tcp://acme-plc01:4840"));
+
+        let plain_path = dir.join("plain.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &namespaces, &plain_path, &ExportContext::default()).unwrap();
+        assert!(!anonymize::mapping_path(&plain_path).exists());
+    }
+
+    fn count_crawl_entries(value: &Value) -> usize {
+        value
+            .as_object()
+            .map(|object| {
+                object
+                    .iter()
+                    .filter(|(key, child)| !key.starts_with('_') && child.is_object())
+                    .map(|(_, child)| if child.get("_nodeId").is_some() { 1 + count_crawl_entries(child) } else { 1 })
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_crawl_json_nests_children_under_their_parents() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("crawl_tree");
+        let node = |id: &str, name: &str, node_class: NodeClass, parent: Option<&str>| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, id),
+            browse_name: format!("2:{}", name),
+            display_name: name.to_string(),
+            node_class,
+            type_definition: None,
+            has_children: node_class.has_children(),
+            crawl_root: None,
+            parent_id: parent.map(|parent| opcua::types::NodeId::new(2, parent)),
+        };
+        let nodes = vec![
+            node("Plant", "Plant", NodeClass::Object, None),
+            node("Plant.Line1", "Line1", NodeClass::Object, Some("Plant")),
+            node("Plant.Line2", "Line2", NodeClass::Object, Some("Plant")),
+            node("Plant.Line1.Speed", "Speed", NodeClass::Variable, Some("Plant.Line1")),
+            node("Plant.Line1.Temp", "Temp", NodeClass::Variable, Some("Plant.Line1")),
+            node("Plant.Line2.Speed", "Speed", NodeClass::Variable, Some("Plant.Line2")),
+            node("Plant.Line2.Speed2", "Speed", NodeClass::Variable, Some("Plant.Line2")),
+            node("Plant.Line2.Empty", "Empty", NodeClass::Object, Some("Plant.Line2")),
+            node("Stray", "Stray", NodeClass::Variable, Some("NotCrawled")),
+        ];
+
+        let json_path = dir.join("crawl.json");
+        ExportEngine::export_crawl_result_to_json(&nodes, &[], &json_path, &ExportContext::default()).unwrap();
+        let parsed: Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+
+        let top: Vec<&String> = parsed.as_object().unwrap().keys().collect();
+        assert_eq!(top, ["Plant", "Stray"]);
+        let plant = &parsed["Plant"];
+        assert_eq!(plant["_nodeId"], "ns=2;s=Plant");
+        assert_eq!(plant["Line1"]["_nodeClass"], "Object");
+        assert_eq!(plant["Line1"]["Speed"]["nodeId"], "ns=2;s=Plant.Line1.Speed");
+        assert_eq!(plant["Line1"]["Temp"]["nodeClass"], "Variable");
+
+        let line2 = plant["Line2"].as_object().unwrap();
+        assert!(!line2.contains_key("Speed"));
+        assert_eq!(line2["Speed (ns=2;s=Plant.Line2.Speed)"]["displayName"], "Speed");
+        assert_eq!(line2["Speed (ns=2;s=Plant.Line2.Speed2)"]["nodeId"], "ns=2;s=Plant.Line2.Speed2");
+        assert_eq!(line2["Empty"]["_nodeId"], "ns=2;s=Plant.Line2.Empty");
+        assert_eq!(parsed["Stray"]["nodeId"], "ns=2;s=Stray");
+
+        assert_eq!(count_crawl_entries(&parsed), nodes.len());
+    }
+
+    #[test]
+    fn test_crawl_json_keeps_nodes_on_parent_cycles() {
+        use crate::opcua::browser::NodeClass;
+
+        let node = |id: &str, parent: &str| BrowsedNode {
+            node_id: opcua::types::NodeId::new(2, id),
+            browse_name: format!("2:{}", id),
+            display_name: id.to_string(),
+            node_class: NodeClass::Object,
+            type_definition: None,
+            has_children: true,
+            crawl_root: None,
+            parent_id: Some(opcua::types::NodeId::new(2, parent)),
+        };
+        let nodes = vec![node("A", "B"), node("B", "A"), node("C", "A")];
+        let tree = Value::Object(crawl_tree(&nodes));
+        assert_eq!(count_crawl_entries(&tree), nodes.len());
+        assert_eq!(tree["A"]["C"]["_nodeId"], "ns=2;s=C");
+    }
+
+    #[test]
+    fn test_manifest_row_count_matches_exported_rows() {
+        use crate::opcua::browser::NodeClass;
+
+        let dir = TestDir::new("manifest");
+        let nodes: Vec<BrowsedNode> = ["Speed", "Temp", "Speed"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| BrowsedNode {
+                node_id: opcua::types::NodeId::new(2, i as u32),
+                browse_name: format!("2:{}", name),
+                display_name: name.to_string(),
+                node_class: NodeClass::Variable,
+                type_definition: None,
+                has_children: false,
+                crawl_root: None,
+                parent_id: None,
+            })
+            .collect();
+        let context = ExportContext {
+            write_manifest: true,
+            endpoint: Some("opc.tcp://plc:4840".to_string()),
+            ..ExportContext::default()
+        }
+        .with_option("max_depth", 3);
+
+        let csv_path = dir.join("crawl.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &[], &csv_path, &context).unwrap();
+        let manifest: Value = serde_json::from_str(&std::fs::read_to_string(manifest_path(&csv_path)).unwrap()).unwrap();
+        let csv_rows = std::fs::read_to_string(&csv_path).unwrap().lines().count() - 1;
+        assert_eq!(manifest["row_count"], csv_rows);
+        assert_eq!(manifest["kind"], "crawl_csv");
+        assert_eq!(manifest["file"], "crawl.csv");
+        assert_eq!(manifest["options"]["max_depth"], "3");
+
+        let json_path = dir.join("crawl.json");
+        ExportEngine::export_crawl_result_to_json(&nodes, &[], &json_path, &context).unwrap();
+        let manifest: Value = serde_json::from_str(&std::fs::read_to_string(manifest_path(&json_path)).unwrap()).unwrap();
+        let exported: Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(manifest["row_count"], exported.as_object().unwrap().len());
+
+        let profile_path = dir.join("profile.json");
+        let profile = ServerProfile::simulated(vec!["http://opcfoundation.org/UA/".to_string()]);
+        ExportEngine::export_server_profile(&profile, &profile_path, &context).unwrap();
+        let manifest: Value = serde_json::from_str(&std::fs::read_to_string(manifest_path(&profile_path)).unwrap()).unwrap();
+        assert_eq!(manifest["kind"], "server_profile_json");
+        assert_eq!(manifest["row_count"], 1);
+        let report_path = dir.join("profile.md");
+        ExportEngine::export_server_profile(&profile, &report_path, &context).unwrap();
+        let manifest: Value = serde_json::from_str(&std::fs::read_to_string(manifest_path(&report_path)).unwrap()).unwrap();
+        assert_eq!(manifest["kind"], "server_profile_markdown");
+
+        let certificates_path = dir.join("certificates.csv");
+        std::fs::write(dir.join("broken.der"), b"not a certificate").unwrap();
+        let entries = [crate::opcua::certificates::CertificateEntry::load(
+            crate::opcua::certificates::CertStore::Trusted,
+            crate::opcua::certificates::CertificateInfo { path: dir.join("broken.der"), name: "broken.der".to_string() },
+        )];
+        ExportEngine::export_certificates_to_csv(&entries, &certificates_path, &context).unwrap();
+        let manifest: Value = serde_json::from_str(&std::fs::read_to_string(manifest_path(&certificates_path)).unwrap()).unwrap();
+        let csv_rows = std::fs::read_to_string(&certificates_path).unwrap().lines().count() - 1;
+        assert_eq!(manifest["kind"], "certificates_csv");
+        assert_eq!(manifest["row_count"], csv_rows);
+
+        let silent_path = dir.join("silent.csv");
+        ExportEngine::export_crawl_result_to_csv(&nodes, &[], &silent_path, &ExportContext::default()).unwrap();
+        assert!(!manifest_path(&silent_path).exists());
+    }
+
+    #[test]
+    fn test_watchlist_exports_mark_local_timestamps() {
+        use opcua::types::{DataValue, DateTime, Variant};
+
+        let dir = TestDir::new("timestamp_origin");
+        let mut stamped = MonitoredData::new(opcua::types::NodeId::new(2, "Stamped"), "Stamped".to_string());
+        let mut value = DataValue::value_only(Variant::Double(1.0));
+        value.source_timestamp = Some(DateTime::now());
+        stamped.update(&value);
+        let mut bare = MonitoredData::new(opcua::types::NodeId::new(2, "Bare"), "Bare".to_string());
+        bare.update(&DataValue::value_only(Variant::Double(2.0)));
+        let items = [stamped, bare];
+
+        let json_path = dir.join("watchlist.json");
+        ExportEngine::export_watchlist_to_json(&items, &json_path, NumberFormat::FullPrecision, &TimestampFormat::Iso8601Millis, &ExportContext::default()).unwrap();
+        let exported: Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(exported[0]["timestamp_origin"], "source");
+        assert_eq!(exported[1]["timestamp_origin"], "local");
+        assert!(!exported[1]["timestamp"].as_str().unwrap().contains("(local)"));
+
+        let csv_path = dir.join("watchlist.csv");
+        ExportEngine::export_watchlist_to_csv(&items, &csv_path, NumberFormat::FullPrecision, &TimestampFormat::Iso8601Millis, &ExportContext::default()).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        assert!(csv.starts_with("name,node_id,value,unit,status,timestamp,timestamp_origin\n"));
+        assert!(csv.lines().nth(2).unwrap().ends_with(",local"));
+    }
+
+    #[test]
+    fn test_manifest_schema_is_stable() {
+        let manifest = ExportManifest::new(ExportKind::WatchlistJson, Path::new("/tmp/watchlist.json"), 2, &ExportContext::default());
+        assert_eq!(manifest_path(Path::new("/tmp/watchlist.json")), Path::new("/tmp/watchlist.json.manifest.json"));
+
+        let json = serde_json::to_value(&manifest).unwrap();
+        let keys: Vec<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+        assert_eq!(
+            keys,
+            ["columns", "endpoint", "exported_at", "file", "kind", "manifest_version", "options", "row_count", "tool", "tool_version"]
+        );
+        assert_eq!(json["manifest_version"], 1);
+        assert_eq!(json["columns"][2], serde_json::json!({"name": "value", "type": "variant"}));
+        assert!(json["endpoint"].is_null());
+    }
+
+    #[test]
+    fn test_variant_ua_json_encoding() {
+        assert_eq!(variant_to_ua_json(&Variant::Empty), Value::Null);
+        assert_eq!(variant_to_ua_json(&Variant::Int32(-5)).to_string(), r#"{"Body":-5,"Type":6}"#);
+        assert_eq!(variant_to_ua_json(&Variant::Int64(1 << 40)).to_string(), r#"{"Body":"1099511627776","Type":8}"#);
+        assert_eq!(variant_to_ua_json(&Variant::Double(f64::NAN)).to_string(), r#"{"Body":"NaN","Type":11}"#);
+        assert_eq!(
+            variant_to_ua_json(&Variant::from(vec![1u16, 2u16])).to_string(),
+            r#"{"Body":[1,2],"Type":5}"#
+        );
+        assert_eq!(variant_type_name(&Variant::from(vec![1u16, 2u16])), "Array<UInt16>");
+        assert_eq!(variant_type_name(&Variant::Boolean(true)), "Boolean");
+        assert_eq!(variant_to_binary_hex(&Variant::Int32(1)), "06 01 00 00 00");
+    }
+
+    const NOISY: f64 = 0.1 + 0.2;
+
+    #[test]
+    fn test_json_number_formats() {
+        let double = Variant::Double(NOISY);
+        assert_eq!(variant_to_json(&double, NumberFormat::FullPrecision).to_string(), "0.30000000000000004");
+        assert_eq!(variant_to_json(&double, NumberFormat::Fixed(2)).to_string(), "0.3");
+        assert_eq!(variant_to_json(&double, NumberFormat::Shortest).to_string(), "0.30000000000000004");
+
+        let float = Variant::Float(0.1);
+        assert_eq!(variant_to_json(&float, NumberFormat::FullPrecision).to_string(), "0.10000000149011612");
+        assert_eq!(variant_to_json(&float, NumberFormat::Fixed(3)).to_string(), "0.1");
+        assert_eq!(variant_to_json(&float, NumberFormat::Shortest).to_string(), "0.1");
+
+        assert_eq!(variant_to_json(&Variant::Int32(-7), NumberFormat::Fixed(2)).to_string(), "-7");
+    }
+
+    #[test]
+    fn test_csv_number_formats() {
+        let double = Variant::Double(NOISY);
+        assert_eq!(variant_to_csv(&double, NumberFormat::FullPrecision), "0.30000000000000004");
+        assert_eq!(variant_to_csv(&double, NumberFormat::Fixed(2)), "0.30");
+        assert_eq!(variant_to_csv(&Variant::Float(0.1), NumberFormat::Shortest), "0.1");
+        assert_eq!(variant_to_csv(&Variant::Double(1234.5), NumberFormat::Fixed(0)), "1234");
+    }
+
+    #[test]
+    fn test_non_finite_values_stay_valid_json() {
+        for format in [NumberFormat::FullPrecision, NumberFormat::Fixed(2), NumberFormat::Shortest] {
+            let values = [
+                variant_to_json(&Variant::Double(f64::NAN), format),
+                variant_to_json(&Variant::Double(f64::INFINITY), format),
+                variant_to_json(&Variant::Float(f32::NEG_INFINITY), format),
+            ];
+            let text = serde_json::to_string(&values).unwrap();
+            assert_eq!(text, r#"["NaN","Infinity","-Infinity"]"#);
+            assert!(serde_json::from_str::<Value>(&text).is_ok());
+        }
+        assert_eq!(variant_to_csv(&Variant::Double(f64::NAN), NumberFormat::Fixed(2)), "NaN");
+    }
 }
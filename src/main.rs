@@ -13,6 +13,8 @@
 
 
 use denginks_opcua_diagnostic::app;
+use denginks_opcua_diagnostic::config::settings::{Settings, MIN_WINDOW_SIZE};
+use denginks_opcua_diagnostic::utils::renderer::{RendererBackend, RendererInfo};
 
 use anyhow::Result;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -48,7 +50,7 @@ fn main() -> Result<()> {
     if mesa_dll_exists {
         tracing::info!("Mesa3D opengl32.dll detected - using glow (software OpenGL) renderer");
         
-        return run_with_renderer(runtime.handle().clone(), eframe::Renderer::Glow);
+        return run_with_renderer(runtime.handle().clone(), eframe::Renderer::Glow, RendererInfo::new(RendererBackend::Glow, true));
     }
 
     
@@ -65,14 +67,15 @@ fn main() -> Result<()> {
         std::env::set_var("WGPU_POWER_PREF", "low");
     }
 
-    let wgpu_result = run_with_renderer(runtime.handle().clone(), eframe::Renderer::Wgpu);
+    let wgpu_result = run_with_renderer(runtime.handle().clone(), eframe::Renderer::Wgpu, RendererInfo::new(RendererBackend::Wgpu, false));
     
     if let Err(wgpu_err) = wgpu_result {
         tracing::warn!("wgpu renderer failed: {}. Trying glow (OpenGL) fallback...", wgpu_err);
         
         
         tracing::info!("Attempting to start with glow renderer (OpenGL)");
-        let glow_result = run_with_renderer(runtime.handle().clone(), eframe::Renderer::Glow);
+        let renderer_info = RendererInfo::new(RendererBackend::Glow, false).with_wgpu_error(wgpu_err.to_string());
+        let glow_result = run_with_renderer(runtime.handle().clone(), eframe::Renderer::Glow, renderer_info);
         
         if let Err(glow_err) = glow_result {
             tracing::error!("Both wgpu and glow renderers failed!");
@@ -112,17 +115,23 @@ fn check_mesa_dll() -> bool {
 }
 
 /// Run the application with the specified renderer
-fn run_with_renderer(runtime_handle: tokio::runtime::Handle, renderer: eframe::Renderer) -> Result<(), anyhow::Error> {
+fn run_with_renderer(runtime_handle: tokio::runtime::Handle, renderer: eframe::Renderer, renderer_info: RendererInfo) -> Result<(), anyhow::Error> {
     let renderer_name = match renderer {
         eframe::Renderer::Wgpu => "wgpu",
         eframe::Renderer::Glow => "glow",
     };
     
+    let layout = Settings::load().layout;
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(layout.inner_size())
+        .with_min_inner_size(MIN_WINDOW_SIZE)
+        .with_title("DENGINKS OPC-UA Diagnostic Tool");
+    if let Some(position) = layout.window_position {
+        viewport = viewport.with_position(position);
+    }
+    
     let native_options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([1400.0, 900.0])
-            .with_min_inner_size([800.0, 600.0])
-            .with_title("DENGINKS OPC-UA Diagnostic Tool"),
+        viewport,
         renderer,
         hardware_acceleration: eframe::HardwareAcceleration::Preferred,
         ..Default::default()
@@ -134,7 +143,7 @@ fn run_with_renderer(runtime_handle: tokio::runtime::Handle, renderer: eframe::R
         Box::new(move |cc| {
             setup_egui_style(cc);
             tracing::info!("Successfully initialized {} renderer", renderer_name);
-            Ok(Box::new(app::DiagnosticApp::new(cc, runtime_handle.clone())))
+            Ok(Box::new(app::DiagnosticApp::new(cc, runtime_handle.clone(), renderer_info.clone())))
         }),
     )
     .map_err(|e| anyhow::anyhow!("{}", e))
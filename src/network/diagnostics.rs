@@ -11,12 +11,15 @@ use tokio::time::timeout;
 use tokio_util::sync::CancellationToken;
 
 use crate::network::discovery;
-use crate::utils::i18n::{self, t, T, Language};
+use crate::utils::i18n::{self, t, t_fmt, T, Language};
 
 
 pub const OPCUA_COMMON_PORTS: &[u16] = &[4840, 4841, 4842, 4843, 48010, 48020, 62541];
 
 
+const PROGRESS_THROTTLE: Duration = Duration::from_millis(250);
+
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum StepStatus {
     Pending,
@@ -52,6 +55,8 @@ pub struct DiagnosticStep {
     pub details: String,
     /// Time taken in milliseconds
     pub duration_ms: u64,
+    
+    pub progress: Option<(usize, usize)>,
 }
 
 impl DiagnosticStep {
@@ -62,9 +67,15 @@ impl DiagnosticStep {
             status: StepStatus::Pending,
             details: String::new(),
             duration_ms: 0,
+            progress: None,
         }
     }
 
+    pub fn with_progress(mut self, done: usize, total: usize) -> Self {
+        self.progress = Some((done, total));
+        self
+    }
+
     pub fn running(mut self, details: impl Into<String>) -> Self {
         self.status = StepStatus::Running;
         self.details = details.into();
@@ -127,6 +138,23 @@ impl ParsedInput {
     }
 }
 
+
+#[derive(Debug, Default)]
+struct ProgressThrottle {
+    last_sent: Option<Instant>,
+}
+
+impl ProgressThrottle {
+    
+    fn ready(&mut self, force: bool) -> bool {
+        let due = force || self.last_sent.map_or(true, |last| last.elapsed() >= PROGRESS_THROTTLE);
+        if due {
+            self.last_sent = Some(Instant::now());
+        }
+        due
+    }
+}
+
 /// Result of a port scan
 #[derive(Debug, Clone)]
 pub struct PortScanResult {
@@ -149,6 +177,10 @@ pub struct DiagnosticResult {
     pub endpoints: Vec<discovery::EndpointInfo>,
     /// Total time taken
     pub total_duration_ms: u64,
+    
+    pub parsed: Option<ParsedInput>,
+    
+    pub resolved_ip: Option<String>,
 }
 
 impl DiagnosticResult {
@@ -160,6 +192,30 @@ impl DiagnosticResult {
             recommended_url: None,
             endpoints: Vec::new(),
             total_duration_ms: 0,
+            parsed: None,
+            resolved_ip: None,
+        }
+    }
+
+    
+    pub fn discard_from(&mut self, from: StepId) {
+        let index = STEP_ORDER.iter().position(|id| *id == from).unwrap_or(0);
+        let discarded = &STEP_ORDER[index..];
+        self.steps.retain(|step| !discarded.contains(&step.id));
+
+        if discarded.contains(&StepId::ValidateInput) {
+            self.parsed = None;
+        }
+        if discarded.contains(&StepId::ResolveDns) {
+            self.resolved_ip = None;
+        }
+        if discarded.contains(&StepId::ScanPorts) {
+            self.open_ports.clear();
+        }
+        if discarded.contains(&StepId::DiscoverEndpoints) {
+            self.endpoints.clear();
+            self.recommended_url = None;
+            self.overall_success = false;
         }
     }
 }
@@ -177,7 +233,7 @@ impl Default for DiagnosticResult {
 /// - Hostname: `myserver.local`
 /// - IP with port: `192.168.1.100:4840`
 /// - Full URL: `opc.tcp://192.168.1.100:4840/Path`
-pub fn parse_user_input(input: &str) -> ParsedInput {
+pub fn parse_user_input(input: &str, lang: Language) -> ParsedInput {
     let trimmed = input.trim();
     let mut result = ParsedInput {
         host: String::new(),
@@ -187,7 +243,7 @@ pub fn parse_user_input(input: &str) -> ParsedInput {
     };
 
     if trimmed.is_empty() {
-        result.errors.push("Input cannot be empty".to_string());
+        result.errors.push(t(T::InputEmpty, lang).to_string());
         return result;
     }
 
@@ -196,7 +252,7 @@ pub fn parse_user_input(input: &str) -> ParsedInput {
         result.had_scheme = true;
         rest
     } else if trimmed.contains("://") {
-        result.errors.push("Only opc.tcp:// scheme is supported".to_string());
+        result.errors.push(t(T::OnlyOpcTcpScheme, lang).to_string());
         return result;
     } else {
         trimmed
@@ -214,11 +270,11 @@ pub fn parse_user_input(input: &str) -> ParsedInput {
             if let Some(port_str) = after_bracket.strip_prefix(':') {
                 match port_str.parse::<u16>() {
                     Ok(p) => result.port = Some(p),
-                    Err(_) => result.errors.push(format!("Invalid port: {}", port_str)),
+                    Err(_) => result.errors.push(t_fmt(T::InvalidPort, lang, &[port_str])),
                 }
             }
         } else {
-            result.errors.push("Invalid IPv6 address format".to_string());
+            result.errors.push(t(T::InvalidIpv6, lang).to_string());
         }
     } else {
         // IPv4 or hostname
@@ -238,177 +294,137 @@ pub fn parse_user_input(input: &str) -> ParsedInput {
                 }
             }
             _ => {
-                result.errors.push("Invalid host:port format".to_string());
+                result.errors.push(t(T::InvalidHostPort, lang).to_string());
             }
         }
     }
 
     
     if result.host.is_empty() {
-        result.errors.push("Host cannot be empty".to_string());
+        result.errors.push(t(T::HostEmpty, lang).to_string());
     }
 
     result
 }
 
 
-pub async fn run_diagnostic(
-    input: &str,
-    progress_tx: mpsc::Sender<DiagnosticStep>,
-    cancel: CancellationToken,
-    lang: Language,
-) -> DiagnosticResult {
-    let start = Instant::now();
-    let mut result = DiagnosticResult::new();
+pub const STEP_ORDER: [StepId; 4] = [
+    StepId::ValidateInput,
+    StepId::ResolveDns,
+    StepId::ScanPorts,
+    StepId::DiscoverEndpoints,
+];
 
-    
-    let step1 = DiagnosticStep::new(StepId::ValidateInput, t(T::ValidatingUrl, lang));
-    let _ = progress_tx.send(step1.clone().running(t(T::ValidatingUrl, lang))).await;
 
-    let parsed = parse_user_input(input);
-    
+pub fn validate_input(input: &str, lang: Language) -> (DiagnosticStep, Option<ParsedInput>) {
+    let step = DiagnosticStep::new(StepId::ValidateInput, t(T::ValidatingUrl, lang));
+    let parsed = parse_user_input(input, lang);
     if !parsed.is_valid() {
-        let step = step1.failed(parsed.errors.join(", "), 0);
-        let _ = progress_tx.send(step.clone()).await;
-        result.steps.push(step);
-        result.total_duration_ms = start.elapsed().as_millis() as u64;
-        return result;
+        return (step.failed(parsed.errors.join(", "), 0), None);
     }
+    let port = parsed.port.map(|p| p.to_string()).unwrap_or_else(|| t(T::PortNotSpecified, lang).to_string());
+    let step = step.success(t_fmt(T::HostPortDetail, lang, &[&parsed.host, &port]), 0);
+    (step, Some(parsed))
+}
 
-    let step = step1.success(format!("Host: {}, Port: {:?}", parsed.host, parsed.port), 0);
-    let _ = progress_tx.send(step.clone()).await;
-    result.steps.push(step);
-
-    
-    if cancel.is_cancelled() {
-        result.total_duration_ms = start.elapsed().as_millis() as u64;
-        return result;
-    }
 
-    
-    let step2 = DiagnosticStep::new(StepId::ResolveDns, t(T::ResolvingDns, lang));
-    let _ = progress_tx.send(step2.clone().running(format!("Resolving {}...", parsed.host))).await;
+pub fn resolve_dns(parsed: &ParsedInput, lang: Language) -> (DiagnosticStep, Option<String>) {
+    let step = DiagnosticStep::new(StepId::ResolveDns, t(T::ResolvingDns, lang));
 
     let dns_start = Instant::now();
     let addr_result = format!("{}:4840", parsed.host).to_socket_addrs();
     let dns_duration = dns_start.elapsed().as_millis() as u64;
 
-    let resolved_ip = match addr_result {
-        Ok(mut addrs) => {
-            if let Some(addr) = addrs.next() {
+    match addr_result {
+        Ok(mut addrs) => match addrs.next() {
+            Some(addr) => {
                 let ip = addr.ip().to_string();
-                let step = step2.success(format!("{} → {}", parsed.host, ip), dns_duration);
-                let _ = progress_tx.send(step.clone()).await;
-                result.steps.push(step);
-                Some(ip)
-            } else {
-                let step = step2.failed(t(T::DnsFailed, lang), dns_duration);
-                let _ = progress_tx.send(step.clone()).await;
-                result.steps.push(step);
-                result.total_duration_ms = start.elapsed().as_millis() as u64;
-                return result;
+                (step.success(format!("{} → {}", parsed.host, ip), dns_duration), Some(ip))
             }
-        }
-        Err(e) => {
-            let step = step2.failed(format!("{}: {}", t(T::DnsFailed, lang), e), dns_duration);
-            let _ = progress_tx.send(step.clone()).await;
-            result.steps.push(step);
-            result.total_duration_ms = start.elapsed().as_millis() as u64;
-            return result;
-        }
-    };
-
-    
-    if cancel.is_cancelled() {
-        result.total_duration_ms = start.elapsed().as_millis() as u64;
-        return result;
+            None => (step.failed(t(T::DnsFailed, lang), dns_duration), None),
+        },
+        Err(e) => (step.failed(format!("{}: {}", t(T::DnsFailed, lang), e), dns_duration), None),
     }
+}
 
-    
-    let step3 = DiagnosticStep::new(StepId::ScanPorts, t(T::ScanningPorts, lang));
-    
-    
-    let ports_to_scan: Vec<u16> = if let Some(p) = parsed.port {
-        vec![p]
-    } else {
-        OPCUA_COMMON_PORTS.to_vec()
-    };
 
-    let _ = progress_tx.send(step3.clone().running(format!(
-        "{}: {:?}",
-        t(T::ScanningPorts, lang),
-        ports_to_scan
-    ))).await;
+fn ports_to_scan(parsed: &ParsedInput) -> Vec<u16> {
+    match parsed.port {
+        Some(p) => vec![p],
+        None => OPCUA_COMMON_PORTS.to_vec(),
+    }
+}
+
 
+pub async fn scan_ports(
+    host: &str,
+    ports: &[u16],
+    progress_tx: Option<&mpsc::Sender<DiagnosticStep>>,
+    cancel: &CancellationToken,
+    lang: Language,
+) -> (DiagnosticStep, Vec<PortScanResult>) {
+    let step = DiagnosticStep::new(StepId::ScanPorts, t(T::ScanningPorts, lang));
     let scan_start = Instant::now();
-    let host = resolved_ip.as_ref().unwrap_or(&parsed.host);
-    
-    for port in &ports_to_scan {
+    let mut results = Vec::with_capacity(ports.len());
+    let mut throttle = ProgressThrottle::default();
+
+    for port in ports {
         if cancel.is_cancelled() {
             break;
         }
 
         let addr = format!("{}:{}", host, port);
-        
         let open = matches!(timeout(Duration::from_secs(2), TcpStream::connect(&addr)).await, Ok(Ok(_)));
+        results.push(PortScanResult { port: *port, open });
 
-        result.open_ports.push(PortScanResult {
-            port: *port,
-            open,
-        });
+        let done = results.len();
+        if let Some(tx) = progress_tx.filter(|_| throttle.ready(done == ports.len())) {
+            let open_so_far = results.iter().filter(|p| p.open).count();
+            let details = t_fmt(T::PortsChecked, lang, &[&done.to_string(), &ports.len().to_string(), &open_so_far.to_string()]);
+            let _ = tx.send(step.clone().running(details).with_progress(done, ports.len())).await;
+        }
     }
 
     let scan_duration = scan_start.elapsed().as_millis() as u64;
-    let open_count = result.open_ports.iter().filter(|p| p.open).count();
-
-    if open_count == 0 {
-        let step = step3.failed(
-            format!("{} (tested: {:?})", t(T::NoOpenPorts, lang), ports_to_scan),
-            scan_duration,
-        );
-        let _ = progress_tx.send(step.clone()).await;
-        result.steps.push(step);
-        result.total_duration_ms = start.elapsed().as_millis() as u64;
-        return result;
-    }
-
-    let open_ports_str: Vec<String> = result.open_ports.iter()
+    let open_ports: Vec<String> = results.iter()
         .filter(|p| p.open)
         .map(|p| p.port.to_string())
         .collect();
 
-    let step = step3.success(
-        format!("{}: {}", t(T::PortsOpen, lang), open_ports_str.join(", ")),
-        scan_duration,
-    );
-    let _ = progress_tx.send(step.clone()).await;
-    result.steps.push(step);
-
-    
-    if cancel.is_cancelled() {
-        result.total_duration_ms = start.elapsed().as_millis() as u64;
-        return result;
-    }
+    let step = if open_ports.is_empty() {
+        step.failed(format!("{} ({}: {:?})", t(T::NoOpenPorts, lang), t(T::TestedPorts, lang), ports), scan_duration)
+    } else {
+        step.success(format!("{}: {}", t(T::PortsOpen, lang), open_ports.join(", ")), scan_duration)
+    };
+    (step, results)
+}
 
-    
-    let step4 = DiagnosticStep::new(StepId::DiscoverEndpoints, t(T::DiscoveringEndpoints, lang));
-    let _ = progress_tx.send(step4.clone().running(t(T::DiscoveringEndpoints, lang))).await;
 
+pub async fn discover(
+    parsed: &ParsedInput,
+    open_ports: &[PortScanResult],
+    progress_tx: Option<&mpsc::Sender<DiagnosticStep>>,
+    cancel: &CancellationToken,
+    lang: Language,
+) -> (DiagnosticStep, Vec<discovery::EndpointInfo>) {
+    let step = DiagnosticStep::new(StepId::DiscoverEndpoints, t(T::DiscoveringEndpoints, lang));
     let discovery_start = Instant::now();
-    
-    for port_result in result.open_ports.iter().filter(|p| p.open) {
+    let mut found = Vec::new();
+    let candidates: Vec<&PortScanResult> = open_ports.iter().filter(|p| p.open).collect();
+
+    for (index, port_result) in candidates.iter().enumerate() {
         if cancel.is_cancelled() {
             break;
         }
 
         let url = parsed.to_url(port_result.port);
-        
+        if let Some(tx) = progress_tx {
+            let details = t_fmt(T::QueryingEndpointUrl, lang, &[&url, &(index + 1).to_string(), &candidates.len().to_string()]);
+            let _ = tx.send(step.clone().running(details).with_progress(index, candidates.len())).await;
+        }
         match discovery::discover_endpoints(&url).await {
             Ok(endpoints) if !endpoints.is_empty() => {
-                let recommended_url = endpoints[0].endpoint_url.clone();
-                result.endpoints = endpoints;
-                result.recommended_url = Some(recommended_url);
-                result.overall_success = true;
+                found = endpoints;
                 break;
             }
             _ => continue,
@@ -416,37 +432,112 @@ pub async fn run_diagnostic(
     }
 
     let discovery_duration = discovery_start.elapsed().as_millis() as u64;
-
-    if result.overall_success {
-        let step = step4.success(
-            format!("{} endpoints found at {}", 
-                result.endpoints.len(),
-                result.recommended_url.as_ref().unwrap_or(&String::new())
-            ),
-            discovery_duration,
-        );
-        let _ = progress_tx.send(step.clone()).await;
-        result.steps.push(step);
-    } else {
-        let step = step4.warning(
-            i18n::t(T::NoEndpointsFound, lang).to_string(),
+    let step = match found.first() {
+        Some(first) => step.success(
+            t_fmt(T::EndpointsFoundAt, lang, &[&found.len().to_string(), &first.endpoint_url]),
             discovery_duration,
-        );
-        let _ = progress_tx.send(step.clone()).await;
-        result.steps.push(step);
+        ),
+        None => step.warning(i18n::t(T::NoEndpointsFound, lang).to_string(), discovery_duration),
+    };
+    (step, found)
+}
+
+
+pub async fn run_diagnostic(
+    input: &str,
+    progress_tx: mpsc::Sender<DiagnosticStep>,
+    cancel: CancellationToken,
+    lang: Language,
+) -> DiagnosticResult {
+    resume_diagnostic(input, StepId::ValidateInput, DiagnosticResult::new(), progress_tx, cancel, lang).await
+}
+
+
+
+pub async fn resume_diagnostic(
+    input: &str,
+    from: StepId,
+    previous: DiagnosticResult,
+    progress_tx: mpsc::Sender<DiagnosticStep>,
+    cancel: CancellationToken,
+    lang: Language,
+) -> DiagnosticResult {
+    let start = Instant::now();
+    let mut result = previous;
+    let first = STEP_ORDER.iter().position(|id| *id == from).unwrap_or(0);
+    let first = if first > 0 && result.parsed.is_none() { 0 } else { first };
+    result.discard_from(STEP_ORDER[first]);
+    let kept_duration: u64 = result.steps.iter().map(|s| s.duration_ms).sum();
+
+    for id in &STEP_ORDER[first..] {
+        if cancel.is_cancelled() {
+            break;
+        }
+        if !run_step(*id, input, &mut result, &progress_tx, &cancel, lang).await {
+            break;
+        }
     }
 
-    result.total_duration_ms = start.elapsed().as_millis() as u64;
+    result.total_duration_ms = kept_duration + start.elapsed().as_millis() as u64;
     result
 }
 
+
+async fn run_step(
+    id: StepId,
+    input: &str,
+    result: &mut DiagnosticResult,
+    progress_tx: &mpsc::Sender<DiagnosticStep>,
+    cancel: &CancellationToken,
+    lang: Language,
+) -> bool {
+    let running = |name: T, details: String| DiagnosticStep::new(id, t(name, lang)).running(details);
+
+    let step = match (id, result.parsed.clone()) {
+        (StepId::ValidateInput, _) => {
+            let _ = progress_tx.send(running(T::ValidatingUrl, t(T::ValidatingUrl, lang).to_string())).await;
+            let (step, parsed) = validate_input(input, lang);
+            result.parsed = parsed;
+            step
+        }
+        (StepId::ResolveDns, Some(parsed)) => {
+            let _ = progress_tx.send(running(T::ResolvingDns, t_fmt(T::ResolvingHost, lang, &[&parsed.host]))).await;
+            let (step, ip) = resolve_dns(&parsed, lang);
+            result.resolved_ip = ip;
+            step
+        }
+        (StepId::ScanPorts, Some(parsed)) => {
+            let ports = ports_to_scan(&parsed);
+            let _ = progress_tx.send(running(T::ScanningPorts, format!("{}: {:?}", t(T::ScanningPorts, lang), ports))).await;
+            let host = result.resolved_ip.clone().unwrap_or(parsed.host);
+            let (step, open_ports) = scan_ports(&host, &ports, Some(progress_tx), cancel, lang).await;
+            result.open_ports = open_ports;
+            step
+        }
+        (StepId::DiscoverEndpoints, Some(parsed)) => {
+            let _ = progress_tx.send(running(T::DiscoveringEndpoints, t(T::DiscoveringEndpoints, lang).to_string())).await;
+            let (step, endpoints) = discover(&parsed, &result.open_ports, Some(progress_tx), cancel, lang).await;
+            result.recommended_url = endpoints.first().map(|e| e.endpoint_url.clone());
+            result.overall_success = !endpoints.is_empty();
+            result.endpoints = endpoints;
+            step
+        }
+        (_, None) => return false,
+    };
+
+    let proceed = step.status != StepStatus::Failed;
+    let _ = progress_tx.send(step.clone()).await;
+    result.steps.push(step);
+    proceed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_parse_ip_only() {
-        let result = parse_user_input("192.168.1.100");
+        let result = parse_user_input("192.168.1.100", Language::English);
         assert!(result.is_valid());
         assert_eq!(result.host, "192.168.1.100");
         assert_eq!(result.port, None);
@@ -455,7 +546,7 @@ mod tests {
 
     #[test]
     fn test_parse_ip_with_port() {
-        let result = parse_user_input("192.168.1.100:4840");
+        let result = parse_user_input("192.168.1.100:4840", Language::English);
         assert!(result.is_valid());
         assert_eq!(result.host, "192.168.1.100");
         assert_eq!(result.port, Some(4840));
@@ -463,7 +554,7 @@ mod tests {
 
     #[test]
     fn test_parse_full_url() {
-        let result = parse_user_input("opc.tcp://myserver.local:4840/UA/Server");
+        let result = parse_user_input("opc.tcp://myserver.local:4840/UA/Server", Language::English);
         assert!(result.is_valid());
         assert_eq!(result.host, "myserver.local");
         assert_eq!(result.port, Some(4840));
@@ -472,7 +563,7 @@ mod tests {
 
     #[test]
     fn test_parse_hostname_only() {
-        let result = parse_user_input("myserver.local");
+        let result = parse_user_input("myserver.local", Language::English);
         assert!(result.is_valid());
         assert_eq!(result.host, "myserver.local");
         assert_eq!(result.port, None);
@@ -480,14 +571,122 @@ mod tests {
 
     #[test]
     fn test_parse_invalid_scheme() {
-        let result = parse_user_input("http://192.168.1.100:4840");
+        let result = parse_user_input("http://192.168.1.100:4840", Language::English);
         assert!(!result.is_valid());
         assert!(result.errors[0].contains("opc.tcp://"));
     }
 
+    #[test]
+    fn test_parse_errors_follow_language() {
+        let result = parse_user_input("http://192.168.1.100:4840", Language::Spanish);
+        assert_eq!(result.errors, vec!["Solo se admite el esquema opc.tcp://".to_string()]);
+
+        let (step, _) = validate_input("plc:4840", Language::Spanish);
+        assert_eq!(step.details, "Host: plc, Puerto: 4840");
+    }
+
     #[test]
     fn test_parse_empty() {
-        let result = parse_user_input("");
+        let result = parse_user_input("", Language::English);
         assert!(!result.is_valid());
     }
+
+    #[test]
+    fn test_validate_and_resolve_steps() {
+        let (step, parsed) = validate_input("http://x", Language::English);
+        assert_eq!(step.status, StepStatus::Failed);
+        assert!(parsed.is_none());
+
+        let (step, parsed) = validate_input("127.0.0.1:4840", Language::English);
+        assert_eq!(step.status, StepStatus::Success);
+        let (step, ip) = resolve_dns(&parsed.unwrap(), Language::English);
+        assert_eq!(step.status, StepStatus::Success);
+        assert_eq!(ip.as_deref(), Some("127.0.0.1"));
+    }
+
+    #[test]
+    fn test_discard_from_keeps_earlier_steps() {
+        let (validate, parsed) = validate_input("127.0.0.1", Language::English);
+        let mut result = DiagnosticResult::new();
+        result.steps.push(validate);
+        result.steps.push(DiagnosticStep::new(StepId::ResolveDns, "dns").failed("boom", 5));
+        result.parsed = parsed;
+
+        result.discard_from(StepId::ResolveDns);
+        assert_eq!(result.steps.len(), 1);
+        assert_eq!(result.steps[0].id, StepId::ValidateInput);
+        assert!(result.parsed.is_some());
+
+        result.discard_from(StepId::ValidateInput);
+        assert!(result.steps.is_empty());
+        assert!(result.parsed.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_ports_detects_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let cancel = CancellationToken::new();
+        let (tx, mut rx) = mpsc::channel(32);
+        let (step, ports) = scan_ports("127.0.0.1", &[open_port, closed_port], Some(&tx), &cancel, Language::English).await;
+        assert_eq!(step.status, StepStatus::Success);
+        assert_eq!(step.progress, None);
+        assert_eq!(ports.len(), 2);
+        assert!(ports[0].open);
+        assert!(!ports[1].open);
+
+        let mut updates = Vec::new();
+        while let Ok(update) = rx.try_recv() {
+            updates.push(update);
+        }
+        let last = updates.last().unwrap();
+        assert_eq!((last.status.clone(), last.progress), (StepStatus::Running, Some((2, 2))));
+        assert_eq!(last.details, "2/2 ports checked, 1 open so far");
+
+        let (step, ports) = scan_ports("127.0.0.1", &[closed_port], None, &cancel, Language::English).await;
+        assert_eq!(step.status, StepStatus::Failed);
+        assert!(!ports[0].open);
+    }
+
+    #[test]
+    fn test_progress_throttle_lets_first_and_forced_updates_through() {
+        let mut throttle = ProgressThrottle::default();
+        assert!(throttle.ready(false));
+        assert!(!throttle.ready(false));
+        assert!(throttle.ready(true));
+        throttle.last_sent = Some(Instant::now() - PROGRESS_THROTTLE);
+        assert!(throttle.ready(false));
+    }
+
+    #[tokio::test]
+    async fn test_discover_without_open_ports_warns() {
+        let (_, parsed) = validate_input("127.0.0.1", Language::English);
+        let (step, endpoints) = discover(&parsed.unwrap(), &[], None, &CancellationToken::new(), Language::English).await;
+        assert_eq!(step.status, StepStatus::Warning);
+        assert!(endpoints.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resume_reruns_from_step() {
+        let (tx, mut rx) = mpsc::channel(32);
+        let (validate, parsed) = validate_input("127.0.0.1:1", Language::English);
+        let mut previous = DiagnosticResult::new();
+        previous.steps.push(validate);
+        previous.steps.push(DiagnosticStep::new(StepId::ResolveDns, "dns").failed("boom", 5));
+        previous.parsed = parsed;
+
+        let result = resume_diagnostic("ignored", StepId::ResolveDns, previous, tx, CancellationToken::new(), Language::English).await;
+        let ids: Vec<StepId> = result.steps.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![StepId::ValidateInput, StepId::ResolveDns, StepId::ScanPorts]);
+        assert_eq!(result.steps[1].status, StepStatus::Success);
+        assert_eq!(result.steps[2].status, StepStatus::Failed);
+
+        let first = rx.try_recv().unwrap();
+        assert_eq!((first.id, first.status), (StepId::ResolveDns, StepStatus::Running));
+    }
 }
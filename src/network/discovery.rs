@@ -4,11 +4,12 @@
 
 
 use opcua::client::ClientBuilder;
-use opcua::types::MessageSecurityMode as OpcMessageSecurityMode;
+use opcua::types::{EndpointDescription, MessageSecurityMode as OpcMessageSecurityMode};
+use serde::Serialize;
 use crate::utils::i18n::{self, T, Language};
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EndpointInfo {
     
     pub security_policy_name: String,
@@ -27,6 +28,39 @@ impl EndpointInfo {
     pub fn allows_anonymous(&self) -> bool {
         self.user_tokens.iter().any(|t| t.to_lowercase().contains("anonymous"))
     }
+
+    
+    pub fn allows_username(&self) -> bool {
+        self.user_tokens.iter().any(|t| t.starts_with("UserName"))
+    }
+
+    
+    pub fn allows_certificate(&self) -> bool {
+        self.user_tokens.iter().any(|t| t.starts_with("Certificate"))
+    }
+
+    
+    pub fn security_rank(&self) -> (u8, u8) {
+        let mode = match self.security_mode.as_str() {
+            "SignAndEncrypt" => 2,
+            "Sign" => 1,
+            _ => 0,
+        };
+        let policy = match self.security_policy_name.replace(['_', '-'], "").as_str() {
+            "Aes256Sha256RsaPss" => 5,
+            "Aes128Sha256RsaOaep" => 4,
+            "Basic256Sha256" => 3,
+            "Basic256" => 2,
+            "Basic128Rsa15" => 1,
+            _ => 0,
+        };
+        (mode, policy)
+    }
+}
+
+
+pub fn sort_by_security(endpoints: &mut [EndpointInfo]) {
+    endpoints.sort_by_key(|ep| std::cmp::Reverse(ep.security_rank()));
 }
 
 
@@ -53,56 +87,55 @@ pub async fn discover_endpoints(discovery_url: &str) -> Result<Vec<EndpointInfo>
     tracing::info!("Discovered {} endpoints", endpoints.len());
 
     
-    let endpoint_infos: Vec<EndpointInfo> = endpoints
-        .into_iter()
-        .map(|ep| {
-            
-            let policy_uri = ep.security_policy_uri.as_ref().to_string();
-            let policy_name = parse_security_policy_name(&policy_uri);
-
-            
-            let mode_str = match ep.security_mode {
-                OpcMessageSecurityMode::None => "None",
-                OpcMessageSecurityMode::Sign => "Sign",
-                OpcMessageSecurityMode::SignAndEncrypt => "SignAndEncrypt",
-                _ => "Unknown",
-            };
-
-            
-            let user_tokens: Vec<String> = ep
-                .user_identity_tokens
-                .as_ref()
-                .map(|tokens| {
-                    tokens
-                        .iter()
-                        .map(|t| {
-                            let policy_id = t.policy_id.as_ref().to_string();
-                            let token_type = match t.token_type {
-                                opcua::types::UserTokenType::Anonymous => "Anonymous",
-                                opcua::types::UserTokenType::UserName => "UserName",
-                                opcua::types::UserTokenType::Certificate => "Certificate",
-                                opcua::types::UserTokenType::IssuedToken => "IssuedToken",
-                            };
-                            format!("{} ({})", token_type, policy_id)
-                        })
-                        .collect()
+    let endpoint_infos: Vec<EndpointInfo> = endpoints.iter().map(endpoint_info_from).collect();
+
+    Ok(endpoint_infos)
+}
+
+
+pub fn endpoint_info_from(ep: &EndpointDescription) -> EndpointInfo {
+    let policy_uri = ep.security_policy_uri.as_ref().to_string();
+    let policy_name = parse_security_policy_name(&policy_uri);
+
+    
+    let mode_str = match ep.security_mode {
+        OpcMessageSecurityMode::None => "None",
+        OpcMessageSecurityMode::Sign => "Sign",
+        OpcMessageSecurityMode::SignAndEncrypt => "SignAndEncrypt",
+        _ => "Unknown",
+    };
+
+    
+    let user_tokens: Vec<String> = ep
+        .user_identity_tokens
+        .as_ref()
+        .map(|tokens| {
+            tokens
+                .iter()
+                .map(|t| {
+                    let policy_id = t.policy_id.as_ref().to_string();
+                    let token_type = match t.token_type {
+                        opcua::types::UserTokenType::Anonymous => "Anonymous",
+                        opcua::types::UserTokenType::UserName => "UserName",
+                        opcua::types::UserTokenType::Certificate => "Certificate",
+                        opcua::types::UserTokenType::IssuedToken => "IssuedToken",
+                    };
+                    format!("{} ({})", token_type, policy_id)
                 })
-                .unwrap_or_default();
-
-            
-            let has_certificate = !ep.server_certificate.is_null();
-
-            EndpointInfo {
-                security_policy_name: policy_name,
-                security_mode: mode_str.to_string(),
-                has_certificate,
-                user_tokens,
-                endpoint_url: ep.endpoint_url.as_ref().to_string(),
-            }
+                .collect()
         })
-        .collect();
+        .unwrap_or_default();
 
-    Ok(endpoint_infos)
+    
+    let has_certificate = !ep.server_certificate.is_null();
+
+    EndpointInfo {
+        security_policy_name: policy_name,
+        security_mode: mode_str.to_string(),
+        has_certificate,
+        user_tokens,
+        endpoint_url: ep.endpoint_url.as_ref().to_string(),
+    }
 }
 
 
@@ -171,4 +204,29 @@ mod tests {
             "None"
         );
     }
+
+    #[test]
+    fn test_sort_strongest_first() {
+        let endpoint = |policy: &str, mode: &str| EndpointInfo {
+            security_policy_name: policy.to_string(),
+            security_mode: mode.to_string(),
+            has_certificate: true,
+            user_tokens: vec!["Anonymous (anon)".to_string()],
+            endpoint_url: "opc.tcp://plc:4840".to_string(),
+        };
+        let mut endpoints = vec![
+            endpoint("None", "None"),
+            endpoint("Basic256Sha256", "Sign"),
+            endpoint("Aes128_Sha256_RsaOaep", "SignAndEncrypt"),
+            endpoint("Basic256Sha256", "SignAndEncrypt"),
+        ];
+        sort_by_security(&mut endpoints);
+        let order: Vec<_> = endpoints.iter().map(|ep| (ep.security_policy_name.as_str(), ep.security_mode.as_str())).collect();
+        assert_eq!(order, [
+            ("Aes128_Sha256_RsaOaep", "SignAndEncrypt"),
+            ("Basic256Sha256", "SignAndEncrypt"),
+            ("Basic256Sha256", "Sign"),
+            ("None", "None"),
+        ]);
+    }
 }
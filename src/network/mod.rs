@@ -3,3 +3,4 @@
 pub mod diagnostics;
 pub mod discovery;
 pub mod precheck;
+pub mod reachability;
@@ -0,0 +1,171 @@
+
+
+
+
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+use crate::app::BackendMessage;
+
+
+pub const MAX_SAMPLES: usize = 720;
+
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProbeSample {
+    
+    pub at: Instant,
+    
+    pub latency: Option<Duration>,
+}
+
+impl ProbeSample {
+    
+    pub fn is_up(&self) -> bool {
+        self.latency.is_some()
+    }
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ReachabilityHistory {
+    
+    pub samples: VecDeque<ProbeSample>,
+}
+
+impl ReachabilityHistory {
+    
+    pub fn push(&mut self, sample: ProbeSample) {
+        self.samples.push_back(sample);
+        while self.samples.len() > MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+    }
+
+    
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    
+    pub fn uptime_ratio(&self) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let up = self.samples.iter().filter(|s| s.is_up()).count();
+        Some(up as f32 / self.samples.len() as f32)
+    }
+
+    
+    pub fn drop_count(&self) -> usize {
+        self.samples
+            .iter()
+            .zip(self.samples.iter().skip(1))
+            .filter(|(prev, next)| prev.is_up() && !next.is_up())
+            .count()
+    }
+
+    
+    pub fn average_latency(&self) -> Option<Duration> {
+        let latencies: Vec<Duration> = self.samples.iter().filter_map(|s| s.latency).collect();
+        if latencies.is_empty() {
+            return None;
+        }
+        Some(latencies.iter().sum::<Duration>() / latencies.len() as u32)
+    }
+}
+
+
+pub fn parse_target(input: &str) -> Result<(String, u16), String> {
+    let input = input.trim();
+    if input.starts_with("opc.tcp://") {
+        crate::network::precheck::parse_endpoint_url(input)
+    } else {
+        crate::network::precheck::parse_endpoint_url(&format!("opc.tcp://{}", input))
+    }
+}
+
+
+pub async fn tcp_probe(host: &str, port: u16) -> Option<Duration> {
+    let start = Instant::now();
+    match tokio::time::timeout(PROBE_TIMEOUT, tokio::net::TcpStream::connect((host, port))).await {
+        Ok(Ok(_stream)) => Some(start.elapsed()),
+        _ => None,
+    }
+}
+
+
+pub async fn run_reachability_monitor(
+    host: String,
+    port: u16,
+    interval: Duration,
+    tx: mpsc::Sender<BackendMessage>,
+    cancel: CancellationToken,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = ticker.tick() => {}
+        }
+
+        let at = Instant::now();
+        let latency = tokio::select! {
+            _ = cancel.cancelled() => break,
+            latency = tcp_probe(&host, port) => latency,
+        };
+        if tx.send(BackendMessage::ReachabilitySample(ProbeSample { at, latency })).is_err() {
+            break;
+        }
+    }
+
+    tracing::info!("Reachability monitor for {}:{} stopped", host, port);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(up: bool) -> ProbeSample {
+        ProbeSample {
+            at: Instant::now(),
+            latency: up.then(|| Duration::from_millis(10)),
+        }
+    }
+
+    #[test]
+    fn test_history_statistics() {
+        let mut history = ReachabilityHistory::default();
+        assert_eq!(history.uptime_ratio(), None);
+
+        for up in [true, true, false, false, true, false, true, true] {
+            history.push(sample(up));
+        }
+        assert_eq!(history.drop_count(), 2);
+        assert_eq!(history.uptime_ratio(), Some(5.0 / 8.0));
+        assert_eq!(history.average_latency(), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut history = ReachabilityHistory::default();
+        for _ in 0..MAX_SAMPLES + 10 {
+            history.push(sample(true));
+        }
+        assert_eq!(history.samples.len(), MAX_SAMPLES);
+    }
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target("plc01:4841").unwrap(), ("plc01".to_string(), 4841));
+        assert_eq!(parse_target(" opc.tcp://10.0.0.5 ").unwrap(), ("10.0.0.5".to_string(), 4840));
+        assert!(parse_target("").is_err());
+    }
+}
@@ -3,14 +3,18 @@
 
 
 use anyhow::{Context, Result};
-use std::sync::Arc;
+use std::future::Future;
+use std::time::Duration;
 
-use opcua::client::Session;
+use crate::opcua::read_only::ReadOnlyGuard;
 use opcua::types::{
-    BrowseDescription, BrowseDirection, BrowseResultMask,
-    NodeId, ReferenceTypeId,
+    Argument, AttributeId, BrowseDescription, BrowseDirection, BrowsePath, BrowseResult, BrowseResultMask,
+    ByteString, NodeId, ReferenceDescription, QualifiedName, ReadValueId, ReferenceTypeId, RelativePath, RelativePathElement,
+    DateTime, StatusCode, TimestampsToReturn, VariableId, Variant, ViewDescription,
 };
 
+use crate::opcua::service_timing::{timed, Service};
+
 
 #[derive(Debug, Clone)]
 pub struct BrowsedNode {
@@ -26,6 +30,10 @@ pub struct BrowsedNode {
     pub type_definition: Option<NodeId>,
     
     pub has_children: bool,
+    
+    pub crawl_root: Option<NodeId>,
+    
+    pub parent_id: Option<NodeId>,
 }
 
 
@@ -58,6 +66,11 @@ impl NodeClass {
         }
     }
 
+    
+    pub fn has_children(&self) -> bool {
+        matches!(self, NodeClass::Object | NodeClass::ObjectType | NodeClass::View)
+    }
+
     /// Convert from OPC-UA node class enum
     pub fn from_opcua(node_class: opcua::types::NodeClass) -> Self {
         match node_class {
@@ -92,8 +105,13 @@ impl std::fmt::Display for NodeClass {
 
 
 
-pub async fn browse_node(session: Arc<Session>, parent_node_id: &NodeId) -> Result<Vec<BrowsedNode>> {
-    tracing::debug!("Browsing node: {:?}", parent_node_id);
+pub async fn browse_node(session: ReadOnlyGuard, parent_node_id: &NodeId) -> Result<Vec<BrowsedNode>> {
+    browse_node_in_view(session, parent_node_id, None).await
+}
+
+
+pub async fn browse_node_in_view(session: ReadOnlyGuard, parent_node_id: &NodeId, view: Option<&NodeId>) -> Result<Vec<BrowsedNode>> {
+    tracing::debug!("Browsing node: {:?} (view {:?})", parent_node_id, view);
 
     
     let browse_description = BrowseDescription {
@@ -106,50 +124,563 @@ pub async fn browse_node(session: Arc<Session>, parent_node_id: &NodeId) -> Resu
     };
 
     
-    let browse_result = session
-        .browse(&[browse_description], 0, None)
+    let view = view.map(|view_id| ViewDescription {
+        view_id: view_id.clone(),
+        timestamp: DateTime::null(),
+        view_version: 0,
+    });
+    let references = browse_references(session, browse_description, view).await?;
+
+    
+    let nodes: Vec<BrowsedNode> = references
+        .iter()
+        .map(|reference| {
+            let node_class = NodeClass::from_opcua(reference.node_class);
+            
+            BrowsedNode {
+                node_id: reference.node_id.node_id.clone(),
+                browse_name: reference.browse_name.to_string(),
+                display_name: reference.display_name.text.to_string(),
+                node_class,
+                type_definition: Some(reference.type_definition.node_id.clone()),
+                has_children: node_class.has_children(),
+                crawl_root: None,
+                parent_id: None,
+            }
+        })
+        .collect();
+
+    tracing::debug!("Found {} children for {:?}", nodes.len(), parent_node_id);
+
+    Ok(nodes)
+}
+
+
+
+pub async fn browse_references(
+    session: ReadOnlyGuard,
+    description: BrowseDescription,
+    view: Option<ViewDescription>,
+) -> Result<Vec<ReferenceDescription>> {
+    let browse_result = timed(session.timings(), Service::Browse, session.browse(&[description], 0, view))
         .await
         .context("Browse request failed")?;
 
-    if browse_result.is_empty() {
+    let Some(first) = browse_result.into_iter().next() else {
         return Ok(Vec::new());
+    };
+
+    let mut release = ContinuationRelease { session: session.clone(), point: None };
+    collect_references(first, &mut release.point, |point| {
+        let session = session.clone();
+        async move {
+            timed(session.timings(), Service::BrowseNext, session.browse_next(false, &[point]))
+                .await
+                .context("BrowseNext request failed")?
+                .into_iter()
+                .next()
+                .context("BrowseNext returned no result")
+        }
+    })
+    .await
+}
+
+
+async fn collect_references<F, Fut>(
+    first: BrowseResult,
+    pending: &mut Option<ByteString>,
+    mut next: F,
+) -> Result<Vec<ReferenceDescription>>
+where
+    F: FnMut(ByteString) -> Fut,
+    Fut: Future<Output = Result<BrowseResult>>,
+{
+    let mut result = first;
+    let mut references = Vec::new();
+    loop {
+        if !result.status_code.is_good() {
+            return Err(result.status_code).with_context(|| format!("Browse failed with status: {:?}", result.status_code));
+        }
+        references.extend(result.references.take().unwrap_or_default());
+        if result.continuation_point.is_null_or_empty() {
+            *pending = None;
+            return Ok(references);
+        }
+        *pending = Some(result.continuation_point.clone());
+        result = next(result.continuation_point).await?;
+        *pending = None;
+    }
+}
+
+
+struct ContinuationRelease {
+    session: ReadOnlyGuard,
+    point: Option<ByteString>,
+}
+
+impl Drop for ContinuationRelease {
+    fn drop(&mut self) {
+        let Some(point) = self.point.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let session = self.session.clone();
+        runtime.spawn(async move {
+            if let Err(status) = session.browse_next(true, &[point]).await {
+                tracing::debug!("Releasing browse continuation point failed: {}", status);
+            }
+        });
     }
+}
+
+
+pub const BROWSE_MAX_ATTEMPTS: u32 = 3;
+
+
+const BROWSE_BACKOFF_MS: [u64; 3] = [250, 500, 1000];
+
 
-    let result = &browse_result[0];
+const TRANSIENT_BROWSE_STATUS: [StatusCode; 6] = [
+    StatusCode::BadTooManyOperations,
+    StatusCode::BadSessionNotActivated,
+    StatusCode::BadServerTooBusy,
+    StatusCode::BadTimeout,
+    StatusCode::BadRequestTimeout,
+    StatusCode::BadResourceUnavailable,
+];
 
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrowseFailure {
     
-    if !result.status_code.is_good() {
-        anyhow::bail!("Browse failed with status: {:?}", result.status_code);
-    }
-
-    
-    let nodes: Vec<BrowsedNode> = result
-        .references
-        .as_ref()
-        .map(|refs| {
-            refs.iter()
-                .map(|reference| {
-                    let node_class = NodeClass::from_opcua(reference.node_class);
-                    
-                    BrowsedNode {
-                        node_id: reference.node_id.node_id.clone(),
-                        browse_name: reference.browse_name.to_string(),
-                        display_name: reference.display_name.text.to_string(),
-                        node_class,
-                        type_definition: Some(reference.type_definition.node_id.clone()),
-                        has_children: matches!(node_class, NodeClass::Object | NodeClass::ObjectType | NodeClass::View),
-                    }
-                })
-                .collect()
+    AccessDenied,
+    
+    Error(String),
+}
+
+impl BrowseFailure {
+    
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        match error.downcast_ref::<StatusCode>() {
+            Some(&StatusCode::BadUserAccessDenied) => Self::AccessDenied,
+            _ => Self::Error(format!("{:#}", error)),
+        }
+    }
+}
+
+
+pub fn browse_retry_delay(status: StatusCode, attempt: u32) -> Option<Duration> {
+    if attempt >= BROWSE_MAX_ATTEMPTS || !TRANSIENT_BROWSE_STATUS.contains(&status) {
+        return None;
+    }
+    let index = (attempt.max(1) as usize - 1).min(BROWSE_BACKOFF_MS.len() - 1);
+    Some(Duration::from_millis(BROWSE_BACKOFF_MS[index]))
+}
+
+
+pub async fn browse_node_with_retry(session: ReadOnlyGuard, parent_node_id: &NodeId, view: Option<&NodeId>) -> Result<Vec<BrowsedNode>> {
+    let mut attempt = 1;
+    loop {
+        let error = match browse_node_in_view(session.clone(), parent_node_id, view).await {
+            Ok(nodes) => return Ok(nodes),
+            Err(e) => e,
+        };
+        let status = error.downcast_ref::<StatusCode>().copied();
+        match status.and_then(|status| browse_retry_delay(status, attempt)) {
+            Some(delay) => {
+                tracing::info!(
+                    "Browse of {:?} failed on attempt {}/{} ({:#}), retrying in {:?}",
+                    parent_node_id, attempt, BROWSE_MAX_ATTEMPTS, error, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            None if attempt > 1 => {
+                tracing::warn!("Browse of {:?} failed after {} attempts: {:#}", parent_node_id, attempt, error);
+                return Err(error.context(format!("failed after {} attempts", attempt)));
+            }
+            None => return Err(error),
+        }
+    }
+}
+
+
+pub const FOLDER_VARIABLES_MAX_BROWSES: usize = 2000;
+
+
+#[derive(Debug, Default)]
+pub struct CollectedVariables {
+    
+    pub variables: Vec<BrowsedNode>,
+    
+    pub truncated: bool,
+}
+
+
+pub async fn collect_variables<F, Fut>(root: &NodeId, max_depth: usize, mut browse: F) -> Result<CollectedVariables>
+where
+    F: FnMut(NodeId) -> Fut,
+    Fut: Future<Output = Result<Vec<BrowsedNode>>>,
+{
+    let mut variables: Vec<BrowsedNode> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    let mut queue = std::collections::VecDeque::from([(root.clone(), 0)]);
+    seen.insert(root.clone());
+    let mut browses = 0;
+    let mut truncated = false;
+    while let Some((node_id, depth)) = queue.pop_front() {
+        if browses >= FOLDER_VARIABLES_MAX_BROWSES {
+            tracing::warn!("Stopped collecting variables under {:?} after {} browse calls", root, browses);
+            truncated = true;
+            break;
+        }
+        browses += 1;
+        let children = match browse(node_id.clone()).await {
+            Ok(children) => children,
+            Err(e) if node_id == *root => return Err(e),
+            Err(e) => {
+                tracing::warn!("Failed to browse {:?} while collecting variables: {:#}", node_id, e);
+                continue;
+            }
+        };
+        for child in children {
+            if !seen.insert(child.node_id.clone()) {
+                continue;
+            }
+            match child.node_class {
+                NodeClass::Variable => variables.push(child),
+                NodeClass::Object if child.has_children && depth + 1 < max_depth => queue.push_back((child.node_id, depth + 1)),
+                _ => {}
+            }
+        }
+    }
+    Ok(CollectedVariables { variables, truncated })
+}
+
+
+pub async fn browse_variables(session: ReadOnlyGuard, root: &NodeId, max_depth: usize) -> Result<CollectedVariables> {
+    collect_variables(root, max_depth, |node_id| {
+        let session = session.clone();
+        async move { browse_node_with_retry(session, &node_id, None).await }
+    })
+    .await
+}
+
+
+pub async fn list_views(session: ReadOnlyGuard) -> Result<Vec<BrowsedNode>> {
+    let views_folder = NodeId::from(opcua::types::ObjectId::ViewsFolder);
+    let mut views: Vec<BrowsedNode> = browse_node(session, &views_folder)
+        .await?
+        .into_iter()
+        .filter(|node| node.node_class == NodeClass::View)
+        .collect();
+    views.sort_by_key(|v| v.display_name.to_lowercase());
+    Ok(views)
+}
+
+
+pub async fn read_node(session: ReadOnlyGuard, node_id: &NodeId) -> Result<BrowsedNode> {
+    let nodes_to_read = [
+        ReadValueId::new(node_id.clone(), AttributeId::BrowseName),
+        ReadValueId::new(node_id.clone(), AttributeId::DisplayName),
+        ReadValueId::new(node_id.clone(), AttributeId::NodeClass),
+    ];
+
+    let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+    if let Some(status) = results.first().and_then(|dv| dv.status).filter(|status| status.is_bad()) {
+        anyhow::bail!("{}", crate::opcua::status_codes::translate_status_code(status));
+    }
+
+    let value_of = |index: usize| results.get(index).and_then(|dv| dv.value.as_ref());
+
+    let browse_name = match value_of(0) {
+        Some(Variant::QualifiedName(qn)) => qn.to_string(),
+        _ => node_id.to_string(),
+    };
+    let display_name = match value_of(1) {
+        Some(Variant::LocalizedText(lt)) => lt.text.to_string(),
+        _ => browse_name.clone(),
+    };
+    let node_class = match value_of(2) {
+        Some(Variant::Int32(v)) => opcua::types::NodeClass::try_from(*v)
+            .map(NodeClass::from_opcua)
+            .unwrap_or(NodeClass::Unknown),
+        _ => NodeClass::Unknown,
+    };
+
+    Ok(BrowsedNode {
+        node_id: node_id.clone(),
+        browse_name,
+        display_name,
+        node_class,
+        type_definition: None,
+        has_children: node_class.has_children(),
+        crawl_root: None,
+        parent_id: None,
+    })
+}
+
+
+
+
+pub async fn read_display_names(session: ReadOnlyGuard, node_ids: &[NodeId]) -> Result<Vec<Option<String>>> {
+    if node_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let nodes_to_read: Vec<ReadValueId> = node_ids
+        .iter()
+        .map(|id| ReadValueId::new(id.clone(), AttributeId::DisplayName))
+        .collect();
+
+    let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+
+    Ok((0..node_ids.len())
+        .map(|i| match results.get(i).and_then(|dv| dv.value.as_ref()) {
+            Some(Variant::LocalizedText(lt)) if !lt.text.is_empty() => Some(lt.text.to_string()),
+            _ => None,
         })
-        .unwrap_or_default();
+        .collect())
+}
 
-    tracing::debug!("Found {} children for {:?}", nodes.len(), parent_node_id);
 
-    Ok(nodes)
+pub async fn read_namespace_array(session: ReadOnlyGuard) -> Result<Vec<String>> {
+    let node = ReadValueId::new(NodeId::from(VariableId::Server_NamespaceArray), AttributeId::Value);
+    let results = timed(session.timings(), Service::Read, session.read(&[node], TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+
+    let value = results.into_iter().next().context("Empty read response")?;
+    if let Some(status) = value.status.filter(|s| !s.is_good()) {
+        anyhow::bail!("NamespaceArray read failed: {}", status);
+    }
+    match value.value {
+        Some(Variant::Array(array)) => Ok(array
+            .values
+            .iter()
+            .map(|v| match v {
+                Variant::String(s) => s.to_string(),
+                other => format!("{:?}", other),
+            })
+            .collect()),
+        other => anyhow::bail!("Unexpected NamespaceArray value: {:?}", other),
+    }
 }
 
 
+const MAX_PATH_DEPTH: usize = 32;
+
+
+
+pub async fn find_path_from_root(session: ReadOnlyGuard, node_id: &NodeId) -> Result<Vec<NodeId>> {
+    let root = NodeId::from(opcua::types::ObjectId::RootFolder);
+    let mut path = Vec::new();
+    let mut current = node_id.clone();
+
+    while current != root {
+        if path.len() >= MAX_PATH_DEPTH {
+            anyhow::bail!("No path to the root folder within {} levels", MAX_PATH_DEPTH);
+        }
+
+        let browse_description = BrowseDescription {
+            node_id: current.clone(),
+            browse_direction: BrowseDirection::Inverse,
+            reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
+            include_subtypes: true,
+            node_class_mask: 0xFF,
+            result_mask: BrowseResultMask::None as u32,
+        };
+
+        let browse_result = timed(session.timings(), Service::Browse, session.browse(&[browse_description], 0, None))
+            .await
+            .context("Browse request failed")?;
+
+        let parent = browse_result
+            .first()
+            .filter(|r| r.status_code.is_good())
+            .and_then(|r| r.references.as_ref())
+            .and_then(|refs| refs.first())
+            .map(|r| r.node_id.node_id.clone())
+            .with_context(|| format!("Node {} has no parent", current))?;
+
+        if parent == *node_id || path.contains(&parent) {
+            anyhow::bail!("Reference loop while resolving path of {}", node_id);
+        }
+        path.push(parent.clone());
+        current = parent;
+    }
+
+    path.reverse();
+    Ok(path)
+}
+
+
+
+pub fn parse_browse_path(path: &str) -> Result<Vec<QualifiedName>> {
+    let segments: Vec<QualifiedName> = path
+        .split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.split_once(':') {
+            Some((ns, name)) if !ns.is_empty() && ns.chars().all(|c| c.is_ascii_digit()) => {
+                ns.parse::<u16>()
+                    .map(|ns| QualifiedName::new(ns, name))
+                    .map_err(|_| anyhow::anyhow!("Invalid namespace index in '{}'", segment))
+            }
+            _ => Ok(QualifiedName::new(0, segment)),
+        })
+        .collect::<Result<_>>()?;
+
+    if segments.is_empty() {
+        anyhow::bail!("Empty browse path");
+    }
+    Ok(segments)
+}
+
+
+pub async fn translate_path(session: ReadOnlyGuard, start: &NodeId, segments: &[QualifiedName]) -> Result<NodeId> {
+    let browse_path = BrowsePath {
+        starting_node: start.clone(),
+        relative_path: RelativePath {
+            elements: Some(
+                segments
+                    .iter()
+                    .map(|name| RelativePathElement {
+                        reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
+                        is_inverse: false,
+                        include_subtypes: true,
+                        target_name: name.clone(),
+                    })
+                    .collect(),
+            ),
+        },
+    };
+
+    let results = timed(session.timings(), Service::TranslateBrowsePaths, session.translate_browse_paths_to_node_ids(&[browse_path]))
+        .await
+        .context("TranslateBrowsePathsToNodeIds request failed")?;
+
+    let result = results.into_iter().next().context("Empty TranslateBrowsePathsToNodeIds response")?;
+    if !result.status_code.is_good() {
+        anyhow::bail!("{}", crate::opcua::status_codes::translate_status_code(result.status_code));
+    }
+
+    result
+        .targets
+        .unwrap_or_default()
+        .into_iter()
+        .find(|target| target.target_id.server_index == 0 && target.remaining_path_index == u32::MAX)
+        .map(|target| target.target_id.node_id)
+        .context("Path did not resolve to a local node")
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgumentInfo {
+    
+    pub name: String,
+    
+    pub data_type: NodeId,
+    
+    pub value_rank: i32,
+    
+    pub array_dimensions: Vec<u32>,
+    
+    pub description: String,
+}
+
+impl ArgumentInfo {
+    
+    pub fn from_variant(value: Option<&Variant>) -> Vec<ArgumentInfo> {
+        let Some(Variant::Array(array)) = value else {
+            return Vec::new();
+        };
+        array
+            .values
+            .iter()
+            .filter_map(|value| match value {
+                Variant::ExtensionObject(object) => object.inner_as::<Argument>(),
+                _ => None,
+            })
+            .map(|argument| ArgumentInfo {
+                name: argument.name.to_string(),
+                data_type: argument.data_type.clone(),
+                value_rank: argument.value_rank,
+                array_dimensions: argument.array_dimensions.clone().unwrap_or_default(),
+                description: argument.description.text.to_string(),
+            })
+            .collect()
+    }
+}
+
+
+const METHOD_ARGUMENT_PROPERTIES: [&str; 2] = ["InputArguments", "OutputArguments"];
+
+
+pub async fn read_method_arguments(session: ReadOnlyGuard, method_node_id: &NodeId) -> Result<(Vec<ArgumentInfo>, Vec<ArgumentInfo>)> {
+    let browse_paths: Vec<BrowsePath> = METHOD_ARGUMENT_PROPERTIES
+        .iter()
+        .map(|name| BrowsePath {
+            starting_node: method_node_id.clone(),
+            relative_path: RelativePath {
+                elements: Some(vec![RelativePathElement {
+                    reference_type_id: ReferenceTypeId::HasProperty.into(),
+                    is_inverse: false,
+                    include_subtypes: false,
+                    target_name: QualifiedName::new(0, *name),
+                }]),
+            },
+        })
+        .collect();
+
+    let results = timed(session.timings(), Service::TranslateBrowsePaths, session.translate_browse_paths_to_node_ids(&browse_paths))
+        .await
+        .context("TranslateBrowsePathsToNodeIds request failed")?;
+
+    
+    let properties: Vec<Option<NodeId>> = results
+        .into_iter()
+        .map(|result| {
+            result
+                .targets
+                .filter(|_| result.status_code.is_good())
+                .unwrap_or_default()
+                .into_iter()
+                .find(|target| target.target_id.server_index == 0)
+                .map(|target| target.target_id.node_id)
+        })
+        .collect();
+    let nodes_to_read: Vec<ReadValueId> = properties
+        .iter()
+        .flatten()
+        .map(|id| ReadValueId::new(id.clone(), AttributeId::Value))
+        .collect();
+    if nodes_to_read.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let values = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+
+    let mut values = values.into_iter();
+    let mut arguments = properties.iter().map(|property| {
+        property
+            .as_ref()
+            .and_then(|_| values.next())
+            .map(|dv| ArgumentInfo::from_variant(dv.value.as_ref()))
+            .unwrap_or_default()
+    });
+    let inputs = arguments.next().unwrap_or_default();
+    let outputs = arguments.next().unwrap_or_default();
+    Ok((inputs, outputs))
+}
 
 #[cfg(test)]
 mod tests {
@@ -161,4 +692,203 @@ mod tests {
         assert_eq!(NodeClass::Variable.icon(), "📊");
         assert_eq!(NodeClass::Method.icon(), "⚡");
     }
+
+    #[test]
+    fn test_parse_browse_path() {
+        let segments = parse_browse_path("/Objects/2:MyDevice/ Temperature ").unwrap();
+        assert_eq!(segments, vec![
+            QualifiedName::new(0, "Objects"),
+            QualifiedName::new(2, "MyDevice"),
+            QualifiedName::new(0, "Temperature"),
+        ]);
+        assert_eq!(parse_browse_path("Objects/Line:1").unwrap()[1], QualifiedName::new(0, "Line:1"));
+        assert!(parse_browse_path("//").is_err());
+        assert!(parse_browse_path("/99999:X").is_err());
+    }
+
+    #[test]
+    fn test_browse_retry_only_for_transient_status() {
+        assert_eq!(browse_retry_delay(StatusCode::BadTooManyOperations, 1), Some(Duration::from_millis(250)));
+        assert_eq!(browse_retry_delay(StatusCode::BadSessionNotActivated, 2), Some(Duration::from_millis(500)));
+        assert_eq!(browse_retry_delay(StatusCode::BadServerTooBusy, BROWSE_MAX_ATTEMPTS), None);
+
+        for definitive in [
+            StatusCode::BadNodeIdUnknown,
+            StatusCode::BadNodeIdInvalid,
+            StatusCode::BadUserAccessDenied,
+            StatusCode::BadViewIdUnknown,
+        ] {
+            assert_eq!(browse_retry_delay(definitive, 1), None, "{:?}", definitive);
+        }
+    }
+
+    #[test]
+    fn test_browse_status_survives_error_context() {
+        let error = Err::<(), _>(StatusCode::BadTooManyOperations)
+            .context("Browse request failed")
+            .unwrap_err();
+        assert_eq!(error.downcast_ref::<StatusCode>(), Some(&StatusCode::BadTooManyOperations));
+    }
+
+    #[test]
+    fn test_access_denied_is_classified_separately() {
+        let denied = Err::<(), _>(StatusCode::BadUserAccessDenied)
+            .context("Browse failed with status: BadUserAccessDenied")
+            .unwrap_err();
+        assert_eq!(BrowseFailure::from_error(&denied), BrowseFailure::AccessDenied);
+
+        let unknown = Err::<(), _>(StatusCode::BadNodeIdUnknown).context("Browse failed").unwrap_err();
+        assert!(matches!(BrowseFailure::from_error(&unknown), BrowseFailure::Error(message) if message.contains("Browse failed")));
+    }
+
+    fn batch(names: &[&str], continuation_point: &[u8]) -> BrowseResult {
+        BrowseResult {
+            status_code: StatusCode::Good,
+            continuation_point: ByteString::from(continuation_point.to_vec()),
+            references: Some(
+                names
+                    .iter()
+                    .map(|name| ReferenceDescription {
+                        node_id: NodeId::new(2, *name).into(),
+                        browse_name: QualifiedName::new(2, *name),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_continuation_batches_are_merged() {
+        let mut later = vec![batch(&["C", "D"], b"2"), batch(&["E"], b"")].into_iter();
+        let mut requested = Vec::new();
+        let mut pending = None;
+        let references = collect_references(batch(&["A", "B"], b"1"), &mut pending, |point| {
+            requested.push(point);
+            let next = later.next().context("no more batches");
+            async move { next }
+        })
+        .await
+        .unwrap();
+
+        let names: Vec<String> = references.iter().map(|r| r.browse_name.name.to_string()).collect();
+        assert_eq!(names, ["A", "B", "C", "D", "E"]);
+        assert_eq!(requested, [ByteString::from(b"1".to_vec()), ByteString::from(b"2".to_vec())]);
+        assert!(pending.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_leaves_continuation_point_to_release() {
+        let mut pending = None;
+        let error = collect_references(batch(&["A"], b"1"), &mut pending, |_| async {
+            Err::<BrowseResult, _>(StatusCode::BadTimeout).context("BrowseNext request failed")
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(error.downcast_ref::<StatusCode>(), Some(&StatusCode::BadTimeout));
+        assert_eq!(pending, Some(ByteString::from(b"1".to_vec())));
+
+        let mut pending = None;
+        let mut bad = batch(&[], b"");
+        bad.status_code = StatusCode::BadContinuationPointInvalid;
+        let error = collect_references(batch(&["A"], b"1"), &mut pending, |_| {
+            let bad = bad.clone();
+            async move { Ok(bad) }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(error.downcast_ref::<StatusCode>(), Some(&StatusCode::BadContinuationPointInvalid));
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn test_method_arguments_from_property_value() {
+        let argument = |name: &str, data_type: u32, value_rank: i32| Argument {
+            name: name.into(),
+            data_type: NodeId::new(0, data_type),
+            value_rank,
+            array_dimensions: (value_rank == 1).then(|| vec![0]),
+            description: opcua::types::LocalizedText::new("en", &format!("{} of the recipe", name)),
+        };
+        let value = Variant::Array(Box::new(
+            opcua::types::Array::new(opcua::types::VariantScalarTypeId::ExtensionObject, vec![
+                Variant::from(opcua::types::ExtensionObject::from_message(argument("RecipeId", 7, -1))),
+                Variant::from(opcua::types::ExtensionObject::from_message(argument("Setpoints", 11, 1))),
+            ])
+            .unwrap(),
+        ));
+
+        let arguments = ArgumentInfo::from_variant(Some(&value));
+        assert_eq!(arguments.len(), 2);
+        assert_eq!(arguments[0].name, "RecipeId");
+        assert_eq!(arguments[0].data_type, NodeId::new(0, 7u32));
+        assert_eq!(arguments[0].description, "RecipeId of the recipe");
+        assert_eq!(arguments[1].value_rank, 1);
+        assert_eq!(arguments[1].array_dimensions, vec![0]);
+
+        assert!(ArgumentInfo::from_variant(None).is_empty());
+        assert!(ArgumentInfo::from_variant(Some(&Variant::Int32(3))).is_empty());
+    }
+
+    fn child(parent: &str, name: &str, node_class: NodeClass) -> BrowsedNode {
+        BrowsedNode {
+            node_id: NodeId::new(2, name),
+            browse_name: name.to_string(),
+            display_name: name.to_string(),
+            node_class,
+            type_definition: None,
+            has_children: node_class == NodeClass::Object,
+            crawl_root: None,
+            parent_id: Some(NodeId::new(2, parent)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_variables_respects_depth_and_skips_revisits() {
+        let tree = |node_id: &NodeId| -> Result<Vec<BrowsedNode>> {
+            match node_id.to_string().as_str() {
+                "ns=2;s=Line" => Ok(vec![
+                    child("Line", "Speed", NodeClass::Variable),
+                    child("Line", "Start", NodeClass::Method),
+                    child("Line", "Motor", NodeClass::Object),
+                    child("Line", "Broken", NodeClass::Object),
+                ]),
+                "ns=2;s=Motor" => Ok(vec![child("Motor", "Current", NodeClass::Variable), child("Motor", "Line", NodeClass::Object)]),
+                _ => anyhow::bail!("BadNodeIdUnknown"),
+            }
+        };
+        let names = |nodes: Vec<BrowsedNode>| nodes.into_iter().map(|n| n.display_name).collect::<Vec<_>>();
+        let root = NodeId::new(2, "Line");
+
+        let direct = collect_variables(&root, 1, |id| std::future::ready(tree(&id))).await.unwrap();
+        assert!(!direct.truncated);
+        assert_eq!(names(direct.variables), ["Speed"]);
+
+        let mut browsed = Vec::new();
+        let recursive = collect_variables(&root, 3, |id| {
+            browsed.push(id.clone());
+            std::future::ready(tree(&id))
+        })
+        .await
+        .unwrap();
+        assert_eq!(names(recursive.variables), ["Speed", "Current"]);
+        assert_eq!(browsed.len(), 3);
+
+        assert!(collect_variables(&NodeId::new(2, "Missing"), 1, |id| std::future::ready(tree(&id))).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_collect_variables_reports_browse_budget_exhaustion() {
+        let mut level = 0;
+        let endless = |_: NodeId| {
+            level += 1;
+            std::future::ready(Ok(vec![
+                child("Level", &format!("Value{}", level), NodeClass::Variable),
+                child("Level", &format!("Level{}", level), NodeClass::Object),
+            ]))
+        };
+        let collected = collect_variables(&NodeId::new(2, "Root"), usize::MAX, endless).await.unwrap();
+        assert!(collected.truncated);
+        assert_eq!(collected.variables.len(), FOLDER_VARIABLES_MAX_BROWSES);
+    }
 }
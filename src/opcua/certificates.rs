@@ -3,8 +3,12 @@
 
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 use std::fs;
+use x509_cert::der::{Decode, DecodePem, Encode};
+use x509_cert::Certificate;
 
 
 pub struct CertificateManager {
@@ -124,6 +128,20 @@ impl CertificateManager {
     }
 
     
+    pub fn inventory(&self) -> Vec<CertificateEntry> {
+        let client = self.get_client_cert().map(|info| (CertStore::Client, info));
+        let trusted = self.list_trusted_certs().into_iter().map(|info| (CertStore::Trusted, info));
+        let rejected = self.list_rejected_certs().into_iter().map(|info| (CertStore::Rejected, info));
+
+        client
+            .into_iter()
+            .chain(trusted)
+            .chain(rejected)
+            .map(|(store, info)| CertificateEntry::load(store, info))
+            .collect()
+    }
+
+    
     pub fn trust_certificate(&self, cert_path: &Path) -> Result<()> {
         if !cert_path.exists() {
             anyhow::bail!("Certificate file not found: {:?}", cert_path);
@@ -182,6 +200,93 @@ pub struct CertificateInfo {
     pub name: String,
 }
 
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertStore {
+    Client,
+    Trusted,
+    Rejected,
+}
+
+impl CertStore {
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            CertStore::Client => "client",
+            CertStore::Trusted => "trusted",
+            CertStore::Rejected => "rejected",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateDetails {
+    
+    pub subject: String,
+    
+    pub issuer: String,
+    
+    pub not_before: DateTime<Utc>,
+    
+    pub not_after: DateTime<Utc>,
+    
+    pub sha256_thumbprint: String,
+}
+
+impl CertificateDetails {
+    
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let cert = if data.starts_with(b"-----BEGIN") {
+            Certificate::from_pem(data).context("Invalid PEM certificate")?
+        } else {
+            Certificate::from_der(data).context("Invalid DER certificate")?
+        };
+        let der = cert.to_der().context("Failed to re-encode certificate")?;
+        let validity = &cert.tbs_certificate.validity;
+        let timestamp = |t: &x509_cert::time::Time| {
+            DateTime::<Utc>::from_timestamp(t.to_unix_duration().as_secs() as i64, 0)
+                .context("Certificate date out of range")
+        };
+
+        Ok(Self {
+            subject: cert.tbs_certificate.subject.to_string(),
+            issuer: cert.tbs_certificate.issuer.to_string(),
+            not_before: timestamp(&validity.not_before)?,
+            not_after: timestamp(&validity.not_after)?,
+            sha256_thumbprint: Sha256::digest(&der).iter().map(|b| format!("{:02X}", b)).collect(),
+        })
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct CertificateEntry {
+    
+    pub store: CertStore,
+    
+    pub info: CertificateInfo,
+    
+    pub details: Option<CertificateDetails>,
+}
+
+impl CertificateEntry {
+    
+    pub fn load(store: CertStore, info: CertificateInfo) -> Self {
+        let details = fs::read(&info.path)
+            .context("Failed to read certificate")
+            .and_then(|data| CertificateDetails::parse(&data));
+        if let Err(e) = &details {
+            tracing::warn!("Could not parse certificate {:?}: {:#}", info.path, e);
+        }
+        Self {
+            store,
+            info,
+            details: details.ok(),
+        }
+    }
+}
+
 impl Default for CertificateManager {
     fn default() -> Self {
         Self::new().expect("Failed to create CertificateManager")
@@ -4,19 +4,29 @@
 
 
 use anyhow::{Context, Result};
-use std::sync::Arc;
+use futures::TryStreamExt;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::task::JoinHandle;
 use std::sync::atomic::{AtomicU32, Ordering};
 
-use opcua::client::{Client, ClientBuilder, IdentityToken, Session, Password, MonitoredItem};
-use opcua::types::{EndpointDescription, MessageSecurityMode as OpcMessageSecurityMode, UserTokenPolicy, UserTokenType, StatusCode, NodeId, DataValue};
+use opcua::client::{Client, ClientBuilder, IdentityToken, Session, SessionPollResult, Password, MonitoredItem};
+use opcua::crypto::{PrivateKey, X509};
+use opcua::types::{EndpointDescription, MessageSecurityMode as OpcMessageSecurityMode, UserTokenPolicy, UserTokenType, StatusCode, NodeId, DataValue, Variant};
 
 use crate::config::bookmarks::{AuthMethod, MessageSecurityMode, SecurityPolicy, ServerBookmark};
 use crate::opcua::certificates::CertificateManager;
+use crate::opcua::read_only::ReadOnlyGuard;
+use crate::opcua::service_timing::{timed, Service, ServiceTimings, SharedServiceTimings};
+use crate::opcua::subscription::{CreatedItem, MonitoredItemConfig, MonitoringOptions, SubscriptionConfig};
 
 static NEXT_CLIENT_HANDLE: AtomicU32 = AtomicU32::new(1);
 
 
+const EVENT_QUEUE_SIZE: u32 = 100;
+
+
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     
@@ -27,26 +37,34 @@ pub struct ClientConfig {
     pub security_mode: MessageSecurityMode,
     
     pub auth_method: AuthMethod,
+    
+    pub skip_endpoint_matching: bool,
 }
 
 impl ClientConfig {
     
-    #[allow(dead_code)]
     pub fn from_bookmark(bookmark: &ServerBookmark) -> Self {
         Self {
             endpoint_url: bookmark.endpoint_url.clone(),
             security_policy: bookmark.security_policy.clone(),
             security_mode: bookmark.security_mode.clone(),
             auth_method: bookmark.auth_method.clone(),
+            skip_endpoint_matching: false,
         }
     }
 
     
-    pub fn identity_token(&self) -> IdentityToken {
+    pub fn identity_token(&self) -> Result<IdentityToken> {
         match &self.auth_method {
-            AuthMethod::Anonymous => IdentityToken::Anonymous,
+            AuthMethod::Anonymous => Ok(IdentityToken::Anonymous),
             AuthMethod::UserPassword { username, password } => {
-                IdentityToken::UserName(username.clone(), Password::from(password.clone()))
+                Ok(IdentityToken::UserName(username.clone(), Password::from(password.clone())))
+            }
+            AuthMethod::Certificate { cert_path, key_path } => {
+                let cert = load_user_certificate(cert_path)?;
+                let key = PrivateKey::read_pem_file(key_path)
+                    .map_err(|_| anyhow::anyhow!("Invalid private key: {}", key_path.display()))?;
+                Ok(IdentityToken::new_x509(cert, key))
             }
         }
     }
@@ -83,8 +101,187 @@ impl ClientConfig {
                 issuer_endpoint_url: Default::default(),
                 security_policy_uri: Default::default(),
             },
+            AuthMethod::Certificate { .. } => UserTokenPolicy {
+                policy_id: "certificate".into(),
+                token_type: UserTokenType::Certificate,
+                issued_token_type: Default::default(),
+                issuer_endpoint_url: Default::default(),
+                security_policy_uri: Default::default(),
+            },
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointSelection {
+    
+    pub endpoint_url: String,
+    
+    pub security_policy: String,
+    
+    pub security_mode: OpcMessageSecurityMode,
+}
+
+impl EndpointSelection {
+    
+    pub fn from_config(config: &ClientConfig) -> Self {
+        Self {
+            endpoint_url: config.endpoint_url.clone(),
+            security_policy: config.security_policy_string().to_string(),
+            security_mode: config.opcua_message_security_mode(),
+        }
+    }
+
+    
+    pub fn from_description(endpoint: &EndpointDescription) -> Self {
+        let uri = endpoint.security_policy_uri.as_ref();
+        Self {
+            endpoint_url: endpoint.endpoint_url.to_string(),
+            security_policy: uri.rsplit('#').next().unwrap_or(uri).to_string(),
+            security_mode: endpoint.security_mode,
+        }
+    }
+
+    
+    pub fn security_label(&self) -> String {
+        format!("{} / {:?}", self.security_policy, self.security_mode)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointMismatch {
+    Url,
+    SecurityPolicy,
+    SecurityMode,
+}
+
+
+
+fn endpoint_url_key(url: &str) -> (u16, String) {
+    let port = crate::network::precheck::parse_endpoint_url(url).map_or(4840, |(_, port)| port);
+    let path = url
+        .strip_prefix("opc.tcp://")
+        .and_then(|rest| rest.split_once('/'))
+        .map_or("", |(_, path)| path.trim_end_matches('/'));
+    (port, path.to_string())
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointCheck {
+    
+    pub requested: EndpointSelection,
+    
+    pub negotiated: EndpointSelection,
+}
+
+impl EndpointCheck {
+    
+    pub fn mismatches(&self) -> Vec<EndpointMismatch> {
+        let mut mismatches = Vec::new();
+        if endpoint_url_key(&self.requested.endpoint_url) != endpoint_url_key(&self.negotiated.endpoint_url) {
+            mismatches.push(EndpointMismatch::Url);
+        }
+        if !self.requested.security_policy.eq_ignore_ascii_case(&self.negotiated.security_policy) {
+            mismatches.push(EndpointMismatch::SecurityPolicy);
         }
+        if self.requested.security_mode != self.negotiated.security_mode {
+            mismatches.push(EndpointMismatch::SecurityMode);
+        }
+        mismatches
+    }
+}
+
+
+fn load_user_certificate(path: &Path) -> Result<X509> {
+    let data = std::fs::read(path)
+        .with_context(|| format!("Failed to read user certificate {}", path.display()))?;
+    X509::from_pem(&data)
+        .or_else(|_| X509::from_der(&data))
+        .map_err(|_| anyhow::anyhow!("Invalid user certificate: {}", path.display()))
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SessionStats {
+    
+    pub connected_since: Instant,
+    
+    pub reconnect_count: u32,
+    
+    pub total_downtime: Duration,
+    
+    pub disconnected_since: Option<Instant>,
+    
+    established: bool,
+}
+
+impl SessionStats {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            connected_since: now,
+            reconnect_count: 0,
+            total_downtime: Duration::ZERO,
+            disconnected_since: None,
+            established: false,
+        }
+    }
+
+    
+    pub fn on_connected(&mut self, now: Instant) {
+        if !self.established {
+            self.established = true;
+            self.connected_since = now;
+            return;
+        }
+        self.reconnect_count += 1;
+        if let Some(since) = self.disconnected_since.take() {
+            self.total_downtime += now.saturating_duration_since(since);
+        }
+    }
+
+    
+    pub fn on_connection_lost(&mut self, now: Instant) {
+        if self.disconnected_since.is_none() {
+            self.disconnected_since = Some(now);
+        }
+    }
+
+    
+    pub fn downtime(&self, now: Instant) -> Duration {
+        self.total_downtime
+            + self.disconnected_since
+                .map(|since| now.saturating_duration_since(since))
+                .unwrap_or(Duration::ZERO)
     }
+
+    
+    pub fn uptime(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.connected_since)
+            .saturating_sub(self.downtime(now))
+    }
+}
+
+
+pub const CONNECTION_LOSS_GRACE: Duration = Duration::from_secs(3);
+
+
+pub fn session_alive(event_loop_finished: bool, stats: &SessionStats, now: Instant) -> bool {
+    !event_loop_finished
+        && stats.disconnected_since.map_or(true, |since| now.saturating_duration_since(since) < CONNECTION_LOSS_GRACE)
+}
+
+
+pub type SharedSessionStats = Arc<Mutex<SessionStats>>;
+
+
+fn event_loop_alive(handle: &JoinHandle<StatusCode>, stats: &SharedSessionStats, now: Instant) -> bool {
+    let Ok(stats) = stats.lock() else {
+        return false;
+    };
+    session_alive(handle.is_finished(), &stats, now)
 }
 
 /// OPC-UA client wrapper with session management
@@ -95,8 +292,11 @@ pub struct OpcUaClient {
     /// The active session (if connected)
     session: Arc<Session>,
     /// Event loop handle
-    #[allow(dead_code)]
     event_loop_handle: JoinHandle<StatusCode>,
+    
+    stats: SharedSessionStats,
+    
+    timings: SharedServiceTimings,
 }
 
 impl OpcUaClient {
@@ -131,14 +331,34 @@ impl OpcUaClient {
 
         tracing::info!("Connecting to endpoint: {:?}", endpoint.endpoint_url);
 
-        // Connect to matching endpoint
-        let (session, event_loop) = client
-            .connect_to_matching_endpoint(endpoint, config.identity_token())
-            .await
-            .context("Failed to connect to endpoint")?;
-
-        // Spawn the event loop
-        let event_loop_handle = event_loop.spawn();
+        let identity_token = config.identity_token()?;
+        let (session, event_loop) = if config.skip_endpoint_matching {
+            tracing::info!("Endpoint matching disabled, connecting directly to {}", config.endpoint_url);
+            client
+                .connect_to_endpoint_directly(endpoint, identity_token)
+                .context("Failed to connect directly to endpoint")?
+        } else {
+            tracing::info!("Connecting via GetEndpoints matching");
+            client
+                .connect_to_matching_endpoint(endpoint, identity_token)
+                .await
+                .context("Failed to connect to endpoint")?
+        };
+
+        // Spawn the event loop, recording connection losses and reconnects
+        let stats: SharedSessionStats = Arc::new(Mutex::new(SessionStats::new(Instant::now())));
+        let loop_stats = stats.clone();
+        let event_loop_handle = tokio::spawn(async move {
+            let stream = event_loop.enter();
+            tokio::pin!(stream);
+            loop {
+                match stream.try_next().await {
+                    Ok(Some(event)) => record_session_event(&loop_stats, &event),
+                    Ok(None) => break StatusCode::Good,
+                    Err(e) => break e,
+                }
+            }
+        });
 
         // Wait for connection to be established
         session.wait_for_connection().await;
@@ -149,6 +369,8 @@ impl OpcUaClient {
             client,
             session,
             event_loop_handle,
+            stats,
+            timings: Arc::new(Mutex::new(ServiceTimings::default())),
         })
     }
 
@@ -159,25 +381,38 @@ impl OpcUaClient {
         tracing::info!("Disconnected successfully");
     }
 
-    /// Get a reference to the session for operations
-    pub fn session(&self) -> Arc<Session> {
-        self.session.clone()
+    
+    pub fn stats(&self) -> SharedSessionStats {
+        self.stats.clone()
+    }
+
+    
+    pub fn service_timings(&self) -> SharedServiceTimings {
+        self.timings.clone()
+    }
+
+    
+    pub fn negotiated_endpoint(&self) -> EndpointDescription {
+        self.session.endpoint_info().endpoint.clone()
+    }
+
+    /// Get read-only access to the session for operations
+    pub fn session(&self) -> ReadOnlyGuard {
+        ReadOnlyGuard::new(self.session.clone(), self.timings.clone())
     }
 
     /// Check if the session is still connected
-    /// Note: This checks if the session object exists; actual connection state
-    /// may need to be verified through a session service call
+    /// The event loop must still be running and the secure channel must not have
+    /// been down for longer than the grace period the client gets to reconnect on its own
     pub fn is_connected(&self) -> bool {
-        // The session object exists, assume connected unless we get an error
-        // The connection_state is checked via keepalives in the event loop
-        true
+        event_loop_alive(&self.event_loop_handle, &self.stats, Instant::now())
     }
 
     /// Create a subscription for monitoring items
     /// Returns the subscription ID
     pub async fn create_subscription<F>(
         &self,
-        publishing_interval: std::time::Duration,
+        config: SubscriptionConfig,
         callback: F,
     ) -> Result<u32>
     where
@@ -185,18 +420,18 @@ impl OpcUaClient {
     {
         use opcua::client::DataChangeCallback;
 
-        tracing::info!("Creating subscription with interval {:?}", publishing_interval);
+        tracing::info!("Creating subscription with {:?}", config);
 
-        let subscription_id = self.session
+        let subscription_id = timed(&self.timings, Service::CreateSubscription, self.session
             .create_subscription(
-                publishing_interval,
-                10,     
-                30,     
-                0,      
-                0,      
+                config.publishing_interval(),
+                config.lifetime_count,
+                config.max_keepalive_count,
+                config.max_notifications,
+                config.priority,
                 true,   
                 DataChangeCallback::new(callback),
-            )
+            ))
             .await
             .context("Failed to create subscription")?;
 
@@ -204,6 +439,81 @@ impl OpcUaClient {
         Ok(subscription_id)
     }
 
+    /// Create a subscription that delivers event notifications instead of data changes
+    pub async fn create_event_subscription<F>(
+        &self,
+        config: SubscriptionConfig,
+        callback: F,
+    ) -> Result<u32>
+    where
+        F: FnMut(Option<Vec<Variant>>, &MonitoredItem) + Send + Sync + 'static,
+    {
+        use opcua::client::EventCallback;
+
+        tracing::info!("Creating event subscription with {:?}", config);
+        let subscription_id = timed(&self.timings, Service::CreateSubscription, self.session
+            .create_subscription(
+                config.publishing_interval(),
+                config.lifetime_count,
+                config.max_keepalive_count,
+                config.max_notifications,
+                config.priority,
+                true,
+                EventCallback::new(callback),
+            ))
+            .await
+            .context("Failed to create event subscription")?;
+
+        tracing::info!("Created event subscription with ID: {}", subscription_id);
+        Ok(subscription_id)
+    }
+
+    /// Monitor the EventNotifier attribute of a node, selecting the fields in `events::EVENT_FIELDS`
+    pub async fn add_event_monitored_item(&self, subscription_id: u32, notifier: &NodeId) -> Result<u32> {
+        use opcua::types::{AttributeId, MonitoredItemCreateRequest, MonitoringMode, MonitoringParameters, ReadValueId, TimestampsToReturn};
+
+        tracing::info!("Monitoring events of {:?} in subscription {}", notifier, subscription_id);
+        let request = MonitoredItemCreateRequest::new(
+            ReadValueId::new(notifier.clone(), AttributeId::EventNotifier),
+            MonitoringMode::Reporting,
+            MonitoringParameters {
+                client_handle: NEXT_CLIENT_HANDLE.fetch_add(1, Ordering::Relaxed),
+                sampling_interval: 0.0,
+                filter: crate::opcua::events::event_filter(),
+                queue_size: EVENT_QUEUE_SIZE,
+                discard_oldest: true,
+            },
+        );
+
+        let result = timed(&self.timings, Service::CreateMonitoredItems, self.session.create_monitored_items(subscription_id, TimestampsToReturn::Neither, vec![request]))
+            .await
+            .context("Failed to create event monitored item")?
+            .into_iter()
+            .next()
+            .context("CreateMonitoredItems returned no result")?;
+        if result.result.status_code.is_bad() {
+            return Err(result.result.status_code)
+                .with_context(|| format!("The server rejected the event monitored item on {}", notifier));
+        }
+        Ok(result.result.monitored_item_id)
+    }
+
+    /// Change the timing parameters of an existing subscription
+    pub async fn modify_subscription(&self, subscription_id: u32, config: SubscriptionConfig) -> Result<()> {
+        tracing::info!("Modifying subscription {} to {:?}", subscription_id, config);
+        timed(&self.timings, Service::ModifySubscription, self.session
+            .modify_subscription(
+                subscription_id,
+                config.publishing_interval(),
+                config.lifetime_count,
+                config.max_keepalive_count,
+                config.max_notifications,
+                config.priority,
+            ))
+            .await
+            .with_context(|| format!("Failed to modify subscription {}", subscription_id))
+    }
+
 
 
     
@@ -211,47 +521,141 @@ impl OpcUaClient {
     pub async fn add_monitored_items(
         &self,
         subscription_id: u32,
-        node_ids: &[NodeId],
-    ) -> Result<Vec<(NodeId, u32, u32)>> {
-        use opcua::types::{MonitoredItemCreateRequest, TimestampsToReturn};
+        items: &[(NodeId, MonitoredItemConfig)],
+        options: MonitoringOptions,
+    ) -> Result<(Vec<CreatedItem>, Vec<(NodeId, StatusCode)>)> {
+        use opcua::types::MonitoredItemCreateRequest;
 
-        if node_ids.is_empty() {
-            return Ok(Vec::new());
+        if items.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
         }
 
-        tracing::info!("Adding {} monitored items to subscription {}", node_ids.len(), subscription_id);
+        tracing::info!(
+            "Adding {} monitored items to subscription {} (queue size {}, discard oldest {}, timestamps {:?})",
+            items.len(), subscription_id, options.queue_size, options.discard_oldest, options.timestamps
+        );
 
         
-        let mut items = Vec::with_capacity(node_ids.len());
-        let mut handles = Vec::with_capacity(node_ids.len());
+        let mut requests = Vec::with_capacity(items.len());
+        let mut handles = Vec::with_capacity(items.len());
+        let node_ids: Vec<&NodeId> = items.iter().map(|(node_id, _)| node_id).collect();
 
-        for node_id in node_ids {
+        for (node_id, parameters) in items {
             let client_handle = NEXT_CLIENT_HANDLE.fetch_add(1, Ordering::Relaxed);
             let mut request: MonitoredItemCreateRequest = node_id.clone().into();
-            request.requested_parameters.client_handle = client_handle;
-            items.push(request);
+            request.requested_parameters = parameters.monitoring_parameters(options, client_handle);
+            requests.push(request);
             handles.push(client_handle);
         }
 
         
-        let results = self.session
-            .create_monitored_items(subscription_id, TimestampsToReturn::Both, items)
+        let results = timed(&self.timings, Service::CreateMonitoredItems, self.session.create_monitored_items(subscription_id, options.timestamps.to_opcua(), requests))
             .await
             .context("Failed to create monitored items")?;
 
         
-        let mut pairs = Vec::new();
+        let mut created = Vec::new();
+        let mut failed = Vec::new();
         for (i, result) in results.iter().enumerate() {
             if result.result.status_code.is_good() {
-                pairs.push((node_ids[i].clone(), result.result.monitored_item_id, handles[i]));
+                created.push(CreatedItem {
+                    node_id: node_ids[i].clone(),
+                    monitored_item_id: result.result.monitored_item_id,
+                    client_handle: handles[i],
+                    revised_sampling_interval: result.result.revised_sampling_interval,
+                    revised_queue_size: result.result.revised_queue_size,
+                });
                 tracing::debug!("Monitored item created: {:?} -> ID: {}, Handle: {}", node_ids[i], result.result.monitored_item_id, handles[i]);
             } else {
                 tracing::warn!("Failed to create monitored item for {:?}: {:?}", node_ids[i], result.result.status_code);
+                failed.push((node_ids[i].clone(), result.result.status_code));
             }
         }
 
-        tracing::info!("Successfully created {} monitored items", pairs.len());
-        Ok(pairs)
+        tracing::info!("Successfully created {} monitored items", created.len());
+        Ok((created, failed))
+    }
+
+    
+    pub async fn modify_monitored_items(
+        &self,
+        subscription_id: u32,
+        items: &[(u32, u32, MonitoredItemConfig)],
+        options: MonitoringOptions,
+    ) -> Result<Vec<Result<(f64, u32), StatusCode>>> {
+        use opcua::types::MonitoredItemModifyRequest;
+
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        tracing::info!("Modifying {} monitored items in subscription {}", items.len(), subscription_id);
+        let requests: Vec<MonitoredItemModifyRequest> = items
+            .iter()
+            .map(|(monitored_item_id, client_handle, parameters)| MonitoredItemModifyRequest {
+                monitored_item_id: *monitored_item_id,
+                requested_parameters: parameters.monitoring_parameters(options, *client_handle),
+            })
+            .collect();
+
+        let results = timed(&self.timings, Service::ModifyMonitoredItems, self.session.modify_monitored_items(subscription_id, options.timestamps.to_opcua(), &requests))
+            .await
+            .context("Failed to modify monitored items")?;
+
+        Ok(results
+            .into_iter()
+            .zip(items)
+            .map(|(result, (monitored_item_id, _, _))| {
+                if result.status_code.is_good() {
+                    Ok((result.revised_sampling_interval, result.revised_queue_size))
+                } else {
+                    tracing::warn!("Failed to modify monitored item {}: {:?}", monitored_item_id, result.status_code);
+                    Err(result.status_code)
+                }
+            })
+            .collect())
+    }
+
+    
+    pub async fn read_values(&self, node_ids: &[NodeId]) -> Result<Vec<DataValue>> {
+        use opcua::types::{AttributeId, ReadValueId, TimestampsToReturn};
+
+        let nodes_to_read: Vec<ReadValueId> = node_ids
+            .iter()
+            .map(|id| ReadValueId::new(id.clone(), AttributeId::Value))
+            .collect();
+        timed(&self.timings, Service::Read, self.session.read(&nodes_to_read, TimestampsToReturn::Both, 0.0))
+            .await
+            .context("Read request failed")
+    }
+
+    
+    pub async fn read_node_attributes(&self, node_id: &NodeId) -> Result<crate::opcua::node_attributes::NodeAttributes> {
+        crate::opcua::node_attributes::read_node_attributes(self.session(), node_id).await
+    }
+
+    
+    pub async fn read_raw_history(
+        &self,
+        node_id: &NodeId,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        max_values: u32,
+    ) -> Result<Vec<(f64, f64)>> {
+        crate::opcua::history::read_raw_history(self.session(), node_id, start, end, max_values).await
+    }
+
+    
+    pub async fn read_node_value(&self, node_id: &NodeId) -> Result<DataValue> {
+        use opcua::types::{AttributeId, ReadValueId, TimestampsToReturn};
+
+        let node_to_read = [ReadValueId::new(node_id.clone(), AttributeId::Value)];
+        timed(&self.timings, Service::Read, self.session().read(&node_to_read, TimestampsToReturn::Both, 0.0))
+            .await
+            .context("Read request failed")?
+            .into_iter()
+            .next()
+            .context("Read response contained no results")
     }
 
     
@@ -281,7 +685,6 @@ impl OpcUaClient {
     }
 
     
-    #[allow(dead_code)]
     pub async fn delete_subscription(&self, subscription_id: u32) -> Result<()> {
         tracing::info!("Deleting subscription {}", subscription_id);
         
@@ -299,3 +702,154 @@ impl OpcUaClient {
         Ok(())
     }
 }
+
+
+fn record_session_event(stats: &SharedSessionStats, event: &SessionPollResult) {
+    let Ok(mut stats) = stats.lock() else { return };
+    match event {
+        SessionPollResult::Reconnected(mode) => {
+            stats.on_connected(Instant::now());
+            if stats.reconnect_count > 0 {
+                tracing::warn!("Session reconnected ({:?}), total reconnects: {}", mode, stats.reconnect_count);
+            }
+        }
+        SessionPollResult::ConnectionLost(status) => {
+            tracing::warn!("Session connection lost: {}", status);
+            stats.on_connection_lost(Instant::now());
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_stats_initial_connect_is_not_a_reconnect() {
+        let start = Instant::now();
+        let mut stats = SessionStats::new(start);
+        stats.on_connected(start);
+        assert_eq!(stats.reconnect_count, 0);
+        assert_eq!(stats.downtime(start + Duration::from_secs(5)), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_session_stats_accumulates_downtime() {
+        let start = Instant::now();
+        let mut stats = SessionStats::new(start);
+        stats.on_connected(start);
+
+        stats.on_connection_lost(start + Duration::from_secs(10));
+        assert_eq!(stats.downtime(start + Duration::from_secs(12)), Duration::from_secs(2));
+
+        stats.on_connected(start + Duration::from_secs(13));
+        stats.on_connection_lost(start + Duration::from_secs(20));
+        stats.on_connected(start + Duration::from_secs(24));
+
+        assert_eq!(stats.reconnect_count, 2);
+        assert_eq!(stats.total_downtime, Duration::from_secs(7));
+        assert_eq!(stats.uptime(start + Duration::from_secs(30)), Duration::from_secs(23));
+    }
+
+    #[test]
+    fn test_session_is_dead_after_grace_or_when_event_loop_exits() {
+        let start = Instant::now();
+        let mut stats = SessionStats::new(start);
+        stats.on_connected(start);
+        assert!(session_alive(false, &stats, start));
+        assert!(!session_alive(true, &stats, start));
+
+        stats.on_connection_lost(start + Duration::from_secs(10));
+        assert!(session_alive(false, &stats, start + Duration::from_secs(11)));
+        assert!(!session_alive(false, &stats, start + Duration::from_secs(10) + CONNECTION_LOSS_GRACE));
+
+        stats.on_connected(start + Duration::from_secs(20));
+        assert!(session_alive(false, &stats, start + Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn test_finished_event_loop_is_not_connected() {
+        let start = Instant::now();
+        let stats: SharedSessionStats = Arc::new(Mutex::new(SessionStats::new(start)));
+        stats.lock().unwrap().on_connected(start);
+
+        let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            let _ = stop_rx.await;
+            StatusCode::BadConnectionClosed
+        });
+        assert!(event_loop_alive(&handle, &stats, start));
+
+        stop_tx.send(()).unwrap();
+        while !handle.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        assert!(!event_loop_alive(&handle, &stats, start));
+    }
+
+    #[test]
+    fn test_certificate_auth_reports_missing_files() {
+        let config = ClientConfig {
+            endpoint_url: "opc.tcp://plc:4840".to_string(),
+            security_policy: SecurityPolicy::Basic256Sha256,
+            security_mode: MessageSecurityMode::SignAndEncrypt,
+            auth_method: AuthMethod::Certificate {
+                cert_path: "missing/user.der".into(),
+                key_path: "missing/user.pem".into(),
+            },
+            skip_endpoint_matching: false,
+        };
+        assert_eq!(config.user_token_policy().token_type, UserTokenType::Certificate);
+        let err = config.identity_token().unwrap_err().to_string();
+        assert!(err.contains("user.der"), "{}", err);
+    }
+
+    fn selection(url: &str, policy: &str, mode: OpcMessageSecurityMode) -> EndpointSelection {
+        EndpointSelection { endpoint_url: url.to_string(), security_policy: policy.to_string(), security_mode: mode }
+    }
+
+    #[test]
+    fn test_endpoint_selection_from_description_uses_short_policy_name() {
+        let description = EndpointDescription {
+            endpoint_url: "opc.tcp://plc:4840/UA".into(),
+            security_policy_uri: "http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256".into(),
+            security_mode: OpcMessageSecurityMode::SignAndEncrypt,
+            ..Default::default()
+        };
+        let config = ClientConfig {
+            endpoint_url: "opc.tcp://plc:4840/UA".to_string(),
+            security_policy: SecurityPolicy::Basic256Sha256,
+            security_mode: MessageSecurityMode::SignAndEncrypt,
+            auth_method: AuthMethod::Anonymous,
+            skip_endpoint_matching: false,
+        };
+        let check = EndpointCheck {
+            requested: EndpointSelection::from_config(&config),
+            negotiated: EndpointSelection::from_description(&description),
+        };
+        assert_eq!(check.negotiated.security_policy, "Basic256Sha256");
+        assert_eq!(check.negotiated.security_label(), "Basic256Sha256 / SignAndEncrypt");
+        assert!(check.mismatches().is_empty());
+    }
+
+    #[test]
+    fn test_endpoint_check_reports_each_difference() {
+        let requested = selection("opc.tcp://192.168.0.10:4840/UA/Server", "Basic256Sha256", OpcMessageSecurityMode::SignAndEncrypt);
+
+        let same_server_other_host = selection("opc.tcp://PLC01:4840/UA/Server/", "basic256sha256", OpcMessageSecurityMode::SignAndEncrypt);
+        let check = EndpointCheck { requested: requested.clone(), negotiated: same_server_other_host };
+        assert!(check.mismatches().is_empty());
+
+        let other_endpoint = selection("opc.tcp://192.168.0.10:4841/UA/Secure", "None", OpcMessageSecurityMode::None);
+        let check = EndpointCheck { requested: requested.clone(), negotiated: other_endpoint };
+        assert_eq!(
+            check.mismatches(),
+            vec![EndpointMismatch::Url, EndpointMismatch::SecurityPolicy, EndpointMismatch::SecurityMode],
+        );
+
+        let downgraded = selection("opc.tcp://192.168.0.10/UA/Server", "Basic256Sha256", OpcMessageSecurityMode::Sign);
+        let check = EndpointCheck { requested, negotiated: downgraded };
+        assert_eq!(check.mismatches(), vec![EndpointMismatch::SecurityMode]);
+    }
+}
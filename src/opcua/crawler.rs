@@ -2,14 +2,14 @@
 
 
 
-use std::sync::Arc;
-use std::collections::HashSet;
-use std::time::Instant;
-use opcua::client::Session;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use crate::opcua::read_only::ReadOnlyGuard;
 use opcua::types::NodeId;
 use anyhow::Result;
+use tokio_util::sync::CancellationToken;
 
-use crate::opcua::browser::{browse_node, BrowsedNode};
+use crate::opcua::browser::{browse_node_with_retry, read_node, BrowsedNode};
 
 
 #[derive(Debug, Clone)]
@@ -19,46 +19,300 @@ pub struct CrawlConfig {
     
     pub max_nodes: usize,
     
-    pub start_node: NodeId,
+    pub start_nodes: Vec<NodeId>,
+    
+    pub include_start_node: bool,
+}
+
+impl CrawlConfig {
+    
+    pub fn tags_results(&self) -> bool {
+        self.start_nodes.len() > 1
+    }
+
+    
+    pub fn start_nodes_label(&self) -> String {
+        self.start_nodes.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(" | ")
+    }
+}
+
+impl Default for CrawlConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 5,
+            max_nodes: 500_000,
+            start_nodes: vec![NodeId::from(opcua::types::ObjectId::RootFolder)],
+            include_start_node: false,
+        }
+    }
+}
+
+
+pub const CRAWL_CHUNK_SIZE: usize = 1000;
+
+
+pub type ChunkSink = Box<dyn FnMut(Vec<BrowsedNode>) + Send>;
+
+
+pub const PROGRESS_EVERY_NODES: usize = 100;
+
+
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrawlProgress {
+    
+    pub nodes: usize,
+    
+    pub depth: usize,
+    
+    pub current: String,
+}
+
+
+pub type ProgressSink = Box<dyn FnMut(CrawlProgress) + Send>;
+
+
+#[derive(Debug, Default)]
+pub struct ProgressThrottle {
+    last_nodes: usize,
+    last_at: Option<Instant>,
+}
+
+impl ProgressThrottle {
+    
+    pub fn due(&mut self, nodes: usize, now: Instant) -> bool {
+        let due = match self.last_at {
+            None => true,
+            Some(last_at) => {
+                nodes >= self.last_nodes + PROGRESS_EVERY_NODES || now.duration_since(last_at) >= PROGRESS_INTERVAL
+            }
+        };
+        if due {
+            self.last_nodes = nodes;
+            self.last_at = Some(now);
+        }
+        due
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrawlSummary {
+    
+    pub total: usize,
+    
+    pub duration: Duration,
+    
+    pub truncated: bool,
+    
+    pub cancelled: bool,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct CrawlConflict {
+    
+    pub first: BrowsedNode,
+    
+    pub second: BrowsedNode,
+}
+
+impl CrawlConflict {
+    
+    pub fn differences(&self) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.first.display_name != self.second.display_name {
+            fields.push("display_name");
+        }
+        if self.first.browse_name != self.second.browse_name {
+            fields.push("browse_name");
+        }
+        if self.first.node_class != self.second.node_class {
+            fields.push("node_class");
+        }
+        fields
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct DuplicateTracker {
+    seen: HashMap<NodeId, BrowsedNode>,
+    conflicts: Vec<CrawlConflict>,
+}
+
+impl DuplicateTracker {
+    
+    pub fn admit(&mut self, node: &BrowsedNode) -> bool {
+        let Some(first) = self.seen.get(&node.node_id) else {
+            self.seen.insert(node.node_id.clone(), node.clone());
+            return true;
+        };
+        let conflict = CrawlConflict { first: first.clone(), second: node.clone() };
+        let already_recorded = self.conflicts.iter().any(|known| {
+            known.second.node_id == node.node_id
+                && known.second.display_name == node.display_name
+                && known.second.browse_name == node.browse_name
+                && known.second.node_class == node.node_class
+        });
+        if !conflict.differences().is_empty() && !already_recorded {
+            tracing::warn!("NodeId {} seen again with different metadata: {:?}", node.node_id, conflict.differences());
+            self.conflicts.push(conflict);
+        }
+        false
+    }
+
+    
+    pub fn conflicts(&self) -> &[CrawlConflict] {
+        &self.conflicts
+    }
+
+    
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.conflicts.clear();
+    }
 }
 
 
 pub struct Crawler {
-    session: Arc<Session>,
+    session: ReadOnlyGuard,
     visited: HashSet<String>,
     results: Vec<BrowsedNode>,
     config: CrawlConfig,
+    sink: Option<(usize, ChunkSink)>,
+    progress: Option<(ProgressThrottle, ProgressSink)>,
+    total: usize,
+    root: Option<NodeId>,
+    duplicates: DuplicateTracker,
+    cancel: CancellationToken,
+    path: Vec<String>,
 }
 
 impl Crawler {
-    pub fn new(session: Arc<Session>, config: CrawlConfig) -> Self {
+    pub fn new(session: ReadOnlyGuard, config: CrawlConfig, cancel: CancellationToken) -> Self {
         Self {
             session,
             visited: HashSet::new(),
             results: Vec::new(),
             config,
+            sink: None,
+            progress: None,
+            total: 0,
+            root: None,
+            duplicates: DuplicateTracker::default(),
+            cancel,
+            path: Vec::new(),
         }
     }
 
     
+    pub fn with_chunk_sink(mut self, chunk_size: usize, sink: ChunkSink) -> Self {
+        self.sink = Some((chunk_size.max(1), sink));
+        self
+    }
+
+    
+    pub fn with_progress_sink(mut self, sink: ProgressSink) -> Self {
+        self.progress = Some((ProgressThrottle::default(), sink));
+        self
+    }
+
+    
+    pub async fn crawl_streaming(&mut self) -> Result<CrawlSummary> {
+        let start = Instant::now();
+        self.run().await?;
+        self.flush();
+        Ok(CrawlSummary {
+            total: self.total,
+            duration: start.elapsed(),
+            truncated: self.total >= self.config.max_nodes,
+            cancelled: self.cancel.is_cancelled(),
+        })
+    }
+
+    
     pub async fn crawl(&mut self) -> Result<Vec<BrowsedNode>> {
+        self.run().await?;
+        Ok(std::mem::take(&mut self.results))
+    }
+
+    async fn run(&mut self) -> Result<()> {
         self.visited.clear();
         self.results.clear();
+        self.duplicates.clear();
+        self.path.clear();
+        self.total = 0;
 
-        tracing::info!("Starting crawl from {:?} with depth {}", self.config.start_node, self.config.max_depth);
+        tracing::info!("Starting crawl from {} with depth {}", self.config.start_nodes_label(), self.config.max_depth);
         let start = Instant::now();
 
-        
-        self.crawl_recursive(&self.config.start_node.clone(), 0).await?;
+        for start_node in self.config.start_nodes.clone() {
+            if self.total >= self.config.max_nodes || self.cancel.is_cancelled() {
+                break;
+            }
+            self.root = self.config.tags_results().then(|| start_node.clone());
+
+            if self.config.include_start_node && !self.visited.contains(&start_node.to_string()) {
+                match read_node(self.session.clone(), &start_node).await {
+                    Ok(node) => self.push(node),
+                    Err(e) => tracing::warn!("Failed to read start node {:?}: {}", start_node, e),
+                }
+            }
 
-        tracing::info!("Crawl finished. Found {} nodes in {:?}", self.results.len(), start.elapsed());
-        Ok(self.results.clone())
+            
+            self.crawl_recursive(&start_node, 0).await?;
+        }
+
+        if self.cancel.is_cancelled() {
+            tracing::info!("Crawl cancelled. Found {} nodes in {:?}", self.total, start.elapsed());
+        } else {
+            tracing::info!("Crawl finished. Found {} nodes in {:?}", self.total, start.elapsed());
+        }
+        Ok(())
+    }
+
+    
+    pub fn conflicts(&self) -> &[CrawlConflict] {
+        self.duplicates.conflicts()
+    }
+
+    fn push(&mut self, mut node: BrowsedNode) {
+        if !self.duplicates.admit(&node) {
+            return;
+        }
+        node.crawl_root = self.root.clone();
+        self.total += 1;
+        self.results.push(node);
+        if matches!(&self.sink, Some((chunk_size, _)) if self.results.len() >= *chunk_size) {
+            self.flush();
+        }
+    }
+
+    
+    fn report_progress(&mut self, depth: usize, current: &str) {
+        let nodes = self.total;
+        if let Some((throttle, sink)) = self.progress.as_mut() {
+            if throttle.due(nodes, Instant::now()) {
+                sink(CrawlProgress { nodes, depth, current: browse_path(&self.path, current) });
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some((_, sink)) = self.sink.as_mut() {
+            if !self.results.is_empty() {
+                sink(std::mem::take(&mut self.results));
+            }
+        }
     }
 
     #[async_recursion::async_recursion]
     async fn crawl_recursive(&mut self, node_id: &NodeId, depth: usize) -> Result<()> {
         
-        if depth >= self.config.max_depth {
+        if depth >= self.config.max_depth || self.cancel.is_cancelled() {
             return Ok(());
         }
         
@@ -74,27 +328,100 @@ impl Crawler {
         self.visited.insert(node_str);
 
         
-        match browse_node(self.session.clone(), node_id).await {
+        let browsed = tokio::select! {
+            _ = self.cancel.cancelled() => return Ok(()),
+            result = browse_node_with_retry(self.session.clone(), node_id, None) => result,
+        };
+        match browsed {
             Ok(children) => {
-                for child in children {
-                    
-                    self.results.push(child.clone());
-
-                    
-                    if child.has_children {
-                        self.crawl_recursive(&child.node_id, depth + 1).await?;
+                for mut child in children {
+                    if self.total >= self.config.max_nodes || self.cancel.is_cancelled() {
+                        break;
                     }
+                    child.parent_id = Some(node_id.clone());
+
+                    let has_children = child.has_children;
+                    let child_id = child.node_id.clone();
+                    let display_name = child.display_name.clone();
+                    self.push(child);
+                    self.report_progress(depth + 1, &display_name);
+
                     
-                    if self.results.len() >= self.config.max_nodes {
-                        break;
+                    if has_children {
+                        self.path.push(display_name);
+                        let result = self.crawl_recursive(&child_id, depth + 1).await;
+                        self.path.pop();
+                        result?;
                     }
                 }
             }
             Err(e) => {
-                tracing::warn!("Failed to browse node {:?}: {}", node_id, e);
+                tracing::warn!("Failed to browse node {:?}: {:#}", node_id, e);
             }
         }
 
         Ok(())
     }
 }
+
+
+fn browse_path(parents: &[String], current: &str) -> String {
+    parents.iter().map(String::as_str).chain(std::iter::once(current)).collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcua::browser::NodeClass;
+    use crate::utils::test_nodes::TestNode;
+
+    fn node(id: &str, display_name: &str, browse_name: &str, node_class: NodeClass) -> BrowsedNode {
+        TestNode::new(NodeId::new(2, id), display_name).class(node_class).browse_name(browse_name).build()
+    }
+
+    #[test]
+    fn test_browse_path_joins_parents_and_current() {
+        assert_eq!(browse_path(&[], "Objects"), "Objects");
+        assert_eq!(browse_path(&["Objects".into(), "Line1".into()], "Motor1"), "Objects/Line1/Motor1");
+    }
+
+    #[test]
+    fn test_progress_is_throttled_by_count_and_time() {
+        let start = Instant::now();
+        let mut throttle = ProgressThrottle::default();
+        assert!(throttle.due(1, start));
+        assert!(!throttle.due(50, start + Duration::from_millis(10)));
+        assert!(throttle.due(1 + PROGRESS_EVERY_NODES, start + Duration::from_millis(20)));
+        assert!(!throttle.due(120, start + Duration::from_millis(30)));
+        assert!(throttle.due(121, start + Duration::from_millis(20) + PROGRESS_INTERVAL));
+    }
+
+    #[test]
+    fn test_duplicate_with_same_metadata_is_not_a_conflict() {
+        let mut tracker = DuplicateTracker::default();
+        assert!(tracker.admit(&node("Speed", "Speed", "2:Speed", NodeClass::Variable)));
+        assert!(!tracker.admit(&node("Speed", "Speed", "2:Speed", NodeClass::Variable)));
+        assert!(tracker.admit(&node("Power", "Power", "2:Power", NodeClass::Variable)));
+        assert!(tracker.conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_conflicting_metadata_records_both_variants() {
+        let mut tracker = DuplicateTracker::default();
+        assert!(tracker.admit(&node("Speed", "Speed", "2:Speed", NodeClass::Variable)));
+        assert!(!tracker.admit(&node("Speed", "Line 1 Speed", "2:Speed", NodeClass::Variable)));
+        assert!(!tracker.admit(&node("Speed", "Line 1 Speed", "2:Speed", NodeClass::Variable)));
+        assert!(!tracker.admit(&node("Speed", "Speed", "3:Speed", NodeClass::Object)));
+
+        let conflicts = tracker.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].first.display_name, "Speed");
+        assert_eq!(conflicts[0].second.display_name, "Line 1 Speed");
+        assert_eq!(conflicts[0].differences(), vec!["display_name"]);
+        assert_eq!(conflicts[1].differences(), vec!["browse_name", "node_class"]);
+
+        tracker.clear();
+        assert!(tracker.conflicts().is_empty());
+        assert!(tracker.admit(&node("Speed", "Speed", "2:Speed", NodeClass::Variable)));
+    }
+}
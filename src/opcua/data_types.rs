@@ -0,0 +1,147 @@
+
+
+
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{AttributeId, Identifier, NodeId, ReadValueId, TimestampsToReturn, Variant};
+
+use crate::opcua::node_names::NodeNameCache;
+use crate::opcua::service_timing::{timed, Service};
+
+
+const DATA_TYPE_BATCH_SIZE: usize = 500;
+
+
+const BUILTIN_TYPES: [&str; 25] = [
+    "Boolean", "SByte", "Byte", "Int16", "UInt16", "Int32", "UInt32", "Int64", "UInt64",
+    "Float", "Double", "String", "DateTime", "Guid", "ByteString", "XmlElement", "NodeId",
+    "ExpandedNodeId", "StatusCode", "QualifiedName", "LocalizedText", "Structure",
+    "DataValue", "BaseDataType", "DiagnosticInfo",
+];
+
+
+pub fn builtin_type_name(data_type: &NodeId) -> Option<&'static str> {
+    match (data_type.namespace, &data_type.identifier) {
+        (0, Identifier::Numeric(id)) if (1..=25).contains(id) => Some(BUILTIN_TYPES[*id as usize - 1]),
+        _ => None,
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct DataTypeCache {
+    
+    entries: HashMap<NodeId, Option<NodeId>>,
+}
+
+impl DataTypeCache {
+    
+    pub fn take_unknown(&mut self, node_ids: &[NodeId]) -> Vec<NodeId> {
+        let mut unknown = Vec::new();
+        for node_id in node_ids {
+            if !self.entries.contains_key(node_id) {
+                self.entries.insert(node_id.clone(), None);
+                unknown.push(node_id.clone());
+            }
+        }
+        unknown
+    }
+
+    
+    pub fn resolve(&mut self, node_id: NodeId, data_type: Option<NodeId>) {
+        self.entries.insert(node_id, data_type);
+    }
+
+    
+    pub fn custom_types(&self) -> Vec<NodeId> {
+        let mut types: Vec<NodeId> = self
+            .entries
+            .values()
+            .flatten()
+            .filter(|id| builtin_type_name(id).is_none())
+            .cloned()
+            .collect();
+        types.sort_by_key(|id| id.to_string());
+        types.dedup();
+        types
+    }
+
+    
+    pub fn labels(&self, names: &NodeNameCache) -> HashMap<NodeId, String> {
+        self.entries
+            .iter()
+            .filter_map(|(node_id, data_type)| {
+                let data_type = data_type.as_ref()?;
+                let label = match builtin_type_name(data_type) {
+                    Some(name) => name.to_string(),
+                    None => names.name(data_type)?.to_string(),
+                };
+                Some((node_id.clone(), label))
+            })
+            .collect()
+    }
+
+    
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+
+pub async fn read_data_types(session: ReadOnlyGuard, node_ids: &[NodeId]) -> Result<Vec<Option<NodeId>>> {
+    let mut data_types = Vec::with_capacity(node_ids.len());
+
+    for batch in node_ids.chunks(DATA_TYPE_BATCH_SIZE) {
+        let nodes_to_read: Vec<ReadValueId> = batch
+            .iter()
+            .map(|id| ReadValueId::new(id.clone(), AttributeId::DataType))
+            .collect();
+
+        let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+            .await
+            .context("Read request failed")?;
+
+        data_types.extend((0..batch.len()).map(|i| match results.get(i).and_then(|dv| dv.value.as_ref()) {
+            Some(Variant::NodeId(id)) => Some((**id).clone()),
+            _ => None,
+        }));
+    }
+
+    Ok(data_types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_use_builtin_names_then_resolved_names() {
+        let double = NodeId::new(2, "Speed");
+        let custom = NodeId::new(2, "Mode");
+        let unresolved = NodeId::new(2, "Other");
+        let mode_type = NodeId::new(3, 3001);
+
+        let mut cache = DataTypeCache::default();
+        assert_eq!(cache.take_unknown(&[double.clone(), custom.clone(), unresolved.clone()]).len(), 3);
+        assert!(cache.take_unknown(std::slice::from_ref(&double)).is_empty());
+
+        cache.resolve(double.clone(), Some(NodeId::new(0, 11)));
+        cache.resolve(custom.clone(), Some(mode_type.clone()));
+        assert_eq!(cache.custom_types(), vec![mode_type.clone()]);
+
+        let mut names = NodeNameCache::default();
+        assert_eq!(cache.labels(&names).get(&double).map(String::as_str), Some("Double"));
+        assert!(!cache.labels(&names).contains_key(&custom));
+
+        names.resolve(mode_type, Some("MachineMode".into()));
+        let labels = cache.labels(&names);
+        assert_eq!(labels.get(&custom).map(String::as_str), Some("MachineMode"));
+        assert!(!labels.contains_key(&unresolved));
+
+        assert_eq!(builtin_type_name(&NodeId::new(0, 1)), Some("Boolean"));
+        assert_eq!(builtin_type_name(&NodeId::new(0, 26)), None);
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{
+    AttributeId, BrowsePath, EUInformation, NodeId, QualifiedName, ReadValueId, ReferenceTypeId, RelativePath,
+    RelativePathElement, TimestampsToReturn, Variant,
+};
+
+use crate::opcua::service_timing::{timed, Service};
+
+
+const UNIT_BATCH_SIZE: usize = 500;
+
+
+pub fn unit_label(value: &Variant) -> Option<String> {
+    let Variant::ExtensionObject(object) = value else { return None };
+    let eu = object.inner_as::<EUInformation>()?;
+    [eu.display_name.text.as_ref(), eu.description.text.as_ref()]
+        .into_iter()
+        .map(str::trim)
+        .find(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+
+fn engineering_units_path(node_id: &NodeId) -> BrowsePath {
+    BrowsePath {
+        starting_node: node_id.clone(),
+        relative_path: RelativePath {
+            elements: Some(vec![RelativePathElement {
+                reference_type_id: ReferenceTypeId::HasProperty.into(),
+                is_inverse: false,
+                include_subtypes: true,
+                target_name: QualifiedName::new(0, "EngineeringUnits"),
+            }]),
+        },
+    }
+}
+
+
+pub async fn read_engineering_units(session: ReadOnlyGuard, node_ids: &[NodeId]) -> Result<Vec<Option<String>>> {
+    let mut units = Vec::with_capacity(node_ids.len());
+
+    for batch in node_ids.chunks(UNIT_BATCH_SIZE) {
+        let paths: Vec<BrowsePath> = batch.iter().map(engineering_units_path).collect();
+        let results = timed(session.timings(), Service::TranslateBrowsePaths, session.translate_browse_paths_to_node_ids(&paths))
+            .await
+            .context("TranslateBrowsePathsToNodeIds request failed")?;
+
+        let properties: Vec<Option<NodeId>> = (0..batch.len())
+            .map(|i| {
+                let result = results.get(i).filter(|r| r.status_code.is_good())?;
+                result.targets.as_ref()?
+                    .iter()
+                    .find(|target| target.target_id.server_index == 0)
+                    .map(|target| target.target_id.node_id.clone())
+            })
+            .collect();
+
+        let nodes_to_read: Vec<ReadValueId> = properties
+            .iter()
+            .flatten()
+            .map(|id| ReadValueId::new(id.clone(), AttributeId::Value))
+            .collect();
+        let mut values = if nodes_to_read.is_empty() {
+            Vec::new()
+        } else {
+            timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+                .await
+                .context("Read request failed")?
+        }
+        .into_iter();
+
+        units.extend(properties.iter().map(|property| {
+            property.as_ref()?;
+            values.next()?.value.as_ref().and_then(unit_label)
+        }));
+    }
+
+    Ok(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcua::types::{ExtensionObject, LocalizedText};
+
+    fn eu(display_name: &str, description: &str) -> Variant {
+        Variant::ExtensionObject(ExtensionObject::from_message(EUInformation {
+            namespace_uri: "http://www.opcfoundation.org/UA/units/un/cefact".into(),
+            unit_id: 4408652,
+            display_name: LocalizedText::new("", display_name),
+            description: LocalizedText::new("", description),
+        }))
+    }
+
+    #[test]
+    fn test_unit_label_prefers_display_name() {
+        assert_eq!(unit_label(&eu("°C", "degree Celsius")).as_deref(), Some("°C"));
+        assert_eq!(unit_label(&eu(" ", "degree Celsius")).as_deref(), Some("degree Celsius"));
+        assert_eq!(unit_label(&eu("", "")), None);
+        assert_eq!(unit_label(&Variant::String("°C".into())), None);
+    }
+}
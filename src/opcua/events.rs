@@ -0,0 +1,169 @@
+use std::collections::VecDeque;
+
+use opcua::types::{
+    ContentFilter, DateTime, EventFilter, ExtensionObject, NodeId, ObjectId, ObjectTypeId, SimpleAttributeOperand, Variant,
+};
+
+
+pub const MAX_EVENTS: usize = 1000;
+
+
+pub const EVENT_FIELDS: [&str; 5] = ["EventType", "SourceName", "Severity", "Message", "Time"];
+
+
+pub fn default_notifier() -> NodeId {
+    ObjectId::Server.into()
+}
+
+
+pub fn event_filter() -> ExtensionObject {
+    let select_clauses = EVENT_FIELDS
+        .iter()
+        .map(|field| SimpleAttributeOperand::new_value(ObjectTypeId::BaseEventType, field))
+        .collect();
+    ExtensionObject::from_message(EventFilter { select_clauses: Some(select_clauses), where_clause: ContentFilter { elements: None } })
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventData {
+    
+    pub event_type: Option<NodeId>,
+    
+    pub source_name: String,
+    
+    pub severity: u16,
+    
+    pub message: String,
+    
+    pub time: Option<DateTime>,
+    
+    pub received_at: DateTime,
+}
+
+impl EventData {
+    
+    pub fn from_fields(fields: &[Variant], received_at: DateTime) -> Self {
+        let field = |index: usize| fields.get(index).unwrap_or(&Variant::Empty);
+        Self {
+            event_type: match field(0) {
+                Variant::NodeId(id) => Some((**id).clone()),
+                _ => None,
+            },
+            source_name: match field(1) {
+                Variant::String(s) => s.to_string(),
+                Variant::Empty => String::new(),
+                other => crate::opcua::subscription::format_variant(other),
+            },
+            severity: match field(2) {
+                Variant::UInt16(v) => *v,
+                other => crate::opcua::subscription::variant_to_f64(other).map_or(0, |v| v.clamp(0.0, 1000.0) as u16),
+            },
+            message: match field(3) {
+                Variant::LocalizedText(text) => text.text.to_string(),
+                Variant::String(s) => s.to_string(),
+                _ => String::new(),
+            },
+            time: match field(4) {
+                Variant::DateTime(dt) => Some(**dt),
+                _ => None,
+            },
+            received_at,
+        }
+    }
+
+    
+    pub fn display_time(&self) -> DateTime {
+        self.time.unwrap_or(self.received_at)
+    }
+
+    
+    pub fn event_type_string(&self) -> String {
+        self.event_type.as_ref().map_or_else(|| "---".to_string(), |id| id.to_string())
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct EventLog {
+    events: VecDeque<EventData>,
+}
+
+impl EventLog {
+    
+    pub fn push(&mut self, event: EventData) {
+        if self.events.len() >= MAX_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    
+    pub fn events(&self) -> impl DoubleEndedIterator<Item = &EventData> {
+        self.events.iter()
+    }
+
+    
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcua::types::{LocalizedText, UAString};
+
+    #[test]
+    fn test_event_fields_are_decoded_in_select_order() {
+        let time = DateTime::from(chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        let fields = vec![
+            Variant::from(NodeId::from(ObjectTypeId::AlarmConditionType)),
+            Variant::from(UAString::from("Boiler1")),
+            Variant::UInt16(800),
+            Variant::from(LocalizedText::new("en", "High temperature")),
+            Variant::from(time),
+        ];
+        let event = EventData::from_fields(&fields, DateTime::now());
+        assert_eq!(event.event_type, Some(ObjectTypeId::AlarmConditionType.into()));
+        assert_eq!(event.source_name, "Boiler1");
+        assert_eq!(event.severity, 800);
+        assert_eq!(event.message, "High temperature");
+        assert_eq!(event.display_time(), time);
+
+        let received = DateTime::now();
+        let partial = EventData::from_fields(&[Variant::Empty], received);
+        assert_eq!(partial.event_type_string(), "---");
+        assert_eq!(partial.severity, 0);
+        assert_eq!(partial.display_time(), received);
+    }
+
+    #[test]
+    fn test_event_log_is_bounded() {
+        let mut log = EventLog::default();
+        for severity in 0..MAX_EVENTS + 3 {
+            log.push(EventData::from_fields(&[Variant::Empty, Variant::Empty, Variant::UInt16(severity as u16)], DateTime::now()));
+        }
+        assert_eq!(log.len(), MAX_EVENTS);
+        assert_eq!(log.events().next().unwrap().severity, 3);
+    }
+
+    #[test]
+    fn test_event_filter_selects_all_fields() {
+        let filter = event_filter();
+        let filter = filter.inner_as::<EventFilter>().unwrap();
+        let clauses = filter.select_clauses.as_ref().unwrap();
+        assert_eq!(clauses.len(), EVENT_FIELDS.len());
+        assert_eq!(clauses[3].browse_path.as_ref().unwrap()[0].name.as_ref(), "Message");
+    }
+}
@@ -0,0 +1,206 @@
+use anyhow::{Context, Result};
+use std::future::Future;
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use crate::opcua::subscription::variant_to_f64;
+use opcua::client::HistoryReadAction;
+use opcua::types::{
+    ByteString, DataValue, DateTime, HistoryData, HistoryReadResult, HistoryReadValueId, NodeId,
+    ReadRawModifiedDetails, TimestampsToReturn,
+};
+
+use crate::opcua::service_timing::{timed, Service};
+
+
+pub const DEFAULT_MAX_HISTORY_VALUES: u32 = 10_000;
+
+
+pub const MAX_HISTORY_VALUES: u32 = 100_000;
+
+
+pub fn history_points(values: &[DataValue]) -> Vec<(f64, f64)> {
+    values
+        .iter()
+        .filter(|dv| !dv.status.is_some_and(|s| s.is_bad()))
+        .filter_map(|dv| {
+            let timestamp = dv.source_timestamp.or(dv.server_timestamp)?;
+            let value = dv.value.as_ref().and_then(variant_to_f64)?;
+            Some((timestamp.as_chrono().timestamp_millis() as f64 / 1000.0, value))
+        })
+        .collect()
+}
+
+
+async fn collect_history<F, Fut>(
+    max_values: usize,
+    pending: &mut Option<ByteString>,
+    mut fetch: F,
+) -> Result<Vec<(f64, f64)>>
+where
+    F: FnMut(ByteString) -> Fut,
+    Fut: Future<Output = Result<HistoryReadResult>>,
+{
+    let mut points = Vec::new();
+    let mut continuation_point = ByteString::null();
+    loop {
+        let result = fetch(continuation_point).await?;
+        *pending = None;
+        if result.status_code.is_bad() {
+            return Err(result.status_code).with_context(|| format!("HistoryRead failed with status: {:?}", result.status_code));
+        }
+        let values = result
+            .history_data
+            .inner_as::<HistoryData>()
+            .and_then(|data| data.data_values.as_deref())
+            .unwrap_or_default();
+        points.extend(history_points(values));
+
+        continuation_point = result.continuation_point;
+        if continuation_point.is_null_or_empty() {
+            return Ok(points);
+        }
+        *pending = Some(continuation_point.clone());
+        if points.len() >= max_values {
+            points.truncate(max_values);
+            return Ok(points);
+        }
+    }
+}
+
+
+struct HistoryRelease {
+    session: ReadOnlyGuard,
+    node_id: NodeId,
+    details: ReadRawModifiedDetails,
+    point: Option<ByteString>,
+}
+
+impl Drop for HistoryRelease {
+    fn drop(&mut self) {
+        let Some(point) = self.point.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let session = self.session.clone();
+        let details = HistoryReadAction::ReadRawModifiedDetails(self.details.clone());
+        let node = HistoryReadValueId { node_id: self.node_id.clone(), continuation_point: point, ..Default::default() };
+        runtime.spawn(async move {
+            if let Err(status) = session.history_read(details, TimestampsToReturn::Neither, true, &[node]).await {
+                tracing::debug!("Releasing history continuation point failed: {}", status);
+            }
+        });
+    }
+}
+
+
+pub async fn read_raw_history(
+    session: ReadOnlyGuard,
+    node_id: &NodeId,
+    start: chrono::DateTime<chrono::Utc>,
+    end: chrono::DateTime<chrono::Utc>,
+    max_values: u32,
+) -> Result<Vec<(f64, f64)>> {
+    tracing::debug!("Reading raw history of {:?} from {} to {}", node_id, start, end);
+    if end <= start {
+        anyhow::bail!("The history range ends before it starts");
+    }
+    let max_values = max_values.clamp(1, MAX_HISTORY_VALUES);
+    let details = ReadRawModifiedDetails {
+        is_read_modified: false,
+        start_time: DateTime::from(start),
+        end_time: DateTime::from(end),
+        num_values_per_node: max_values,
+        return_bounds: false,
+    };
+
+    let mut release = HistoryRelease { session: session.clone(), node_id: node_id.clone(), details: details.clone(), point: None };
+    let points = collect_history(max_values as usize, &mut release.point, |continuation_point| {
+        let session = session.clone();
+        let details = HistoryReadAction::ReadRawModifiedDetails(details.clone());
+        let node = HistoryReadValueId { node_id: node_id.clone(), continuation_point, ..Default::default() };
+        async move {
+            timed(session.timings(), Service::HistoryRead, session.history_read(details, TimestampsToReturn::Source, false, &[node]))
+                .await
+                .context("HistoryRead request failed")?
+                .into_iter()
+                .next()
+                .context("HistoryRead returned no result")
+        }
+    })
+    .await?;
+
+    tracing::debug!("Read {} historical values of {:?}", points.len(), node_id);
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcua::types::{ExtensionObject, StatusCode, Variant};
+
+    fn batch(start: i64, count: i64, continuation_point: &[u8]) -> HistoryReadResult {
+        let data_values = (start..start + count)
+            .map(|second| DataValue {
+                value: Some(Variant::Double(second as f64 * 0.5)),
+                source_timestamp: Some(DateTime::from(chrono::DateTime::from_timestamp(1_700_000_000 + second, 0).unwrap())),
+                ..Default::default()
+            })
+            .collect();
+        HistoryReadResult {
+            status_code: StatusCode::Good,
+            continuation_point: ByteString::from(continuation_point.to_vec()),
+            history_data: ExtensionObject::from_message(HistoryData { data_values: Some(data_values) }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_batches_are_followed_until_complete() {
+        let mut batches = vec![batch(0, 3, b"1"), batch(3, 3, b"2"), batch(6, 2, b"")].into_iter();
+        let mut requested = Vec::new();
+        let mut pending = None;
+        let points = collect_history(100, &mut pending, |continuation_point| {
+            requested.push(continuation_point);
+            let next = batches.next().context("no more batches");
+            async move { next }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], (1_700_000_000.0, 0.0));
+        assert_eq!(points[7], (1_700_000_007.0, 3.5));
+        assert!(requested[0].is_null_or_empty());
+        assert_eq!(requested[1..], [ByteString::from(b"1".to_vec()), ByteString::from(b"2".to_vec())]);
+        assert!(pending.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_history_stops_at_max_values_and_keeps_point_to_release() {
+        let mut batches = vec![batch(0, 3, b"1"), batch(3, 3, b"2"), batch(6, 3, b"")].into_iter();
+        let mut pending = None;
+        let points = collect_history(5, &mut pending, |_| {
+            let next = batches.next().context("no more batches");
+            async move { next }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(points.len(), 5);
+        assert_eq!(pending, Some(ByteString::from(b"2".to_vec())));
+    }
+
+    #[test]
+    fn test_history_points_skip_bad_and_non_numeric_values() {
+        let timestamp = Some(DateTime::from(chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap()));
+        let values = [
+            DataValue { value: Some(Variant::Int32(4)), source_timestamp: timestamp, ..Default::default() },
+            DataValue { value: Some(Variant::Int32(5)), source_timestamp: timestamp, status: Some(StatusCode::BadNoData), ..Default::default() },
+            DataValue { value: Some(Variant::from("text")), source_timestamp: timestamp, ..Default::default() },
+            DataValue { value: Some(Variant::Int32(6)), ..Default::default() },
+            DataValue { value: Some(Variant::Int32(7)), server_timestamp: timestamp, ..Default::default() },
+        ];
+        assert_eq!(history_points(&values), vec![(1_700_000_000.0, 4.0), (1_700_000_000.0, 7.0)]);
+    }
+}
@@ -8,5 +8,19 @@ pub mod certificates;
 pub mod client;
 pub mod subscription;
 pub mod crawler;
+pub mod data_types;
+pub mod engineering_units;
+pub mod events;
+pub mod history;
+pub mod network_activity;
+pub mod node_attributes;
+pub mod node_details;
+pub mod node_names;
+pub mod read_only;
+pub mod server_diagnostics;
+pub mod server_profile;
+pub mod service_timing;
+pub mod simulation;
 pub mod status_codes;
 pub mod subscription_manager;
+pub mod tag_validation;
@@ -0,0 +1,101 @@
+
+
+
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+
+pub const ACTIVITY_WINDOW: Duration = Duration::from_secs(1);
+
+static OUTBOUND_REQUESTS: AtomicU64 = AtomicU64::new(0);
+static INBOUND_NOTIFICATIONS: AtomicU64 = AtomicU64::new(0);
+
+
+pub fn record_request() {
+    OUTBOUND_REQUESTS.fetch_add(1, Ordering::Relaxed);
+}
+
+
+pub fn record_notification() {
+    INBOUND_NOTIFICATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+
+pub fn counters() -> (u64, u64) {
+    (OUTBOUND_REQUESTS.load(Ordering::Relaxed), INBOUND_NOTIFICATIONS.load(Ordering::Relaxed))
+}
+
+
+pub fn reset() {
+    OUTBOUND_REQUESTS.store(0, Ordering::Relaxed);
+    INBOUND_NOTIFICATIONS.store(0, Ordering::Relaxed);
+}
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Activity {
+    
+    pub outbound: bool,
+    
+    pub inbound: bool,
+}
+
+
+#[derive(Debug, Default)]
+pub struct ActivityIndicator {
+    
+    last_counters: (u64, u64),
+    
+    last_outbound: Option<Instant>,
+    
+    last_inbound: Option<Instant>,
+}
+
+impl ActivityIndicator {
+    
+    pub fn observe(&mut self, counters: (u64, u64), now: Instant) -> Activity {
+        if counters.0 != self.last_counters.0 {
+            self.last_outbound = Some(now);
+        }
+        if counters.1 != self.last_counters.1 {
+            self.last_inbound = Some(now);
+        }
+        self.last_counters = counters;
+
+        let recent = |at: Option<Instant>| at.is_some_and(|at| now.duration_since(at) < ACTIVITY_WINDOW);
+        Activity {
+            outbound: recent(self.last_outbound),
+            inbound: recent(self.last_inbound),
+        }
+    }
+
+    
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_expires_after_window() {
+        let mut indicator = ActivityIndicator::default();
+        let start = Instant::now();
+        assert_eq!(indicator.observe((0, 0), start), Activity::default());
+
+        let active = indicator.observe((3, 0), start);
+        assert!(active.outbound && !active.inbound);
+
+        let later = indicator.observe((3, 1), start + Duration::from_millis(500));
+        assert_eq!(later, Activity { outbound: true, inbound: true });
+
+        let idle = indicator.observe((3, 1), start + Duration::from_millis(1600));
+        assert_eq!(idle, Activity::default());
+
+        indicator.reset();
+        assert_eq!(indicator.observe((0, 0), start), Activity::default());
+    }
+}
@@ -0,0 +1,285 @@
+
+
+
+
+use anyhow::{Context, Result};
+use std::time::{Duration, Instant};
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{AttributeId, DataValue, NodeId, ReadValueId, StatusCode, TimestampsToReturn, Variant};
+
+use crate::opcua::service_timing::{timed, Service};
+
+
+pub const SELECTION_DEBOUNCE: Duration = Duration::from_millis(150);
+
+
+const PROPERTY_ATTRIBUTES: [AttributeId; 9] = [
+    AttributeId::Value,
+    AttributeId::DataType,
+    AttributeId::AccessLevel,
+    AttributeId::UserAccessLevel,
+    AttributeId::Description,
+    AttributeId::ValueRank,
+    AttributeId::MinimumSamplingInterval,
+    AttributeId::Historizing,
+    AttributeId::ArrayDimensions,
+];
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeAttributes {
+    
+    pub value: Option<DataValue>,
+    
+    pub data_type: Option<NodeId>,
+    
+    pub access_level: Option<u8>,
+    
+    pub user_access_level: Option<u8>,
+    
+    pub description: Option<String>,
+    
+    pub value_rank: Option<i32>,
+    
+    pub minimum_sampling_interval: Option<f64>,
+    
+    pub historizing: Option<bool>,
+    
+    pub array_dimensions: Option<Vec<u32>>,
+    
+    pub refused: Vec<(AttributeId, StatusCode)>,
+}
+
+impl NodeAttributes {
+    
+    pub fn from_results(results: &[DataValue]) -> Self {
+        let good = |index: usize| {
+            results
+                .get(index)
+                .filter(|dv| dv.status.map_or(true, |s| s.is_good()))
+                .and_then(|dv| dv.value.as_ref())
+        };
+        Self {
+            value: results.first().filter(|dv| !dv.status.is_some_and(|s| s.is_bad())).cloned(),
+            data_type: match good(1) {
+                Some(Variant::NodeId(id)) => Some((**id).clone()),
+                _ => None,
+            },
+            access_level: match good(2) {
+                Some(Variant::Byte(level)) => Some(*level),
+                _ => None,
+            },
+            user_access_level: match good(3) {
+                Some(Variant::Byte(level)) => Some(*level),
+                _ => None,
+            },
+            description: match good(4) {
+                Some(Variant::LocalizedText(text)) if !text.text.is_empty() => Some(text.text.to_string()),
+                _ => None,
+            },
+            value_rank: match good(5) {
+                Some(Variant::Int32(rank)) => Some(*rank),
+                _ => None,
+            },
+            minimum_sampling_interval: match good(6) {
+                Some(Variant::Double(interval)) => Some(*interval),
+                _ => None,
+            },
+            historizing: match good(7) {
+                Some(Variant::Boolean(historizing)) => Some(*historizing),
+                _ => None,
+            },
+            array_dimensions: match good(8) {
+                Some(Variant::Array(array)) => array
+                    .values
+                    .iter()
+                    .map(|dimension| match dimension {
+                        Variant::UInt32(length) => Some(*length),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => None,
+            },
+            refused: PROPERTY_ATTRIBUTES
+                .iter()
+                .zip(results)
+                .filter_map(|(attribute, dv)| {
+                    dv.status
+                        .filter(|status| status.is_bad() && *status != StatusCode::BadAttributeIdInvalid)
+                        .map(|status| (*attribute, status))
+                })
+                .collect(),
+        }
+    }
+}
+
+
+pub fn array_dimensions_label(dimensions: &[u32]) -> String {
+    if dimensions.is_empty() {
+        return "-".to_string();
+    }
+    dimensions
+        .iter()
+        .map(|length| if *length == 0 { "*".to_string() } else { length.to_string() })
+        .collect::<Vec<_>>()
+        .join(" × ")
+}
+
+
+pub fn access_level_label(level: u8) -> String {
+    const FLAGS: [(u8, &str); 5] = [
+        (0x01, "CurrentRead"),
+        (0x02, "CurrentWrite"),
+        (0x04, "HistoryRead"),
+        (0x08, "HistoryWrite"),
+        (0x10, "SemanticChange"),
+    ];
+    let names: Vec<&str> = FLAGS.iter().filter(|(bit, _)| level & bit != 0).map(|(_, name)| *name).collect();
+    if names.is_empty() {
+        "None".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+
+pub async fn read_node_attributes(session: ReadOnlyGuard, node_id: &NodeId) -> Result<NodeAttributes> {
+    let nodes_to_read: Vec<ReadValueId> = PROPERTY_ATTRIBUTES
+        .iter()
+        .map(|attribute| ReadValueId::new(node_id.clone(), *attribute))
+        .collect();
+
+    let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Both, 0.0))
+        .await
+        .context("Read request failed")?;
+
+    Ok(NodeAttributes::from_results(&results))
+}
+
+
+#[derive(Debug, Default)]
+pub struct AttributeFetcher {
+    
+    selected: Option<NodeId>,
+    
+    changed_at: Option<Instant>,
+    
+    generation: u64,
+    
+    requested: bool,
+}
+
+impl AttributeFetcher {
+    
+    pub fn observe_selection(&mut self, node_id: Option<&NodeId>, now: Instant) -> bool {
+        if self.selected.as_ref() == node_id {
+            return false;
+        }
+        self.selected = node_id.cloned();
+        self.changed_at = Some(now);
+        self.generation += 1;
+        self.requested = false;
+        true
+    }
+
+    
+    pub fn poll(&mut self, now: Instant) -> Option<(u64, NodeId)> {
+        if self.requested {
+            return None;
+        }
+        let node_id = self.selected.clone()?;
+        let changed_at = self.changed_at?;
+        if now.duration_since(changed_at) < SELECTION_DEBOUNCE {
+            return None;
+        }
+        self.requested = true;
+        Some((self.generation, node_id))
+    }
+
+    
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        if self.requested || self.selected.is_none() {
+            return None;
+        }
+        let elapsed = now.duration_since(self.changed_at?);
+        Some(SELECTION_DEBOUNCE.saturating_sub(elapsed))
+    }
+
+    
+    pub fn is_current(&self, generation: u64) -> bool {
+        generation == self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_selections_collapse_into_one_request() {
+        let start = Instant::now();
+        let mut fetcher = AttributeFetcher::default();
+        let nodes: Vec<NodeId> = (0..4).map(|i| NodeId::new(2, i)).collect();
+
+        for (i, node) in nodes.iter().enumerate() {
+            let now = start + Duration::from_millis(i as u64 * 50);
+            assert!(fetcher.observe_selection(Some(node), now));
+            assert_eq!(fetcher.poll(now), None);
+        }
+        assert!(!fetcher.observe_selection(Some(&nodes[3]), start + Duration::from_millis(160)));
+        assert_eq!(fetcher.poll(start + Duration::from_millis(200)), None);
+
+        let (generation, node) = fetcher.poll(start + Duration::from_millis(300)).unwrap();
+        assert_eq!(node, nodes[3]);
+        assert!(fetcher.is_current(generation));
+        assert_eq!(fetcher.poll(start + Duration::from_millis(400)), None);
+
+        fetcher.observe_selection(Some(&nodes[0]), start + Duration::from_millis(500));
+        assert!(!fetcher.is_current(generation));
+        fetcher.observe_selection(None, start + Duration::from_millis(510));
+        assert_eq!(fetcher.poll(start + Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn test_attributes_from_results() {
+        use opcua::types::LocalizedText;
+
+        let bad = DataValue { status: Some(StatusCode::BadAttributeIdInvalid), ..Default::default() };
+        let results = vec![
+            DataValue::value_only(Variant::Double(21.5)),
+            DataValue::value_only(Variant::NodeId(Box::new(NodeId::new(0, 11)))),
+            DataValue::value_only(Variant::Byte(0x03)),
+            DataValue::value_only(Variant::Byte(0x01)),
+            DataValue::value_only(Variant::LocalizedText(Box::new(LocalizedText::new("en", "Pump speed")))),
+            DataValue::value_only(Variant::Int32(-1)),
+            bad.clone(),
+            DataValue::value_only(Variant::Boolean(false)),
+            DataValue::value_only(Variant::from(vec![2u32, 0u32])),
+        ];
+        let attributes = NodeAttributes::from_results(&results);
+        assert_eq!(attributes.data_type, Some(NodeId::new(0, 11)));
+        assert_eq!(attributes.access_level.map(access_level_label).as_deref(), Some("CurrentRead, CurrentWrite"));
+        assert_eq!(attributes.description.as_deref(), Some("Pump speed"));
+        assert_eq!(attributes.value_rank, Some(-1));
+        assert_eq!(attributes.minimum_sampling_interval, None);
+        assert_eq!(attributes.historizing, Some(false));
+        assert_eq!(attributes.array_dimensions.as_deref().map(array_dimensions_label).as_deref(), Some("2 × *"));
+        assert!(attributes.refused.is_empty());
+
+        let denied = DataValue { status: Some(StatusCode::BadUserAccessDenied), ..Default::default() };
+        let mut restricted = results.clone();
+        restricted[3] = denied.clone();
+        restricted[4] = denied;
+        let restricted = NodeAttributes::from_results(&restricted);
+        assert!(restricted.user_access_level.is_none() && restricted.description.is_none());
+        assert_eq!(
+            restricted.refused,
+            vec![(AttributeId::UserAccessLevel, StatusCode::BadUserAccessDenied), (AttributeId::Description, StatusCode::BadUserAccessDenied)],
+        );
+
+        let object = NodeAttributes::from_results(&[bad.clone(), bad]);
+        assert!(object.value.is_none() && object.data_type.is_none());
+        assert_eq!(access_level_label(0), "None");
+    }
+}
@@ -0,0 +1,385 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{
+    AttributeId, BrowseDescription, BrowseDirection, BrowseResultMask, DataValue, EUInformation,
+    NodeId, Range, ReadValueId, ReferenceDescription, ReferenceTypeId, TimestampsToReturn, Variant,
+};
+
+use crate::export::{variant_to_json, variant_type_name, NumberFormat};
+use crate::opcua::browser::{BrowsedNode, NodeClass};
+use crate::opcua::node_attributes::access_level_label;
+use crate::opcua::service_timing::{timed, Service};
+use crate::opcua::subscription::MonitoredData;
+
+
+pub const RECENT_HISTORY: usize = 60;
+
+
+const DETAIL_ATTRIBUTES: [(&str, AttributeId); 8] = [
+    ("Value", AttributeId::Value),
+    ("DataType", AttributeId::DataType),
+    ("AccessLevel", AttributeId::AccessLevel),
+    ("UserAccessLevel", AttributeId::UserAccessLevel),
+    ("Description", AttributeId::Description),
+    ("ValueRank", AttributeId::ValueRank),
+    ("MinimumSamplingInterval", AttributeId::MinimumSamplingInterval),
+    ("Historizing", AttributeId::Historizing),
+];
+
+
+const EU_PROPERTIES: [&str; 2] = ["EngineeringUnits", "EURange"];
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Field {
+    
+    Value(Value),
+    
+    Error { error: String },
+}
+
+impl Field {
+    
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Error { error: message.into() }
+    }
+
+    
+    fn from_attribute(name: &str, result: Option<&DataValue>) -> Self {
+        let Some(dv) = result else {
+            return Self::error("no result returned");
+        };
+        if let Some(status) = dv.status.filter(|s| s.is_bad()) {
+            return Self::error(status.to_string());
+        }
+        let Some(variant) = dv.value.as_ref() else {
+            return Self::error("no value returned");
+        };
+        Self::Value(match (name, variant) {
+            ("Value", _) => json!({
+                "value": variant_json(variant),
+                "type": variant_type_name(variant),
+                "status": dv.status.unwrap_or_default().to_string(),
+                "sourceTimestamp": dv.source_timestamp.map(|t| t.as_chrono().to_rfc3339()),
+                "serverTimestamp": dv.server_timestamp.map(|t| t.as_chrono().to_rfc3339()),
+            }),
+            ("AccessLevel" | "UserAccessLevel", Variant::Byte(level)) => json!({
+                "raw": level,
+                "flags": access_level_label(*level),
+            }),
+            _ => variant_json(variant),
+        })
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeReference {
+    
+    pub reference_type: String,
+    
+    pub node_id: String,
+    
+    pub browse_name: String,
+    
+    pub display_name: String,
+    
+    pub node_class: String,
+}
+
+impl NodeReference {
+    
+    fn from_description(reference: &ReferenceDescription) -> Self {
+        Self {
+            reference_type: reference_type_name(&reference.reference_type_id),
+            node_id: reference.node_id.node_id.to_string(),
+            browse_name: reference.browse_name.to_string(),
+            display_name: reference.display_name.text.to_string(),
+            node_class: NodeClass::from_opcua(reference.node_class).to_string(),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitoredSnapshot {
+    
+    pub value: Value,
+    
+    pub status: String,
+    
+    pub source_timestamp: Option<String>,
+    
+    pub server_timestamp: Option<String>,
+    
+    pub monitored_item_id: Option<u32>,
+    
+    pub unit: Option<String>,
+    
+    pub history: Vec<Value>,
+}
+
+impl MonitoredSnapshot {
+    
+    pub fn from_data(data: &MonitoredData) -> Self {
+        let skip = data.history.len().saturating_sub(RECENT_HISTORY);
+        Self {
+            value: data.value.as_ref().map(variant_json).unwrap_or(Value::Null),
+            status: data.status.to_string(),
+            source_timestamp: data.source_timestamp.map(|t| t.as_chrono().to_rfc3339()),
+            server_timestamp: data.server_timestamp.map(|t| t.as_chrono().to_rfc3339()),
+            monitored_item_id: data.monitored_item_id,
+            unit: data.unit.clone(),
+            history: data.history
+                .iter()
+                .skip(skip)
+                .map(|(secs, value)| {
+                    let at = chrono::DateTime::from_timestamp_millis((secs * 1000.0).round() as i64)
+                        .map(|dt| dt.to_rfc3339());
+                    json!({ "timestamp": at, "value": value })
+                })
+                .collect(),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeDetails {
+    
+    pub generated_at: String,
+    
+    pub node_id: String,
+    
+    pub browse_name: String,
+    
+    pub display_name: String,
+    
+    pub node_class: String,
+    
+    pub type_definition: Option<String>,
+    
+    pub data_type_name: Option<String>,
+    
+    pub attributes: BTreeMap<&'static str, Field>,
+    
+    pub forward_references: Field,
+    
+    pub inverse_references: Field,
+    
+    pub monitored: Option<MonitoredSnapshot>,
+}
+
+impl NodeDetails {
+    
+    pub fn new(node: &BrowsedNode) -> Self {
+        Self {
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            node_id: node.node_id.to_string(),
+            browse_name: node.browse_name.clone(),
+            display_name: node.display_name.clone(),
+            node_class: node.node_class.to_string(),
+            type_definition: node.type_definition.as_ref().filter(|id| !id.is_null()).map(|id| id.to_string()),
+            data_type_name: None,
+            attributes: BTreeMap::new(),
+            forward_references: Field::error("not read"),
+            inverse_references: Field::error("not read"),
+            monitored: None,
+        }
+    }
+
+    
+    pub fn unreadable(node: &BrowsedNode, reason: &str) -> Self {
+        let mut details = Self::new(node);
+        for (name, _) in DETAIL_ATTRIBUTES {
+            details.attributes.insert(name, Field::error(reason));
+        }
+        details.forward_references = Field::error(reason);
+        details.inverse_references = Field::error(reason);
+        details
+    }
+
+    
+    pub fn with_monitored(mut self, data: Option<&MonitoredData>) -> Self {
+        self.monitored = data.map(MonitoredSnapshot::from_data);
+        self
+    }
+}
+
+
+fn variant_json(variant: &Variant) -> Value {
+    if let Variant::ExtensionObject(object) = variant {
+        if let Some(eu) = object.inner_as::<EUInformation>() {
+            return json!({
+                "displayName": eu.display_name.text.to_string(),
+                "description": eu.description.text.to_string(),
+                "unitId": eu.unit_id,
+                "namespaceUri": eu.namespace_uri.to_string(),
+            });
+        }
+        if let Some(range) = object.inner_as::<Range>() {
+            return json!({ "low": range.low, "high": range.high });
+        }
+    }
+    match variant {
+        Variant::LocalizedText(text) => Value::String(text.text.to_string()),
+        other => variant_to_json(other, NumberFormat::FullPrecision),
+    }
+}
+
+
+fn reference_type_name(id: &NodeId) -> String {
+    id.as_reference_type_id()
+        .map(|reference_type| format!("{:?}", reference_type))
+        .unwrap_or_else(|_| id.to_string())
+}
+
+
+pub async fn read_node_details(session: ReadOnlyGuard, node: &BrowsedNode) -> NodeDetails {
+    let mut details = NodeDetails::new(node);
+
+    let nodes_to_read: Vec<ReadValueId> = DETAIL_ATTRIBUTES
+        .iter()
+        .map(|(_, attribute)| ReadValueId::new(node.node_id.clone(), *attribute))
+        .collect();
+    match timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Both, 0.0)).await {
+        Ok(results) => {
+            for (index, (name, _)) in DETAIL_ATTRIBUTES.iter().enumerate() {
+                details.attributes.insert(name, Field::from_attribute(name, results.get(index)));
+            }
+        }
+        Err(e) => {
+            for (name, _) in DETAIL_ATTRIBUTES {
+                details.attributes.insert(name, Field::error(format!("Read request failed: {}", e)));
+            }
+        }
+    }
+
+    match browse_all_references(session.clone(), &node.node_id).await {
+        Ok(references) => {
+            let (forward, inverse): (Vec<&ReferenceDescription>, Vec<&ReferenceDescription>) =
+                references.iter().partition(|reference| reference.is_forward);
+            let to_field = |refs: &[&ReferenceDescription]| {
+                Field::Value(refs.iter().map(|r| json!(NodeReference::from_description(r))).collect())
+            };
+            details.forward_references = to_field(&forward);
+            details.inverse_references = to_field(&inverse);
+            if node.node_class == NodeClass::Variable {
+                read_eu_properties(session, &forward, &mut details).await;
+            }
+        }
+        Err(e) => {
+            details.forward_references = Field::error(format!("{:#}", e));
+            details.inverse_references = Field::error(format!("{:#}", e));
+        }
+    }
+
+    details
+}
+
+
+async fn browse_all_references(session: ReadOnlyGuard, node_id: &NodeId) -> Result<Vec<ReferenceDescription>> {
+    let description = BrowseDescription {
+        node_id: node_id.clone(),
+        browse_direction: BrowseDirection::Both,
+        reference_type_id: ReferenceTypeId::References.into(),
+        include_subtypes: true,
+        node_class_mask: 0,
+        result_mask: BrowseResultMask::All as u32,
+    };
+    crate::opcua::browser::browse_references(session, description, None).await
+}
+
+
+async fn read_eu_properties(session: ReadOnlyGuard, forward: &[&ReferenceDescription], details: &mut NodeDetails) {
+    let found: Vec<(&'static str, NodeId)> = EU_PROPERTIES
+        .iter()
+        .filter_map(|name| {
+            forward.iter()
+                .find(|r| r.browse_name.name.as_ref() == *name)
+                .map(|r| (*name, r.node_id.node_id.clone()))
+        })
+        .collect();
+    for name in EU_PROPERTIES {
+        if !found.iter().any(|(found_name, _)| *found_name == name) {
+            details.attributes.insert(name, Field::error("not defined on this node"));
+        }
+    }
+    if found.is_empty() {
+        return;
+    }
+
+    let nodes_to_read: Vec<ReadValueId> = found
+        .iter()
+        .map(|(_, id)| ReadValueId::new(id.clone(), AttributeId::Value))
+        .collect();
+    match timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0)).await {
+        Ok(results) => {
+            for (index, (name, _)) in found.iter().enumerate() {
+                details.attributes.insert(name, Field::from_attribute(name, results.get(index)));
+            }
+        }
+        Err(e) => {
+            for (name, _) in &found {
+                details.attributes.insert(name, Field::error(format!("Read request failed: {}", e)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_nodes::TestNode;
+    use opcua::types::StatusCode;
+
+    fn node() -> BrowsedNode {
+        TestNode::new(NodeId::new(2, "Boiler.Temperature"), "Temperature").build()
+    }
+
+    #[test]
+    fn test_unreadable_fields_serialize_as_errors() {
+        let mut details = NodeDetails::new(&node());
+        details.attributes.insert("Value", Field::from_attribute("Value", Some(&DataValue::new_now(Variant::Double(21.5)))));
+        let denied = DataValue { status: Some(StatusCode::BadUserAccessDenied), ..DataValue::null() };
+        details.attributes.insert("Description", Field::from_attribute("Description", Some(&denied)));
+        details.attributes.insert("AccessLevel", Field::from_attribute("AccessLevel", Some(&DataValue::value_only(Variant::Byte(3)))));
+        details.attributes.insert("ValueRank", Field::from_attribute("ValueRank", None));
+
+        let json = serde_json::to_value(&details).unwrap();
+        assert_eq!(json["attributes"]["Value"]["value"], json!(21.5));
+        assert_eq!(json["attributes"]["Value"]["type"], json!("Double"));
+        assert_eq!(json["attributes"]["Description"]["error"], json!("BadUserAccessDenied"));
+        assert_eq!(json["attributes"]["AccessLevel"], json!({ "raw": 3, "flags": "CurrentRead, CurrentWrite" }));
+        assert!(json["attributes"]["ValueRank"]["error"].is_string());
+        assert_eq!(json["forwardReferences"]["error"], json!("not read"));
+        assert_eq!(json["monitored"], Value::Null);
+    }
+
+    #[test]
+    fn test_monitored_snapshot_keeps_recent_history() {
+        let mut data = MonitoredData::new(NodeId::new(2, "Boiler.Temperature"), "Temperature".to_string());
+        for i in 0..(RECENT_HISTORY + 10) {
+            data.update(&DataValue::new_now(Variant::Int32(i as i32)));
+        }
+        let details = NodeDetails::new(&node()).with_monitored(Some(&data));
+        let snapshot = details.monitored.unwrap();
+        assert_eq!(snapshot.history.len(), RECENT_HISTORY);
+        assert_eq!(snapshot.history.last().unwrap()["value"], json!((RECENT_HISTORY + 9) as f64));
+        assert_eq!(snapshot.value, json!(RECENT_HISTORY + 9));
+    }
+
+    #[test]
+    fn test_reference_type_names() {
+        assert_eq!(reference_type_name(&ReferenceTypeId::HasProperty.into()), "HasProperty");
+        assert_eq!(reference_type_name(&NodeId::new(3, 5000)), "ns=3;i=5000");
+    }
+}
@@ -0,0 +1,97 @@
+
+
+
+
+use std::collections::HashMap;
+
+use opcua::types::NodeId;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NameLookup {
+    
+    Pending,
+    
+    Resolved(String),
+    
+    Unavailable,
+}
+
+
+#[derive(Debug, Default)]
+pub struct NodeNameCache {
+    entries: HashMap<NodeId, NameLookup>,
+}
+
+impl NodeNameCache {
+    
+    pub fn get(&self, node_id: &NodeId) -> Option<&NameLookup> {
+        self.entries.get(node_id)
+    }
+
+    
+    
+    pub fn take_unknown(&mut self, node_ids: &[NodeId]) -> Vec<NodeId> {
+        let mut unknown = Vec::new();
+        for node_id in node_ids {
+            if !self.entries.contains_key(node_id) {
+                self.entries.insert(node_id.clone(), NameLookup::Pending);
+                unknown.push(node_id.clone());
+            }
+        }
+        unknown
+    }
+
+    
+    pub fn resolve(&mut self, node_id: NodeId, name: Option<String>) {
+        let lookup = match name {
+            Some(name) => NameLookup::Resolved(name),
+            None => NameLookup::Unavailable,
+        };
+        self.entries.insert(node_id, lookup);
+    }
+
+    
+    pub fn name(&self, node_id: &NodeId) -> Option<&str> {
+        match self.entries.get(node_id) {
+            Some(NameLookup::Resolved(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    
+    pub fn label(&self, node_id: &NodeId) -> String {
+        match self.name(node_id) {
+            Some(name) => format!("{} ({})", name, node_id),
+            None => node_id.to_string(),
+        }
+    }
+
+    
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_lifecycle() {
+        let mut cache = NodeNameCache::default();
+        let id = NodeId::from(opcua::types::VariableTypeId::BaseDataVariableType);
+
+        assert_eq!(cache.take_unknown(std::slice::from_ref(&id)), vec![id.clone()]);
+        assert!(cache.take_unknown(std::slice::from_ref(&id)).is_empty());
+        assert_eq!(cache.label(&id), "i=63");
+
+        cache.resolve(id.clone(), Some("BaseDataVariableType".into()));
+        assert_eq!(cache.label(&id), "BaseDataVariableType (i=63)");
+
+        let missing = NodeId::new(2, 999);
+        cache.resolve(missing.clone(), None);
+        assert_eq!(cache.get(&missing), Some(&NameLookup::Unavailable));
+        assert_eq!(cache.label(&missing), "ns=2;i=999");
+    }
+}
@@ -0,0 +1,131 @@
+//! Read-only access to an OPC-UA session.
+//!
+//! The rest of the application only ever sees a [`ReadOnlyGuard`], never the
+//! underlying `Session`. The guard forwards the Read, HistoryRead, Browse,
+//! BrowseNext and TranslateBrowsePaths services and nothing else, so there is no path from
+//! the UI or the background tasks to Write, Call or any node management
+//! service. Subscription and monitored item management stay inside
+//! [`OpcUaClient`](crate::opcua::client::OpcUaClient).
+//!
+//! Adding a write would mean adding a method here, which is the point: the
+//! boundary is one small file that reviewers can watch. Reads compile:
+//!
+//! ```no_run
+//! use denginks_opcua_diagnostic::opcua::read_only::ReadOnlyGuard;
+//! use opcua::types::TimestampsToReturn;
+//!
+//! async fn read(guard: ReadOnlyGuard) {
+//!     let _ = guard.read(&[], TimestampsToReturn::Neither, 0.0).await;
+//! }
+//! ```
+//!
+//! Writes, method calls and reaching the session behind the guard do not:
+//!
+//! ```compile_fail
+//! use denginks_opcua_diagnostic::opcua::read_only::ReadOnlyGuard;
+//!
+//! async fn write(guard: ReadOnlyGuard) {
+//!     let _ = guard.write(&[]).await;
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use denginks_opcua_diagnostic::opcua::read_only::ReadOnlyGuard;
+//!
+//! async fn call(guard: ReadOnlyGuard) {
+//!     let _ = guard.call_one(Default::default()).await;
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use denginks_opcua_diagnostic::opcua::read_only::ReadOnlyGuard;
+//!
+//! fn escape(guard: &ReadOnlyGuard) -> &opcua::client::Session {
+//!     &guard.session
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use opcua::client::{HistoryReadAction, Session};
+
+use crate::opcua::service_timing::SharedServiceTimings;
+use opcua::types::{
+    BrowseDescription, BrowsePath, BrowsePathResult, BrowseResult, ByteString, DataValue, EndpointDescription,
+    HistoryReadResult, HistoryReadValueId, ReadValueId, StatusCode, TimestampsToReturn, ViewDescription,
+};
+
+
+#[derive(Clone)]
+pub struct ReadOnlyGuard {
+    session: Arc<Session>,
+    
+    timings: SharedServiceTimings,
+}
+
+impl ReadOnlyGuard {
+    
+    pub(in crate::opcua) fn new(session: Arc<Session>, timings: SharedServiceTimings) -> Self {
+        Self { session, timings }
+    }
+
+    
+    pub fn timings(&self) -> &SharedServiceTimings {
+        &self.timings
+    }
+
+    
+    pub async fn read(
+        &self,
+        nodes_to_read: &[ReadValueId],
+        timestamps_to_return: TimestampsToReturn,
+        max_age: f64,
+    ) -> Result<Vec<DataValue>, StatusCode> {
+        self.session.read(nodes_to_read, timestamps_to_return, max_age).await
+    }
+
+    
+    pub async fn history_read(
+        &self,
+        history_read_details: HistoryReadAction,
+        timestamps_to_return: TimestampsToReturn,
+        release_continuation_points: bool,
+        nodes_to_read: &[HistoryReadValueId],
+    ) -> Result<Vec<HistoryReadResult>, StatusCode> {
+        self.session
+            .history_read(history_read_details, timestamps_to_return, release_continuation_points, nodes_to_read)
+            .await
+    }
+
+    
+    pub async fn browse(
+        &self,
+        nodes_to_browse: &[BrowseDescription],
+        max_references_per_node: u32,
+        view: Option<ViewDescription>,
+    ) -> Result<Vec<BrowseResult>, StatusCode> {
+        self.session.browse(nodes_to_browse, max_references_per_node, view).await
+    }
+
+    
+    pub async fn browse_next(
+        &self,
+        release_continuation_points: bool,
+        continuation_points: &[ByteString],
+    ) -> Result<Vec<BrowseResult>, StatusCode> {
+        self.session.browse_next(release_continuation_points, continuation_points).await
+    }
+
+    
+    pub async fn translate_browse_paths_to_node_ids(
+        &self,
+        browse_paths: &[BrowsePath],
+    ) -> Result<Vec<BrowsePathResult>, StatusCode> {
+        self.session.translate_browse_paths_to_node_ids(browse_paths).await
+    }
+
+    
+    pub fn endpoint(&self) -> &EndpointDescription {
+        &self.session.endpoint_info().endpoint
+    }
+}
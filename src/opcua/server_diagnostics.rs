@@ -0,0 +1,131 @@
+
+
+
+
+use anyhow::{Context, Result};
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{AttributeId, NodeId, ReadValueId, TimestampsToReturn, VariableId, Variant};
+
+use crate::opcua::service_timing::{timed, Service};
+
+
+pub const REFRESH_INTERVAL_SECS: u64 = 5;
+
+
+const COUNTERS: [(&str, VariableId); 12] = [
+    ("CurrentSessionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_CurrentSessionCount),
+    ("CumulatedSessionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_CumulatedSessionCount),
+    ("RejectedSessionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_RejectedSessionCount),
+    ("SecurityRejectedSessionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_SecurityRejectedSessionCount),
+    ("SessionTimeoutCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_SessionTimeoutCount),
+    ("SessionAbortCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_SessionAbortCount),
+    ("CurrentSubscriptionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_CurrentSubscriptionCount),
+    ("CumulatedSubscriptionCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_CumulatedSubscriptionCount),
+    ("PublishingIntervalCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_PublishingIntervalCount),
+    ("RejectedRequestsCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_RejectedRequestsCount),
+    ("SecurityRejectedRequestsCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_SecurityRejectedRequestsCount),
+    ("ServerViewCount", VariableId::Server_ServerDiagnostics_ServerDiagnosticsSummary_ServerViewCount),
+];
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServerDiagnostics {
+    
+    pub enabled: Option<bool>,
+    
+    pub counters: Vec<(&'static str, Option<u32>)>,
+    
+    pub max_sessions: Option<u32>,
+    
+    pub max_subscriptions: Option<u32>,
+}
+
+impl ServerDiagnostics {
+    
+    pub fn counter(&self, name: &str) -> Option<u32> {
+        self.counters.iter().find(|(n, _)| *n == name).and_then(|(_, v)| *v)
+    }
+
+    
+    pub fn is_rejection_counter(name: &str) -> bool {
+        name.contains("Rejected") || name.contains("Abort") || name.contains("Timeout")
+    }
+
+    
+    pub fn session_usage(&self) -> Option<f32> {
+        usage(self.counter("CurrentSessionCount"), self.max_sessions)
+    }
+
+    
+    pub fn subscription_usage(&self) -> Option<f32> {
+        usage(self.counter("CurrentSubscriptionCount"), self.max_subscriptions)
+    }
+}
+
+fn usage(current: Option<u32>, max: Option<u32>) -> Option<f32> {
+    match (current, max) {
+        (Some(current), Some(max)) if max > 0 => Some(current as f32 / max as f32),
+        _ => None,
+    }
+}
+
+fn as_u32(value: Option<&Variant>) -> Option<u32> {
+    match value? {
+        Variant::UInt32(v) => Some(*v),
+        Variant::Int32(v) => u32::try_from(*v).ok(),
+        Variant::UInt16(v) => Some(*v as u32),
+        _ => None,
+    }
+}
+
+
+pub async fn read_server_diagnostics(session: ReadOnlyGuard) -> Result<ServerDiagnostics> {
+    let mut nodes_to_read: Vec<ReadValueId> = vec![
+        ReadValueId::new(NodeId::from(VariableId::Server_ServerDiagnostics_EnabledFlag), AttributeId::Value),
+        ReadValueId::new(NodeId::from(VariableId::Server_ServerCapabilities_MaxSessions), AttributeId::Value),
+        ReadValueId::new(NodeId::from(VariableId::Server_ServerCapabilities_MaxSubscriptions), AttributeId::Value),
+    ];
+    nodes_to_read.extend(
+        COUNTERS.iter().map(|(_, id)| ReadValueId::new(NodeId::from(*id), AttributeId::Value)),
+    );
+
+    let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+
+    let value_of = |index: usize| results.get(index).and_then(|dv| dv.value.as_ref());
+
+    Ok(ServerDiagnostics {
+        enabled: match value_of(0) {
+            Some(Variant::Boolean(b)) => Some(*b),
+            _ => None,
+        },
+        max_sessions: as_u32(value_of(1)).filter(|v| *v > 0),
+        max_subscriptions: as_u32(value_of(2)).filter(|v| *v > 0),
+        counters: COUNTERS
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (*name, as_u32(value_of(i + 3))))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_ratios() {
+        let diagnostics = ServerDiagnostics {
+            enabled: Some(true),
+            counters: vec![("CurrentSessionCount", Some(45)), ("CurrentSubscriptionCount", None)],
+            max_sessions: Some(50),
+            max_subscriptions: Some(100),
+        };
+        assert_eq!(diagnostics.session_usage(), Some(0.9));
+        assert_eq!(diagnostics.subscription_usage(), None);
+        assert!(ServerDiagnostics::is_rejection_counter("RejectedSessionCount"));
+        assert!(!ServerDiagnostics::is_rejection_counter("CurrentSessionCount"));
+    }
+}
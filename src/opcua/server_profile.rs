@@ -0,0 +1,274 @@
+
+
+
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fmt::Write as _;
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{AttributeId, NodeId, ReadValueId, TimestampsToReturn, VariableId, Variant};
+
+use crate::network::discovery::{endpoint_info_from, EndpointInfo};
+use crate::opcua::service_timing::{timed, Service};
+
+
+const BUILD_INFO: [(&str, VariableId); 6] = [
+    ("ProductName", VariableId::Server_ServerStatus_BuildInfo_ProductName),
+    ("ProductUri", VariableId::Server_ServerStatus_BuildInfo_ProductUri),
+    ("ManufacturerName", VariableId::Server_ServerStatus_BuildInfo_ManufacturerName),
+    ("SoftwareVersion", VariableId::Server_ServerStatus_BuildInfo_SoftwareVersion),
+    ("BuildNumber", VariableId::Server_ServerStatus_BuildInfo_BuildNumber),
+    ("BuildDate", VariableId::Server_ServerStatus_BuildInfo_BuildDate),
+];
+
+
+const STATUS: [(&str, VariableId); 3] = [
+    ("State", VariableId::Server_ServerStatus_State),
+    ("StartTime", VariableId::Server_ServerStatus_StartTime),
+    ("CurrentTime", VariableId::Server_ServerStatus_CurrentTime),
+];
+
+
+const OPERATION_LIMITS: [(&str, VariableId); 10] = [
+    ("MaxNodesPerRead", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerRead),
+    ("MaxNodesPerWrite", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerWrite),
+    ("MaxNodesPerBrowse", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerBrowse),
+    ("MaxNodesPerTranslateBrowsePathsToNodeIds", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerTranslateBrowsePathsToNodeIds),
+    ("MaxNodesPerRegisterNodes", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerRegisterNodes),
+    ("MaxNodesPerMethodCall", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerMethodCall),
+    ("MaxNodesPerNodeManagement", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerNodeManagement),
+    ("MaxMonitoredItemsPerCall", VariableId::Server_ServerCapabilities_OperationLimits_MaxMonitoredItemsPerCall),
+    ("MaxNodesPerHistoryReadData", VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryReadData),
+    ("MaxBrowseContinuationPoints", VariableId::Server_ServerCapabilities_MaxBrowseContinuationPoints),
+];
+
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ServerProfile {
+    
+    pub generated_at: String,
+    
+    pub endpoint: Option<EndpointInfo>,
+    
+    pub discovered_endpoints: Vec<EndpointInfo>,
+    
+    pub namespaces: Vec<String>,
+
+    #[serde(serialize_with = "entries_as_map")]
+    pub server_status: Vec<(&'static str, Option<String>)>,
+
+    #[serde(serialize_with = "entries_as_map")]
+    pub build_info: Vec<(&'static str, Option<String>)>,
+
+    #[serde(serialize_with = "entries_as_map")]
+    pub operation_limits: Vec<(&'static str, Option<u32>)>,
+    
+    pub client_renderer: Option<String>,
+}
+
+fn entries_as_map<S: serde::Serializer, V: Serialize>(entries: &[(&'static str, V)], serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_map(entries.iter().map(|(name, value)| (name, value)))
+}
+
+fn server_state_name(value: i32) -> &'static str {
+    match value {
+        0 => "Running",
+        1 => "Failed",
+        2 => "NoConfiguration",
+        3 => "Suspended",
+        4 => "Shutdown",
+        5 => "Test",
+        6 => "CommunicationFault",
+        _ => "Unknown",
+    }
+}
+
+fn as_text(value: Option<&Variant>) -> Option<String> {
+    match value? {
+        Variant::Empty => None,
+        Variant::String(s) if s.is_null() => None,
+        Variant::String(s) => Some(s.to_string()),
+        Variant::DateTime(dt) => Some(dt.as_chrono().to_rfc3339()),
+        Variant::Int32(v) => Some(server_state_name(*v).to_string()),
+        other => Some(crate::opcua::subscription::format_variant(other)),
+    }
+}
+
+fn as_u32(value: Option<&Variant>) -> Option<u32> {
+    match value? {
+        Variant::UInt32(v) => Some(*v),
+        Variant::UInt16(v) => Some(*v as u32),
+        Variant::Int32(v) => u32::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+impl ServerProfile {
+    
+    pub fn simulated(namespaces: Vec<String>) -> Self {
+        Self {
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            namespaces,
+            server_status: vec![("State", Some(server_state_name(0).to_string()))],
+            build_info: vec![("ProductName", Some("Demo simulation".to_string()))],
+            ..Self::default()
+        }
+    }
+
+    
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        let _ = writeln!(md, "# OPC-UA Server Profile\n");
+        let _ = writeln!(md, "Generated: {}\n", self.generated_at);
+
+        let _ = writeln!(md, "## Negotiated Endpoint\n");
+        match &self.endpoint {
+            Some(ep) => {
+                let _ = writeln!(md, "- URL: `{}`", ep.endpoint_url);
+                let _ = writeln!(md, "- Security: {} / {}", ep.security_policy_name, ep.security_mode);
+                let _ = writeln!(md, "- User tokens: {}\n", ep.user_tokens.join(", "));
+            }
+            None => {
+                let _ = writeln!(md, "_Not available_\n");
+            }
+        }
+
+        let _ = writeln!(md, "## Discovered Endpoints\n");
+        if self.discovered_endpoints.is_empty() {
+            let _ = writeln!(md, "_Not available_\n");
+        } else {
+            let _ = writeln!(md, "| URL | Security Policy | Mode | User Tokens |");
+            let _ = writeln!(md, "|---|---|---|---|");
+            for ep in &self.discovered_endpoints {
+                let _ = writeln!(
+                    md,
+                    "| `{}` | {} | {} | {} |",
+                    ep.endpoint_url, ep.security_policy_name, ep.security_mode, ep.user_tokens.join(", ")
+                );
+            }
+            md.push('\n');
+        }
+
+        let _ = writeln!(md, "## Namespaces\n");
+        let _ = writeln!(md, "| Index | URI |");
+        let _ = writeln!(md, "|---|---|");
+        for (i, uri) in self.namespaces.iter().enumerate() {
+            let _ = writeln!(md, "| {} | {} |", i, uri);
+        }
+        md.push('\n');
+
+        let sections = [("Server Status", &self.server_status), ("Build Info", &self.build_info)];
+        for (title, entries) in sections {
+            let _ = writeln!(md, "## {}\n", title);
+            for (name, value) in entries {
+                let _ = writeln!(md, "- {}: {}", name, value.as_deref().unwrap_or("---"));
+            }
+            md.push('\n');
+        }
+
+        let _ = writeln!(md, "## Operation Limits\n");
+        for (name, value) in &self.operation_limits {
+            let value = value.map(|v| v.to_string()).unwrap_or_else(|| "not reported".to_string());
+            let _ = writeln!(md, "- {}: {}", name, value);
+        }
+
+        if let Some(renderer) = &self.client_renderer {
+            let _ = writeln!(md, "\n## Client\n");
+            let _ = writeln!(md, "- Renderer: {}", renderer);
+        }
+
+        md
+    }
+}
+
+
+pub async fn read_server_profile(session: ReadOnlyGuard) -> Result<ServerProfile> {
+    let variables: Vec<VariableId> = STATUS
+        .iter()
+        .chain(BUILD_INFO.iter())
+        .chain(OPERATION_LIMITS.iter())
+        .map(|(_, id)| *id)
+        .collect();
+    let nodes_to_read: Vec<ReadValueId> = variables
+        .iter()
+        .map(|id| ReadValueId::new(NodeId::from(*id), AttributeId::Value))
+        .collect();
+
+    let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+        .await
+        .context("Read request failed")?;
+    let value_of = |index: usize| results.get(index).and_then(|dv| dv.value.as_ref());
+
+    let namespaces = match crate::opcua::browser::read_namespace_array(session.clone()).await {
+        Ok(namespaces) => namespaces,
+        Err(e) => {
+            tracing::warn!("Server profile without NamespaceArray: {:#}", e);
+            Vec::new()
+        }
+    };
+
+    let endpoint = endpoint_info_from(session.endpoint());
+    let discovered_endpoints = match crate::network::discovery::discover_endpoints(&endpoint.endpoint_url).await {
+        Ok(endpoints) => endpoints,
+        Err(e) => {
+            tracing::warn!("Server profile without endpoint list: {}", e);
+            Vec::new()
+        }
+    };
+
+    let build_offset = STATUS.len();
+    let limits_offset = build_offset + BUILD_INFO.len();
+    Ok(ServerProfile {
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        endpoint: Some(endpoint),
+        discovered_endpoints,
+        namespaces,
+        server_status: STATUS
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (*name, as_text(value_of(i))))
+            .collect(),
+        build_info: BUILD_INFO
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (*name, as_text(value_of(build_offset + i))))
+            .collect(),
+        operation_limits: OPERATION_LIMITS
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (*name, as_u32(value_of(limits_offset + i)).filter(|v| *v > 0)))
+            .collect(),
+        client_renderer: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_report() {
+        let profile = ServerProfile {
+            generated_at: "2024-01-01T00:00:00+00:00".to_string(),
+            endpoint: None,
+            discovered_endpoints: Vec::new(),
+            namespaces: vec!["http://opcfoundation.org/UA/".to_string(), "urn:plant".to_string()],
+            server_status: vec![("State", as_text(Some(&Variant::Int32(0))))],
+            build_info: vec![("ProductName", Some("PLC".to_string())), ("BuildNumber", None)],
+            operation_limits: vec![("MaxNodesPerRead", Some(1000)), ("MaxNodesPerWrite", None)],
+            client_renderer: Some("glow (OpenGL)".to_string()),
+        };
+        let md = profile.to_markdown();
+        assert!(md.contains("| 1 | urn:plant |"));
+        assert!(md.contains("- State: Running"));
+        assert!(md.contains("- BuildNumber: ---"));
+        assert!(md.contains("- MaxNodesPerRead: 1000"));
+        assert!(md.contains("- MaxNodesPerWrite: not reported"));
+        assert!(md.contains("- Renderer: glow (OpenGL)"));
+
+        let json = serde_json::to_value(&profile).unwrap();
+        assert_eq!(json["operation_limits"]["MaxNodesPerRead"], 1000);
+        assert!(json["operation_limits"]["MaxNodesPerWrite"].is_null());
+    }
+}
@@ -0,0 +1,162 @@
+
+
+
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+
+pub const MAX_SAMPLES_PER_SERVICE: usize = 200;
+
+
+pub const SLOW_CALL_THRESHOLD: Duration = Duration::from_secs(2);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Service {
+    Browse,
+    BrowseNext,
+    Read,
+    HistoryRead,
+    TranslateBrowsePaths,
+    CreateSubscription,
+    ModifySubscription,
+    CreateMonitoredItems,
+    ModifyMonitoredItems,
+}
+
+impl Service {
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Service::Browse => "Browse",
+            Service::BrowseNext => "BrowseNext",
+            Service::Read => "Read",
+            Service::HistoryRead => "HistoryRead",
+            Service::TranslateBrowsePaths => "TranslateBrowsePaths",
+            Service::CreateSubscription => "CreateSubscription",
+            Service::ModifySubscription => "ModifySubscription",
+            Service::CreateMonitoredItems => "CreateMonitoredItems",
+            Service::ModifyMonitoredItems => "ModifyMonitoredItems",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingStats {
+    
+    pub count: usize,
+    
+    pub min: Duration,
+    
+    pub avg: Duration,
+    
+    pub p95: Duration,
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ServiceTimings {
+    
+    samples: HashMap<Service, VecDeque<Duration>>,
+    
+    pub last_slow_call: Option<(Service, Duration)>,
+}
+
+impl ServiceTimings {
+    
+    pub fn record(&mut self, service: Service, elapsed: Duration) {
+        let samples = self.samples.entry(service).or_default();
+        samples.push_back(elapsed);
+        while samples.len() > MAX_SAMPLES_PER_SERVICE {
+            samples.pop_front();
+        }
+        if elapsed > SLOW_CALL_THRESHOLD {
+            self.last_slow_call = Some((service, elapsed));
+        }
+    }
+
+    
+    pub fn stats(&self, service: Service) -> Option<TimingStats> {
+        let samples = self.samples.get(&service).filter(|s| !s.is_empty())?;
+        let mut sorted: Vec<Duration> = samples.iter().copied().collect();
+        sorted.sort();
+
+        let p95_index = ((sorted.len() as f64 * 0.95).ceil() as usize).clamp(1, sorted.len()) - 1;
+        Some(TimingStats {
+            count: sorted.len(),
+            min: sorted[0],
+            avg: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+            p95: sorted[p95_index],
+        })
+    }
+
+    
+    pub fn all_stats(&self) -> Vec<(Service, TimingStats)> {
+        let mut services: Vec<Service> = self.samples.keys().copied().collect();
+        services.sort();
+        services.into_iter().filter_map(|s| self.stats(s).map(|stats| (s, stats))).collect()
+    }
+}
+
+
+pub type SharedServiceTimings = Arc<Mutex<ServiceTimings>>;
+
+
+pub async fn timed<T>(timings: &SharedServiceTimings, service: Service, call: impl Future<Output = T>) -> T {
+    crate::opcua::network_activity::record_request();
+    let start = Instant::now();
+    let result = call.await;
+    let elapsed = start.elapsed();
+    if elapsed > SLOW_CALL_THRESHOLD {
+        tracing::warn!("{} call took {:?} (threshold {:?})", service.name(), elapsed, SLOW_CALL_THRESHOLD);
+    }
+    if let Ok(mut timings) = timings.lock() {
+        timings.record(service, elapsed);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_aggregation() {
+        let mut timings = ServiceTimings::default();
+        assert!(timings.stats(Service::Read).is_none());
+
+        for ms in 1..=100 {
+            timings.record(Service::Read, Duration::from_millis(ms));
+        }
+        let stats = timings.stats(Service::Read).unwrap();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.avg, Duration::from_micros(50_500));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert!(timings.last_slow_call.is_none());
+
+        timings.record(Service::Browse, Duration::from_secs(3));
+        let single = timings.stats(Service::Browse).unwrap();
+        assert_eq!((single.min, single.p95), (Duration::from_secs(3), Duration::from_secs(3)));
+        assert_eq!(timings.last_slow_call, Some((Service::Browse, Duration::from_secs(3))));
+        assert_eq!(timings.all_stats().len(), 2);
+    }
+
+    #[test]
+    fn test_ring_buffer_is_bounded() {
+        let mut timings = ServiceTimings::default();
+        for _ in 0..MAX_SAMPLES_PER_SERVICE {
+            timings.record(Service::Browse, Duration::from_secs(1));
+        }
+        for _ in 0..10 {
+            timings.record(Service::Browse, Duration::from_millis(1));
+        }
+        let stats = timings.stats(Service::Browse).unwrap();
+        assert_eq!(stats.count, MAX_SAMPLES_PER_SERVICE);
+        assert_eq!(stats.min, Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,436 @@
+
+
+
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+use opcua::types::{DataTypeId, DataValue, NodeId, Variant};
+
+use crate::app::BackendMessage;
+use crate::opcua::browser::{BrowsedNode, NodeClass};
+use crate::opcua::crawler::CrawlConfig;
+use crate::opcua::subscription::variant_to_f64;
+
+
+pub const DEMO_ENDPOINT: &str = "demo://simulation";
+
+
+pub const DEMO_SUBSCRIPTION_ID: u32 = u32::MAX;
+
+
+const DEMO_NAMESPACE: u16 = 2;
+
+
+pub const DEMO_NAMESPACE_URI: &str = "urn:denginks:opcua-diagnostic:demo";
+
+
+pub const TICK_INTERVAL_MS: u64 = 500;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Signal {
+
+    Sine { period_secs: f64, amplitude: f64 },
+
+    Ramp { period_secs: f64, max: i32 },
+
+    Random { min: f64, max: f64 },
+
+    Square { period_secs: f64 },
+
+    State,
+}
+
+impl Signal {
+
+    pub fn data_type(&self) -> DataTypeId {
+        match self {
+            Signal::Sine { .. } | Signal::Random { .. } => DataTypeId::Double,
+            Signal::Ramp { .. } => DataTypeId::Int32,
+            Signal::Square { .. } => DataTypeId::Boolean,
+            Signal::State => DataTypeId::String,
+        }
+    }
+
+    pub fn sample(&self, t: f64, seed: u32) -> Variant {
+        match *self {
+            Signal::Sine { period_secs, amplitude } => {
+                let phase = seed as f64 * 0.37;
+                Variant::Double(amplitude * (std::f64::consts::TAU * t / period_secs + phase).sin())
+            }
+            Signal::Ramp { period_secs, max } => {
+                let fraction = t.rem_euclid(period_secs) / period_secs;
+                Variant::Int32((fraction * max as f64) as i32)
+            }
+            Signal::Random { min, max } => {
+                let tick = (t * 1000.0 / TICK_INTERVAL_MS as f64) as u64;
+                Variant::Double(min + (max - min) * pseudo_random(tick, seed))
+            }
+            Signal::Square { period_secs } => {
+                Variant::Boolean(t.rem_euclid(period_secs) < period_secs / 2.0)
+            }
+            Signal::State => {
+                const STATES: [&str; 4] = ["Running", "Idle", "Maintenance", "Fault"];
+                let index = ((t / 30.0) as usize + seed as usize) % STATES.len();
+                Variant::String(STATES[index].into())
+            }
+        }
+    }
+}
+
+
+fn pseudo_random(tick: u64, seed: u32) -> f64 {
+    let mut x = tick.wrapping_mul(6364136223846793005).wrapping_add(seed as u64 * 1442695040888963407 + 1);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    (x % 10_000) as f64 / 10_000.0
+}
+
+
+pub struct Simulation {
+
+    pub space: DemoAddressSpace,
+
+    pub cancel: CancellationToken,
+
+    pub started: Instant,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct DemoVariable {
+
+    pub node_id: NodeId,
+
+    pub handle: u32,
+
+    pub signal: Signal,
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct DemoAddressSpace {
+
+    pub root_nodes: Vec<BrowsedNode>,
+
+    pub node_cache: HashMap<NodeId, Vec<BrowsedNode>>,
+
+    pub variables: Vec<DemoVariable>,
+}
+
+impl DemoAddressSpace {
+
+    pub fn build() -> Self {
+        let mut space = Self::default();
+
+        let objects = folder("Demo", "Demo Server");
+        space.root_nodes.push(objects.clone());
+
+        let mut top_level = Vec::new();
+
+        let sine = folder("Demo.Sine", "Sine");
+        let sine_vars = (1..=10)
+            .map(|i| (format!("Sine{:02}", i), Signal::Sine { period_secs: 10.0 * i as f64, amplitude: 10.0 * i as f64 }))
+            .collect();
+        space.add_folder(&sine, sine_vars);
+        top_level.push(sine);
+
+        let ramp = folder("Demo.Ramp", "Ramp");
+        let ramp_vars = (1..=10)
+            .map(|i| (format!("Ramp{:02}", i), Signal::Ramp { period_secs: 15.0 * i as f64, max: 100 * i }))
+            .collect();
+        space.add_folder(&ramp, ramp_vars);
+        top_level.push(ramp);
+
+        let random = folder("Demo.Random", "Random");
+        let random_vars = (1..=10)
+            .map(|i| (format!("Random{:02}", i), Signal::Random { min: 0.0, max: 10.0 * i as f64 }))
+            .collect();
+        space.add_folder(&random, random_vars);
+        top_level.push(random);
+
+        let boolean = folder("Demo.Boolean", "Boolean");
+        let boolean_vars = (1..=5)
+            .map(|i| (format!("Toggle{:02}", i), Signal::Square { period_secs: 4.0 * i as f64 }))
+            .collect();
+        space.add_folder(&boolean, boolean_vars);
+        top_level.push(boolean);
+
+        let plant = folder("Demo.Plant", "Plant");
+        let mut lines = Vec::new();
+        for line in 1..=3 {
+            let line_folder = folder(&format!("Demo.Plant.Line{}", line), &format!("Line{}", line));
+            let line_vars = vec![
+                ("Temperature".to_string(), Signal::Sine { period_secs: 60.0, amplitude: 5.0 + line as f64 }),
+                ("Pressure".to_string(), Signal::Random { min: 1.0, max: 1.5 + line as f64 * 0.1 }),
+                ("Speed".to_string(), Signal::Ramp { period_secs: 120.0, max: 1500 }),
+                ("Running".to_string(), Signal::Square { period_secs: 45.0 }),
+                ("State".to_string(), Signal::State),
+            ];
+            space.add_folder(&line_folder, line_vars);
+            lines.push(line_folder);
+        }
+        space.node_cache.insert(plant.node_id.clone(), lines);
+        top_level.push(plant);
+
+        space.node_cache.insert(objects.node_id.clone(), top_level);
+        space
+    }
+
+    fn add_folder(&mut self, parent: &BrowsedNode, vars: Vec<(String, Signal)>) {
+        let parent_id = match &parent.node_id.identifier {
+            opcua::types::Identifier::String(s) => s.to_string(),
+            _ => parent.node_id.to_string(),
+        };
+
+        let mut children = Vec::with_capacity(vars.len());
+        for (name, signal) in vars {
+            let node = variable(&format!("{}.{}", parent_id, name), &name);
+            self.variables.push(DemoVariable {
+                node_id: node.node_id.clone(),
+                handle: self.variables.len() as u32 + 1,
+                signal,
+            });
+            children.push(node);
+        }
+        self.node_cache.insert(parent.node_id.clone(), children);
+    }
+
+
+    pub fn namespaces(&self) -> Vec<String> {
+        vec![
+            "http://opcfoundation.org/UA/".to_string(),
+            "urn:denginks:opcua-diagnostic:simulation".to_string(),
+            DEMO_NAMESPACE_URI.to_string(),
+        ]
+    }
+
+
+    pub fn handle_for(&self, node_id: &NodeId) -> Option<u32> {
+        self.variables.iter().find(|v| &v.node_id == node_id).map(|v| v.handle)
+    }
+
+
+    pub fn data_type_of(&self, node_id: &NodeId) -> Option<NodeId> {
+        self.variables.iter().find(|v| &v.node_id == node_id).map(|v| v.signal.data_type().into())
+    }
+
+
+    pub fn read_value(&self, node_id: &NodeId, t: f64) -> Option<DataValue> {
+        self.variables.iter().find(|v| &v.node_id == node_id).map(|v| DataValue::new_now(v.signal.sample(t, v.handle)))
+    }
+
+
+    pub fn read_history(&self, node_id: &NodeId, from: f64, to: f64, count: usize) -> Option<Vec<(f64, f64)>> {
+        let variable = self.variables.iter().find(|v| &v.node_id == node_id)?;
+        let count = count.max(2);
+        let step = (to - from) / (count - 1) as f64;
+        Some(
+            (0..count)
+                .map(|i| from + step * i as f64)
+                .filter_map(|t| variant_to_f64(&variable.signal.sample(t, variable.handle)).map(|value| (t, value)))
+                .collect(),
+        )
+    }
+
+
+    pub fn crawl(&self, config: &CrawlConfig) -> Vec<BrowsedNode> {
+        let mut results: Vec<BrowsedNode> = Vec::new();
+        for start_node in &config.start_nodes {
+            let first = results.len();
+            if config.include_start_node && !results.iter().any(|n| &n.node_id == start_node) {
+                if let Some(start) = self.find_node(start_node) {
+                    results.push(start);
+                }
+            }
+            self.crawl_recursive(start_node, 0, config, &mut results);
+            if config.tags_results() {
+                for node in &mut results[first..] {
+                    node.crawl_root = Some(start_node.clone());
+                }
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        results.retain(|node| seen.insert(node.node_id.clone()));
+        results.truncate(config.max_nodes);
+        results
+    }
+
+    fn crawl_recursive(&self, node_id: &NodeId, depth: usize, config: &CrawlConfig, results: &mut Vec<BrowsedNode>) {
+        if depth >= config.max_depth {
+            return;
+        }
+
+        let children = if *node_id == opcua::types::ObjectId::RootFolder {
+            &self.root_nodes
+        } else {
+            match self.node_cache.get(node_id) {
+                Some(children) => children,
+                None => return,
+            }
+        };
+
+        for child in children {
+            results.push(BrowsedNode { parent_id: Some(node_id.clone()), ..child.clone() });
+            if child.has_children {
+                self.crawl_recursive(&child.node_id, depth + 1, config, results);
+            }
+            if results.len() >= config.max_nodes {
+                break;
+            }
+        }
+    }
+
+    pub fn find_node(&self, node_id: &NodeId) -> Option<BrowsedNode> {
+        self.root_nodes
+            .iter()
+            .chain(self.node_cache.values().flatten())
+            .find(|n| &n.node_id == node_id)
+            .cloned()
+    }
+}
+
+fn folder(id: &str, name: &str) -> BrowsedNode {
+    BrowsedNode {
+        node_id: NodeId::new(DEMO_NAMESPACE, id),
+        browse_name: format!("{}:{}", DEMO_NAMESPACE, name),
+        display_name: name.to_string(),
+        node_class: NodeClass::Object,
+        type_definition: Some(NodeId::from(opcua::types::ObjectTypeId::FolderType)),
+        has_children: true,
+        crawl_root: None,
+        parent_id: None,
+    }
+}
+
+fn variable(id: &str, name: &str) -> BrowsedNode {
+    BrowsedNode {
+        node_id: NodeId::new(DEMO_NAMESPACE, id),
+        browse_name: format!("{}:{}", DEMO_NAMESPACE, name),
+        display_name: name.to_string(),
+        node_class: NodeClass::Variable,
+        type_definition: Some(NodeId::from(opcua::types::VariableTypeId::BaseDataVariableType)),
+        has_children: false,
+        crawl_root: None,
+        parent_id: None,
+    }
+}
+
+
+pub async fn run_simulation(
+    variables: Vec<DemoVariable>,
+    tx: std::sync::mpsc::Sender<BackendMessage>,
+    cancel: CancellationToken,
+) {
+    let start = std::time::Instant::now();
+    let mut interval = tokio::time::interval(Duration::from_millis(TICK_INTERVAL_MS));
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = interval.tick() => {}
+        }
+
+        let t = start.elapsed().as_secs_f64();
+        for var in &variables {
+            let value = DataValue::new_now(var.signal.sample(t, var.handle));
+            if tx.send(BackendMessage::DataChange(var.handle, value)).is_err() {
+                return;
+            }
+        }
+    }
+
+    tracing::info!("Simulation stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_address_space_shape() {
+        let space = DemoAddressSpace::build();
+        assert_eq!(space.root_nodes.len(), 1);
+        assert!(space.variables.len() >= 45);
+
+        for node in space.node_cache.values().flatten() {
+            if node.has_children {
+                assert!(space.node_cache.contains_key(&node.node_id), "missing children for {}", node.display_name);
+            }
+        }
+
+        let mut handles: Vec<u32> = space.variables.iter().map(|v| v.handle).collect();
+        handles.dedup();
+        assert_eq!(handles.len(), space.variables.len());
+    }
+
+    #[test]
+    fn test_demo_crawl_respects_limits() {
+        let space = DemoAddressSpace::build();
+        let config = CrawlConfig { max_depth: 10, ..CrawlConfig::default() };
+        let all = space.crawl(&config);
+        let variables = all.iter().filter(|n| n.node_class == NodeClass::Variable).count();
+        assert_eq!(variables, space.variables.len());
+
+        let shallow = space.crawl(&CrawlConfig { max_depth: 1, ..config });
+        assert_eq!(shallow.len(), 1);
+    }
+
+    #[test]
+    fn test_demo_crawl_from_several_start_nodes_tags_each_result() {
+        let space = DemoAddressSpace::build();
+        let sine = NodeId::new(DEMO_NAMESPACE, "Demo.Sine");
+        let ramp = NodeId::new(DEMO_NAMESPACE, "Demo.Ramp");
+
+        let single = space.crawl(&CrawlConfig { start_nodes: vec![sine.clone()], ..CrawlConfig::default() });
+        assert_eq!(single.len(), 10);
+        assert!(single.iter().all(|n| n.crawl_root.is_none()));
+        assert!(single.iter().all(|n| n.parent_id.as_ref() == Some(&sine)));
+
+        let config = CrawlConfig {
+            start_nodes: vec![sine.clone(), ramp.clone(), sine.clone()],
+            include_start_node: true,
+            ..CrawlConfig::default()
+        };
+        let both = space.crawl(&config);
+        assert_eq!(both.len(), 22);
+        assert_eq!(both.iter().filter(|n| n.crawl_root.as_ref() == Some(&sine)).count(), 11);
+        assert_eq!(both.iter().filter(|n| n.crawl_root.as_ref() == Some(&ramp)).count(), 11);
+    }
+
+    #[test]
+    fn test_read_value_samples_variables_only() {
+        let space = DemoAddressSpace::build();
+        let variable = &space.variables[0];
+        let value = space.read_value(&variable.node_id, 5.0).unwrap();
+        assert_eq!(value.value, Some(variable.signal.sample(5.0, variable.handle)));
+        assert!(value.source_timestamp.is_some());
+        assert!(space.read_value(&NodeId::new(DEMO_NAMESPACE, "Demo.Ramp"), 5.0).is_none());
+    }
+
+    #[test]
+    fn test_read_history_spans_the_requested_range() {
+        let space = DemoAddressSpace::build();
+        let variable = &space.variables[0];
+        let history = space.read_history(&variable.node_id, -60.0, 0.0, 7).unwrap();
+        assert_eq!(history.len(), 7);
+        assert_eq!(history[0].0, -60.0);
+        assert_eq!(history[6].0, 0.0);
+        assert!(space.read_history(&NodeId::new(DEMO_NAMESPACE, "Demo.Ramp"), -60.0, 0.0, 7).is_none());
+    }
+
+    #[test]
+    fn test_signal_samples() {
+        assert_eq!(Signal::Ramp { period_secs: 10.0, max: 100 }.sample(5.0, 0), Variant::Int32(50));
+        assert_eq!(Signal::Square { period_secs: 4.0 }.sample(1.0, 0), Variant::Boolean(true));
+        assert_eq!(Signal::Square { period_secs: 4.0 }.sample(3.0, 0), Variant::Boolean(false));
+
+        match (Signal::Random { min: 2.0, max: 3.0 }).sample(12.3, 7) {
+            Variant::Double(v) => assert!((2.0..3.0).contains(&v)),
+            other => panic!("unexpected variant {:?}", other),
+        }
+    }
+}
@@ -4,13 +4,43 @@
 
 
 use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
 
-use opcua::types::{DataValue, NodeId, StatusCode, Variant, DateTime};
+use opcua::types::{
+    DataChangeFilter, DataChangeTrigger, DataValue, DateTime, DeadbandType, ExtensionObject, MonitoringParameters, NodeId,
+    StatusCode, TimestampsToReturn, Variant,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::status_codes::{info_bits, InfoBits};
+use crate::utils::timestamps::TimestampFormat;
 
 
 pub const MAX_HISTORY_POINTS: usize = 600;
 
 
+pub const MAX_REPAIR_ATTEMPTS: u32 = 3;
+
+
+pub const DEFAULT_PUBLISHING_INTERVAL_MS: u64 = 500;
+
+
+pub fn is_invalidated_status(status: StatusCode) -> bool {
+    status == StatusCode::BadMonitoredItemIdInvalid || status == StatusCode::BadNoSubscription
+}
+
+
+pub fn is_filter_rejected(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BadMonitoredItemFilterUnsupported
+            | StatusCode::BadMonitoredItemFilterInvalid
+            | StatusCode::BadFilterNotAllowed
+            | StatusCode::BadDeadbandFilterInvalid
+    )
+}
+
+
 #[derive(Debug, Clone)]
 pub struct MonitoredData {
     
@@ -33,6 +63,30 @@ pub struct MonitoredData {
     pub show_in_trend: bool,
     
     pub trend_color: Option<[u8; 3]>,
+    
+    pub trend_style: Option<TrendStyle>,
+    
+    pub last_update: Option<std::time::Instant>,
+    
+    pub info_bits: InfoBits,
+    
+    pub overflow_count: u32,
+    
+    pub pending_display: Option<DataValue>,
+    
+    pub client_handle: Option<u32>,
+    
+    pub revised_sampling_interval: Option<f64>,
+    
+    pub revised_queue_size: Option<u32>,
+    
+    pub parameters: MonitoredItemConfig,
+    
+    pub repair_attempts: u32,
+    
+    pub unit: Option<String>,
+    
+    pub received_at: Option<DateTime>,
 }
 
 impl MonitoredData {
@@ -49,6 +103,18 @@ impl MonitoredData {
             history: VecDeque::with_capacity(MAX_HISTORY_POINTS),
             show_in_trend: false,
             trend_color: None,
+            trend_style: None,
+            last_update: None,
+            info_bits: InfoBits::default(),
+            overflow_count: 0,
+            pending_display: None,
+            client_handle: None,
+            revised_sampling_interval: None,
+            revised_queue_size: None,
+            parameters: MonitoredItemConfig::default(),
+            repair_attempts: 0,
+            unit: None,
+            received_at: None,
         }
     }
 
@@ -59,34 +125,106 @@ impl MonitoredData {
 
     
     pub fn update(&mut self, data_value: &DataValue) {
+        self.record_sample(data_value);
+        self.apply_display(data_value);
+        self.pending_display = None;
+    }
+
+    
+    pub fn update_throttled(&mut self, data_value: &DataValue, min_interval: Duration) {
+        self.record_sample(data_value);
+        if self.display_due(min_interval) {
+            self.apply_display(data_value);
+            self.pending_display = None;
+        } else {
+            self.pending_display = Some(data_value.clone());
+        }
+    }
+
+    
+    pub fn flush_pending(&mut self, min_interval: Duration) -> bool {
+        if self.pending_display.is_none() || !self.display_due(min_interval) {
+            return false;
+        }
+        if let Some(data_value) = self.pending_display.take() {
+            self.apply_display(&data_value);
+        }
+        true
+    }
+
+    
+    pub fn accepts_initial_value(&self, data_value: &DataValue) -> bool {
+        if self.last_update.is_none() {
+            return true;
+        }
+        let latest = self.pending_display
+            .as_ref()
+            .and_then(|dv| dv.source_timestamp)
+            .or(self.source_timestamp);
+        match (data_value.source_timestamp, latest) {
+            (Some(read), Some(seen)) => read > seen,
+            _ => false,
+        }
+    }
+
+    fn display_due(&self, min_interval: Duration) -> bool {
+        self.last_update.map_or(true, |t| t.elapsed() >= min_interval)
+    }
+
+    fn apply_display(&mut self, data_value: &DataValue) {
         self.value = data_value.value.clone();
         self.status = data_value.status.unwrap_or(StatusCode::Good);
         self.source_timestamp = data_value.source_timestamp;
         self.server_timestamp = data_value.server_timestamp;
+        self.received_at = Some(DateTime::now());
+        self.last_update = Some(std::time::Instant::now());
+        self.info_bits = info_bits(self.status.bits());
+    }
 
-        
-        if let Some(ref variant) = self.value {
-            if let Some(numeric) = variant_to_f64(variant) {
-                let timestamp = self.source_timestamp
-                    .map(|dt| dt.as_chrono().timestamp_millis() as f64 / 1000.0)
-                    .unwrap_or_else(|| {
-                        std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .map(|d| d.as_secs_f64())
-                            .unwrap_or(0.0)
-                    });
-
-                self.history.push_back((timestamp, numeric));
-
-                
-                while self.history.len() > MAX_HISTORY_POINTS {
-                    self.history.pop_front();
-                }
+    
+    fn record_sample(&mut self, data_value: &DataValue) {
+        let status = data_value.status.unwrap_or(StatusCode::Good);
+        if info_bits(status.bits()).overflow {
+            self.overflow_count += 1;
+        }
+
+        if let Some(numeric) = data_value.value.as_ref().and_then(variant_to_f64) {
+            let timestamp = data_value.source_timestamp
+                .map(|dt| dt.as_chrono().timestamp_millis() as f64 / 1000.0)
+                .unwrap_or_else(|| {
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_secs_f64())
+                        .unwrap_or(0.0)
+                });
+
+            self.history.push_back((timestamp, numeric));
+
+            
+            while self.history.len() > MAX_HISTORY_POINTS {
+                self.history.pop_front();
             }
         }
     }
 
     
+    pub fn creation_failed(&self) -> bool {
+        self.monitored_item_id.is_none()
+            && self.status.is_bad()
+            && self.status != StatusCode::BadWaitingForInitialData
+    }
+
+    
+    pub fn is_invalidated(&self) -> bool {
+        is_invalidated_status(self.status)
+    }
+
+    
+    pub fn repair_exhausted(&self) -> bool {
+        self.is_invalidated() && self.repair_attempts >= MAX_REPAIR_ATTEMPTS
+    }
+
+    
     pub fn value_string(&self) -> String {
         match &self.value {
             Some(v) => format_variant(v),
@@ -95,6 +233,13 @@ impl MonitoredData {
     }
 
     
+    pub fn is_stale(&self, now: std::time::Instant, threshold: std::time::Duration) -> bool {
+        self.last_update
+            .map(|t| now.saturating_duration_since(t) > threshold)
+            .unwrap_or(true)
+    }
+
+    
     pub fn quality_icon(&self) -> &'static str {
         if self.status.is_good() {
             "OK"
@@ -106,13 +251,20 @@ impl MonitoredData {
     }
 
     
-    pub fn timestamp_string(&self) -> String {
+    pub fn display_timestamp(&self) -> Option<(DateTime, TimestampOrigin)> {
         self.source_timestamp
-            .map(|dt| {
-                let chrono_dt = dt.as_chrono();
-                chrono_dt.format("%d-%m-%Y %H:%M:%S").to_string()
-            })
-            .unwrap_or_else(|| "---".to_string())
+            .map(|dt| (dt, TimestampOrigin::Source))
+            .or_else(|| self.server_timestamp.map(|dt| (dt, TimestampOrigin::Server)))
+            .or_else(|| self.received_at.map(|dt| (dt, TimestampOrigin::Local)))
+    }
+
+    
+    pub fn timestamp_string(&self, format: &TimestampFormat) -> String {
+        match self.display_timestamp() {
+            Some((dt, TimestampOrigin::Local)) => format!("{} (local)", format.format(dt.as_chrono())),
+            Some((dt, _)) => format.format(dt.as_chrono()),
+            None => "---".to_string(),
+        }
     }
 }
 
@@ -161,8 +313,7 @@ pub fn format_variant(variant: &Variant) -> String {
 }
 
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SubscriptionConfig {
     
     pub publishing_interval_ms: u64,
@@ -179,8 +330,8 @@ pub struct SubscriptionConfig {
 impl Default for SubscriptionConfig {
     fn default() -> Self {
         Self {
-            publishing_interval_ms: 1000,  
-            lifetime_count: 10,
+            publishing_interval_ms: DEFAULT_PUBLISHING_INTERVAL_MS,
+            lifetime_count: 90,
             max_keepalive_count: 30,
             max_notifications: 0,  
             priority: 0,
@@ -188,6 +339,243 @@ impl Default for SubscriptionConfig {
     }
 }
 
+impl SubscriptionConfig {
+    
+    pub fn publishing_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.publishing_interval_ms)
+    }
+
+    
+    pub fn min_lifetime_count(max_keepalive_count: u32) -> u32 {
+        max_keepalive_count.saturating_mul(3)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimestampsMode {
+    Source,
+    Server,
+    #[default]
+    Both,
+    Neither,
+}
+
+impl TimestampsMode {
+    
+    pub const ALL: [TimestampsMode; 4] = [TimestampsMode::Source, TimestampsMode::Server, TimestampsMode::Both, TimestampsMode::Neither];
+
+    
+    pub fn to_opcua(self) -> TimestampsToReturn {
+        match self {
+            TimestampsMode::Source => TimestampsToReturn::Source,
+            TimestampsMode::Server => TimestampsToReturn::Server,
+            TimestampsMode::Both => TimestampsToReturn::Both,
+            TimestampsMode::Neither => TimestampsToReturn::Neither,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineDash {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl LineDash {
+    
+    pub const ALL: [LineDash; 3] = [LineDash::Solid, LineDash::Dashed, LineDash::Dotted];
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrendStyle {
+    
+    pub width: f32,
+    
+    pub markers: bool,
+    
+    pub dash: LineDash,
+}
+
+impl TrendStyle {
+    
+    pub const MIN_WIDTH: f32 = 1.0;
+    
+    pub const MAX_WIDTH: f32 = 4.0;
+
+    
+    pub fn clamped(self) -> Self {
+        let width = if self.width.is_finite() { self.width.clamp(Self::MIN_WIDTH, Self::MAX_WIDTH) } else { 2.0 };
+        Self { width, ..self }
+    }
+}
+
+impl Default for TrendStyle {
+    fn default() -> Self {
+        Self { width: 2.0, markers: false, dash: LineDash::Solid }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampOrigin {
+    Source,
+    Server,
+    Local,
+}
+
+impl TimestampOrigin {
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampOrigin::Source => "source",
+            TimestampOrigin::Server => "server",
+            TimestampOrigin::Local => "local",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitoringOptions {
+    
+    pub queue_size: u32,
+    
+    pub discard_oldest: bool,
+    
+    pub timestamps: TimestampsMode,
+}
+
+impl Default for MonitoringOptions {
+    fn default() -> Self {
+        Self { queue_size: 1, discard_oldest: true, timestamps: TimestampsMode::Both }
+    }
+}
+
+impl MonitoringOptions {
+    
+    pub fn apply(&self, parameters: &mut MonitoringParameters) {
+        parameters.queue_size = self.queue_size.max(1);
+        parameters.discard_oldest = self.discard_oldest;
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Deadband {
+    #[default]
+    None,
+    Absolute(f64),
+    Percent(f64),
+}
+
+impl Deadband {
+    
+    pub fn filter(self) -> ExtensionObject {
+        let (deadband_type, deadband_value) = match self {
+            Deadband::None => return ExtensionObject::null(),
+            Deadband::Absolute(value) => (DeadbandType::Absolute, value),
+            Deadband::Percent(value) => (DeadbandType::Percent, value.clamp(0.0, 100.0)),
+        };
+        ExtensionObject::from_message(DataChangeFilter {
+            trigger: DataChangeTrigger::StatusValue,
+            deadband_type: deadband_type as u32,
+            deadband_value: deadband_value.max(0.0),
+        })
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MonitoredItemConfig {
+    
+    pub sampling_interval_ms: Option<f64>,
+    
+    pub queue_size: Option<u32>,
+    
+    pub deadband: Deadband,
+}
+
+impl MonitoredItemConfig {
+    
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    
+    pub fn has_filter(&self) -> bool {
+        self.deadband != Deadband::None
+    }
+
+    
+    pub fn without_filter(self) -> Self {
+        Self { deadband: Deadband::None, ..self }
+    }
+
+    
+    pub fn apply(&self, parameters: &mut MonitoringParameters) {
+        if let Some(interval) = self.sampling_interval_ms {
+            parameters.sampling_interval = interval.max(0.0);
+        }
+        if let Some(queue_size) = self.queue_size {
+            parameters.queue_size = queue_size.max(1);
+        }
+        parameters.filter = self.deadband.filter();
+    }
+
+    
+    pub fn monitoring_parameters(&self, options: MonitoringOptions, client_handle: u32) -> MonitoringParameters {
+        let mut parameters = MonitoringParameters { client_handle, ..Default::default() };
+        options.apply(&mut parameters);
+        self.apply(&mut parameters);
+        parameters
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedItem {
+    
+    pub node_id: NodeId,
+    
+    pub monitored_item_id: u32,
+    
+    pub client_handle: u32,
+    
+    pub revised_sampling_interval: f64,
+    
+    pub revised_queue_size: u32,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddItemsOutcome {
+    
+    pub subscription_id: u32,
+    
+    pub created: Vec<CreatedItem>,
+    
+    pub failed: Vec<(NodeId, StatusCode)>,
+    
+    pub filter_rejected: Vec<NodeId>,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModifiedItem {
+    
+    pub revised_sampling_interval: f64,
+    
+    pub revised_queue_size: u32,
+    
+    pub filter_rejected: bool,
+}
+
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -209,6 +597,10 @@ pub struct SubscriptionState {
     pub node_to_handle: HashMap<NodeId, u32>,
     
     pub handle_to_server_id: HashMap<u32, u32>,
+    
+    pub additional_subscriptions: Vec<u32>,
+    
+    pub handle_to_subscription: HashMap<u32, u32>,
 }
 
 impl SubscriptionState {
@@ -223,6 +615,7 @@ impl SubscriptionState {
     pub fn unregister_by_node(&mut self, node_id: &NodeId) -> Option<u32> {
         if let Some(handle) = self.node_to_handle.remove(node_id) {
             self.handle_to_node.remove(&handle);
+            self.handle_to_subscription.remove(&handle);
             self.handle_to_server_id.remove(&handle)
         } else {
             None
@@ -230,11 +623,41 @@ impl SubscriptionState {
     }
 
     
+    pub fn remap_item(&mut self, node_id: NodeId, monitored_item_id: u32, handle: u32) -> Option<u32> {
+        let previous = self.node_to_handle.get(&node_id).copied().filter(|old| *old != handle);
+        if let Some(old) = previous {
+            self.handle_to_node.remove(&old);
+            self.handle_to_server_id.remove(&old);
+            self.handle_to_subscription.remove(&old);
+        }
+        self.register_item(node_id, monitored_item_id, handle);
+        previous
+    }
+
+    
     pub fn clear(&mut self) {
         self.subscription_id = None;
         self.handle_to_node.clear();
         self.node_to_handle.clear();
         self.handle_to_server_id.clear();
+        self.additional_subscriptions.clear();
+        self.handle_to_subscription.clear();
+    }
+
+    
+    pub fn assign_subscription(&mut self, handle: u32, subscription_id: u32) {
+        self.handle_to_subscription.insert(handle, subscription_id);
+    }
+
+    
+    pub fn subscription_for_node(&self, node_id: &NodeId) -> Option<u32> {
+        let handle = self.node_to_handle.get(node_id)?;
+        self.handle_to_subscription.get(handle).copied().or(self.subscription_id)
+    }
+
+    
+    pub fn target_subscription(&self) -> Option<u32> {
+        self.additional_subscriptions.last().copied().or(self.subscription_id)
     }
 
     
@@ -256,9 +679,114 @@ mod tests {
         assert_eq!(data.display_name, "Test Variable");
         assert!(data.value.is_none());
         assert!(!data.status.is_good());
+        assert!(!data.creation_failed());
     }
 
     #[test]
+    fn test_trend_style_defaults_and_clamping() {
+        let style: TrendStyle = serde_json::from_str(r#"{"dash": "Dotted"}"#).unwrap();
+        assert_eq!(style, TrendStyle { dash: LineDash::Dotted, ..TrendStyle::default() });
+        assert_eq!(TrendStyle { width: 9.0, ..style }.clamped().width, TrendStyle::MAX_WIDTH);
+        assert_eq!(TrendStyle { width: 0.0, ..style }.clamped().width, TrendStyle::MIN_WIDTH);
+        assert_eq!(TrendStyle { width: f32::NAN, ..style }.clamped().width, 2.0);
+    }
+
+    #[test]
+    fn test_monitoring_options_apply_queue_and_discard_policy() {
+        use opcua::types::MonitoredItemCreateRequest;
+
+        let mut request: MonitoredItemCreateRequest = NodeId::new(2, "Spike").into();
+        MonitoringOptions { queue_size: 10, discard_oldest: false, ..Default::default() }.apply(&mut request.requested_parameters);
+        assert_eq!(request.requested_parameters.queue_size, 10);
+        assert!(!request.requested_parameters.discard_oldest);
+
+        MonitoringOptions { queue_size: 0, discard_oldest: true, ..Default::default() }.apply(&mut request.requested_parameters);
+        assert_eq!(request.requested_parameters.queue_size, 1);
+        assert!(request.requested_parameters.discard_oldest);
+    }
+
+    #[test]
+    fn test_missing_timestamps_fall_back_to_marked_local_time() {
+        let format = TimestampFormat::Iso8601Millis;
+        let mut data = MonitoredData::new(NodeId::new(2, "Var"), "Var".to_string());
+        assert_eq!(data.timestamp_string(&format), "---");
+
+        let mut value = DataValue::value_only(Variant::Int32(1));
+        data.update(&value);
+        assert!(matches!(data.display_timestamp(), Some((_, TimestampOrigin::Local))));
+        assert!(data.timestamp_string(&format).ends_with(" (local)"));
+
+        value.server_timestamp = Some(DateTime::now());
+        data.update(&value);
+        assert!(matches!(data.display_timestamp(), Some((_, TimestampOrigin::Server))));
+        assert!(!data.timestamp_string(&format).contains("(local)"));
+
+        value.source_timestamp = Some(DateTime::now());
+        data.update(&value);
+        assert_eq!(data.display_timestamp().map(|(_, origin)| origin), Some(TimestampOrigin::Source));
+        assert_eq!(TimestampsMode::default().to_opcua(), TimestampsToReturn::Both);
+    }
+
+    #[test]
+    fn test_creation_failed() {
+        let mut data = MonitoredData::new(NodeId::new(2, "Missing"), "Missing".to_string());
+        data.status = StatusCode::BadNodeIdUnknown;
+        assert!(data.creation_failed());
+
+        data.monitored_item_id = Some(7);
+        assert!(!data.creation_failed());
+    }
+
+    #[test]
+    fn test_update_counts_overflow() {
+        let mut data = MonitoredData::new(NodeId::new(2, "Var"), "Var".to_string());
+        let mut value = DataValue::new_now(Variant::Int32(1));
+        data.update(&value);
+        assert_eq!(data.overflow_count, 0);
+
+        value.status = Some(StatusCode::from(0x0000_0480));
+        data.update(&value);
+        data.update(&value);
+        assert!(data.info_bits.overflow);
+        assert_eq!(data.overflow_count, 2);
+        assert!(data.status.is_good());
+    }
+
+    #[test]
+    fn test_throttled_update_records_every_sample() {
+        let mut data = MonitoredData::new(NodeId::new(2, "Fast"), "Fast".to_string());
+        let interval = Duration::from_secs(60);
+        for i in 0..5 {
+            data.update_throttled(&DataValue::new_now(Variant::Int32(i)), interval);
+        }
+        assert_eq!(data.history.len(), 5);
+        assert_eq!(data.value, Some(Variant::Int32(0)));
+        assert_eq!(data.pending_display.as_ref().and_then(|dv| dv.value.clone()), Some(Variant::Int32(4)));
+        assert!(!data.flush_pending(interval));
+
+        assert!(data.flush_pending(Duration::ZERO));
+        assert_eq!(data.value, Some(Variant::Int32(4)));
+        assert!(data.pending_display.is_none());
+        assert!(!data.flush_pending(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_initial_read_does_not_override_newer_publish() {
+        let mut data = MonitoredData::new(NodeId::new(2, "Slow"), "Slow".to_string());
+        let read = DataValue::new_at(Variant::Int32(1), DateTime::from(1_000_000_000i64));
+        assert!(data.accepts_initial_value(&read));
+
+        let publish = DataValue::new_at(Variant::Int32(2), DateTime::from(2_000_000_000i64));
+        data.update(&publish);
+        assert!(!data.accepts_initial_value(&read));
+        assert!(!data.accepts_initial_value(&DataValue::value_only(Variant::Int32(3))));
+
+        let newer = DataValue::new_at(Variant::Int32(4), DateTime::from(3_000_000_000i64));
+        assert!(data.accepts_initial_value(&newer));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn test_variant_to_f64() {
         assert_eq!(variant_to_f64(&Variant::Int32(42)), Some(42.0));
         assert_eq!(variant_to_f64(&Variant::Float(3.14)), Some(3.14_f32 as f64));
@@ -282,4 +810,24 @@ mod tests {
         assert_eq!(removed, Some(100));
         assert!(state.get_node_id(1).is_none());
     }
+
+    #[test]
+    fn test_item_parameters_override_watchlist_defaults() {
+        let options = MonitoringOptions { queue_size: 5, ..MonitoringOptions::default() };
+        let defaults = MonitoredItemConfig::default().monitoring_parameters(options, 42);
+        assert_eq!(defaults.client_handle, 42);
+        assert_eq!(defaults.queue_size, 5);
+        assert_eq!(defaults.sampling_interval, 0.0);
+        assert!(defaults.filter.is_null());
+
+        let parameters = MonitoredItemConfig { sampling_interval_ms: Some(250.0), queue_size: Some(0), deadband: Deadband::Percent(150.0) };
+        assert!(!parameters.is_default());
+        let custom = parameters.monitoring_parameters(options, 42);
+        assert_eq!(custom.sampling_interval, 250.0);
+        assert_eq!(custom.queue_size, 1);
+        let filter = custom.filter.inner_as::<DataChangeFilter>().unwrap();
+        assert_eq!(filter.deadband_type, DeadbandType::Percent as u32);
+        assert_eq!(filter.deadband_value, 100.0);
+        assert_eq!(filter.trigger, DataChangeTrigger::StatusValue);
+    }
 }
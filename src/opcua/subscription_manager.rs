@@ -7,9 +7,9 @@ use std::sync::Arc;
 use tokio::runtime::Handle;
 use tokio::sync::RwLock;
 
-use opcua::types::{NodeId, StatusCode};
+use opcua::types::{DataValue, NodeId, StatusCode};
 use crate::opcua::client::OpcUaClient;
-use crate::opcua::subscription::{MonitoredData, SubscriptionState};
+use crate::opcua::subscription::{is_filter_rejected, is_invalidated_status, AddItemsOutcome, ModifiedItem, MonitoredItemConfig, MonitoredData, MonitoringOptions, SubscriptionConfig, SubscriptionState, MAX_REPAIR_ATTEMPTS};
 use crate::app::BackendMessage;
 use crate::opcua::browser::BrowsedNode;
 
@@ -37,6 +37,122 @@ pub struct SubscriptionManager {
     
     
     pub creating_subscription: bool,
+    
+    
+    pub read_initial_values: bool,
+    
+    pub monitoring_options: MonitoringOptions,
+    
+    pub repair_queue: Vec<NodeId>,
+    
+    pub creating_additional_subscription: bool,
+    
+    pub subscription_config: SubscriptionConfig,
+    
+    pub applied_config: Option<SubscriptionConfig>,
+    
+    pub restore_items: Vec<(NodeId, String)>,
+}
+
+
+async fn add_items(
+    client: &OpcUaClient,
+    sub_id: u32,
+    items: &[(NodeId, MonitoredItemConfig)],
+    options: MonitoringOptions,
+    read_initial: bool,
+    tx: &std::sync::mpsc::Sender<BackendMessage>,
+) {
+    let (mut created, mut failed) = match client.add_monitored_items(sub_id, items, options).await {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = tx.send(BackendMessage::Error(format!("Failed to add items: {}", e)));
+            return;
+        }
+    };
+    let retry = take_filter_retries(items, &mut failed);
+    let filter_rejected: Vec<NodeId> = retry.iter().map(|(node_id, _)| node_id.clone()).collect();
+    if !retry.is_empty() {
+        tracing::warn!("Server rejected the deadband filter of {} items, creating them without it", retry.len());
+        match client.add_monitored_items(sub_id, &retry, options).await {
+            Ok((retried, still_failed)) => {
+                created.extend(retried);
+                failed.extend(still_failed);
+            }
+            Err(e) => {
+                tracing::warn!("Creating monitored items without a filter failed: {:#}", e);
+                failed.extend(filter_rejected.iter().map(|node_id| (node_id.clone(), StatusCode::BadMonitoredItemFilterUnsupported)));
+            }
+        }
+    }
+    let handles: Vec<(NodeId, u32)> = if read_initial {
+        created.iter().map(|item| (item.node_id.clone(), item.client_handle)).collect()
+    } else {
+        Vec::new()
+    };
+    let _ = tx.send(BackendMessage::MonitoredItemsAdded(AddItemsOutcome { subscription_id: sub_id, created, failed, filter_rejected }));
+    if handles.is_empty() {
+        return;
+    }
+
+    let ids: Vec<NodeId> = handles.iter().map(|(node_id, _)| node_id.clone()).collect();
+    match client.read_values(&ids).await {
+        Ok(values) => {
+            let initial: Vec<(u32, DataValue)> = handles
+                .into_iter()
+                .zip(values)
+                .filter(|(_, dv)| dv.value.is_some() && !dv.status.is_some_and(|s| s.is_bad()))
+                .map(|((_, handle), dv)| (handle, dv))
+                .collect();
+            let _ = tx.send(BackendMessage::InitialValues(initial));
+        }
+        Err(e) => tracing::warn!("Initial read of {} watchlist items failed: {:#}", ids.len(), e),
+    }
+}
+
+
+fn take_filter_retries(items: &[(NodeId, MonitoredItemConfig)], failed: &mut Vec<(NodeId, StatusCode)>) -> Vec<(NodeId, MonitoredItemConfig)> {
+    let mut retry = Vec::new();
+    failed.retain(|(node_id, status)| {
+        let config = items.iter().find(|(id, _)| id == node_id).map(|(_, config)| *config);
+        match config {
+            Some(config) if config.has_filter() && is_filter_rejected(*status) => {
+                retry.push((node_id.clone(), config.without_filter()));
+                false
+            }
+            _ => true,
+        }
+    });
+    retry
+}
+
+async fn create_subscription(
+    client: &OpcUaClient,
+    config: SubscriptionConfig,
+    additional: bool,
+    tx: &std::sync::mpsc::Sender<BackendMessage>,
+) {
+    let tx_cb = tx.clone();
+    let callback = move |data_value: opcua::types::DataValue, item: &opcua::client::MonitoredItem| {
+        crate::opcua::network_activity::record_notification();
+        let item_id = item.client_handle();
+        let _ = tx_cb.send(BackendMessage::DataChange(item_id, data_value));
+    };
+
+    match client.create_subscription(config, callback).await {
+        Ok(id) if additional => {
+            let _ = tx.send(BackendMessage::AdditionalSubscriptionCreated(id));
+        }
+        Ok(id) => {
+            let _ = tx.send(BackendMessage::SubscriptionCreated(id));
+        }
+        Err(e) if additional => {
+            let _ = tx.send(BackendMessage::AdditionalSubscriptionFailed(e.to_string()));
+        }
+        Err(e) => {
+            let _ = tx.send(BackendMessage::Error(format!("Failed to create subscription: {}", e)));
+        }
+    }
 }
 
 impl SubscriptionManager {
@@ -50,25 +166,84 @@ impl SubscriptionManager {
         self.monitored_items.clear();
         self.subscription_state.clear();
         self.pending_monitored_items.clear();
+        self.repair_queue.clear();
         self.creating_subscription = false;
+        self.creating_additional_subscription = false;
+        self.applied_config = None;
+    }
+
+    
+    pub fn subscription_ids(&self) -> Vec<u32> {
+        self.subscription_state.subscription_id
+            .into_iter()
+            .chain(self.subscription_state.additional_subscriptions.iter().copied())
+            .collect()
+    }
+
+    
+    pub fn config_outdated(&self) -> bool {
+        self.applied_config.is_some_and(|applied| applied != self.subscription_config)
+    }
+
+    
+    pub fn remember_for_restore(&mut self) {
+        let mut items: Vec<(NodeId, String)> = self.monitored_items
+            .values()
+            .map(|item| (item.node_id.clone(), item.display_name.clone()))
+            .collect();
+        items.sort_by(|a, b| a.1.cmp(&b.1));
+        self.restore_items = items;
+    }
+
+    
+    pub fn take_restore_items(&mut self) -> Vec<(NodeId, String)> {
+        std::mem::take(&mut self.restore_items)
+    }
+
+    
+    pub fn within_limit(&self, nodes: &[BrowsedNode], max_items: usize) -> (Vec<BrowsedNode>, usize) {
+        let mut accepted: Vec<BrowsedNode> = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        let mut dropped = 0;
+        for node in nodes {
+            if self.monitored_items.contains_key(&node.node_id) || !seen.insert(&node.node_id) {
+                continue;
+            }
+            if self.monitored_items.len() + accepted.len() >= max_items {
+                dropped += 1;
+            } else {
+                accepted.push(node.clone());
+            }
+        }
+        (accepted, dropped)
     }
 
     
     pub fn request_add_to_watchlist(&mut self, node: &BrowsedNode) -> SubscriptionAction {
-        if self.monitored_items.contains_key(&node.node_id) {
+        self.request_add_many_to_watchlist(std::slice::from_ref(node))
+    }
+
+    
+    pub fn request_add_many_to_watchlist(&mut self, nodes: &[BrowsedNode]) -> SubscriptionAction {
+        let mut new_ids = Vec::new();
+        for node in nodes {
+            if self.monitored_items.contains_key(&node.node_id) {
+                continue;
+            }
+            let data = MonitoredData::new(node.node_id.clone(), node.display_name.clone());
+            self.monitored_items.insert(node.node_id.clone(), data);
+            new_ids.push(node.node_id.clone());
+        }
+        if new_ids.is_empty() {
             return SubscriptionAction::None;
         }
 
         
-        let data = MonitoredData::new(node.node_id.clone(), node.display_name.clone());
-        self.monitored_items.insert(node.node_id.clone(), data);
-
-        
         if self.subscription_state.subscription_id.is_some() {
-             SubscriptionAction::AddItems(vec![node.node_id.clone()])
+             SubscriptionAction::AddItems(new_ids)
         } else {
              
-             self.pending_monitored_items.push(node.node_id.clone());
+             self.pending_monitored_items.extend(new_ids);
              
              
              if !self.creating_subscription {
@@ -82,32 +257,44 @@ impl SubscriptionManager {
     
     pub fn spawn_subscription_task(
         &self,
+        additional: bool,
         runtime: &Handle,
         opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
         backend_tx: std::sync::mpsc::Sender<BackendMessage>,
     ) {
         let tx = backend_tx;
         let client_handle = opcua_client;
+        let config = self.subscription_config;
         
         runtime.spawn(async move {
             let guard = client_handle.read().await;
             if let Some(client) = guard.as_ref() {
-                
-                let tx_cb = tx.clone();
-                let callback = move |data_value: opcua::types::DataValue, item: &opcua::client::MonitoredItem| {
-                    let item_id = item.client_handle();
-                    let _ = tx_cb.send(BackendMessage::DataChange(item_id, data_value));
-                };
-
-                match client.create_subscription(std::time::Duration::from_millis(500), callback).await {
-                    Ok(id) => {
-                        let _ = tx.send(BackendMessage::SubscriptionCreated(id));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(BackendMessage::Error(format!("Failed to create subscription: {}", e)));
-                    }
+                create_subscription(client, config, additional, &tx).await;
+            }
+        });
+    }
+
+    pub fn spawn_modify_subscriptions_task(
+        &self,
+        runtime: &Handle,
+        opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
+        backend_tx: std::sync::mpsc::Sender<BackendMessage>,
+    ) {
+        let subscription_ids = self.subscription_ids();
+        let config = self.subscription_config;
+        runtime.spawn(async move {
+            let guard = opcua_client.read().await;
+            let Some(client) = guard.as_ref() else {
+                return;
+            };
+            let mut result = Ok(config);
+            for subscription_id in subscription_ids {
+                if let Err(e) = client.modify_subscription(subscription_id, config).await {
+                    result = Err(format!("{:#}", e));
+                    break;
                 }
             }
+            let _ = backend_tx.send(BackendMessage::SubscriptionsModified(result));
         });
     }
 
@@ -123,21 +310,17 @@ impl SubscriptionManager {
         
         if self.pending_monitored_items.is_empty() { return; }
         let node_ids = std::mem::take(&mut self.pending_monitored_items);
+        let items = self.with_parameters(node_ids);
         
         let tx = backend_tx;
         let client_handle = opcua_client;
         
+        let read_initial = self.read_initial_values;
+        let options = self.monitoring_options;
         runtime.spawn(async move {
             let guard = client_handle.read().await;
             if let Some(client) = guard.as_ref() {
-                match client.add_monitored_items(sub_id, &node_ids).await {
-                    Ok(pairs) => {
-                         let _ = tx.send(BackendMessage::MonitoredItemsAdded(pairs));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(BackendMessage::Error(format!("Failed to add items: {}", e)));
-                    }
-                }
+                add_items(client, sub_id, &items, options, read_initial, &tx).await;
             }
         });
     }
@@ -149,35 +332,117 @@ impl SubscriptionManager {
         opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
         backend_tx: std::sync::mpsc::Sender<BackendMessage>,
     ) {
-         let sub_id = self.subscription_state.subscription_id.unwrap_or(0);
+         let sub_id = self.subscription_state.target_subscription().unwrap_or(0);
          if sub_id == 0 { return; }
-         
-         let tx = backend_tx;
-         let client_handle = opcua_client;
+         self.spawn_add_items_to_subscription(sub_id, node_ids, runtime, opcua_client, backend_tx);
+    }
+
+    pub fn spawn_add_items_to_subscription(
+        &self,
+        sub_id: u32,
+        node_ids: Vec<NodeId>,
+        runtime: &Handle,
+        opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
+        backend_tx: std::sync::mpsc::Sender<BackendMessage>,
+    ) {
+        if node_ids.is_empty() { return; }
+        let items = self.with_parameters(node_ids);
+        let tx = backend_tx;
+        let client_handle = opcua_client;
 
-         runtime.spawn(async move {
+        let read_initial = self.read_initial_values;
+        let options = self.monitoring_options;
+        runtime.spawn(async move {
             let guard = client_handle.read().await;
             if let Some(client) = guard.as_ref() {
-                match client.add_monitored_items(sub_id, &node_ids).await {
-                    Ok(pairs) => {
-                         let _ = tx.send(BackendMessage::MonitoredItemsAdded(pairs));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(BackendMessage::Error(format!("Failed to add items: {}", e)));
-                    }
-                }
+                add_items(client, sub_id, &items, options, read_initial, &tx).await;
             }
         });
     }
 
+    fn with_parameters(&self, node_ids: Vec<NodeId>) -> Vec<(NodeId, MonitoredItemConfig)> {
+        node_ids
+            .into_iter()
+            .map(|node_id| {
+                let parameters = self.monitored_items.get(&node_id).map(|item| item.parameters).unwrap_or_default();
+                (node_id, parameters)
+            })
+            .collect()
+    }
+
+    
+    pub fn set_item_parameters(&mut self, node_id: &NodeId, parameters: MonitoredItemConfig) -> Option<(u32, u32, u32)> {
+        let item = self.monitored_items.get_mut(node_id)?;
+        item.parameters = parameters;
+        let monitored_item_id = item.monitored_item_id?;
+        let client_handle = item.client_handle?;
+        let sub_id = self.subscription_state.subscription_for_node(node_id)?;
+        Some((sub_id, monitored_item_id, client_handle))
+    }
+
+    
+    pub fn spawn_modify_item_task(
+        &mut self,
+        node_id: &NodeId,
+        parameters: MonitoredItemConfig,
+        runtime: &Handle,
+        opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
+        backend_tx: std::sync::mpsc::Sender<BackendMessage>,
+    ) {
+        let Some((sub_id, monitored_item_id, client_handle)) = self.set_item_parameters(node_id, parameters) else {
+            return;
+        };
+        let node_id = node_id.clone();
+        let options = self.monitoring_options;
+        runtime.spawn(async move {
+            let guard = opcua_client.read().await;
+            let Some(client) = guard.as_ref() else {
+                return;
+            };
+            let modify = |parameters: MonitoredItemConfig| async move {
+                client
+                    .modify_monitored_items(sub_id, &[(monitored_item_id, client_handle, parameters)], options)
+                    .await
+                    .map(|results| results.into_iter().next().unwrap_or(Err(StatusCode::BadUnexpectedError)))
+            };
+            let mut filter_rejected = false;
+            let mut result = modify(parameters).await;
+            if parameters.has_filter() && matches!(result, Ok(Err(status)) if is_filter_rejected(status)) {
+                tracing::warn!("Server rejected the deadband filter of {:?}, modifying it without one", node_id);
+                filter_rejected = true;
+                result = modify(parameters.without_filter()).await;
+            }
+            let result = match result {
+                Ok(Ok((revised_sampling_interval, revised_queue_size))) => {
+                    Ok(ModifiedItem { revised_sampling_interval, revised_queue_size, filter_rejected })
+                }
+                Ok(Err(status)) => Err(crate::opcua::status_codes::translate_status_code(status)),
+                Err(e) => Err(format!("{:#}", e)),
+            };
+            let _ = backend_tx.send(BackendMessage::MonitoredItemModified(node_id, result));
+        });
+    }
+
+    
+    pub fn handle_monitored_item_modified(&mut self, node_id: &NodeId, modified: ModifiedItem) {
+        if let Some(item) = self.monitored_items.get_mut(node_id) {
+            item.revised_sampling_interval = Some(modified.revised_sampling_interval);
+            item.revised_queue_size = Some(modified.revised_queue_size);
+            if modified.filter_rejected {
+                item.parameters = item.parameters.without_filter();
+            }
+        }
+    }
+
     pub fn remove_from_watchlist(
         &mut self,
         node_id: &NodeId,
         runtime: &Handle,
         opcua_client: Arc<RwLock<Option<OpcUaClient>>>,
     ) {
+        let sub_id = self.subscription_state.subscription_for_node(node_id);
         if let Some(item_id) = self.subscription_state.unregister_by_node(node_id) {
-             if let Some(sub_id) = self.subscription_state.subscription_id {
+             if let Some(sub_id) = sub_id {
                  self.spawn_remove_items_task(sub_id, vec![item_id], runtime, opcua_client);
              }
         }
@@ -200,21 +465,400 @@ impl SubscriptionManager {
         });
     }
     
-    pub fn handle_data_change(&mut self, handle: u32, value: opcua::types::DataValue) {
-        if let Some(node_id) = self.subscription_state.get_node_id(handle) {
-             if let Some(item) = self.monitored_items.get_mut(node_id) {
-                item.update(&value);
+    pub fn handle_data_change(&mut self, handle: u32, value: opcua::types::DataValue, min_interval: std::time::Duration) -> Option<String> {
+        let node_id = self.subscription_state.get_node_id(handle)?.clone();
+        let item = self.monitored_items.get_mut(&node_id)?;
+        let overflows_before = item.overflow_count;
+        let status = value.status.unwrap_or(StatusCode::Good);
+        item.update_throttled(&value, min_interval);
+        if status.is_good() {
+            item.repair_attempts = 0;
+        }
+        let overflowed = (overflows_before == 0 && item.overflow_count > 0).then(|| item.display_name.clone());
+        if is_invalidated_status(status) {
+            item.status = status;
+            self.queue_repair(&node_id);
+        }
+        overflowed
+    }
+
+    
+    fn queue_repair(&mut self, node_id: &NodeId) {
+        let due = self.monitored_items.get(node_id)
+            .is_some_and(|item| item.is_invalidated() && item.repair_attempts < MAX_REPAIR_ATTEMPTS);
+        if due && !self.repair_queue.contains(node_id) {
+            self.repair_queue.push(node_id.clone());
+        }
+    }
+
+    
+    pub fn take_repairs(&mut self) -> Vec<NodeId> {
+        let node_ids = std::mem::take(&mut self.repair_queue);
+        for node_id in &node_ids {
+            if let Some(item) = self.monitored_items.get_mut(node_id) {
+                item.repair_attempts += 1;
+                tracing::info!("Re-creating invalidated monitored item {} (attempt {}/{})", node_id, item.repair_attempts, MAX_REPAIR_ATTEMPTS);
+            }
+        }
+        node_ids
+    }
+
+    
+    pub fn request_recreate(&mut self, node_id: &NodeId) -> Option<NodeId> {
+        let item = self.monitored_items.get_mut(node_id)?;
+        item.repair_attempts = 0;
+        self.repair_queue.retain(|id| id != node_id);
+        Some(node_id.clone())
+    }
+    
+    pub fn handle_initial_values(&mut self, values: Vec<(u32, DataValue)>, min_interval: std::time::Duration) {
+        for (handle, value) in values {
+            let accepted = self.subscription_state.get_node_id(handle)
+                .and_then(|node_id| self.monitored_items.get(node_id))
+                .is_some_and(|item| item.accepts_initial_value(&value));
+            if accepted {
+                self.handle_data_change(handle, value, min_interval);
             }
         }
     }
     
-    pub fn handle_monitored_items_added(&mut self, pairs: Vec<(NodeId, u32, u32)>) {
-         for (node_id, item_id, handle) in pairs {
-            self.subscription_state.register_item(node_id.clone(), item_id, handle);
-            if let Some(item) = self.monitored_items.get_mut(&node_id) {
-                item.monitored_item_id = Some(item_id);
+    pub fn flush_pending_updates(&mut self, min_interval: std::time::Duration) -> bool {
+        let mut flushed = false;
+        for item in self.monitored_items.values_mut() {
+            flushed |= item.flush_pending(min_interval);
+        }
+        flushed
+    }
+    
+    pub fn handle_monitored_items_failed(&mut self, failed: &[(NodeId, StatusCode)]) {
+        for (node_id, status) in failed {
+            if let Some(item) = self.monitored_items.get_mut(node_id) {
+                item.status = *status;
+            }
+            self.queue_repair(node_id);
+        }
+    }
+
+    
+    pub fn overflow_items(&self) -> Vec<NodeId> {
+        self.monitored_items
+            .values()
+            .filter(|item| item.creation_failed() && item.status == StatusCode::BadTooManyMonitoredItems)
+            .map(|item| item.node_id.clone())
+            .collect()
+    }
+
+    
+    pub fn request_additional_subscription(&mut self) -> bool {
+        if self.creating_additional_subscription || self.subscription_state.subscription_id.is_none() || self.overflow_items().is_empty() {
+            return false;
+        }
+        self.creating_additional_subscription = true;
+        true
+    }
+
+    
+    pub fn handle_additional_subscription_created(&mut self, subscription_id: u32) -> Vec<NodeId> {
+        self.creating_additional_subscription = false;
+        self.subscription_state.additional_subscriptions.push(subscription_id);
+        let moved = self.overflow_items();
+        for node_id in &moved {
+            if let Some(item) = self.monitored_items.get_mut(node_id) {
+                item.status = StatusCode::BadWaitingForInitialData;
+            }
+        }
+        moved
+    }
+
+    
+    pub fn handle_additional_subscription_failed(&mut self) {
+        self.creating_additional_subscription = false;
+    }
+
+    pub fn is_current_outcome(&self, outcome: &AddItemsOutcome) -> bool {
+        self.subscription_ids().contains(&outcome.subscription_id)
+    }
+
+    pub fn handle_monitored_items_added(&mut self, outcome: AddItemsOutcome) {
+         if !self.is_current_outcome(&outcome) {
+            tracing::debug!("Ignoring monitored items created on stale subscription {}", outcome.subscription_id);
+            return;
+         }
+         self.handle_monitored_items_failed(&outcome.failed);
+         for node_id in &outcome.filter_rejected {
+            if let Some(item) = self.monitored_items.get_mut(node_id) {
+                item.parameters = item.parameters.without_filter();
+            }
+         }
+         for created in outcome.created {
+            self.subscription_state.remap_item(created.node_id.clone(), created.monitored_item_id, created.client_handle);
+            self.subscription_state.assign_subscription(created.client_handle, outcome.subscription_id);
+            if let Some(item) = self.monitored_items.get_mut(&created.node_id) {
+                item.monitored_item_id = Some(created.monitored_item_id);
+                item.client_handle = Some(created.client_handle);
+                item.revised_sampling_interval = Some(created.revised_sampling_interval);
+                item.revised_queue_size = Some(created.revised_queue_size);
                 item.status = StatusCode::Good; 
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcua::subscription::CreatedItem;
+    use opcua::types::Variant;
+
+    fn manager_with(node_id: &NodeId, monitored_item_id: u32, handle: u32) -> SubscriptionManager {
+        let mut manager = SubscriptionManager::new();
+        manager.monitored_items.insert(node_id.clone(), MonitoredData::new(node_id.clone(), "Level".to_string()));
+        manager.subscription_state.subscription_id = Some(1);
+        manager.handle_monitored_items_added(added(1, vec![created(node_id, monitored_item_id, handle)], Vec::new()));
+        manager
+    }
+
+    fn added(subscription_id: u32, created: Vec<CreatedItem>, failed: Vec<(NodeId, StatusCode)>) -> AddItemsOutcome {
+        AddItemsOutcome { subscription_id, created, failed, filter_rejected: Vec::new() }
+    }
+
+    fn created(node_id: &NodeId, monitored_item_id: u32, client_handle: u32) -> CreatedItem {
+        CreatedItem {
+            node_id: node_id.clone(),
+            monitored_item_id,
+            client_handle,
+            revised_sampling_interval: 250.0,
+            revised_queue_size: 1,
+        }
+    }
+
+    fn status_only(status: StatusCode) -> DataValue {
+        DataValue { status: Some(status), ..DataValue::null() }
+    }
+
+    #[test]
+    fn test_recreated_item_is_remapped_to_the_new_handle() {
+        let node_id = NodeId::new(2, "Level");
+        let mut manager = manager_with(&node_id, 100, 1);
+
+        manager.handle_data_change(1, status_only(StatusCode::BadMonitoredItemIdInvalid), std::time::Duration::ZERO);
+        assert!(manager.monitored_items[&node_id].is_invalidated());
+        assert_eq!(manager.take_repairs(), vec![node_id.clone()]);
+
+        manager.handle_monitored_items_added(added(1, vec![created(&node_id, 200, 2)], Vec::new()));
+        let state = &manager.subscription_state;
+        assert_eq!(state.get_node_id(2), Some(&node_id));
+        assert_eq!(state.get_node_id(1), None);
+        assert_eq!(state.node_to_handle.get(&node_id), Some(&2));
+        assert_eq!(state.handle_to_server_id.get(&2), Some(&200));
+        assert!(!state.handle_to_server_id.contains_key(&1));
+        assert_eq!(manager.monitored_items[&node_id].monitored_item_id, Some(200));
+
+        manager.handle_data_change(1, DataValue::new_now(Variant::Int32(1)), std::time::Duration::ZERO);
+        assert!(manager.monitored_items[&node_id].value.is_none());
+        manager.handle_data_change(2, DataValue::new_now(Variant::Int32(2)), std::time::Duration::ZERO);
+        assert_eq!(manager.monitored_items[&node_id].value, Some(Variant::Int32(2)));
+        assert_eq!(manager.monitored_items[&node_id].repair_attempts, 0);
+    }
+
+    #[test]
+    fn test_repairs_stop_after_the_retry_cap() {
+        let node_id = NodeId::new(2, "Level");
+        let mut manager = manager_with(&node_id, 100, 1);
+        let failed = [(node_id.clone(), StatusCode::BadNoSubscription)];
+
+        for _ in 0..MAX_REPAIR_ATTEMPTS {
+            manager.handle_monitored_items_failed(&failed);
+            assert_eq!(manager.take_repairs().len(), 1);
+        }
+        manager.handle_monitored_items_failed(&failed);
+        assert!(manager.take_repairs().is_empty());
+        assert!(manager.monitored_items[&node_id].repair_exhausted());
+
+        assert_eq!(manager.request_recreate(&node_id), Some(node_id.clone()));
+        assert!(!manager.monitored_items[&node_id].repair_exhausted());
+        assert_eq!(manager.request_recreate(&NodeId::new(2, "Unknown")), None);
+    }
+
+    #[test]
+    fn test_other_failures_are_not_repaired() {
+        let node_id = NodeId::new(2, "Level");
+        let mut manager = manager_with(&node_id, 100, 1);
+        manager.handle_monitored_items_failed(&[(node_id.clone(), StatusCode::BadNodeIdUnknown)]);
+        manager.handle_data_change(1, status_only(StatusCode::BadCommunicationError), std::time::Duration::ZERO);
+        assert!(manager.take_repairs().is_empty());
+    }
+
+    #[test]
+    fn test_overflow_items_are_routed_to_an_additional_subscription() {
+        let first = NodeId::new(2, "Tank1");
+        let second = NodeId::new(2, "Tank2");
+        let mut manager = manager_with(&first, 100, 1);
+        manager.monitored_items.insert(second.clone(), MonitoredData::new(second.clone(), "Tank2".to_string()));
+
+        manager.handle_monitored_items_added(added(1, Vec::new(), vec![(second.clone(), StatusCode::BadTooManyMonitoredItems)]));
+        assert_eq!(manager.overflow_items(), vec![second.clone()]);
+        assert!(manager.take_repairs().is_empty());
+
+        assert!(manager.request_additional_subscription());
+        assert!(!manager.request_additional_subscription());
+        manager.handle_additional_subscription_failed();
+        assert_eq!(manager.overflow_items(), vec![second.clone()]);
+        assert!(manager.request_additional_subscription());
+        assert_eq!(manager.handle_additional_subscription_created(2), vec![second.clone()]);
+        assert_eq!(manager.subscription_state.target_subscription(), Some(2));
+        assert!(manager.overflow_items().is_empty());
+
+        manager.handle_monitored_items_added(added(2, vec![created(&second, 300, 5)], Vec::new()));
+        let state = &manager.subscription_state;
+        assert_eq!(state.subscription_for_node(&first), Some(1));
+        assert_eq!(state.subscription_for_node(&second), Some(2));
+        assert_eq!(state.get_node_id(5), Some(&second));
+        assert_eq!(state.get_node_id(1), Some(&first));
+
+        manager.handle_data_change(5, DataValue::new_now(Variant::Int32(7)), std::time::Duration::ZERO);
+        assert_eq!(manager.monitored_items[&second].value, Some(Variant::Int32(7)));
+
+        assert_eq!(manager.subscription_state.unregister_by_node(&second), Some(300));
+        assert_eq!(manager.subscription_state.subscription_for_node(&second), None);
+        assert!(!manager.subscription_state.handle_to_subscription.contains_key(&5));
+    }
+
+    #[test]
+    fn test_watchlist_survives_clear_for_restore() {
+        let level = NodeId::new(2, "Level");
+        let mut manager = manager_with(&level, 7, 1);
+        manager.monitored_items.insert(NodeId::new(2, "Flow"), MonitoredData::new(NodeId::new(2, "Flow"), "Flow".to_string()));
+        manager.remember_for_restore();
+        manager.clear();
+        assert!(manager.monitored_items.is_empty());
+
+        let restored = manager.take_restore_items();
+        assert_eq!(restored, vec![(NodeId::new(2, "Flow"), "Flow".to_string()), (level, "Level".to_string())]);
+        assert!(manager.take_restore_items().is_empty());
+    }
+
+    #[test]
+    fn test_watchlist_additions_are_capped() {
+        let level = NodeId::new(2, "Level");
+        let manager = manager_with(&level, 7, 1);
+        let node = |name: &str| BrowsedNode {
+            node_id: NodeId::new(2, name),
+            browse_name: name.to_string(),
+            display_name: name.to_string(),
+            node_class: crate::opcua::browser::NodeClass::Variable,
+            type_definition: None,
+            has_children: false,
+            crawl_root: None,
+            parent_id: None,
+        };
+        let nodes = [node("Level"), node("Flow"), node("Flow"), node("Speed"), node("Torque")];
+
+        let (accepted, dropped) = manager.within_limit(&nodes, 3);
+        let names: Vec<&str> = accepted.iter().map(|n| n.display_name.as_str()).collect();
+        assert_eq!(names, ["Flow", "Speed"]);
+        assert_eq!(dropped, 1);
+
+        let (accepted, dropped) = manager.within_limit(&nodes, 1);
+        assert!(accepted.is_empty());
+        assert_eq!(dropped, 3);
+    }
+
+    #[test]
+    fn test_config_change_marks_active_subscriptions_outdated() {
+        let mut manager = manager_with(&NodeId::new(2, "Level"), 7, 1);
+        manager.subscription_state.additional_subscriptions.push(2);
+        assert_eq!(manager.subscription_ids(), vec![1, 2]);
+        assert!(!manager.config_outdated());
+
+        manager.applied_config = Some(manager.subscription_config);
+        manager.subscription_config.publishing_interval_ms = 250;
+        assert!(manager.config_outdated());
+
+        manager.clear();
+        assert!(!manager.config_outdated());
+        assert!(manager.subscription_ids().is_empty());
+    }
+
+    #[test]
+    fn test_outcome_for_a_deleted_subscription_is_ignored() {
+        let level = NodeId::new(2, "Level");
+        let flow = NodeId::new(2, "Flow");
+        let mut manager = manager_with(&level, 7, 1);
+        manager.monitored_items.insert(flow.clone(), MonitoredData::new(flow.clone(), "Flow".to_string()));
+
+        assert!(!manager.is_current_outcome(&added(9, vec![created(&flow, 8, 2)], Vec::new())));
+        manager.handle_monitored_items_added(added(9, vec![created(&flow, 8, 2)], Vec::new()));
+        assert_eq!(manager.monitored_items[&flow].client_handle, None);
+        assert!(manager.subscription_state.get_node_id(2).is_none());
+        assert_eq!(manager.subscription_state.get_node_id(1), Some(&level));
+    }
+
+    #[test]
+    fn test_item_parameters_are_stored_and_used_for_new_items() {
+        let level = NodeId::new(2, "Level");
+        let mut manager = manager_with(&level, 7, 1);
+        let parameters = MonitoredItemConfig { deadband: crate::opcua::subscription::Deadband::Absolute(0.5), ..MonitoredItemConfig::default() };
+        assert_eq!(manager.set_item_parameters(&level, parameters), Some((1, 7, 1)));
+        assert_eq!(manager.monitored_items[&level].parameters, parameters);
+
+        let pending = NodeId::new(2, "Flow");
+        manager.monitored_items.insert(pending.clone(), MonitoredData::new(pending.clone(), "Flow".to_string()));
+        assert_eq!(manager.set_item_parameters(&pending, parameters), None);
+        assert_eq!(manager.monitored_items[&pending].parameters, parameters);
+        assert_eq!(manager.with_parameters(vec![pending.clone()]), vec![(pending, parameters)]);
+        assert_eq!(manager.set_item_parameters(&NodeId::new(2, "Missing"), parameters), None);
+    }
+
+    #[test]
+    fn test_rejected_filters_are_retried_without_deadband() {
+        use crate::opcua::subscription::Deadband;
+        let level = NodeId::new(2, "Level");
+        let flow = NodeId::new(2, "Flow");
+        let state = NodeId::new(2, "State");
+        let filtered = MonitoredItemConfig { deadband: Deadband::Absolute(0.5), sampling_interval_ms: Some(100.0), ..MonitoredItemConfig::default() };
+        let items = vec![(level.clone(), filtered), (flow.clone(), filtered), (state.clone(), MonitoredItemConfig::default())];
+        let mut failed = vec![
+            (level.clone(), StatusCode::BadMonitoredItemFilterUnsupported),
+            (flow.clone(), StatusCode::BadNodeIdUnknown),
+            (state.clone(), StatusCode::BadFilterNotAllowed),
+        ];
+
+        let retry = take_filter_retries(&items, &mut failed);
+        assert_eq!(retry, vec![(level.clone(), filtered.without_filter())]);
+        assert_eq!(retry[0].1.sampling_interval_ms, Some(100.0));
+        assert_eq!(failed.len(), 2);
+
+        let mut manager = SubscriptionManager::new();
+        manager.subscription_state.subscription_id = Some(1);
+        let mut data = MonitoredData::new(level.clone(), "Level".to_string());
+        data.parameters = filtered;
+        manager.monitored_items.insert(level.clone(), data);
+        let outcome = AddItemsOutcome { filter_rejected: vec![level.clone()], ..added(1, vec![created(&level, 7, 1)], Vec::new()) };
+        manager.handle_monitored_items_added(outcome);
+        assert!(!manager.monitored_items[&level].parameters.has_filter());
+        assert_eq!(manager.monitored_items[&level].status, StatusCode::Good);
+    }
+
+    #[test]
+    fn test_initial_read_seeds_the_row_until_the_first_publish() {
+        let level = NodeId::new(2, "Level");
+        let mut manager = manager_with(&level, 7, 1);
+        manager.monitored_items.get_mut(&level).unwrap().status = StatusCode::BadWaitingForInitialData;
+        let at = |second: i64| Some(opcua::types::DateTime::from(chrono::DateTime::from_timestamp(1_700_000_000 + second, 0).unwrap()));
+
+        let seeded = DataValue { value: Some(Variant::Double(1.5)), source_timestamp: at(0), ..DataValue::null() };
+        manager.handle_initial_values(vec![(1, seeded.clone()), (99, seeded)], std::time::Duration::ZERO);
+        let item = &manager.monitored_items[&level];
+        assert_eq!(item.value, Some(Variant::Double(1.5)));
+        assert_eq!(item.status, StatusCode::Good);
+
+        let published = DataValue { value: Some(Variant::Double(2.5)), source_timestamp: at(5), ..DataValue::null() };
+        manager.handle_data_change(1, published, std::time::Duration::ZERO);
+        assert_eq!(manager.monitored_items[&level].value, Some(Variant::Double(2.5)));
+
+        let late_read = DataValue { value: Some(Variant::Double(1.5)), source_timestamp: at(0), ..DataValue::null() };
+        manager.handle_initial_values(vec![(1, late_read)], std::time::Duration::ZERO);
+        assert_eq!(manager.monitored_items[&level].value, Some(Variant::Double(2.5)));
+    }
+}
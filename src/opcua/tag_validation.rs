@@ -0,0 +1,176 @@
+
+
+
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+use crate::opcua::read_only::ReadOnlyGuard;
+use opcua::types::{AttributeId, DataValue, NodeId, ReadValueId, StatusCode, TimestampsToReturn, Variant};
+
+use crate::opcua::browser::{read_display_names, NodeClass};
+use crate::opcua::service_timing::{timed, Service};
+
+
+const VALIDATION_BATCH_SIZE: usize = 250;
+
+
+const ACCESS_CURRENT_READ: u8 = 0x01;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagVerdict {
+    Pass,
+    Missing,
+    NotVariable,
+    NotReadable,
+}
+
+impl TagVerdict {
+    
+    pub fn passed(&self) -> bool {
+        *self == TagVerdict::Pass
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagCheck {
+    
+    pub node_id: NodeId,
+    
+    pub name: String,
+    
+    pub verdict: TagVerdict,
+    
+    pub status: StatusCode,
+    
+    pub node_class: Option<NodeClass>,
+    
+    pub data_type: Option<NodeId>,
+    
+    pub data_type_name: Option<String>,
+}
+
+fn node_class_from_i32(value: i32) -> NodeClass {
+    match value {
+        1 => NodeClass::Object,
+        2 => NodeClass::Variable,
+        4 => NodeClass::Method,
+        8 => NodeClass::ObjectType,
+        16 => NodeClass::VariableType,
+        32 => NodeClass::ReferenceType,
+        64 => NodeClass::DataType,
+        128 => NodeClass::View,
+        _ => NodeClass::Unknown,
+    }
+}
+
+
+pub fn classify(node_id: NodeId, name: String, node_class: &DataValue, data_type: &DataValue, access: &DataValue) -> TagCheck {
+    let status = node_class.status.unwrap_or(StatusCode::Good);
+    let class = match &node_class.value {
+        Some(Variant::Int32(v)) if status.is_good() => Some(node_class_from_i32(*v)),
+        _ => None,
+    };
+    let data_type = match &data_type.value {
+        Some(Variant::NodeId(id)) => Some((**id).clone()),
+        _ => None,
+    };
+    let readable = matches!(access.value, Some(Variant::Byte(level)) if level & ACCESS_CURRENT_READ != 0);
+
+    let verdict = match class {
+        None => TagVerdict::Missing,
+        Some(NodeClass::Variable) if readable => TagVerdict::Pass,
+        Some(NodeClass::Variable) => TagVerdict::NotReadable,
+        Some(_) => TagVerdict::NotVariable,
+    };
+
+    TagCheck {
+        node_id,
+        name,
+        verdict,
+        status: if verdict == TagVerdict::NotReadable {
+            access.status.unwrap_or(StatusCode::BadUserAccessDenied)
+        } else {
+            status
+        },
+        node_class: class,
+        data_type,
+        data_type_name: None,
+    }
+}
+
+
+pub async fn validate_tags(session: ReadOnlyGuard, tags: &[(NodeId, String)]) -> Result<Vec<TagCheck>> {
+    let mut checks = Vec::with_capacity(tags.len());
+
+    for batch in tags.chunks(VALIDATION_BATCH_SIZE) {
+        let nodes_to_read: Vec<ReadValueId> = batch
+            .iter()
+            .flat_map(|(id, _)| {
+                [AttributeId::NodeClass, AttributeId::DataType, AttributeId::UserAccessLevel]
+                    .into_iter()
+                    .map(move |attribute| ReadValueId::new(id.clone(), attribute))
+            })
+            .collect();
+
+        let results = timed(session.timings(), Service::Read, session.read(&nodes_to_read, TimestampsToReturn::Neither, 0.0))
+            .await
+            .context("Read request failed")?;
+
+        let missing = DataValue {
+            status: Some(StatusCode::BadNodeIdUnknown),
+            ..Default::default()
+        };
+        for (i, (node_id, name)) in batch.iter().enumerate() {
+            let attribute = |offset: usize| results.get(i * 3 + offset).unwrap_or(&missing);
+            checks.push(classify(node_id.clone(), name.clone(), attribute(0), attribute(1), attribute(2)));
+        }
+    }
+
+    let mut data_types: Vec<NodeId> = checks.iter().filter_map(|c| c.data_type.clone()).collect();
+    data_types.sort_by_key(|id| id.to_string());
+    data_types.dedup();
+    let names: HashMap<NodeId, String> = read_display_names(session, &data_types)
+        .await?
+        .into_iter()
+        .zip(data_types)
+        .filter_map(|(name, id)| name.map(|name| (id, name)))
+        .collect();
+    for check in &mut checks {
+        check.data_type_name = check.data_type.as_ref().and_then(|id| names.get(id).cloned());
+    }
+
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(v: Variant) -> DataValue {
+        DataValue::value_only(v)
+    }
+
+    #[test]
+    fn test_classify_verdicts() {
+        let id = NodeId::new(2, "Tag");
+        let double = value(Variant::NodeId(Box::new(NodeId::new(0, 11))));
+
+        let ok = classify(id.clone(), "Tag".into(), &value(Variant::Int32(2)), &double, &value(Variant::Byte(0x03)));
+        assert_eq!(ok.verdict, TagVerdict::Pass);
+        assert_eq!(ok.data_type, Some(NodeId::new(0, 11)));
+
+        let write_only = classify(id.clone(), "Tag".into(), &value(Variant::Int32(2)), &double, &value(Variant::Byte(0x02)));
+        assert_eq!(write_only.verdict, TagVerdict::NotReadable);
+
+        let object = classify(id.clone(), "Tag".into(), &value(Variant::Int32(1)), &DataValue::default(), &DataValue::default());
+        assert_eq!(object.verdict, TagVerdict::NotVariable);
+
+        let unknown = DataValue { status: Some(StatusCode::BadNodeIdUnknown), ..Default::default() };
+        let missing = classify(id, "Tag".into(), &unknown, &unknown, &unknown);
+        assert_eq!(missing.verdict, TagVerdict::Missing);
+        assert_eq!(missing.status, StatusCode::BadNodeIdUnknown);
+    }
+}
@@ -0,0 +1,76 @@
+use eframe::egui;
+
+use crate::utils::audit::AuditLog;
+use crate::utils::i18n::{self, T, Language};
+use crate::utils::timestamps::TimestampFormat;
+
+
+pub enum AuditAction {
+    SetEnabled(bool),
+}
+
+
+#[derive(Default)]
+pub struct AuditPanel {
+    
+    pub filter: String,
+}
+
+impl AuditPanel {
+    
+    pub fn show(&mut self, ui: &mut egui::Ui, log: &AuditLog, timestamp_format: &TimestampFormat, lang: Language) -> Option<AuditAction> {
+        let mut action = None;
+
+        let mut enabled = log.is_enabled();
+        if ui.checkbox(&mut enabled, i18n::t(T::EnableAuditLog, lang))
+            .on_hover_text(i18n::t(T::EnableAuditLogHint, lang))
+            .changed()
+        {
+            action = Some(AuditAction::SetEnabled(enabled));
+        }
+        if log.is_enabled() {
+            let path = log.file_path(chrono::Local::now().date_naive());
+            ui.label(i18n::t_fmt(T::AuditLogFile, lang, &[&path.display().to_string()]));
+        } else {
+            ui.colored_label(egui::Color32::from_rgb(255, 200, 100), i18n::t(T::AuditLogDisabled, lang));
+        }
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            ui.text_edit_singleline(&mut self.filter);
+        });
+        ui.separator();
+
+        let filter = self.filter.to_lowercase();
+        let entries: Vec<_> = log.entries()
+            .rev()
+            .filter(|entry| filter.is_empty() || entry.to_line().to_lowercase().contains(&filter))
+            .collect();
+        if entries.is_empty() {
+            ui.label(i18n::t(T::NoAuditEntries, lang));
+            return action;
+        }
+
+        egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+            egui::Grid::new("audit_grid")
+                .num_columns(4)
+                .spacing([10.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong(i18n::t(T::AuditTimeColumn, lang));
+                    ui.strong(i18n::t(T::AuditEventColumn, lang));
+                    ui.strong(i18n::t(T::AuditEndpointColumn, lang));
+                    ui.strong(i18n::t(T::AuditDetailsColumn, lang));
+                    ui.end_row();
+
+                    for entry in entries {
+                        ui.monospace(timestamp_format.format_in(entry.timestamp));
+                        ui.monospace(entry.event.name());
+                        ui.label(entry.endpoint.as_deref().unwrap_or("-"));
+                        ui.label(&entry.details);
+                        ui.end_row();
+                    }
+                });
+        });
+        action
+    }
+}
@@ -3,7 +3,8 @@
 
 
 use eframe::egui;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use crate::export::{ExportContext, ExportEngine};
 use crate::opcua::certificates::{CertificateManager, CertificateInfo};
 use crate::utils::i18n::{self, T, Language};
 
@@ -17,6 +18,8 @@ pub enum CertAction {
     
     OpenPkiFolder,
     
+    ExportList,
+    
     Refresh,
 }
 
@@ -94,6 +97,7 @@ impl CertificatesPanel {
                     self.status = format!("❌ Error: {}", e);
                 }
             }
+            CertAction::ExportList => {}
             CertAction::Refresh => {
                 self.needs_refresh = true;
             }
@@ -105,6 +109,21 @@ impl CertificatesPanel {
     }
 
     
+    pub fn export_list(&mut self, path: &Path, context: &ExportContext) -> bool {
+        let entries = self.cert_manager.inventory();
+        match ExportEngine::export_certificates_to_csv(&entries, path, context) {
+            Ok(()) => {
+                self.status = format!("✅ Exported {} certificates", entries.len());
+                true
+            }
+            Err(e) => {
+                self.status = format!("❌ Error: {}", e);
+                false
+            }
+        }
+    }
+
+    
     pub fn show(&mut self, ui: &mut egui::Ui, lang: Language) -> Option<CertAction> {
         let mut action = None;
 
@@ -116,6 +135,9 @@ impl CertificatesPanel {
             if ui.button(format!("📂 {}", i18n::t(T::OpenPkiFolder, lang))).clicked() {
                 action = Some(CertAction::OpenPkiFolder);
             }
+            if ui.button(format!("📤 {}", i18n::t(T::ExportCertificateList, lang))).clicked() {
+                action = Some(CertAction::ExportList);
+            }
             if ui.button("🔄").on_hover_text("Refresh").clicked() {
                 action = Some(CertAction::Refresh);
             }
@@ -7,10 +7,11 @@ use std::sync::mpsc;
 use tokio::runtime::Handle;
 
 use crate::app::BackendMessage;
-use crate::config::bookmarks::{AuthMethod, Bookmarks, MessageSecurityMode, SecurityPolicy, ServerBookmark};
-use crate::network::diagnostics::{DiagnosticResult, DiagnosticStep, StepStatus};
-use crate::network::discovery::EndpointInfo;
-use crate::opcua::client::ClientConfig;
+use crate::config::bookmarks::{parse_tags, AuthMethod, Bookmarks, MessageSecurityMode, SecurityPolicy, ServerBookmark};
+use crate::network::diagnostics::{DiagnosticResult, DiagnosticStep, StepId, StepStatus, STEP_ORDER};
+use crate::network::discovery::{sort_by_security, EndpointInfo};
+use crate::opcua::client::{ClientConfig, EndpointCheck, EndpointMismatch, SessionStats};
+use crate::opcua::service_timing::{ServiceTimings, SLOW_CALL_THRESHOLD};
 use crate::opcua::certificates::CertificateManager;
 use crate::utils::i18n::{self, T, Language};
 
@@ -19,7 +20,37 @@ pub enum ConnectionAction {
     Connect(ClientConfig),
     Disconnect,
     StartDiagnostic(String),
+    
+    RetryDiagnosticStep { input: String, from: StepId, previous: DiagnosticResult },
     CancelDiagnostic,
+    
+    SetPinned(bool),
+    
+    SetEndpointFallback(bool),
+    
+    SetAutoReconnect(bool),
+}
+
+
+fn endpoint_policy(ep: &EndpointInfo) -> SecurityPolicy {
+    match ep.security_policy_name.as_str() {
+        "None" => SecurityPolicy::None,
+        "Basic128Rsa15" => SecurityPolicy::Basic128Rsa15,
+        "Basic256" => SecurityPolicy::Basic256,
+        "Basic256Sha256" => SecurityPolicy::Basic256Sha256,
+        "Aes128Sha256RsaOaep" | "Aes128-Sha256-RsaOaep" | "Aes128_Sha256_RsaOaep" => SecurityPolicy::Aes128Sha256RsaOaep,
+        "Aes256Sha256RsaPss" | "Aes256-Sha256-RsaPss" | "Aes256_Sha256_RsaPss" => SecurityPolicy::Aes256Sha256RsaPss,
+        _ => SecurityPolicy::None,
+    }
+}
+
+
+fn endpoint_mode(ep: &EndpointInfo) -> MessageSecurityMode {
+    match ep.security_mode.as_str() {
+        "None" => MessageSecurityMode::None,
+        "Sign" => MessageSecurityMode::Sign,
+        _ => MessageSecurityMode::SignAndEncrypt,
+    }
 }
 
 
@@ -37,9 +68,25 @@ pub struct ConnectionPanel {
     
     password: String,
     
+    use_certificate: bool,
+    
+    cert_path: String,
+    
+    key_path: String,
+    
     bookmark_name: String,
     
     show_add_bookmark: bool,
+    
+    bookmark_tags: String,
+    
+    bookmark_tag_filter: String,
+    
+    editing_bookmark: Option<(usize, String, String)>,
+    
+    loaded_bookmark: Option<usize>,
+    
+    skip_endpoint_matching: bool,
 
     
     is_connecting: bool,
@@ -50,6 +97,8 @@ pub struct ConnectionPanel {
     
     diagnostic_log: Vec<DiagnosticStep>,
     
+    diagnostic_input: String,
+    
     diagnostic_result: Option<DiagnosticResult>,
     
     discovered_endpoints: Vec<EndpointInfo>,
@@ -68,12 +117,21 @@ impl Default for ConnectionPanel {
             use_auth: false,
             username: String::new(),
             password: String::new(),
+            use_certificate: false,
+            cert_path: String::new(),
+            key_path: String::new(),
             bookmark_name: String::new(),
             show_add_bookmark: false,
+            bookmark_tags: String::new(),
+            bookmark_tag_filter: String::new(),
+            editing_bookmark: None,
+            loaded_bookmark: None,
+            skip_endpoint_matching: false,
 
             is_connecting: false,
             is_diagnosing: false,
             diagnostic_log: Vec::new(),
+            diagnostic_input: String::new(),
             diagnostic_result: None,
             discovered_endpoints: Vec::new(),
             selected_endpoint: None,
@@ -97,6 +155,7 @@ impl ConnectionPanel {
     pub fn set_diagnostic_result(&mut self, result: DiagnosticResult) {
         self.is_diagnosing = false;
         self.discovered_endpoints = result.endpoints.clone();
+        sort_by_security(&mut self.discovered_endpoints);
         self.diagnostic_result = Some(result);
         self.diagnostic_start = None;
     }
@@ -112,19 +171,118 @@ impl ConnectionPanel {
     }
 
     
+    pub fn reset_form(&mut self) {
+        *self = Self {
+            is_connecting: self.is_connecting,
+            bookmark_tag_filter: std::mem::take(&mut self.bookmark_tag_filter),
+            ..Self::default()
+        };
+    }
+
+    
     pub fn start_diagnostic(&mut self) {
         self.is_diagnosing = true;
         self.diagnostic_log.clear();
+        self.diagnostic_input = self.server_input.clone();
         self.diagnostic_result = None;
         self.diagnostic_start = Some(std::time::Instant::now());
     }
 
     
+    pub fn fallback_configs(&self, primary: &ClientConfig) -> Vec<ClientConfig> {
+        let is_primary = |ep: &EndpointInfo| endpoint_policy(ep) == primary.security_policy && endpoint_mode(ep) == primary.security_mode;
+        let start = self.discovered_endpoints.iter().position(is_primary).map_or(0, |i| i + 1);
+
+        let mut configs: Vec<ClientConfig> = Vec::new();
+        for ep in &self.discovered_endpoints[start..] {
+            let (policy, mode) = (endpoint_policy(ep), endpoint_mode(ep));
+            let supports_auth = match primary.auth_method {
+                AuthMethod::Anonymous => ep.allows_anonymous(),
+                AuthMethod::UserPassword { .. } => ep.allows_username(),
+                AuthMethod::Certificate { .. } => ep.allows_certificate(),
+            };
+            let duplicate = is_primary(ep) || configs.iter().any(|c| c.security_policy == policy && c.security_mode == mode);
+            if supports_auth && !duplicate {
+                configs.push(ClientConfig {
+                    security_policy: policy,
+                    security_mode: mode,
+                    ..primary.clone()
+                });
+            }
+        }
+        configs
+    }
+
+    
+    fn auth_method(&self) -> AuthMethod {
+        if !self.use_auth {
+            AuthMethod::Anonymous
+        } else if self.use_certificate {
+            AuthMethod::Certificate {
+                cert_path: self.cert_path.trim().into(),
+                key_path: self.key_path.trim().into(),
+            }
+        } else {
+            AuthMethod::UserPassword {
+                username: self.username.clone(),
+                password: self.password.clone(),
+            }
+        }
+    }
+
+    
+    fn load_auth_method(&mut self, auth_method: &AuthMethod) {
+        self.username.clear();
+        self.password.clear();
+        self.cert_path.clear();
+        self.key_path.clear();
+        self.use_auth = !matches!(auth_method, AuthMethod::Anonymous);
+        self.use_certificate = matches!(auth_method, AuthMethod::Certificate { .. });
+        match auth_method {
+            AuthMethod::Anonymous => {}
+            AuthMethod::UserPassword { username, password } => {
+                self.username = username.clone();
+                self.password = password.clone();
+            }
+            AuthMethod::Certificate { cert_path, key_path } => {
+                self.cert_path = cert_path.display().to_string();
+                self.key_path = key_path.display().to_string();
+            }
+        }
+    }
+
+    
+    fn retry_diagnostic_step(&mut self, from: StepId) -> Option<ConnectionAction> {
+        let previous = self.diagnostic_result.take()?;
+        let index = STEP_ORDER.iter().position(|id| *id == from)?;
+        self.diagnostic_log.retain(|step| !STEP_ORDER[index..].contains(&step.id));
+        self.discovered_endpoints.clear();
+        self.selected_endpoint = None;
+        self.is_diagnosing = true;
+        self.diagnostic_start = Some(std::time::Instant::now());
+        Some(ConnectionAction::RetryDiagnosticStep {
+            input: self.diagnostic_input.clone(),
+            from,
+            previous,
+        })
+    }
+
+    
     pub fn set_connecting(&mut self, connecting: bool) {
         self.is_connecting = connecting;
     }
 
     
+    pub fn is_connecting(&self) -> bool {
+        self.is_connecting
+    }
+
+    
+    pub fn loaded_bookmark(&self) -> Option<usize> {
+        self.loaded_bookmark
+    }
+
+    
     fn is_interactive(&self, is_connected: bool, app_busy: bool) -> bool {
         !is_connected && !app_busy && !self.is_connecting && !self.is_diagnosing
     }
@@ -147,25 +305,63 @@ impl ConnectionPanel {
         can_cancel: bool,
         _runtime: &Handle,
         _backend_tx: mpsc::Sender<BackendMessage>,
-        is_connected: bool,
+        connected_endpoint: Option<&str>,
+        pinned: bool,
+        endpoint_fallback: bool,
+        auto_reconnect: bool,
         app_busy: bool,
+        session_stats: Option<&SessionStats>,
+        service_timings: Option<&ServiceTimings>,
+        endpoint_check: Option<&EndpointCheck>,
         lang: Language,
     ) -> (Option<ConnectionAction>, bool) {
         let mut action: Option<ConnectionAction> = None;
         let mut should_disconnect = false;
+        let is_connected = connected_endpoint.is_some();
 
-        ui.heading(format!("🔌 {}", i18n::t(T::Connection, lang)));
+        ui.horizontal(|ui| {
+            ui.heading(format!("🔌 {}", i18n::t(T::Connection, lang)));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.selectable_label(pinned, "📌").on_hover_text(i18n::t(T::PinConnectionPanelHint, lang)).clicked() {
+                    action = Some(ConnectionAction::SetPinned(!pinned));
+                }
+            });
+        });
         ui.separator();
 
+        if let (Some(endpoint), true) = (connected_endpoint, pinned) {
+            ui.horizontal(|ui| {
+                ui.colored_label(egui::Color32::from_rgb(0, 200, 0), "🟢");
+                ui.label(egui::RichText::new(endpoint).monospace());
+            });
+            if ui.button(format!("🔌 {}", i18n::t(T::Disconnect, lang))).clicked() {
+                action = Some(ConnectionAction::Disconnect);
+            }
+            ui.add_space(10.0);
+            ui.separator();
+            ui.label(egui::RichText::new(format!("📚 {}", i18n::t(T::SavedServers, lang))).strong());
+            if let Some(idx) = self.show_bookmarks(ui, bookmarks, lang) {
+                if let Some(bookmark) = bookmarks.servers.get(idx) {
+                    action = Some(ConnectionAction::Connect(ClientConfig::from_bookmark(bookmark)));
+                }
+            }
+            self.show_edit_bookmark_dialog(ui, bookmarks, lang);
+            return (action, false);
+        }
+
         
         if is_connected {
             ui.add_space(5.0);
             if ui.button(format!("🔌 {}", i18n::t(T::Disconnect, lang)))
-                .on_hover_text("Terminates the current OPC UA session")
+                .on_hover_text(i18n::t(T::DisconnectHint, lang))
                 .clicked() 
             {
                 should_disconnect = true;
             }
+            if let Some(stats) = session_stats {
+                ui.add_space(5.0);
+                Self::show_session_info(ui, stats, service_timings, endpoint_check, lang);
+            }
             ui.add_space(10.0);
             ui.separator();
         }
@@ -176,6 +372,7 @@ impl ConnectionPanel {
             .show(ui, |ui| {
                 self.show_bookmarks(ui, bookmarks, lang);
             });
+        self.show_edit_bookmark_dialog(ui, bookmarks, lang);
 
         ui.add_space(10.0);
 
@@ -183,7 +380,7 @@ impl ConnectionPanel {
         egui::CollapsingHeader::new(format!("➕ {}", i18n::t(T::NewConnection, lang)))
             .default_open(!is_connected)
             .show(ui, |ui| {
-                action = self.show_new_connection(ui, bookmarks, display_elapsed, can_cancel, is_connected, app_busy, lang);
+                action = self.show_new_connection(ui, bookmarks, display_elapsed, can_cancel, is_connected, app_busy, endpoint_fallback, auto_reconnect, lang);
             });
 
         if should_disconnect {
@@ -193,53 +390,244 @@ impl ConnectionPanel {
         }
     }
 
-    fn show_bookmarks(&mut self, ui: &mut egui::Ui, bookmarks: &mut Bookmarks, lang: Language) {
+    fn show_session_info(
+        ui: &mut egui::Ui,
+        stats: &SessionStats,
+        service_timings: Option<&ServiceTimings>,
+        endpoint_check: Option<&EndpointCheck>,
+        lang: Language,
+    ) {
+        let now = std::time::Instant::now();
+        egui::CollapsingHeader::new(format!("ℹ {}", i18n::t(T::SessionInfo, lang)))
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("session_info_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(i18n::t(T::Uptime, lang));
+                        ui.label(format_duration(stats.uptime(now)));
+                        ui.end_row();
+
+                        ui.label(i18n::t(T::Reconnects, lang));
+                        let reconnects = egui::RichText::new(stats.reconnect_count.to_string());
+                        if stats.reconnect_count > 0 {
+                            ui.label(reconnects.color(egui::Color32::from_rgb(255, 200, 100)))
+                                .on_hover_text(i18n::t(T::UnstableLinkHint, lang));
+                        } else {
+                            ui.label(reconnects);
+                        }
+                        ui.end_row();
+
+                        ui.label(i18n::t(T::Downtime, lang));
+                        ui.label(format_duration(stats.downtime(now)));
+                        ui.end_row();
+
+                        if let Some(check) = endpoint_check {
+                            Self::show_endpoint_check(ui, check, lang);
+                        }
+                    });
+
+                if stats.disconnected_since.is_some() {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.colored_label(egui::Color32::from_rgb(255, 200, 100), i18n::t(T::Reconnecting, lang));
+                    });
+                }
+
+                if let Some(timings) = service_timings {
+                    Self::show_service_timings(ui, timings, lang);
+                }
+            });
+    }
+
+    fn show_endpoint_check(ui: &mut egui::Ui, check: &EndpointCheck, lang: Language) {
+        let mismatches = check.mismatches();
+        let warn = egui::Color32::from_rgb(255, 200, 100);
+        ui.label(i18n::t(T::RequestedEndpoint, lang));
+        ui.label(check.requested.security_label()).on_hover_text(&check.requested.endpoint_url);
+        ui.end_row();
+
+        ui.label(i18n::t(T::NegotiatedEndpoint, lang));
+        let negotiated = egui::RichText::new(check.negotiated.security_label());
+        if mismatches.is_empty() {
+            ui.label(negotiated).on_hover_text(&check.negotiated.endpoint_url);
+        } else {
+            ui.label(negotiated.color(warn)).on_hover_text(&check.negotiated.endpoint_url);
+        }
+        ui.end_row();
+
+        if mismatches.contains(&EndpointMismatch::Url) {
+            ui.label("");
+            ui.label(egui::RichText::new(&check.negotiated.endpoint_url).monospace().color(warn));
+            ui.end_row();
+        }
+    }
+
+    fn show_service_timings(ui: &mut egui::Ui, timings: &ServiceTimings, lang: Language) {
+        let stats = timings.all_stats();
+        if stats.is_empty() {
+            return;
+        }
+
+        ui.add_space(5.0);
+        ui.label(egui::RichText::new(i18n::t(T::ServiceTimings, lang)).strong());
+        egui::Grid::new("service_timings_grid")
+            .num_columns(4)
+            .spacing([10.0, 2.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.weak(i18n::t(T::Service, lang));
+                ui.weak(i18n::t(T::TimingMin, lang));
+                ui.weak(i18n::t(T::TimingAvg, lang));
+                ui.weak("p95");
+                ui.end_row();
+
+                for (service, s) in stats {
+                    ui.label(service.name()).on_hover_text(format!("n = {}", s.count));
+                    ui.label(format_millis(s.min));
+                    ui.label(format_millis(s.avg));
+                    let p95 = egui::RichText::new(format_millis(s.p95));
+                    if s.p95 > SLOW_CALL_THRESHOLD {
+                        ui.label(p95.color(egui::Color32::from_rgb(255, 200, 100)));
+                    } else {
+                        ui.label(p95);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some((service, elapsed)) = timings.last_slow_call {
+            ui.colored_label(
+                egui::Color32::from_rgb(255, 200, 100),
+                i18n::t_fmt(T::SlowServiceCall, lang, &[service.name(), &elapsed.as_millis().to_string(), &SLOW_CALL_THRESHOLD.as_millis().to_string()]),
+            );
+        }
+    }
+
+    fn show_edit_bookmark_dialog(&mut self, ui: &mut egui::Ui, bookmarks: &mut Bookmarks, lang: Language) {
+        let Some((idx, mut name, mut tags)) = self.editing_bookmark.take() else {
+            return;
+        };
+        let mut keep_open = true;
+        egui::Window::new(i18n::t(T::EditBookmark, lang))
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t(T::Name, lang));
+                    ui.text_edit_singleline(&mut name);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t(T::Tags, lang));
+                    ui.add(egui::TextEdit::singleline(&mut tags).hint_text(i18n::t(T::TagsHint, lang)));
+                });
+                ui.horizontal(|ui| {
+                    if ui.button(i18n::t(T::Save, lang)).clicked() {
+                        if let Some(bookmark) = bookmarks.servers.get_mut(idx) {
+                            bookmark.name = name.trim().to_string();
+                            bookmark.tags = parse_tags(&tags);
+                            let _ = bookmarks.save();
+                        }
+                        keep_open = false;
+                    }
+                    if ui.button(i18n::t(T::Cancel, lang)).clicked() {
+                        keep_open = false;
+                    }
+                });
+            });
+        if keep_open {
+            self.editing_bookmark = Some((idx, name, tags));
+        }
+    }
+
+    fn show_bookmarks(&mut self, ui: &mut egui::Ui, bookmarks: &mut Bookmarks, lang: Language) -> Option<usize> {
         if bookmarks.is_empty() {
             ui.label(i18n::t(T::NoSavedServers, lang));
+            None
         } else {
             let mut to_remove: Option<usize> = None;
             let mut to_load: Option<usize> = None;
+            let mut to_edit: Option<usize> = None;
 
-            for (i, bookmark) in bookmarks.servers.iter().enumerate() {
+            let has_tags = bookmarks.servers.iter().any(|b| !b.tags.is_empty());
+            if has_tags {
+                ui.horizontal(|ui| {
+                    ui.label(format!("🏷 {}", i18n::t(T::TagFilter, lang)));
+                    ui.add(egui::TextEdit::singleline(&mut self.bookmark_tag_filter).desired_width(150.0));
+                });
+                ui.add_space(4.0);
+            }
+
+            let mut show_row = |ui: &mut egui::Ui, i: usize| {
+                let bookmark = &bookmarks.servers[i];
                 ui.horizontal(|ui| {
                     if ui.button("📂").on_hover_text(i18n::t(T::LoadBookmark, lang)).clicked() {
                         to_load = Some(i);
                     }
+                    if ui.button("✏").on_hover_text(i18n::t(T::EditBookmark, lang)).clicked() {
+                        to_edit = Some(i);
+                    }
                     if ui.button("🗑").on_hover_text(i18n::t(T::DeleteBookmark, lang)).clicked() {
                         to_remove = Some(i);
                     }
                     ui.label(&bookmark.name);
+                    if let Some(watchlist) = bookmark.watchlist.as_ref().filter(|w| !w.is_empty()) {
+                        ui.label(format!("📋 {}", watchlist.len()))
+                            .on_hover_text(i18n::t_fmt(T::BookmarkWatchlistHint, lang, &[&watchlist.len().to_string()]));
+                    }
                 });
                 ui.label(format!("  {}", bookmark.endpoint_url));
                 ui.add_space(4.0);
+            };
+
+            if has_tags {
+                for (tag, members) in bookmarks.grouped(&self.bookmark_tag_filter) {
+                    let title = tag.clone().unwrap_or_else(|| i18n::t(T::Untagged, lang).to_string());
+                    egui::CollapsingHeader::new(format!("🏷 {} ({})", title, members.len()))
+                        .id_salt(("bookmark_group", tag))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            for i in members {
+                                show_row(ui, i);
+                            }
+                        });
+                }
+            } else {
+                for i in 0..bookmarks.servers.len() {
+                    show_row(ui, i);
+                }
+            }
+
+            if let Some(idx) = to_edit {
+                let bookmark = &bookmarks.servers[idx];
+                self.editing_bookmark = Some((idx, bookmark.name.clone(), bookmark.tags.join(", ")));
             }
 
             
             if let Some(idx) = to_remove {
                 bookmarks.remove(idx);
                 let _ = bookmarks.save();
+                self.loaded_bookmark = match self.loaded_bookmark {
+                    Some(loaded) if loaded == idx => None,
+                    Some(loaded) if loaded > idx => Some(loaded - 1),
+                    other => other,
+                };
+                self.editing_bookmark = None;
             }
 
             
             if let Some(idx) = to_load {
                 if let Some(bookmark) = bookmarks.servers.get(idx) {
+                    self.loaded_bookmark = Some(idx);
                     self.server_input = bookmark.endpoint_url.clone();
                     self.security_policy = bookmark.security_policy.clone();
                     self.security_mode = bookmark.security_mode.clone();
-                    match &bookmark.auth_method {
-                        AuthMethod::Anonymous => {
-                            self.use_auth = false;
-                            self.username.clear();
-                            self.password.clear();
-                        }
-                        AuthMethod::UserPassword { username, password } => {
-                            self.use_auth = true;
-                            self.username = username.clone();
-                            self.password = password.clone();
-                        }
-                    }
+                    let auth_method = bookmark.auth_method.clone();
+                    self.load_auth_method(&auth_method);
                 }
             }
+            to_load
         }
     }
 
@@ -252,6 +640,8 @@ impl ConnectionPanel {
         can_cancel: bool,
         is_connected: bool,
         app_busy: bool,
+        endpoint_fallback: bool,
+        auto_reconnect: bool,
         lang: Language,
     ) -> Option<ConnectionAction> {
         let mut action: Option<ConnectionAction> = None;
@@ -299,12 +689,18 @@ impl ConnectionPanel {
             } else {
                 let diagnose_enabled = !self.server_input.is_empty() && interactive;
                 if ui.add_enabled(diagnose_enabled, egui::Button::new(format!("🔍 {}", i18n::t(T::Diagnose, lang))))
-                    .on_hover_text("Validates input, resolves DNS, scans ports, and discovers endpoints")
+                    .on_hover_text(i18n::t(T::DiagnoseHint, lang))
                     .clicked() 
                 {
                     self.start_diagnostic();
                     action = Some(ConnectionAction::StartDiagnostic(self.server_input.clone()));
                 }
+                if ui.add_enabled(interactive, egui::Button::new(format!("🧹 {}", i18n::t(T::ClearConnectionForm, lang))))
+                    .on_hover_text(i18n::t(T::ClearConnectionFormHint, lang))
+                    .clicked()
+                {
+                    self.reset_form();
+                }
             }
         });
 
@@ -320,10 +716,12 @@ impl ConnectionPanel {
                     if self.diagnostic_log.is_empty() && self.is_diagnosing {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label(egui::RichText::new("Initializing diagnostic...").color(egui::Color32::from_rgb(100, 200, 255)));
+                            ui.label(egui::RichText::new(i18n::t(T::InitializingDiagnostic, lang)).color(egui::Color32::from_rgb(100, 200, 255)));
                         });
                     }
                     
+                    let can_retry = !self.is_diagnosing && self.diagnostic_result.is_some() && interactive;
+                    let mut retry_from = None;
                     egui::ScrollArea::vertical()
                         .max_height(120.0)
                         .show(ui, |ui| {
@@ -344,6 +742,19 @@ impl ConnectionPanel {
                                         ui.label(egui::RichText::new(format!("({}ms)", step.duration_ms)).weak());
                                     } else if step.status == StepStatus::Running {
                                         ui.spinner();
+                                        if let Some((done, total)) = step.progress.filter(|(_, total)| *total > 0) {
+                                            ui.add(
+                                                egui::ProgressBar::new(done as f32 / total as f32)
+                                                    .desired_width(60.0)
+                                                    .text(egui::RichText::new(format!("{}/{}", done, total)).small()),
+                                            );
+                                        }
+                                    }
+
+                                    if can_retry && matches!(step.status, StepStatus::Failed | StepStatus::Warning)
+                                        && ui.small_button("🔁").on_hover_text(i18n::t(T::RetryStep, lang)).clicked()
+                                    {
+                                        retry_from = Some(step.id);
                                     }
                                 });
                                 
@@ -354,6 +765,9 @@ impl ConnectionPanel {
                                 }
                             }
                         });
+                    if let Some(from) = retry_from {
+                        action = self.retry_diagnostic_step(from);
+                    }
                 });
         }
 
@@ -361,7 +775,7 @@ impl ConnectionPanel {
         if !self.discovered_endpoints.is_empty() {
             ui.add_space(5.0);
             ui.label(egui::RichText::new(
-                i18n::t(T::FoundEndpoints, lang).replace("{}", &self.discovered_endpoints.len().to_string())
+                i18n::t_fmt(T::FoundEndpoints, lang, &[&self.discovered_endpoints.len().to_string()])
             ).strong());
             
             egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
@@ -371,24 +785,12 @@ impl ConnectionPanel {
                         self.selected_endpoint = Some(i);
                         
                         
-                        self.security_policy = match ep.security_policy_name.as_str() {
-                            "None" => SecurityPolicy::None,
-                            "Basic128Rsa15" => SecurityPolicy::Basic128Rsa15,
-                            "Basic256" => SecurityPolicy::Basic256,
-                            "Basic256Sha256" => SecurityPolicy::Basic256Sha256,
-                            "Aes128Sha256RsaOaep" | "Aes128-Sha256-RsaOaep" => SecurityPolicy::Aes128Sha256RsaOaep,
-                            "Aes256Sha256RsaPss" | "Aes256-Sha256-RsaPss" => SecurityPolicy::Aes256Sha256RsaPss,
-                            _ => SecurityPolicy::None,
-                        };
-                        
-                        self.security_mode = match ep.security_mode.as_str() {
-                            "None" => MessageSecurityMode::None,
-                            "Sign" => MessageSecurityMode::Sign,
-                            _ => MessageSecurityMode::SignAndEncrypt,
-                        };
+                        self.security_policy = endpoint_policy(ep);
+                        self.security_mode = endpoint_mode(ep);
                         
                         
                         self.use_auth = !ep.allows_anonymous();
+                        self.use_certificate = self.use_auth && !ep.allows_username() && ep.allows_certificate();
                     }
                 }
             });
@@ -431,7 +833,7 @@ impl ConnectionPanel {
             ui.label(i18n::t(T::SecurityPolicy, lang));
             if security_locked {
                 ui.label(egui::RichText::new(self.security_policy.display_name(lang)).strong());
-                ui.label(egui::RichText::new("🔒").small());
+                ui.label(egui::RichText::new("🔒").small()).on_hover_text(i18n::t(T::SecurityLockedHint, lang));
             } else {
                 egui::ComboBox::from_id_salt("security_policy")
                     .selected_text(self.security_policy.display_name(lang))
@@ -454,7 +856,7 @@ impl ConnectionPanel {
             ui.label(i18n::t(T::SecurityMode, lang));
             if security_locked {
                 ui.label(egui::RichText::new(self.security_mode.display_name(lang)).strong());
-                ui.label(egui::RichText::new("🔒").small());
+                ui.label(egui::RichText::new("🔒").small()).on_hover_text(i18n::t(T::SecurityLockedHint, lang));
             } else {
                 egui::ComboBox::from_id_salt("security_mode")
                     .selected_text(self.security_mode.display_name(lang))
@@ -473,19 +875,56 @@ impl ConnectionPanel {
             }
         });
 
+        if security_locked
+            && ui.add_enabled(interactive, egui::Button::new(format!("🔓 {}", i18n::t(T::UnlockSecurity, lang))).small())
+                .on_hover_text(i18n::t(T::SecurityLockedHint, lang))
+                .clicked()
+        {
+            self.selected_endpoint = None;
+        }
+
         ui.add_space(5.0);
 
         
         ui.add_enabled(interactive && !security_locked, egui::Checkbox::new(&mut self.use_auth, i18n::t(T::UseAuth, lang)));
         if self.use_auth {
             ui.horizontal(|ui| {
-                ui.label(i18n::t(T::Username, lang));
-                ui.add_enabled(interactive, egui::TextEdit::singleline(&mut self.username));
-            });
-            ui.horizontal(|ui| {
-                ui.label(i18n::t(T::Password, lang));
-                ui.add_enabled(interactive, egui::TextEdit::singleline(&mut self.password).password(true));
+                ui.add_enabled_ui(interactive, |ui| {
+                    ui.radio_value(&mut self.use_certificate, false, i18n::t(T::AuthUsernamePassword, lang));
+                    ui.radio_value(&mut self.use_certificate, true, i18n::t(T::AuthCertificate, lang));
+                });
             });
+            if self.use_certificate {
+                path_picker(ui, interactive, i18n::t(T::UserCertificate, lang), &mut self.cert_path, ("Certificate", &["der", "pem", "crt"]), lang);
+                path_picker(ui, interactive, i18n::t(T::UserPrivateKey, lang), &mut self.key_path, ("PEM", &["pem", "key"]), lang);
+            } else {
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t(T::Username, lang));
+                    ui.add_enabled(interactive, egui::TextEdit::singleline(&mut self.username));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t(T::Password, lang));
+                    ui.add_enabled(interactive, egui::TextEdit::singleline(&mut self.password).password(true));
+                });
+            }
+        }
+
+        ui.add_space(5.0);
+        ui.add_enabled(interactive, egui::Checkbox::new(&mut self.skip_endpoint_matching, i18n::t(T::SkipEndpointMatching, lang)))
+            .on_hover_text(i18n::t(T::SkipEndpointMatchingHint, lang));
+        let mut fallback = endpoint_fallback;
+        if ui.add_enabled(interactive, egui::Checkbox::new(&mut fallback, i18n::t(T::EndpointFallback, lang)))
+            .on_hover_text(i18n::t(T::EndpointFallbackHint, lang))
+            .changed()
+        {
+            action = Some(ConnectionAction::SetEndpointFallback(fallback));
+        }
+        let mut reconnect = auto_reconnect;
+        if ui.checkbox(&mut reconnect, i18n::t(T::AutoReconnect, lang))
+            .on_hover_text(i18n::t(T::AutoReconnectHint, lang))
+            .changed()
+        {
+            action = Some(ConnectionAction::SetAutoReconnect(reconnect));
         }
 
         ui.add_space(10.0);
@@ -493,7 +932,9 @@ impl ConnectionPanel {
 
         
         ui.horizontal(|ui| {
-            let connect_enabled = !self.server_input.is_empty() && interactive;
+            let certificate_missing = self.use_auth && self.use_certificate
+                && (self.cert_path.trim().is_empty() || self.key_path.trim().is_empty());
+            let connect_enabled = !self.server_input.is_empty() && interactive && !certificate_missing;
             
             if self.is_connecting {
                 ui.spinner();
@@ -508,25 +949,19 @@ impl ConnectionPanel {
                  }
 
             } else if ui.add_enabled(connect_enabled, egui::Button::new(format!("🔗 {}", i18n::t(T::Connect, lang))))
-                .on_hover_text("Establishes a secure OPC UA session")
+                .on_hover_text(i18n::t(T::ConnectHint, lang))
                 .clicked() 
             {
                 let _pki_dir = CertificateManager::new()
                     .map(|m| m.pki_directory().to_path_buf())
                     .unwrap_or_else(|_| std::path::PathBuf::from("./pki"));
 
-                let auth_method = if self.use_auth {
-                    AuthMethod::UserPassword {
-                        username: self.username.clone(),
-                        password: self.password.clone(),
-                    }
-                } else {
-                    AuthMethod::Anonymous
-                };
+                let auth_method = self.auth_method();
 
                 
                 let endpoint_url = self.diagnostic_result
                     .as_ref()
+                    .filter(|_| !self.skip_endpoint_matching)
                     .and_then(|r| r.recommended_url.clone())
                     .unwrap_or_else(|| {
                         
@@ -544,15 +979,17 @@ impl ConnectionPanel {
                     security_policy: self.security_policy.clone(),
                     security_mode: self.security_mode.clone(),
                     auth_method,
+                    skip_endpoint_matching: self.skip_endpoint_matching,
                 }));
             }
 
             if ui.add_enabled(interactive, egui::Button::new(format!("💾 {}", i18n::t(T::SaveBookmark, lang))))
-                .on_hover_text("Save this server configuration")
+                .on_hover_text(i18n::t(T::SaveBookmarkHint, lang))
                 .clicked() 
             {
                 self.show_add_bookmark = true;
-                self.bookmark_name = format!("Server {}", bookmarks.servers.len() + 1);
+                self.bookmark_name = i18n::t_fmt(T::DefaultBookmarkName, lang, &[&(bookmarks.servers.len() + 1).to_string()]);
+                self.bookmark_tags.clear();
             }
         });
 
@@ -566,17 +1003,14 @@ impl ConnectionPanel {
                         ui.label(i18n::t(T::Name, lang));
                         ui.text_edit_singleline(&mut self.bookmark_name);
                     });
+                    ui.horizontal(|ui| {
+                        ui.label(i18n::t(T::Tags, lang));
+                        ui.add(egui::TextEdit::singleline(&mut self.bookmark_tags).hint_text(i18n::t(T::TagsHint, lang)));
+                    });
                     
                     ui.horizontal(|ui| {
                         if ui.button(i18n::t(T::Save, lang)).clicked() {
-                            let auth_method = if self.use_auth {
-                                AuthMethod::UserPassword {
-                                    username: self.username.clone(),
-                                    password: self.password.clone(),
-                                }
-                            } else {
-                                AuthMethod::Anonymous
-                            };
+                            let auth_method = self.auth_method();
 
                             let endpoint_url = if self.server_input.starts_with("opc.tcp://") {
                                 self.server_input.clone()
@@ -590,6 +1024,8 @@ impl ConnectionPanel {
                                 security_policy: self.security_policy.clone(),
                                 security_mode: self.security_mode.clone(),
                                 auth_method,
+                                watchlist: None,
+                                tags: parse_tags(&self.bookmark_tags),
                             };
 
                             bookmarks.add(bookmark);
@@ -607,3 +1043,124 @@ impl ConnectionPanel {
         action
     }
 }
+
+
+fn path_picker(ui: &mut egui::Ui, interactive: bool, label: &str, path: &mut String, filter: (&str, &[&str]), lang: Language) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        ui.add_enabled(interactive, egui::TextEdit::singleline(path).desired_width(160.0));
+        if ui.add_enabled(interactive, egui::Button::new("📂")).on_hover_text(i18n::t(T::BrowseFile, lang)).clicked() {
+            if let Some(picked) = rfd::FileDialog::new().add_filter(filter.0, filter.1).pick_file() {
+                *path = picked.display().to_string();
+            }
+        }
+    });
+}
+
+
+pub fn format_duration(duration: std::time::Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {:02}m {:02}s", secs / 3600, (secs / 60) % 60, secs % 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+
+fn format_millis(duration: std::time::Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(policy: &str, mode: &str, tokens: &[&str]) -> EndpointInfo {
+        EndpointInfo {
+            security_policy_name: policy.to_string(),
+            security_mode: mode.to_string(),
+            has_certificate: true,
+            user_tokens: tokens.iter().map(|t| t.to_string()).collect(),
+            endpoint_url: "opc.tcp://plc:4840".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fallback_follows_discovery_order() {
+        let mut panel = ConnectionPanel {
+            discovered_endpoints: vec![
+                endpoint("None", "None", &["Anonymous (anon)"]),
+                endpoint("Basic256Sha256", "Sign", &["UserName (user)"]),
+                endpoint("Basic256Sha256", "SignAndEncrypt", &["Anonymous (anon)"]),
+            ],
+            ..ConnectionPanel::default()
+        };
+        sort_by_security(&mut panel.discovered_endpoints);
+
+        let primary = ClientConfig {
+            endpoint_url: "opc.tcp://10.0.0.5:4840".to_string(),
+            security_policy: SecurityPolicy::Basic256Sha256,
+            security_mode: MessageSecurityMode::SignAndEncrypt,
+            auth_method: AuthMethod::Anonymous,
+            skip_endpoint_matching: false,
+        };
+        let fallbacks = panel.fallback_configs(&primary);
+        assert_eq!(fallbacks.len(), 1);
+        assert_eq!(fallbacks[0].security_policy, SecurityPolicy::None);
+        assert_eq!(fallbacks[0].security_mode, MessageSecurityMode::None);
+        assert_eq!(fallbacks[0].endpoint_url, primary.endpoint_url);
+    }
+
+    #[test]
+    fn test_reset_form_restores_defaults() {
+        let mut panel = ConnectionPanel {
+            server_input: "opc.tcp://secure:4843".to_string(),
+            security_policy: SecurityPolicy::Basic256Sha256,
+            security_mode: MessageSecurityMode::SignAndEncrypt,
+            use_auth: true,
+            username: "operator".to_string(),
+            password: "secret".to_string(),
+            loaded_bookmark: Some(2),
+            skip_endpoint_matching: true,
+            bookmark_tag_filter: "plant".to_string(),
+            discovered_endpoints: vec![endpoint("None", "None", &[])],
+            selected_endpoint: Some(0),
+            ..ConnectionPanel::default()
+        };
+        panel.diagnostic_log.push(DiagnosticStep::new(StepId::ScanPorts, "Ports"));
+
+        panel.reset_form();
+        assert!(panel.server_input.is_empty());
+        assert_eq!(panel.security_policy, SecurityPolicy::None);
+        assert_eq!(panel.security_mode, MessageSecurityMode::None);
+        assert!(!panel.use_auth && panel.username.is_empty() && panel.password.is_empty());
+        assert_eq!(panel.loaded_bookmark, None);
+        assert!(!panel.skip_endpoint_matching);
+        assert!(panel.diagnostic_log.is_empty() && panel.discovered_endpoints.is_empty());
+        assert_eq!(panel.selected_endpoint, None);
+        assert_eq!(panel.bookmark_tag_filter, "plant");
+    }
+
+    #[test]
+    fn test_certificate_auth_round_trips_through_form() {
+        let mut panel = ConnectionPanel::default();
+        let auth = AuthMethod::Certificate {
+            cert_path: "pki/user/operator.der".into(),
+            key_path: "pki/user/operator.pem".into(),
+        };
+        panel.load_auth_method(&auth);
+        assert!(panel.use_auth && panel.use_certificate);
+        assert!(matches!(
+            panel.auth_method(),
+            AuthMethod::Certificate { cert_path, key_path }
+                if cert_path.ends_with("operator.der") && key_path.ends_with("operator.pem")
+        ));
+
+        panel.load_auth_method(&AuthMethod::Anonymous);
+        assert!(!panel.use_auth && !panel.use_certificate && panel.cert_path.is_empty());
+        assert!(matches!(panel.auth_method(), AuthMethod::Anonymous));
+    }
+}
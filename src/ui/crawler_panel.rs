@@ -5,19 +5,23 @@
 use eframe::egui;
 use opcua::types::NodeId;
 use crate::opcua::browser::BrowsedNode;
-use crate::opcua::crawler::CrawlConfig;
+use crate::opcua::crawler::{CrawlConfig, CrawlConflict, CrawlProgress};
 use crate::utils::i18n::{self, T, Language};
 
 
 pub enum CrawlerAction {
     StartCrawl(CrawlConfig),
+    StreamToJson(CrawlConfig),
+    StopCrawl,
     ExportJson,
     ExportCsv,
+    ExportConflicts,
     #[allow(dead_code)]
     JumpToNode(NodeId),
 }
 
 
+#[derive(Default)]
 pub struct CrawlerPanel {
     
     pub config: CrawlConfig,
@@ -29,27 +33,175 @@ pub struct CrawlerPanel {
     pub status: String,
     
     pub start_time: Option<std::time::Instant>,
+    
+    pub elapsed: Option<std::time::Duration>,
+    
+    pub namespaces: Vec<String>,
+    
+    pub conflicts: Vec<CrawlConflict>,
+    
+    pub progress: Option<CrawlProgress>,
+    
+    start_node_input: String,
+    
+    start_node_error: Option<String>,
 }
 
-impl Default for CrawlerPanel {
-    fn default() -> Self {
-        Self {
-            config: CrawlConfig {
-                max_depth: 5,
-                max_nodes: 500_000, 
-                start_node: NodeId::from(opcua::types::ObjectId::RootFolder),
-            },
-            results: Vec::new(),
-            is_crawling: false,
-            status: String::new(),
-            start_time: None,
+impl CrawlerPanel {
+    
+    pub fn begin(&mut self, lang: Language) {
+        self.is_crawling = true;
+        self.results.clear();
+        self.namespaces.clear();
+        self.conflicts.clear();
+        self.progress = None;
+        self.status = i18n::t(T::Connecting, lang).to_string();
+        self.start_time = Some(std::time::Instant::now());
+        self.elapsed = None;
+    }
+
+    
+    pub fn append_chunk(&mut self, nodes: Vec<BrowsedNode>) -> usize {
+        if !self.is_crawling {
+            return 0;
         }
+        let count = nodes.len();
+        self.results.extend(nodes);
+        count
+    }
+
+    
+    pub fn update_progress(&mut self, progress: CrawlProgress, lang: Language) {
+        if self.is_crawling {
+            self.status = progress_label(&progress, lang);
+            self.progress = Some(progress);
+        }
+    }
+
+    
+    pub fn finish(&mut self, total: usize, duration: std::time::Duration, truncated: bool, cancelled: bool, conflicts: Vec<CrawlConflict>, lang: Language) {
+        if !self.is_crawling {
+            return;
+        }
+        self.is_crawling = false;
+        self.progress = None;
+        let elapsed = self.start_time.take().map_or(duration, |start| start.elapsed());
+        self.elapsed = Some(elapsed);
+        self.conflicts = conflicts;
+        if cancelled {
+            self.status = i18n::t_fmt(T::CrawlCancelled, lang, &[&total.to_string()]);
+            return;
+        }
+        let mut status = Vec::new();
+        if self.results.is_empty() {
+            status.push(crawl_summary(total, elapsed, lang));
+        }
+        if truncated {
+            status.push(i18n::t(T::CrawlTruncated, lang).to_string());
+        }
+        self.status = status.join(" ");
+    }
+
+    
+    pub fn fail(&mut self, error: &str, lang: Language) {
+        self.is_crawling = false;
+        self.start_time = None;
+        self.progress = None;
+        self.status = i18n::t_fmt(T::CrawlFailed, lang, &[error]);
+    }
+
+    
+    pub fn add_start_node(&mut self, node_id: NodeId) -> bool {
+        if self.config.start_nodes.contains(&node_id) {
+            return false;
+        }
+        self.config.start_nodes.push(node_id);
+        true
+    }
+
+    
+    pub fn remove_start_node(&mut self, index: usize) {
+        if self.config.start_nodes.len() > 1 && index < self.config.start_nodes.len() {
+            self.config.start_nodes.remove(index);
+        }
+    }
+
+    fn show_start_nodes(&mut self, ui: &mut egui::Ui, selected_node: Option<&NodeId>, lang: Language) {
+        ui.label(i18n::t(T::StartNodes, lang));
+        let can_remove = self.config.start_nodes.len() > 1;
+        let mut remove = None;
+        for (index, node_id) in self.config.start_nodes.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.monospace(node_id.to_string());
+                if can_remove && ui.small_button("✖").on_hover_text(i18n::t(T::Remove, lang)).clicked() {
+                    remove = Some(index);
+                }
+            });
+        }
+        if let Some(index) = remove {
+            self.remove_start_node(index);
+        }
+
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.start_node_input)
+                    .hint_text("ns=4;s=Energy")
+                    .desired_width(180.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.button(format!("➕ {}", i18n::t(T::Add, lang))).clicked() || submitted) && !self.start_node_input.trim().is_empty() {
+                match self.start_node_input.trim().parse::<NodeId>() {
+                    Ok(node_id) if !node_id.is_null() => {
+                        self.add_start_node(node_id);
+                        self.start_node_input.clear();
+                        self.start_node_error = None;
+                    }
+                    _ => {
+                        self.start_node_error = Some(i18n::t_fmt(T::InvalidNodeId, lang, &[self.start_node_input.trim()]));
+                    }
+                }
+            }
+        });
+        if let Some(error) = &self.start_node_error {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+        }
+        if ui.add_enabled(selected_node.is_some(), egui::Button::new(format!("🌳 {}", i18n::t(T::UseSelectedTreeNode, lang)))).clicked() {
+            if let Some(node_id) = selected_node {
+                self.add_start_node(node_id.clone());
+            }
+        }
+    }
+
+    fn show_conflicts(&self, ui: &mut egui::Ui, lang: Language) -> bool {
+        let mut export = false;
+        ui.add_space(5.0);
+        let title = egui::RichText::new(format!("⚠ {}", i18n::t_fmt(T::CrawlConflicts, lang, &[&self.conflicts.len().to_string()])))
+            .color(egui::Color32::from_rgb(255, 180, 50));
+        egui::CollapsingHeader::new(title).id_salt("crawl_conflicts").show(ui, |ui| {
+            ui.label(i18n::t(T::CrawlConflictsHint, lang));
+            if ui.button(format!("📄 {}", i18n::t(T::ExportConflicts, lang))).clicked() {
+                export = true;
+            }
+            egui::ScrollArea::vertical().id_salt("crawl_conflicts_scroll").max_height(200.0).show(ui, |ui| {
+                egui::Grid::new("crawl_conflicts_grid").striped(true).show(ui, |ui| {
+                    ui.strong(i18n::t(T::NodeId, lang));
+                    ui.strong(i18n::t(T::FirstSeen, lang));
+                    ui.strong(i18n::t(T::SeenAgain, lang));
+                    ui.end_row();
+                    for conflict in &self.conflicts {
+                        ui.monospace(conflict.first.node_id.to_string());
+                        ui.label(conflict_variant(&conflict.first));
+                        ui.label(conflict_variant(&conflict.second));
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+        export
     }
-}
 
-impl CrawlerPanel {
     
-    pub fn show(&mut self, ui: &mut egui::Ui, is_connected: bool, lang: Language) -> Option<CrawlerAction> {
+    pub fn show(&mut self, ui: &mut egui::Ui, is_connected: bool, selected_node: Option<&NodeId>, lang: Language) -> Option<CrawlerAction> {
         let mut action = None;
 
         ui.heading(format!("🕷 {}", i18n::t(T::Crawler, lang)));
@@ -64,12 +216,10 @@ impl CrawlerPanel {
         
         ui.group(|ui| {
             ui.label(i18n::t(T::Configuration, lang));
-            ui.horizontal(|ui| {
-                ui.label(format!("{} ", i18n::t(T::Node, lang)));
-                ui.label(self.config.start_node.to_string());
-            });
+            self.show_start_nodes(ui, selected_node, lang);
 
             ui.add(egui::Slider::new(&mut self.config.max_depth, 1..=10).text(i18n::t(T::MaxDepth, lang)));
+            ui.checkbox(&mut self.config.include_start_node, i18n::t(T::IncludeStartNode, lang));
             
         });
 
@@ -85,24 +235,37 @@ impl CrawlerPanel {
                 } else {
                      ui.label(i18n::t(T::Checking, lang));
                 }
-            } else if ui.button(format!("▶ {}", i18n::t(T::StartCrawl, lang))).clicked() {
-                action = Some(CrawlerAction::StartCrawl(self.config.clone()));
-                self.is_crawling = true;
-                self.results.clear();
-                self.status = i18n::t(T::Connecting, lang).to_string(); 
-                self.start_time = Some(std::time::Instant::now());
+                if ui.button(format!("⏹ {}", i18n::t(T::Stop, lang))).clicked() {
+                    action = Some(CrawlerAction::StopCrawl);
+                }
+            } else {
+                if ui.button(format!("▶ {}", i18n::t(T::StartCrawl, lang))).clicked() {
+                    action = Some(CrawlerAction::StartCrawl(self.config.clone()));
+                }
+                if ui.button(format!("💾 {}", i18n::t(T::StreamToJson, lang)))
+                    .on_hover_text(i18n::t(T::StreamToJsonHint, lang))
+                    .clicked()
+                {
+                    action = Some(CrawlerAction::StreamToJson(self.config.clone()));
+                }
             }
         });
+        if self.is_crawling && self.progress.is_some() {
+            ui.label(egui::RichText::new(&self.status).weak());
+        }
 
         ui.separator();
 
         
-        if !self.results.is_empty() {
+        if self.is_crawling && !self.results.is_empty() {
+            ui.label(i18n::t_fmt(T::CrawledNodeCount, lang, &[&self.results.len().to_string()]));
+        } else if !self.results.is_empty() {
             ui.vertical(|ui| {
-                ui.colored_label(
-                    egui::Color32::from_rgb(100, 200, 100),
-                    format!("✓ {} {} {}", i18n::t(T::CrawlComplete, lang).split('.').next().unwrap_or("Complete"), self.results.len(), "nodes")
-                );
+                let summary = match self.elapsed {
+                    Some(elapsed) => crawl_summary(self.results.len(), elapsed, lang),
+                    None => i18n::t_fmt(T::CrawlComplete, lang, &[&self.results.len().to_string()]),
+                };
+                ui.colored_label(egui::Color32::from_rgb(100, 200, 100), format!("✓ {}", summary));
                 
                 ui.add_space(10.0);
                 
@@ -114,11 +277,130 @@ impl CrawlerPanel {
                         action = Some(CrawlerAction::ExportCsv);
                     }
                 });
+
+                if !self.conflicts.is_empty() && self.show_conflicts(ui, lang) {
+                    action = Some(CrawlerAction::ExportConflicts);
+                }
             });
-        } else if !self.status.is_empty() {
+        }
+        if !self.status.is_empty() && (!self.is_crawling || (self.results.is_empty() && self.progress.is_none())) {
             ui.label(&self.status);
         }
 
         action
     }
 }
+
+
+fn conflict_variant(node: &BrowsedNode) -> String {
+    format!("{} ({}, {:?})", node.display_name, node.browse_name, node.node_class)
+}
+
+
+fn progress_label(progress: &CrawlProgress, lang: Language) -> String {
+    i18n::t_fmt(T::CrawlProgress, lang, &[&progress.nodes.to_string(), &progress.depth.to_string(), &progress.current])
+}
+
+
+pub fn crawl_summary(count: usize, elapsed: std::time::Duration, lang: Language) -> String {
+    let separator = match lang {
+        Language::English => ',',
+        Language::Spanish => '.',
+    };
+    let digits = count.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    i18n::t_fmt(T::CrawlSummary, lang, &[&grouped, &format!("{:.1}", elapsed.as_secs_f64())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_nodes::TestNode;
+
+    #[test]
+    fn test_start_node_list_keeps_at_least_one_entry() {
+        let mut panel = CrawlerPanel::default();
+        let root = NodeId::from(opcua::types::ObjectId::RootFolder);
+        assert_eq!(panel.config.start_nodes, vec![root.clone()]);
+
+        assert!(panel.add_start_node(NodeId::new(2, "Plant")));
+        assert!(!panel.add_start_node(NodeId::new(2, "Plant")));
+        assert!(panel.add_start_node(NodeId::new(4, "Energy")));
+        assert!(panel.config.tags_results());
+
+        panel.remove_start_node(0);
+        panel.remove_start_node(5);
+        assert_eq!(panel.config.start_nodes, vec![NodeId::new(2, "Plant"), NodeId::new(4, "Energy")]);
+        panel.remove_start_node(1);
+        panel.remove_start_node(0);
+        assert_eq!(panel.config.start_nodes, vec![NodeId::new(2, "Plant")]);
+        assert!(!panel.config.tags_results());
+    }
+
+    fn node(i: u32) -> BrowsedNode {
+        TestNode::new(NodeId::new(2, i), &format!("N{}", i)).build()
+    }
+
+    #[test]
+    fn test_chunks_append_until_done() {
+        let mut panel = CrawlerPanel::default();
+        panel.begin(Language::English);
+        assert_eq!(panel.append_chunk(vec![node(1), node(2)]), 2);
+        assert_eq!(panel.append_chunk(vec![node(3)]), 1);
+        panel.finish(3, std::time::Duration::from_secs(1), false, false, Vec::new(), Language::English);
+
+        assert!(!panel.is_crawling);
+        assert_eq!(panel.results.len(), 3);
+
+        
+        assert_eq!(panel.append_chunk(vec![node(4)]), 0);
+        assert_eq!(panel.results.len(), 3);
+    }
+
+    #[test]
+    fn test_elapsed_is_kept_after_completion() {
+        let mut panel = CrawlerPanel::default();
+        panel.begin(Language::English);
+        panel.start_time = Some(std::time::Instant::now() - std::time::Duration::from_millis(1500));
+        panel.append_chunk(vec![node(1)]);
+        panel.finish(1, std::time::Duration::ZERO, false, false, Vec::new(), Language::English);
+
+        assert!(panel.start_time.is_none());
+        assert!(panel.elapsed.is_some_and(|e| e >= std::time::Duration::from_millis(1500)));
+        assert_eq!(crawl_summary(3412, std::time::Duration::from_millis(18_440), Language::English), "3,412 nodes in 18.4s");
+        assert_eq!(crawl_summary(999, std::time::Duration::from_millis(250), Language::Spanish), "999 nodos en 0.2s");
+        assert_eq!(crawl_summary(1_000_000, std::time::Duration::ZERO, Language::Spanish), "1.000.000 nodos en 0.0s");
+    }
+
+    #[test]
+    fn test_progress_is_shown_only_while_crawling() {
+        let mut panel = CrawlerPanel::default();
+        let progress = || CrawlProgress { nodes: 200, depth: 3, current: "Motor1".into() };
+        panel.update_progress(progress(), Language::English);
+        assert!(panel.progress.is_none());
+
+        panel.begin(Language::English);
+        panel.update_progress(progress(), Language::English);
+        assert_eq!(panel.status, "200 nodes found, depth 3: Motor1");
+        panel.finish(200, std::time::Duration::ZERO, false, false, Vec::new(), Language::English);
+        assert!(panel.progress.is_none());
+    }
+
+    #[test]
+    fn test_cancelled_crawl_keeps_partial_results() {
+        let mut panel = CrawlerPanel::default();
+        panel.begin(Language::English);
+        panel.append_chunk(vec![node(1), node(2)]);
+        panel.finish(2, std::time::Duration::ZERO, false, true, Vec::new(), Language::English);
+
+        assert!(!panel.is_crawling);
+        assert_eq!(panel.results.len(), 2);
+        assert_eq!(panel.status, "Cancelled - 2 nodes collected");
+    }
+}
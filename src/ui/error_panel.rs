@@ -4,7 +4,7 @@
 
 use eframe::egui;
 use std::collections::VecDeque;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::utils::i18n::{self, T, Language};
 
@@ -15,6 +15,12 @@ const MAX_NOTIFICATIONS: usize = 10;
 const TOAST_DURATION_SECS: u64 = 5;
 
 
+const COALESCE_WINDOW: Duration = Duration::from_secs(3);
+
+
+const MAX_CONCURRENT_TOASTS: usize = 3;
+
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorSeverity {
     #[allow(dead_code)]
@@ -48,6 +54,7 @@ pub struct ErrorNotification {
     pub severity: ErrorSeverity,
     pub timestamp: Instant,
     pub details: Option<String>,
+    pub count: u32,
 }
 
 impl ErrorNotification {
@@ -57,6 +64,7 @@ impl ErrorNotification {
             severity,
             timestamp: Instant::now(),
             details: None,
+            count: 1,
         }
     }
 
@@ -66,6 +74,29 @@ impl ErrorNotification {
         self
     }
 
+    /// Message with the repeat counter appended once it has been coalesced
+    pub fn display_message(&self) -> String {
+        if self.count > 1 {
+            format!("{} (×{})", self.message, self.count)
+        } else {
+            self.message.clone()
+        }
+    }
+
+    fn coalesces_with(&self, other: &ErrorNotification) -> bool {
+        self.message == other.message
+            && self.severity == other.severity
+            && self.timestamp.elapsed() < COALESCE_WINDOW
+    }
+
+    /// First `max_chars` characters of the message, with an ellipsis when cut
+    pub fn summary(&self, max_chars: usize) -> String {
+        match self.message.char_indices().nth(max_chars) {
+            Some((end, _)) => format!("{}…", &self.message[..end]),
+            None => self.message.clone(),
+        }
+    }
+
     /// Check if this notification should still be shown as a toast
     pub fn is_toast_active(&self) -> bool {
         self.timestamp.elapsed().as_secs() < TOAST_DURATION_SECS
@@ -80,7 +111,7 @@ pub fn get_common_errors(lang: Language) -> Vec<(&'static str, &'static str, &'s
             ("BadCertificateHostNameInvalid", "Certificate hostname mismatch", "The certificate hostname doesn't match the server. Check your endpoint URL."),
             ("BadCertificateUntrusted", "Certificate not trusted", "The server certificate is not trusted. Add it to your trusted certificates."),
             ("BadSecurityModeRejected", "Security mode rejected", "The server doesn't support this security mode. Try a different security policy."),
-            ("BadIdentityTokenRejected", "Authentication failed", "Username/password rejected. Check your credentials."),
+            ("BadIdentityTokenRejected", "Authentication failed", "Username/password or user certificate rejected. Check your credentials and that the server trusts the certificate."),
             ("BadUserAccessDenied", "Access denied", "Your user account doesn't have permission to access this resource."),
             ("BadConnectionClosed", "Connection closed", "The server closed the connection. It may have restarted or timed out."),
             ("BadTimeout", "Timeout", "The operation took too long. Check network connectivity."),
@@ -92,7 +123,7 @@ pub fn get_common_errors(lang: Language) -> Vec<(&'static str, &'static str, &'s
             ("BadCertificateHostNameInvalid", "Nombre de host no coincide", "El nombre de host del certificado no coincide con el servidor."),
             ("BadCertificateUntrusted", "Certificado no confiable", "El certificado del servidor no es confiable. Agrégalo a certificados confiables."),
             ("BadSecurityModeRejected", "Modo de seguridad rechazado", "El servidor no soporta este modo de seguridad. Prueba otra política."),
-            ("BadIdentityTokenRejected", "Autenticación fallida", "Usuario/contraseña rechazados. Verifica tus credenciales."),
+            ("BadIdentityTokenRejected", "Autenticación fallida", "Usuario/contraseña o certificado de usuario rechazados. Verifica tus credenciales y que el servidor confíe en el certificado."),
             ("BadUserAccessDenied", "Acceso denegado", "Tu cuenta no tiene permiso para acceder a este recurso."),
             ("BadConnectionClosed", "Conexión cerrada", "El servidor cerró la conexión. Puede haberse reiniciado."),
             ("BadTimeout", "Tiempo agotado", "La operación tardó demasiado. Verifica la conectividad de red."),
@@ -112,32 +143,51 @@ pub struct ErrorPanel {
     pub show_panel: bool,
     
     pub show_reference: bool,
+    
+    acknowledged_at: Option<Instant>,
 }
 
 impl ErrorPanel {
     
     pub fn add_error(&mut self, message: impl Into<String>, severity: ErrorSeverity) {
-        let notification = ErrorNotification::new(message, severity);
-        self.notifications.push_front(notification);
-        
-        
-        while self.notifications.len() > MAX_NOTIFICATIONS {
-            self.notifications.pop_back();
-        }
+        self.push(ErrorNotification::new(message, severity));
     }
 
     
-    #[allow(dead_code)]
     pub fn add_error_with_details(&mut self, message: impl Into<String>, details: impl Into<String>, severity: ErrorSeverity) {
-        let notification = ErrorNotification::new(message, severity).with_details(details);
+        self.push(ErrorNotification::new(message, severity).with_details(details));
+    }
+
+    
+    fn push(&mut self, mut notification: ErrorNotification) {
+        if let Some(index) = self.notifications.iter().position(|n| n.coalesces_with(&notification)) {
+            if let Some(existing) = self.notifications.remove(index) {
+                notification.count = existing.count + 1;
+                notification.details = notification.details.or(existing.details);
+            }
+        }
         self.notifications.push_front(notification);
         
+        
         while self.notifications.len() > MAX_NOTIFICATIONS {
             self.notifications.pop_back();
         }
     }
 
     
+    pub fn latest_error(&self) -> Option<&ErrorNotification> {
+        self.notifications
+            .iter()
+            .filter(|n| self.acknowledged_at.map_or(true, |at| n.timestamp > at))
+            .find(|n| n.severity == ErrorSeverity::Error)
+    }
+
+    
+    pub fn acknowledge_errors(&mut self) {
+        self.acknowledged_at = Some(Instant::now());
+    }
+
+    
     pub fn clear(&mut self) {
         self.notifications.clear();
     }
@@ -149,11 +199,17 @@ impl ErrorPanel {
     }
 
     
-    pub fn show_toasts(&self, ctx: &egui::Context) {
-        let active_toasts: Vec<_> = self.notifications.iter()
+    fn active_toasts(&self) -> (Vec<&ErrorNotification>, usize) {
+        let active: Vec<_> = self.notifications.iter()
             .filter(|n| n.is_toast_active())
-            .take(3) 
             .collect();
+        let hidden = active.len().saturating_sub(MAX_CONCURRENT_TOASTS);
+        (active.into_iter().take(MAX_CONCURRENT_TOASTS).collect(), hidden)
+    }
+
+    
+    pub fn show_toasts(&self, ctx: &egui::Context, lang: Language) {
+        let (active_toasts, hidden) = self.active_toasts();
 
         if active_toasts.is_empty() {
             return;
@@ -181,11 +237,19 @@ impl ErrorPanel {
                                 egui::Stroke::new(2.0, frame_color);
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new(toast.severity.icon()).size(16.0));
-                                ui.label(egui::RichText::new(&toast.message).color(egui::Color32::WHITE));
+                                ui.label(egui::RichText::new(toast.display_message()).color(egui::Color32::WHITE));
                             });
                         });
                         ui.add_space(5.0);
                     }
+                    if hidden > 0 {
+                        ui.group(|ui| {
+                            ui.label(
+                                egui::RichText::new(i18n::t_fmt(T::MoreInErrorPanel, lang, &[&hidden.to_string()]))
+                                    .color(egui::Color32::LIGHT_GRAY),
+                            );
+                        });
+                    }
                 });
             });
     }
@@ -242,7 +306,7 @@ impl ErrorPanel {
                             ui.label(notification.severity.icon());
                             ui.vertical(|ui| {
                                 ui.horizontal(|ui| {
-                                    ui.strong(&notification.message);
+                                    ui.strong(notification.display_message());
                                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                         ui.label(egui::RichText::new(&time_str).small().weak());
                                     });
@@ -259,3 +323,65 @@ impl ErrorPanel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_errors_coalesce() {
+        let mut panel = ErrorPanel::default();
+        for _ in 0..12 {
+            panel.add_error("Connection failed: BadTimeout", ErrorSeverity::Error);
+        }
+        panel.add_error("Connection failed: BadTimeout", ErrorSeverity::Warning);
+
+        assert_eq!(panel.notifications.len(), 2);
+        assert_eq!(panel.notifications[1].display_message(), "Connection failed: BadTimeout (×12)");
+        assert_eq!(panel.notifications[0].count, 1);
+    }
+
+    #[test]
+    fn test_coalesced_error_moves_to_front() {
+        let mut panel = ErrorPanel::default();
+        panel.add_error("A", ErrorSeverity::Error);
+        panel.add_error("B", ErrorSeverity::Error);
+        panel.add_error("A", ErrorSeverity::Error);
+
+        let order: Vec<_> = panel.notifications.iter().map(|n| n.display_message()).collect();
+        assert_eq!(order, ["A (×2)", "B"]);
+
+        panel.notifications[0].timestamp = Instant::now() - COALESCE_WINDOW;
+        panel.add_error("A", ErrorSeverity::Error);
+        assert_eq!(panel.notifications.len(), 3);
+        assert_eq!(panel.notifications[0].count, 1);
+    }
+
+    #[test]
+    fn test_toasts_are_capped() {
+        let mut panel = ErrorPanel::default();
+        for i in 0..5 {
+            panel.add_error(format!("Item {} failed", i), ErrorSeverity::Warning);
+        }
+        let (toasts, hidden) = panel.active_toasts();
+        assert_eq!(toasts.len(), MAX_CONCURRENT_TOASTS);
+        assert_eq!(toasts[0].message, "Item 4 failed");
+        assert_eq!(hidden, 2);
+    }
+
+    #[test]
+    fn test_latest_error_chip() {
+        let mut panel = ErrorPanel::default();
+        panel.add_error("Connection failed: BadTimeout while opening the secure channel", ErrorSeverity::Error);
+        panel.add_error("Slow read", ErrorSeverity::Warning);
+
+        let latest = panel.latest_error().unwrap();
+        assert_eq!(latest.summary(18), "Connection failed:…");
+        assert_eq!(ErrorNotification::new("Short", ErrorSeverity::Error).summary(40), "Short");
+
+        panel.notifications[0].timestamp = Instant::now() - Duration::from_secs(1);
+        panel.notifications[1].timestamp = Instant::now() - Duration::from_secs(1);
+        panel.acknowledge_errors();
+        assert!(panel.latest_error().is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use eframe::egui;
+use opcua::types::NodeId;
+
+use crate::opcua::events::{default_notifier, EventData, EventLog};
+use crate::utils::i18n::{self, T, Language};
+use crate::utils::timestamps::TimestampFormat;
+
+
+pub enum EventsAction {
+    
+    Subscribe(NodeId),
+    
+    Unsubscribe,
+    
+    Clear,
+}
+
+
+pub struct EventsPanel {
+    
+    pub filter: String,
+    
+    pub min_severity: u16,
+    
+    notifier_input: String,
+    
+    notifier_error: Option<String>,
+}
+
+impl Default for EventsPanel {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            min_severity: 0,
+            notifier_input: default_notifier().to_string(),
+            notifier_error: None,
+        }
+    }
+}
+
+impl EventsPanel {
+    
+    pub fn matches(&self, event: &EventData) -> bool {
+        if event.severity < self.min_severity {
+            return false;
+        }
+        let needle = self.filter.trim().to_lowercase();
+        needle.is_empty()
+            || event.source_name.to_lowercase().contains(&needle)
+            || event.message.to_lowercase().contains(&needle)
+            || event.event_type_string().to_lowercase().contains(&needle)
+    }
+
+    
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        log: &EventLog,
+        subscribed: Option<&NodeId>,
+        timestamp_format: &TimestampFormat,
+        lang: Language,
+    ) -> Option<EventsAction> {
+        let mut action = None;
+
+        ui.heading(format!("🔔 {}", i18n::t(T::Events, lang)));
+        ui.horizontal(|ui| {
+            if let Some(notifier) = subscribed {
+                ui.colored_label(egui::Color32::GREEN, i18n::t_fmt(T::ReceivingEvents, lang, &[&notifier.to_string()]));
+                if ui.button(format!("⏹ {}", i18n::t(T::UnsubscribeEvents, lang))).clicked() {
+                    action = Some(EventsAction::Unsubscribe);
+                }
+            } else {
+                ui.label(i18n::t(T::EventNotifier, lang));
+                ui.add(egui::TextEdit::singleline(&mut self.notifier_input).desired_width(200.0));
+                if ui.button(format!("🔔 {}", i18n::t(T::SubscribeEvents, lang))).clicked() {
+                    match self.notifier_input.trim().parse::<NodeId>() {
+                        Ok(node_id) if !node_id.is_null() => {
+                            self.notifier_error = None;
+                            action = Some(EventsAction::Subscribe(node_id));
+                        }
+                        _ => {
+                            self.notifier_error = Some(i18n::t_fmt(T::InvalidNodeId, lang, &[self.notifier_input.trim()]));
+                        }
+                    }
+                }
+                if let Some(error) = &self.notifier_error {
+                    ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+                }
+            }
+            if ui.add_enabled(!log.is_empty(), egui::Button::new(format!("🗑 {}", i18n::t(T::ClearAll, lang)))).clicked() {
+                action = Some(EventsAction::Clear);
+            }
+        });
+
+        let events: Vec<&EventData> = log.events().rev().filter(|event| self.matches(event)).collect();
+        ui.horizontal(|ui| {
+            ui.label(format!("🔍 {}", i18n::t(T::FilterName, lang)));
+            ui.add(egui::TextEdit::singleline(&mut self.filter).desired_width(150.0));
+            ui.label(i18n::t(T::MinSeverity, lang));
+            ui.add(egui::DragValue::new(&mut self.min_severity).range(0..=1000).speed(10));
+            ui.label(i18n::t_fmt(T::ShowingItems, lang, &[&events.len().to_string(), &log.len().to_string()]));
+        });
+        ui.separator();
+
+        if events.is_empty() {
+            ui.label(i18n::t(T::NoEvents, lang));
+            return action;
+        }
+
+        egui::Grid::new("events_grid")
+            .num_columns(5)
+            .spacing([10.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong(i18n::t(T::Timestamp, lang));
+                ui.strong(i18n::t(T::Severity, lang));
+                ui.strong(i18n::t(T::EventSource, lang));
+                ui.strong(i18n::t(T::EventType, lang));
+                ui.strong(i18n::t(T::EventMessage, lang));
+                ui.end_row();
+
+                for event in events {
+                    ui.monospace(timestamp_format.format(event.display_time().as_chrono()));
+                    ui.colored_label(severity_color(event.severity), event.severity.to_string());
+                    ui.label(&event.source_name);
+                    ui.label(event.event_type_string());
+                    ui.label(&event.message);
+                    ui.end_row();
+                }
+            });
+        action
+    }
+}
+
+
+fn severity_color(severity: u16) -> egui::Color32 {
+    match severity {
+        700.. => egui::Color32::RED,
+        400..=699 => egui::Color32::from_rgb(255, 165, 0),
+        _ => egui::Color32::GRAY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcua::types::{DateTime, LocalizedText, UAString, Variant};
+
+    fn event(source: &str, severity: u16, message: &str) -> EventData {
+        let fields = [
+            Variant::Empty,
+            Variant::from(UAString::from(source)),
+            Variant::UInt16(severity),
+            Variant::from(LocalizedText::new("", message)),
+        ];
+        EventData::from_fields(&fields, DateTime::now())
+    }
+
+    #[test]
+    fn test_filter_by_text_and_minimum_severity() {
+        let mut panel = EventsPanel::default();
+        assert_eq!(panel.notifier_input, "i=2253");
+        let alarm = event("Boiler1", 800, "High temperature");
+        let info = event("Pump2", 100, "Started");
+        assert!(panel.matches(&alarm) && panel.matches(&info));
+
+        panel.min_severity = 500;
+        assert!(panel.matches(&alarm));
+        assert!(!panel.matches(&info));
+
+        panel.filter = " TEMPERATURE ".into();
+        assert!(panel.matches(&alarm));
+        panel.filter = "pump".into();
+        assert!(!panel.matches(&alarm));
+    }
+}
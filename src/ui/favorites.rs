@@ -0,0 +1,44 @@
+
+
+
+
+use eframe::egui;
+use opcua::types::NodeId;
+
+use crate::config::bookmarks::WatchlistEntry;
+use crate::utils::i18n::{self, T, Language};
+
+
+pub enum FavoritesAction {
+    Open(NodeId),
+    Remove(WatchlistEntry),
+}
+
+
+pub fn show_favorites(ui: &mut egui::Ui, favorites: &[WatchlistEntry], lang: Language) -> Option<FavoritesAction> {
+    let mut action = None;
+
+    egui::CollapsingHeader::new(format!("⭐ {} ({})", i18n::t(T::Favorites, lang), favorites.len()))
+        .id_salt("favorites_list")
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for favorite in favorites {
+                    let node_id = favorite.node_id.parse::<NodeId>().ok();
+                    let response = ui.add_enabled(node_id.is_some(), egui::Button::new(&favorite.display_name).small())
+                        .on_hover_text(&favorite.node_id);
+                    if response.clicked() {
+                        action = node_id.map(FavoritesAction::Open);
+                    }
+                    response.context_menu(|ui| {
+                        if ui.button(format!("☆ {}", i18n::t(T::RemoveFromFavorites, lang))).clicked() {
+                            action = Some(FavoritesAction::Remove(favorite.clone()));
+                            ui.close_menu();
+                        }
+                    });
+                }
+            });
+        });
+
+    action
+}
@@ -0,0 +1,81 @@
+
+
+
+
+use eframe::egui;
+use opcua::types::NodeId;
+
+use crate::utils::i18n::{self, T, Language};
+
+
+pub enum GotoAction {
+    NodeId(NodeId),
+    Path(String),
+}
+
+
+#[derive(Default)]
+pub struct GotoBar {
+    
+    node_id_input: String,
+    
+    path_input: String,
+    
+    error: Option<String>,
+}
+
+impl GotoBar {
+    
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    
+    pub fn show(&mut self, ui: &mut egui::Ui, enabled: bool, lang: Language) -> Option<GotoAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.label(i18n::t(T::GoToNodeId, lang));
+            let response = ui.add_enabled(
+                enabled,
+                egui::TextEdit::singleline(&mut self.node_id_input)
+                    .hint_text("ns=2;s=Device.Temperature")
+                    .desired_width(200.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.add_enabled(enabled, egui::Button::new("→")).clicked() || submitted) && !self.node_id_input.trim().is_empty() {
+                match self.node_id_input.trim().parse::<NodeId>() {
+                    Ok(node_id) => {
+                        self.error = None;
+                        action = Some(GotoAction::NodeId(node_id));
+                    }
+                    Err(_) => {
+                        self.error = Some(i18n::t_fmt(T::InvalidNodeId, lang, &[self.node_id_input.trim()]));
+                    }
+                }
+            }
+
+            ui.separator();
+
+            ui.label(i18n::t(T::GoToPath, lang));
+            let response = ui.add_enabled(
+                enabled,
+                egui::TextEdit::singleline(&mut self.path_input)
+                    .hint_text("/Objects/2:MyDevice/2:Temperature")
+                    .desired_width(240.0),
+            )
+            .on_hover_text(i18n::t(T::GoToPathHint, lang));
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.add_enabled(enabled, egui::Button::new("→")).clicked() || submitted) && !self.path_input.trim().is_empty() {
+                self.error = None;
+                action = Some(GotoAction::Path(self.path_input.trim().to_string()));
+            }
+        });
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+        }
+
+        action
+    }
+}
@@ -0,0 +1,225 @@
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use eframe::egui;
+
+use crate::opcua::browser::BrowsedNode;
+use crate::opcua::history::{DEFAULT_MAX_HISTORY_VALUES, MAX_HISTORY_VALUES};
+use crate::utils::i18n::{self, T, Language};
+
+
+const TIME_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryRange {
+    LastHour,
+    Last8Hours,
+    LastDay,
+    LastWeek,
+    Custom,
+}
+
+impl HistoryRange {
+    pub const ALL: [HistoryRange; 5] = [
+        HistoryRange::LastHour,
+        HistoryRange::Last8Hours,
+        HistoryRange::LastDay,
+        HistoryRange::LastWeek,
+        HistoryRange::Custom,
+    ];
+
+    
+    pub fn duration(self) -> Option<chrono::Duration> {
+        match self {
+            HistoryRange::LastHour => Some(chrono::Duration::hours(1)),
+            HistoryRange::Last8Hours => Some(chrono::Duration::hours(8)),
+            HistoryRange::LastDay => Some(chrono::Duration::days(1)),
+            HistoryRange::LastWeek => Some(chrono::Duration::weeks(1)),
+            HistoryRange::Custom => None,
+        }
+    }
+
+    fn label(self) -> T {
+        match self {
+            HistoryRange::LastHour => T::HistoryLastHour,
+            HistoryRange::Last8Hours => T::HistoryLast8Hours,
+            HistoryRange::LastDay => T::HistoryLastDay,
+            HistoryRange::LastWeek => T::HistoryLastWeek,
+            HistoryRange::Custom => T::HistoryCustomRange,
+        }
+    }
+}
+
+
+pub fn parse_local_time(text: &str) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .map(|local| local.with_timezone(&Utc))
+}
+
+
+fn format_local_time(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format(TIME_FORMATS[0]).to_string()
+}
+
+
+#[derive(Debug, Clone)]
+pub struct HistoryRequest {
+    
+    pub node: BrowsedNode,
+    
+    pub start: DateTime<Utc>,
+    
+    pub end: DateTime<Utc>,
+    
+    pub max_values: u32,
+}
+
+
+pub struct HistoryDialog {
+    
+    node: Option<BrowsedNode>,
+    
+    range: HistoryRange,
+    
+    start: String,
+    
+    end: String,
+    
+    max_values: u32,
+    
+    error: Option<String>,
+}
+
+impl Default for HistoryDialog {
+    fn default() -> Self {
+        Self {
+            node: None,
+            range: HistoryRange::LastHour,
+            start: String::new(),
+            end: String::new(),
+            max_values: DEFAULT_MAX_HISTORY_VALUES,
+            error: None,
+        }
+    }
+}
+
+impl HistoryDialog {
+    
+    pub fn open(&mut self, node: BrowsedNode) {
+        if self.start.is_empty() || self.end.is_empty() {
+            let now = Utc::now();
+            self.start = format_local_time(now - chrono::Duration::hours(1));
+            self.end = format_local_time(now);
+        }
+        self.node = Some(node);
+        self.error = None;
+    }
+
+    
+    pub fn resolve_range(&self, now: DateTime<Utc>, lang: Language) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+        if let Some(duration) = self.range.duration() {
+            return Ok((now - duration, now));
+        }
+        let parse = |text: &str| parse_local_time(text).ok_or_else(|| i18n::t_fmt(T::InvalidHistoryTime, lang, &[text.trim()]));
+        let start = parse(&self.start)?;
+        let end = parse(&self.end)?;
+        if end <= start {
+            return Err(i18n::t(T::HistoryRangeInverted, lang).to_string());
+        }
+        Ok((start, end))
+    }
+
+    
+    pub fn show(&mut self, ctx: &egui::Context, lang: Language) -> Option<HistoryRequest> {
+        let node = self.node.clone()?;
+        let mut open = true;
+        let mut request = None;
+        egui::Window::new(format!("📜 {}", i18n::t(T::ReadHistory, lang)))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(&node.display_name).strong());
+                ui.label(egui::RichText::new(node.node_id.to_string()).monospace().weak());
+                ui.separator();
+
+                egui::Grid::new("history_range_grid").num_columns(2).spacing([10.0, 4.0]).show(ui, |ui| {
+                    ui.label(i18n::t(T::HistoryRange, lang));
+                    egui::ComboBox::from_id_salt("history_range")
+                        .selected_text(i18n::t(self.range.label(), lang))
+                        .show_ui(ui, |ui| {
+                            for range in HistoryRange::ALL {
+                                ui.selectable_value(&mut self.range, range, i18n::t(range.label(), lang));
+                            }
+                        });
+                    ui.end_row();
+
+                    let custom = self.range == HistoryRange::Custom;
+                    ui.label(i18n::t(T::HistoryStart, lang));
+                    ui.add_enabled(custom, egui::TextEdit::singleline(&mut self.start).hint_text(TIME_FORMATS[0]).desired_width(160.0));
+                    ui.end_row();
+
+                    ui.label(i18n::t(T::HistoryEnd, lang));
+                    ui.add_enabled(custom, egui::TextEdit::singleline(&mut self.end).hint_text(TIME_FORMATS[0]).desired_width(160.0));
+                    ui.end_row();
+
+                    ui.label(i18n::t(T::HistoryMaxValues, lang));
+                    ui.add(egui::DragValue::new(&mut self.max_values).range(1..=MAX_HISTORY_VALUES).speed(100));
+                    ui.end_row();
+                });
+
+                if let Some(error) = &self.error {
+                    ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+                }
+                ui.separator();
+                if ui.button(format!("📜 {}", i18n::t(T::ReadHistory, lang))).clicked() {
+                    match self.resolve_range(Utc::now(), lang) {
+                        Ok((start, end)) => {
+                            request = Some(HistoryRequest { node: node.clone(), start, end, max_values: self.max_values });
+                        }
+                        Err(error) => self.error = Some(error),
+                    }
+                }
+            });
+        if !open || request.is_some() {
+            self.node = None;
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_local_time_accepts_optional_seconds() {
+        let with_seconds = parse_local_time(" 2026-03-07 08:15:30 ").unwrap();
+        let without_seconds = parse_local_time("2026-03-07 08:15").unwrap();
+        assert_eq!((with_seconds - without_seconds).num_seconds(), 30);
+        assert_eq!(format_local_time(with_seconds), "2026-03-07 08:15:30");
+        assert!(parse_local_time("07/03/2026").is_none());
+    }
+
+    #[test]
+    fn test_resolve_range_for_presets_and_custom_input() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 7, 12, 0, 0).unwrap();
+        let mut dialog = HistoryDialog::default();
+        assert_eq!(dialog.resolve_range(now, Language::English), Ok((now - chrono::Duration::hours(1), now)));
+
+        dialog.range = HistoryRange::Custom;
+        dialog.start = "2026-03-07 10:00".into();
+        dialog.end = "2026-03-07 09:00".into();
+        assert!(dialog.resolve_range(now, Language::English).is_err());
+
+        dialog.end = "2026-03-07 11:00".into();
+        let (start, end) = dialog.resolve_range(now, Language::English).unwrap();
+        assert_eq!((end - start).num_hours(), 1);
+
+        dialog.start = "yesterday".into();
+        assert!(dialog.resolve_range(now, Language::English).unwrap_err().contains("yesterday"));
+    }
+}
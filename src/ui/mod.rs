@@ -1,11 +1,21 @@
 
 
+pub mod audit_panel;
 pub mod connection;
 pub mod dialogs;
 pub mod error_panel;
+pub mod events_panel;
+pub mod favorites;
+pub mod history_dialog;
 pub mod monitor;
 pub mod properties;
+pub mod settings_panel;
 pub mod tree_view;
 pub mod trending;
 pub mod crawler_panel;
 pub mod certificates_panel;
+pub mod server_diagnostics_panel;
+pub mod reachability_panel;
+pub mod tag_validation_panel;
+pub mod goto_bar;
+pub mod timestamp_format;
@@ -5,43 +5,173 @@
 
 use eframe::egui;
 use egui_extras::{Column, TableBuilder};
-use opcua::types::NodeId;
+use opcua::types::{NodeId, StatusCode, Variant};
 use std::collections::HashMap;
 
-use crate::opcua::subscription::MonitoredData;
+use crate::config::settings::Settings;
+use crate::export::{variant_to_csv, variant_type_name, NumberFormat};
+use crate::utils::timestamps::TimestampFormat;
+use crate::opcua::subscription::{Deadband, MonitoredItemConfig, MonitoredData, SubscriptionConfig, TrendStyle, MAX_REPAIR_ATTEMPTS};
 use crate::utils::i18n::{self, T, Language};
-use crate::ui::trending::color_for_node_id;
+use crate::ui::trending::{color_for_node_id, color_palette, trend_style_editor};
+use crate::utils::status_codes::{info_bits, LimitBits};
+use crate::ui::timestamp_format::TimestampFormatPicker;
+
+
+const STALE_INTERVALS: u32 = 3;
+
+
+const PUBLISHING_INTERVAL_CHOICES_MS: [u64; 7] = [100, 250, 500, 1000, 2000, 5000, 10_000];
+
+
+const RECENT_SAMPLES: usize = 5;
 
 
 pub enum MonitorAction {
     
     Remove(NodeId),
     
+    Detach(NodeId),
+    
     ToggleTrend(NodeId),
     
     ChangeColor(NodeId, [u8; 3]),
     
+    ChangeStyle(NodeId, Option<TrendStyle>),
+    
     ExportCsv,
     
     ExportJson,
+    
+    SaveToBookmark,
+    
+    ImportTagList,
+    
+    QuickAdd(NodeId),
+    
+    QuickAddInvalid(String),
+    
+    Recreate(NodeId),
+    
+    CreateAdditionalSubscription,
+    
+    SetPublishingInterval(u64),
+    
+    SetItemParameters(NodeId, MonitoredItemConfig),
 }
 
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QualityFilter {
+    #[default]
+    All,
+    Problems,
+    Stale,
+}
+
+
 #[derive(Default)]
-pub struct MonitorPanel;
+pub struct MonitorPanel {
+    
+    pub quality_filter: QualityFilter,
+    
+    pub name_filter: String,
+    
+    pub numeric_only: bool,
+    
+    pub export_filtered_only: bool,
+    
+    pub subscription_config: SubscriptionConfig,
+    
+    quick_add_input: String,
+    
+    quick_add_error: Option<String>,
+    
+    export_timestamp_picker: TimestampFormatPicker,
+    
+    selected_row: Option<NodeId>,
+    
+    parameter_draft: Option<(NodeId, MonitoredItemConfig)>,
+}
 
 impl MonitorPanel {
     
+    pub fn matches(&self, item: &MonitoredData, now: std::time::Instant) -> bool {
+        let quality_ok = match self.quality_filter {
+            QualityFilter::All => true,
+            QualityFilter::Problems => !item.status.is_good(),
+            QualityFilter::Stale => item.is_stale(
+                now,
+                self.subscription_config.publishing_interval() * STALE_INTERVALS,
+            ),
+        };
+        if !quality_ok || (self.numeric_only && !item.is_trendable()) {
+            return false;
+        }
+
+        let needle = self.name_filter.trim().to_lowercase();
+        needle.is_empty()
+            || item.display_name.to_lowercase().contains(&needle)
+            || item.node_id.to_string().to_lowercase().contains(&needle)
+    }
+
+    
+    pub fn export_items(&self, monitored_items: &HashMap<NodeId, MonitoredData>) -> Vec<MonitoredData> {
+        let now = std::time::Instant::now();
+        monitored_items
+            .values()
+            .filter(|item| !self.export_filtered_only || self.matches(item, now))
+            .cloned()
+            .collect()
+    }
+
+    
+    pub fn export_options(&self, context: crate::export::ExportContext) -> crate::export::ExportContext {
+        context
+            .with_option("filtered_only", self.export_filtered_only)
+            .with_option("quality_filter", format!("{:?}", self.quality_filter))
+            .with_option("name_filter", self.name_filter.trim())
+            .with_option("numeric_only", self.numeric_only)
+    }
+
+    
     pub fn show(
-        &self,
+        &mut self,
         ui: &mut egui::Ui,
         monitored_items: &HashMap<NodeId, MonitoredData>,
+        data_types: &HashMap<NodeId, String>,
+        can_save_to_bookmark: bool,
+        settings: &mut Settings,
         lang: Language,
     ) -> Option<MonitorAction> {
         let mut action: Option<MonitorAction> = None;
 
         ui.heading(format!("📊 {}", i18n::t(T::Watchlist, lang)));
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.quick_add_input)
+                    .hint_text("ns=2;s=Device.Temperature")
+                    .desired_width(260.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.button(format!("➕ {}", i18n::t(T::Add, lang))).clicked() || submitted) && !self.quick_add_input.trim().is_empty() {
+                match parse_quick_add(&self.quick_add_input, lang) {
+                    Ok(node_id) => {
+                        self.quick_add_error = None;
+                        self.quick_add_input.clear();
+                        action = Some(MonitorAction::QuickAdd(node_id));
+                    }
+                    Err(error) => {
+                        self.quick_add_error = Some(error.clone());
+                        action = Some(MonitorAction::QuickAddInvalid(error));
+                    }
+                }
+            }
+            if let Some(error) = &self.quick_add_error {
+                ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+            }
+        });
         ui.horizontal(|ui| {
              if ui.button(format!("💾 {}", i18n::t(T::ExportCSV, lang))).clicked() {
                  action = Some(MonitorAction::ExportCsv);
@@ -49,6 +179,83 @@ impl MonitorPanel {
              if ui.button(format!("💾 {}", i18n::t(T::ExportJSON, lang))).clicked() {
                  action = Some(MonitorAction::ExportJson);
              }
+             ui.menu_button("⚙", |ui| {
+                 let number_format = &mut settings.export_number_format;
+                 ui.label(i18n::t(T::NumberFormatLabel, lang));
+                 ui.radio_value(number_format, NumberFormat::FullPrecision, i18n::t(T::NumberFullPrecision, lang));
+                 ui.horizontal(|ui| {
+                     let mut decimals = match number_format {
+                         NumberFormat::Fixed(d) => *d,
+                         _ => 3,
+                     };
+                     ui.radio_value(number_format, NumberFormat::Fixed(decimals), i18n::t(T::NumberFixed, lang));
+                     if ui.add(egui::DragValue::new(&mut decimals).range(0..=12)).changed() {
+                         *number_format = NumberFormat::Fixed(decimals);
+                     }
+                 });
+                 ui.radio_value(number_format, NumberFormat::Shortest, i18n::t(T::NumberShortest, lang));
+                 ui.separator();
+                 ui.label(i18n::t(T::ExportTimestampFormat, lang));
+                 self.export_timestamp_picker.show(ui, &mut settings.export_timestamp_format, lang);
+             }).response.on_hover_text(i18n::t(T::ExportOptions, lang));
+             ui.checkbox(&mut self.export_filtered_only, i18n::t(T::ExportFilteredOnly, lang));
+             if ui.button("📥").on_hover_text(i18n::t(T::ImportTagList, lang)).clicked() {
+                 action = Some(MonitorAction::ImportTagList);
+             }
+             if can_save_to_bookmark && ui.button(format!("⭐ {}", i18n::t(T::SaveWatchlistToBookmark, lang))).clicked() {
+                 action = Some(MonitorAction::SaveToBookmark);
+             }
+             let current = self.subscription_config.publishing_interval_ms;
+             let mut selected = current;
+             egui::ComboBox::from_id_salt("publishing_interval")
+                 .selected_text(format!("⏱ {} ms", current))
+                 .show_ui(ui, |ui| {
+                     for ms in PUBLISHING_INTERVAL_CHOICES_MS {
+                         ui.selectable_value(&mut selected, ms, format!("{} ms", ms));
+                     }
+                 })
+                 .response
+                 .on_hover_text(i18n::t(T::PublishingIntervalHint, lang));
+             if selected != current {
+                 action = Some(MonitorAction::SetPublishingInterval(selected));
+             }
+        });
+
+        let over_limit = monitored_items.values()
+            .filter(|item| item.creation_failed() && item.status == StatusCode::BadTooManyMonitoredItems)
+            .count();
+        if over_limit > 0 {
+            ui.horizontal(|ui| {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 165, 0),
+                    format!("⚠ {}", i18n::t_fmt(T::MonitoredItemLimitReached, lang, &[&over_limit.to_string()])),
+                );
+                if ui.button(format!("➕ {}", i18n::t(T::CreateAdditionalSubscription, lang))).clicked() {
+                    action = Some(MonitorAction::CreateAdditionalSubscription);
+                }
+            });
+        }
+
+        let now = std::time::Instant::now();
+        let mut keys: Vec<&NodeId> = monitored_items
+            .iter()
+            .filter(|(_, item)| self.matches(item, now))
+            .map(|(k, _)| k)
+            .collect();
+        keys.sort_by_key(|k| &monitored_items[k].display_name);
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.quality_filter, QualityFilter::All, i18n::t(T::FilterAll, lang));
+            ui.selectable_value(&mut self.quality_filter, QualityFilter::Problems, i18n::t(T::FilterProblems, lang));
+            ui.selectable_value(&mut self.quality_filter, QualityFilter::Stale, i18n::t(T::FilterStale, lang));
+            ui.separator();
+            ui.checkbox(&mut self.numeric_only, i18n::t(T::NumericOnly, lang));
+            ui.separator();
+            ui.label(format!("🔍 {}", i18n::t(T::FilterName, lang)));
+            ui.add(egui::TextEdit::singleline(&mut self.name_filter).desired_width(150.0));
+            ui.label(
+                i18n::t_fmt(T::ShowingItems, lang, &[&keys.len().to_string(), &monitored_items.len().to_string()]),
+            );
         });
         ui.separator();
 
@@ -57,7 +264,18 @@ impl MonitorPanel {
             ui.centered_and_justified(|ui| {
                 ui.label(i18n::t(T::NoItems, lang));
             });
-            return None;
+            return action;
+        }
+
+        if let Some(item) = self.selected_row.as_ref().and_then(|id| monitored_items.get(id)) {
+            let mut close = false;
+            egui::SidePanel::right("watchlist_detail_drawer")
+                .resizable(true)
+                .default_width(320.0)
+                .show_inside(ui, |ui| close = show_item_details(ui, item, lang));
+            if close {
+                self.selected_row = None;
+            }
         }
 
         
@@ -66,22 +284,20 @@ impl MonitorPanel {
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
             .column(Column::auto().resizable(true)) 
+            .column(Column::auto())                 
             .column(Column::remainder())            
             .column(Column::auto())                 
             .column(Column::auto())                 
             .column(Column::auto())                 
             .header(20.0, |mut header| {
                 header.col(|ui| { ui.strong(i18n::t(T::Node, lang)); });
+                header.col(|ui| { ui.strong(i18n::t(T::DataType, lang)); });
                 header.col(|ui| { ui.strong(i18n::t(T::Value, lang)); });
                 header.col(|ui| { ui.strong(i18n::t(T::Quality, lang)); });
                 header.col(|ui| { ui.strong(i18n::t(T::Timestamp, lang)); });
                 header.col(|ui| { ui.strong(i18n::t(T::Actions, lang)); });
             })
             .body(|mut body| {
-                
-                let mut keys: Vec<&NodeId> = monitored_items.keys().collect();
-                keys.sort_by_key(|k| &monitored_items[k].display_name);
-
                 for node_id in keys {
                     let item = &monitored_items[node_id];
                     let is_trendable = item.is_trendable();
@@ -89,12 +305,43 @@ impl MonitorPanel {
                     body.row(20.0, |mut row| {
                         
                         row.col(|ui| {
-                            ui.label(&item.display_name).on_hover_text(node_id.to_string());
+                            let is_selected = self.selected_row.as_ref() == Some(node_id);
+                            let name = if item.creation_failed() {
+                                egui::RichText::new(&item.display_name).color(egui::Color32::RED)
+                            } else {
+                                egui::RichText::new(&item.display_name)
+                            };
+                            let response = ui.selectable_label(is_selected, name).on_hover_text(node_id.to_string());
+                            if response.clicked() {
+                                self.selected_row = if is_selected { None } else { Some(node_id.clone()) };
+                            }
+                            response.context_menu(|ui| {
+                                if (item.is_invalidated() || item.creation_failed())
+                                    && ui.button(format!("🔄 {}", i18n::t(T::Recreate, lang))).clicked()
+                                {
+                                    action = Some(MonitorAction::Recreate(node_id.clone()));
+                                    ui.close_menu();
+                                }
+                                ui.menu_button(format!("⚙ {}", i18n::t(T::MonitoringParameters, lang)), |ui| {
+                                    if let Some(applied) = self.parameters_menu(ui, item, lang) {
+                                        action = Some(applied);
+                                    }
+                                });
+                            });
+                        });
+
+                        
+                        row.col(|ui| {
+                            ui.weak(data_types.get(node_id).map(String::as_str).unwrap_or("—"));
                         });
 
                         
                         row.col(|ui| {
-                            ui.label(item.value_string());
+                            if item.creation_failed() {
+                                ui.colored_label(egui::Color32::RED, crate::opcua::status_codes::translate_status_code(item.status));
+                            } else {
+                                ui.label(item.value_string());
+                            }
                         });
 
                         
@@ -104,13 +351,29 @@ impl MonitorPanel {
                                 "?" => ("?", egui::Color32::from_rgb(255, 165, 0)), 
                                 _ => ("!", egui::Color32::RED),
                             };
-                            ui.colored_label(color, text)
-                                .on_hover_text(crate::opcua::status_codes::translate_status_code(item.status));
+                            ui.horizontal(|ui| {
+                                let mut tooltip = crate::opcua::status_codes::translate_status_code(item.status);
+                                if let Some(limit) = limit_label(item.info_bits.limit, lang) {
+                                    tooltip.push_str(&format!("\n{}", limit));
+                                }
+                                ui.colored_label(color, text).on_hover_text(tooltip);
+                                if item.repair_exhausted() {
+                                    ui.colored_label(egui::Color32::RED, format!("⛓ {}", i18n::t(T::MonitoredItemInvalidated, lang)))
+                                        .on_hover_text(i18n::t_fmt(T::MonitoredItemRepairFailed, lang, &[&MAX_REPAIR_ATTEMPTS.to_string()]));
+                                } else if item.is_invalidated() {
+                                    ui.colored_label(egui::Color32::from_rgb(255, 165, 0), format!("⛓ {}", i18n::t(T::MonitoredItemInvalidated, lang)))
+                                        .on_hover_text(i18n::t(T::MonitoredItemRepairing, lang));
+                                }
+                                if item.overflow_count > 0 {
+                                    ui.colored_label(egui::Color32::from_rgb(255, 165, 0), format!("⚠ {}", i18n::t(T::Overflow, lang)))
+                                        .on_hover_text(i18n::t_fmt(T::OverflowCount, lang, &[&item.overflow_count.to_string()]));
+                                }
+                            });
                         });
 
                         
                         row.col(|ui| {
-                            ui.label(item.timestamp_string());
+                            ui.label(item.timestamp_string(&settings.timestamp_format));
                         });
 
                         
@@ -140,35 +403,24 @@ impl MonitorPanel {
                                     
                                     response.context_menu(|ui| {
                                         ui.label("Select color:");
-                                        
-                                        
-                                        let colors: [[u8; 3]; 12] = [
-                                            [255, 0, 0],     
-                                            [0, 255, 0],     
-                                            [0, 0, 255],     
-                                            [255, 255, 0],   
-                                            [255, 0, 255],   
-                                            [0, 255, 255],   
-                                            [255, 128, 0],   
-                                            [128, 0, 255],   
-                                            [0, 255, 128],   
-                                            [255, 128, 128], 
-                                            [128, 255, 128], 
-                                            [128, 128, 255], 
-                                        ];
-                                        
-                                        ui.horizontal_wrapped(|ui| {
-                                            for rgb in &colors {
-                                                let color = egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
-                                                if ui.add(egui::Button::new("  ").fill(color)).clicked() {
-                                                    action = Some(MonitorAction::ChangeColor(node_id.clone(), *rgb));
-                                                    ui.close_menu();
-                                                }
-                                            }
-                                        });
+                                        if let Some(rgb) = color_palette(ui) {
+                                            action = Some(MonitorAction::ChangeColor(node_id.clone(), rgb));
+                                            ui.close_menu();
+                                        }
+
+                                        ui.separator();
+                                        ui.label(i18n::t(T::TrendLineStyle, lang));
+                                        let mut style = item.trend_style.unwrap_or(settings.trend_style);
+                                        if trend_style_editor(ui, &mut style, lang) {
+                                            action = Some(MonitorAction::ChangeStyle(node_id.clone(), Some(style)));
+                                        }
+                                        if item.trend_style.is_some() && ui.button(i18n::t(T::UseDefaultTrendStyle, lang)).clicked() {
+                                            action = Some(MonitorAction::ChangeStyle(node_id.clone(), None));
+                                            ui.close_menu();
+                                        }
                                     });
                                     
-                                    response.on_hover_text("Right-click to change color");
+                                    response.on_hover_text(i18n::t(T::TrendSwatchHint, lang));
                                 }
                                 
                                 
@@ -188,10 +440,37 @@ impl MonitorPanel {
                                         .on_disabled_hover_text("Cannot graph non-numeric values (dates, strings)");
                                 }
 
+                                let gear = if item.parameters.is_default() {
+                                    egui::RichText::new("⚙")
+                                } else {
+                                    egui::RichText::new("⚙").color(egui::Color32::from_rgb(100, 180, 255))
+                                };
+                                let menu = ui.menu_button(gear, |ui| {
+                                    if let Some(applied) = self.parameters_menu(ui, item, lang) {
+                                        action = Some(applied);
+                                    }
+                                });
+                                if menu.response.clicked() {
+                                    self.parameter_draft = Some((node_id.clone(), item.parameters));
+                                }
+                                menu.response.on_hover_text(i18n::t(T::MonitoringParameters, lang));
+
                                 
-                                if ui.button("🗑").on_hover_text(i18n::t(T::Remove, lang)).clicked() {
+                                let remove = ui.button("🗑").on_hover_text(i18n::t(T::RemoveOrDetachHint, lang));
+                                if remove.clicked() {
                                     action = Some(MonitorAction::Remove(node_id.clone()));
                                 }
+                                remove.context_menu(|ui| {
+                                    if ui.button(i18n::t(T::Remove, lang)).clicked() {
+                                        action = Some(MonitorAction::Remove(node_id.clone()));
+                                        ui.close_menu();
+                                    }
+                                    let can_detach = is_trendable && !item.history.is_empty();
+                                    if ui.add_enabled(can_detach, egui::Button::new(format!("📌 {}", i18n::t(T::DetachKeepCurve, lang)))).clicked() {
+                                        action = Some(MonitorAction::Detach(node_id.clone()));
+                                        ui.close_menu();
+                                    }
+                                });
                             });
                         });
                     });
@@ -200,4 +479,264 @@ impl MonitorPanel {
 
         action
     }
+
+    
+    fn parameters_menu(&mut self, ui: &mut egui::Ui, item: &MonitoredData, lang: Language) -> Option<MonitorAction> {
+        if self.parameter_draft.as_ref().map_or(true, |(id, _)| *id != item.node_id) {
+            self.parameter_draft = Some((item.node_id.clone(), item.parameters));
+        }
+        let (_, draft) = self.parameter_draft.as_mut()?;
+        ui.strong(i18n::t(T::MonitoringParameters, lang));
+        item_parameters_editor(ui, draft, self.subscription_config.publishing_interval_ms as f64, lang);
+        ui.separator();
+        if ui.button(i18n::t(T::Apply, lang)).clicked() {
+            ui.close_menu();
+            return Some(MonitorAction::SetItemParameters(item.node_id.clone(), *draft));
+        }
+        None
+    }
+}
+
+
+pub fn parse_quick_add(input: &str, lang: Language) -> Result<NodeId, String> {
+    match input.trim().parse::<NodeId>() {
+        Ok(node_id) if !node_id.is_null() => Ok(node_id),
+        _ => Err(i18n::t_fmt(T::InvalidNodeId, lang, &[input.trim()])),
+    }
+}
+
+
+fn item_parameters_editor(ui: &mut egui::Ui, parameters: &mut MonitoredItemConfig, default_interval_ms: f64, lang: Language) {
+    egui::Grid::new("item_parameters_grid").num_columns(2).spacing([10.0, 4.0]).show(ui, |ui| {
+        let mut custom_interval = parameters.sampling_interval_ms.is_some();
+        ui.checkbox(&mut custom_interval, i18n::t(T::SamplingInterval, lang));
+        let mut interval = parameters.sampling_interval_ms.unwrap_or(default_interval_ms);
+        ui.add_enabled(custom_interval, egui::DragValue::new(&mut interval).range(0.0..=3_600_000.0).speed(10.0).suffix(" ms"));
+        parameters.sampling_interval_ms = custom_interval.then_some(interval);
+        ui.end_row();
+
+        let mut custom_queue = parameters.queue_size.is_some();
+        ui.checkbox(&mut custom_queue, i18n::t(T::QueueSize, lang));
+        let mut queue_size = parameters.queue_size.unwrap_or(1);
+        ui.add_enabled(custom_queue, egui::DragValue::new(&mut queue_size).range(1..=10_000));
+        parameters.queue_size = custom_queue.then_some(queue_size);
+        ui.end_row();
+
+        ui.label(i18n::t(T::Deadband, lang));
+        ui.horizontal(|ui| {
+            let value = match parameters.deadband {
+                Deadband::None => 0.0,
+                Deadband::Absolute(value) | Deadband::Percent(value) => value,
+            };
+            egui::ComboBox::from_id_salt("item_deadband")
+                .selected_text(i18n::t(deadband_label(parameters.deadband), lang))
+                .show_ui(ui, |ui| {
+                    for choice in [Deadband::None, Deadband::Absolute(value), Deadband::Percent(value.min(100.0))] {
+                        let selected = std::mem::discriminant(&choice) == std::mem::discriminant(&parameters.deadband);
+                        if ui.selectable_label(selected, i18n::t(deadband_label(choice), lang)).clicked() {
+                            parameters.deadband = choice;
+                        }
+                    }
+                });
+            match &mut parameters.deadband {
+                Deadband::None => {}
+                Deadband::Absolute(value) => {
+                    ui.add(egui::DragValue::new(value).range(0.0..=f64::MAX).speed(0.1));
+                }
+                Deadband::Percent(value) => {
+                    ui.add(egui::DragValue::new(value).range(0.0..=100.0).speed(0.1).suffix(" %"));
+                }
+            }
+        });
+        ui.end_row();
+    });
+    ui.weak(i18n::t(T::MonitoringParametersHint, lang));
+}
+
+
+fn deadband_label(deadband: Deadband) -> T {
+    match deadband {
+        Deadband::None => T::DeadbandNone,
+        Deadband::Absolute(_) => T::DeadbandAbsolute,
+        Deadband::Percent(_) => T::DeadbandPercent,
+    }
+}
+
+
+pub fn item_details(item: &MonitoredData, lang: Language) -> Vec<(String, String)> {
+    let iso = TimestampFormat::Iso8601Millis;
+    let timestamp = |ts: &Option<opcua::types::DateTime>| {
+        ts.map(|t| iso.format(t.as_chrono())).unwrap_or_else(|| "---".to_string())
+    };
+    let optional = |value: Option<String>| value.unwrap_or_else(|| "---".to_string());
+    let value = item.value.clone().unwrap_or(Variant::Empty);
+    let skew = match (item.source_timestamp, item.server_timestamp) {
+        (Some(source), Some(server)) => format!(
+            "{:+} ms",
+            (server.as_chrono() - source.as_chrono()).num_milliseconds()
+        ),
+        _ => "---".to_string(),
+    };
+    let bits = info_bits(item.status.bits());
+    let mut flags: Vec<&str> = limit_label(bits.limit, lang).into_iter().collect();
+    if bits.overflow {
+        flags.push(i18n::t(T::Overflow, lang));
+    }
+
+    let mut details = vec![
+        (i18n::t(T::Node, lang).to_string(), item.display_name.clone()),
+        (i18n::t(T::NodeId, lang).to_string(), item.node_id.to_string()),
+        (i18n::t(T::Value, lang).to_string(), variant_to_csv(&value, NumberFormat::FullPrecision)),
+        (i18n::t(T::VariantType, lang).to_string(), variant_type_name(&value)),
+        (i18n::t(T::SourceTimestamp, lang).to_string(), timestamp(&item.source_timestamp)),
+        (i18n::t(T::ServerTimestamp, lang).to_string(), timestamp(&item.server_timestamp)),
+        (i18n::t(T::TimestampSkew, lang).to_string(), skew),
+        (i18n::t(T::Status, lang).to_string(), format!("0x{:08X} {}", item.status.bits(), item.status)),
+        (i18n::t(T::InfoBits, lang).to_string(), if flags.is_empty() { "---".to_string() } else { flags.join(", ") }),
+        (i18n::t(T::MonitoredItemId, lang).to_string(), optional(item.monitored_item_id.map(|id| id.to_string()))),
+        (i18n::t(T::ClientHandle, lang).to_string(), optional(item.client_handle.map(|h| h.to_string()))),
+        (i18n::t(T::RevisedSamplingInterval, lang).to_string(), optional(item.revised_sampling_interval.map(|ms| format!("{} ms", ms)))),
+        (i18n::t(T::RevisedQueueSize, lang).to_string(), optional(item.revised_queue_size.map(|q| q.to_string()))),
+    ];
+    for (i, (ts, value)) in item.history.iter().rev().take(RECENT_SAMPLES).enumerate() {
+        let at = chrono::DateTime::from_timestamp_millis((ts * 1000.0).round() as i64)
+            .map(|dt| iso.format(dt))
+            .unwrap_or_else(|| ts.to_string());
+        details.push((format!("{} #{}", i18n::t(T::RecentSample, lang), i + 1), format!("{} @ {}", value, at)));
+    }
+    details
+}
+
+
+pub fn details_as_text(details: &[(String, String)]) -> String {
+    details.iter().map(|(label, value)| format!("{}: {}", label, value)).collect::<Vec<_>>().join("\n")
+}
+
+
+fn show_item_details(ui: &mut egui::Ui, item: &MonitoredData, lang: Language) -> bool {
+    let mut close = false;
+    let details = item_details(item, lang);
+    ui.horizontal(|ui| {
+        ui.strong(format!("🔎 {}", i18n::t(T::ItemDetails, lang)));
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            close = ui.small_button("✖").on_hover_text(i18n::t(T::Close, lang)).clicked();
+            if ui.small_button("📋").on_hover_text(i18n::t(T::CopyAllAsText, lang)).clicked() {
+                ui.ctx().copy_text(details_as_text(&details));
+            }
+        });
+    });
+    ui.separator();
+    egui::ScrollArea::vertical().id_salt("watchlist_detail_scroll").show(ui, |ui| {
+        egui::Grid::new("watchlist_detail_grid")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for (label, value) in &details {
+                    ui.label(label);
+                    ui.add(egui::Label::new(egui::RichText::new(value).monospace()).wrap());
+                    ui.end_row();
+                }
+            });
+    });
+    close
+}
+
+
+fn limit_label(limit: LimitBits, lang: Language) -> Option<&'static str> {
+    match limit {
+        LimitBits::None => None,
+        LimitBits::Low => Some(i18n::t(T::LimitLow, lang)),
+        LimitBits::High => Some(i18n::t(T::LimitHigh, lang)),
+        LimitBits::Constant => Some(i18n::t(T::LimitConstant, lang)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcua::types::{DataValue, StatusCode, Variant};
+
+    fn item(name: &str, status: StatusCode) -> MonitoredData {
+        let mut item = MonitoredData::new(NodeId::new(2, name), name.to_string());
+        let mut value = DataValue::new_now(Variant::Double(1.0));
+        value.status = Some(status);
+        item.update(&value);
+        item
+    }
+
+    #[test]
+    fn test_filters_compose() {
+        let now = std::time::Instant::now();
+        let good = item("Pump.Speed", StatusCode::Good);
+        let bad = item("Pump.Pressure", StatusCode::BadCommunicationError);
+        let waiting = MonitoredData::new(NodeId::new(2, "Valve"), "Valve".to_string());
+
+        let mut panel = MonitorPanel::default();
+        assert!(panel.matches(&good, now) && panel.matches(&bad, now));
+
+        panel.quality_filter = QualityFilter::Problems;
+        assert!(!panel.matches(&good, now));
+        assert!(panel.matches(&bad, now));
+
+        panel.quality_filter = QualityFilter::Stale;
+        assert!(panel.matches(&waiting, now));
+        assert!(!panel.matches(&good, now));
+        assert!(panel.matches(&good, now + std::time::Duration::from_secs(10)));
+
+        panel.quality_filter = QualityFilter::All;
+        panel.name_filter = "pressure".to_string();
+        assert!(!panel.matches(&good, now));
+        assert!(panel.matches(&bad, now));
+
+        panel.name_filter.clear();
+        panel.numeric_only = true;
+        assert!(panel.matches(&good, now));
+        assert!(!panel.matches(&waiting, now));
+    }
+
+    #[test]
+    fn test_item_details_cover_the_full_data_value() {
+        use opcua::types::DateTime;
+
+        let mut data = MonitoredData::new(NodeId::new(2, "Flow"), "Flow".to_string());
+        let source = DateTime::from(chrono::DateTime::from_timestamp_millis(1_700_000_000_000).unwrap());
+        let server = DateTime::from(chrono::DateTime::from_timestamp_millis(1_700_000_000_040).unwrap());
+        for i in 0..7 {
+            let mut value = DataValue::new_now(Variant::Double(0.1 + i as f64));
+            value.source_timestamp = Some(source);
+            value.server_timestamp = Some(server);
+            value.status = Some(StatusCode::from(0x0000_0480));
+            data.update(&value);
+        }
+        data.monitored_item_id = Some(42);
+        data.client_handle = Some(7);
+        data.revised_queue_size = Some(10);
+
+        let details = item_details(&data, Language::English);
+        let get = |label: &str| details.iter().find(|(l, _)| l == label).map(|(_, v)| v.as_str());
+        assert_eq!(get("Value"), Some("6.1"));
+        assert_eq!(get("Variant type"), Some("Double"));
+        assert_eq!(get("Source timestamp"), Some("2023-11-14T22:13:20.000Z"));
+        assert_eq!(get("Timestamp skew"), Some("+40 ms"));
+        assert_eq!(get("Info bits"), Some("overflow"));
+        assert_eq!(get("Monitored item ID"), Some("42"));
+        assert_eq!(get("Revised sampling interval"), Some("---"));
+        assert_eq!(details.iter().filter(|(l, _)| l.starts_with("Recent sample")).count(), RECENT_SAMPLES);
+
+        let text = details_as_text(&details);
+        assert!(text.lines().any(|line| line == "Client handle: 7"));
+    }
+
+    #[test]
+    fn test_quick_add_accepts_every_node_id_form() {
+        let plc = parse_quick_add(r#" ns=3;s="DB_Data"."Temperature" "#, Language::English).unwrap();
+        assert_eq!(plc, NodeId::new(3, r#""DB_Data"."Temperature""#));
+        assert_eq!(parse_quick_add("i=2258", Language::English), Ok(NodeId::new(0, 2258u32)));
+        assert!(parse_quick_add("ns=2;g=72962B91-FA75-4AE6-8D28-B404DC7DAF63", Language::English).is_ok());
+        assert!(parse_quick_add("ns=2;b=M/RbKBsRVkePCePcx24oRA==", Language::English).is_ok());
+
+        assert_eq!(parse_quick_add("DB_Data.Temperature", Language::English), Err("'DB_Data.Temperature' is not a valid NodeId".to_string()));
+        assert!(parse_quick_add("i=0", Language::English).is_err());
+    }
 }
@@ -1,29 +1,112 @@
+use std::collections::HashMap;
+
 use eframe::egui;
-use crate::opcua::browser::{BrowsedNode, NodeClass};
+use opcua::types::{AttributeId, DataValue, Variant};
+use crate::export::{variant_to_binary_hex, variant_to_ua_json, variant_type_name};
+use crate::opcua::browser::{ArgumentInfo, BrowsedNode, NodeClass};
+use crate::opcua::node_attributes::{access_level_label, array_dimensions_label, NodeAttributes};
+use crate::opcua::subscription::MonitoredData;
 use crate::utils::i18n::{self, T, Language};
+use crate::utils::status_codes::{status_code_color, translate_status_code};
+use crate::utils::timestamps::TimestampFormat;
 
 
 pub enum PropertiesAction {
     AddToWatchlist(BrowsedNode),
+    NavigateTo(opcua::types::NodeId),
+    ToggleFavorite(BrowsedNode),
+    ExportNodeDetails(BrowsedNode),
+    
+    RefreshValue(opcua::types::NodeId),
+    
+    ReadHistory(BrowsedNode),
+}
+
+
+pub enum ValueRead {
+    
+    Pending,
+    
+    Done(DataValue),
+}
+
+
+pub enum MethodArgumentsRead {
+    
+    Pending,
+    
+    Done { inputs: Vec<ArgumentInfo>, outputs: Vec<ArgumentInfo> },
+    
+    Failed(String),
 }
 
 
 pub struct PropertiesPanel<'a> {
     selected_node: &'a Option<BrowsedNode>,
     monitored_data: Option<&'a crate::opcua::subscription::MonitoredData>,
+    type_label: Option<String>,
+    is_favorite: bool,
+    attributes: Option<&'a NodeAttributes>,
+    data_type_label: Option<String>,
+    value_read: Option<&'a ValueRead>,
+    method_arguments: Option<&'a MethodArgumentsRead>,
+    argument_type_labels: HashMap<opcua::types::NodeId, String>,
 }
 
 impl<'a> PropertiesPanel<'a> {
     pub fn new(
         selected_node: &'a Option<BrowsedNode>,
         monitored_data: Option<&'a crate::opcua::subscription::MonitoredData>,
+        type_label: Option<String>,
+        is_favorite: bool,
+        attributes: Option<&'a NodeAttributes>,
+        data_type_label: Option<String>,
+        value_read: Option<&'a ValueRead>,
     ) -> Self {
-        Self { selected_node, monitored_data }
+        Self {
+            selected_node,
+            monitored_data,
+            type_label,
+            is_favorite,
+            attributes,
+            data_type_label,
+            value_read,
+            method_arguments: None,
+            argument_type_labels: HashMap::new(),
+        }
     }
 
-    pub fn show(&self, ui: &mut egui::Ui, lang: Language) -> Option<PropertiesAction> {
+    
+    pub fn with_method_arguments(
+        mut self,
+        method_arguments: Option<&'a MethodArgumentsRead>,
+        argument_type_labels: HashMap<opcua::types::NodeId, String>,
+    ) -> Self {
+        self.method_arguments = method_arguments;
+        self.argument_type_labels = argument_type_labels;
+        self
+    }
+
+    pub fn show(&self, ui: &mut egui::Ui, timestamps: &TimestampFormat, lang: Language) -> Option<PropertiesAction> {
         let mut action = None;
-        ui.heading(i18n::t(T::Properties, lang));
+        ui.horizontal(|ui| {
+            ui.heading(i18n::t(T::Properties, lang));
+            if let Some(node) = self.selected_node {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let (star, hint) = if self.is_favorite {
+                        ("⭐", T::RemoveFromFavorites)
+                    } else {
+                        ("☆", T::AddToFavorites)
+                    };
+                    if ui.selectable_label(self.is_favorite, star).on_hover_text(i18n::t(hint, lang)).clicked() {
+                        action = Some(PropertiesAction::ToggleFavorite(node.clone()));
+                    }
+                    if ui.small_button("🧾").on_hover_text(i18n::t(T::ExportNodeDetails, lang)).clicked() {
+                        action = Some(PropertiesAction::ExportNodeDetails(node.clone()));
+                    }
+                });
+            }
+        });
         ui.separator();
 
         if let Some(node) = self.selected_node {
@@ -59,7 +142,12 @@ impl<'a> PropertiesPanel<'a> {
 
                     if let Some(type_def) = &node.type_definition {
                         ui.label("Type Def:");
-                        ui.label(type_def.to_string());
+                        ui.horizontal(|ui| {
+                            ui.label(self.type_label.clone().unwrap_or_else(|| type_def.to_string()));
+                            if ui.button("→").on_hover_text(i18n::t(T::GoToTypeDefinition, lang)).clicked() {
+                                action = Some(PropertiesAction::NavigateTo(type_def.clone()));
+                            }
+                        });
                         ui.end_row();
                     }
 
@@ -70,11 +158,35 @@ impl<'a> PropertiesPanel<'a> {
                         ui.end_row();
 
                         ui.label(format!("{} ", i18n::t(T::Timestamp, lang)));
-                        ui.label(data.timestamp_string());
+                        ui.label(data.timestamp_string(timestamps));
                         ui.end_row();
+                    } else if let Some(read) = self.value_read.filter(|_| node.node_class == NodeClass::Variable) {
+                        if let Some(node_id) = show_value_read(ui, &node.node_id, read, timestamps, lang) {
+                            action = Some(PropertiesAction::RefreshValue(node_id));
+                        }
+                    } else if let Some(value) = self.attributes.and_then(|attrs| attrs.value.as_ref()).and_then(|dv| dv.value.as_ref()) {
+                        ui.label(format!("{} ", i18n::t(T::Value, lang)));
+                        ui.label(egui::RichText::new(value.to_string()).strong());
+                        ui.end_row();
+                    }
+
+                    if let Some(attrs) = self.attributes {
+                        self.show_attributes(ui, attrs, lang);
                     }
                 });
 
+            if let Some(data) = self.monitored_data {
+                ui.add_space(5.0);
+                egui::CollapsingHeader::new(format!("🔬 {}", i18n::t(T::RawDataValue, lang)))
+                    .default_open(false)
+                    .show(ui, |ui| show_raw_data_value(ui, data, timestamps, lang));
+            }
+
+            if let Some(read) = self.method_arguments.filter(|_| node.node_class == NodeClass::Method) {
+                ui.add_space(5.0);
+                self.show_method_arguments(ui, read, lang);
+            }
+
             ui.add_space(20.0);
             
             
@@ -85,6 +197,9 @@ impl<'a> PropertiesPanel<'a> {
                     if ui.button(format!("📊 {}", i18n::t(T::Watchlist, lang))).on_hover_text("Monitor this value in real-time").clicked() {
                         action = Some(PropertiesAction::AddToWatchlist(node.clone()));
                     }
+                    if ui.button(format!("📜 {}", i18n::t(T::ReadHistory, lang))).on_hover_text(i18n::t(T::ReadHistoryHint, lang)).clicked() {
+                        action = Some(PropertiesAction::ReadHistory(node.clone()));
+                    }
                 });
             }
 
@@ -94,4 +209,207 @@ impl<'a> PropertiesPanel<'a> {
         
         action
     }
+
+    fn show_attributes(&self, ui: &mut egui::Ui, attrs: &NodeAttributes, lang: Language) {
+        let row = |ui: &mut egui::Ui, key: T, text: String| {
+            ui.label(i18n::t(key, lang));
+            ui.label(text);
+            ui.end_row();
+        };
+        if let Some(data_type) = &attrs.data_type {
+            row(ui, T::DataType, self.data_type_label.clone().unwrap_or_else(|| data_type.to_string()));
+        }
+        if let Some(level) = attrs.access_level {
+            row(ui, T::AccessLevel, access_level_label(level));
+        }
+        if let Some(level) = attrs.user_access_level {
+            row(ui, T::UserAccessLevel, access_level_label(level));
+        }
+        if let Some(description) = &attrs.description {
+            row(ui, T::Description, description.clone());
+        }
+        if let Some(rank) = attrs.value_rank {
+            row(ui, T::ValueRank, rank.to_string());
+        }
+        if let Some(interval) = attrs.minimum_sampling_interval {
+            row(ui, T::MinimumSamplingInterval, format!("{} ms", interval));
+        }
+        if let Some(historizing) = attrs.historizing {
+            row(ui, T::Historizing, historizing.to_string());
+        }
+        if let Some(dimensions) = &attrs.array_dimensions {
+            row(ui, T::ArrayDimensions, array_dimensions_label(dimensions));
+        }
+        for (attribute, status) in &attrs.refused {
+            ui.label(egui::RichText::new(i18n::t(attribute_label(*attribute), lang)).weak());
+            ui.label(egui::RichText::new("—").weak())
+                .on_hover_text(i18n::t_fmt(T::AttributeRefused, lang, &[&format!("0x{:08X} {}", status.bits(), translate_status_code(status.bits()))]));
+            ui.end_row();
+        }
+    }
+
+    
+    fn show_method_arguments(&self, ui: &mut egui::Ui, read: &MethodArgumentsRead, lang: Language) {
+        let (inputs, outputs) = match read {
+            MethodArgumentsRead::Pending => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(i18n::t(T::ReadingMethodArguments, lang));
+                });
+                return;
+            }
+            MethodArgumentsRead::Failed(error) => {
+                ui.colored_label(egui::Color32::from_rgb(255, 100, 100), i18n::t_fmt(T::MethodArgumentsFailed, lang, &[error]));
+                return;
+            }
+            MethodArgumentsRead::Done { inputs, outputs } => (inputs, outputs),
+        };
+        for (id, title, arguments) in [("input_arguments", T::InputArguments, inputs), ("output_arguments", T::OutputArguments, outputs)] {
+            egui::CollapsingHeader::new(format!("{} ({})", i18n::t(title, lang), arguments.len()))
+                .id_salt(id)
+                .default_open(true)
+                .show(ui, |ui| {
+                    if arguments.is_empty() {
+                        ui.label(egui::RichText::new(i18n::t(T::NoArguments, lang)).weak());
+                        return;
+                    }
+                    egui::Grid::new(format!("{}_grid", id))
+                        .num_columns(3)
+                        .spacing([10.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong(i18n::t(T::ArgumentName, lang));
+                            ui.strong(i18n::t(T::DataType, lang));
+                            ui.strong(i18n::t(T::Description, lang));
+                            ui.end_row();
+
+                            for argument in arguments {
+                                ui.monospace(&argument.name);
+                                ui.label(self.argument_type_label(argument))
+                                    .on_hover_text(argument.data_type.to_string());
+                                ui.label(&argument.description);
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+    }
+
+    
+    fn argument_type_label(&self, argument: &ArgumentInfo) -> String {
+        let name = self.argument_type_labels
+            .get(&argument.data_type)
+            .cloned()
+            .unwrap_or_else(|| argument.data_type.to_string());
+        match argument.value_rank {
+            rank if rank >= 1 && !argument.array_dimensions.is_empty() => format!("{}[{}]", name, array_dimensions_label(&argument.array_dimensions)),
+            rank if rank >= 1 => format!("{}{}", name, "[]".repeat(rank as usize)),
+            _ => name,
+        }
+    }
+}
+
+
+fn attribute_label(attribute: AttributeId) -> T {
+    match attribute {
+        AttributeId::Value => T::Value,
+        AttributeId::DataType => T::DataType,
+        AttributeId::AccessLevel => T::AccessLevel,
+        AttributeId::UserAccessLevel => T::UserAccessLevel,
+        AttributeId::Description => T::Description,
+        AttributeId::ValueRank => T::ValueRank,
+        AttributeId::MinimumSamplingInterval => T::MinimumSamplingInterval,
+        AttributeId::Historizing => T::Historizing,
+        AttributeId::ArrayDimensions => T::ArrayDimensions,
+        _ => T::Attribute,
+    }
+}
+
+
+fn show_value_read(
+    ui: &mut egui::Ui,
+    node_id: &opcua::types::NodeId,
+    read: &ValueRead,
+    timestamps: &TimestampFormat,
+    lang: Language,
+) -> Option<opcua::types::NodeId> {
+    let mut refresh = None;
+    ui.label(format!("{} ", i18n::t(T::Value, lang)));
+    ui.horizontal(|ui| {
+        let pending = matches!(read, ValueRead::Pending);
+        match read {
+            ValueRead::Pending => {
+                ui.spinner();
+                ui.label(i18n::t(T::ReadingValue, lang));
+            }
+            ValueRead::Done(value) => {
+                let text = value.value.as_ref().map(|v| v.to_string()).unwrap_or_else(|| "---".to_string());
+                ui.label(egui::RichText::new(text).strong());
+            }
+        }
+        if ui.add_enabled(!pending, egui::Button::new("🔄").small()).on_hover_text(i18n::t(T::RefreshValueHint, lang)).clicked() {
+            refresh = Some(node_id.clone());
+        }
+    });
+    ui.end_row();
+
+    if let ValueRead::Done(value) = read {
+        let status = value.status.unwrap_or(opcua::types::StatusCode::Good).bits();
+        let [r, g, b] = status_code_color(status);
+        ui.label(i18n::t(T::Status, lang));
+        ui.colored_label(egui::Color32::from_rgb(r, g, b), translate_status_code(status));
+        ui.end_row();
+
+        ui.label(i18n::t(T::SourceTimestamp, lang));
+        ui.label(value.source_timestamp.map(|t| timestamps.format(t.as_chrono())).unwrap_or_else(|| "---".to_string()));
+        ui.end_row();
+    }
+    refresh
+}
+
+
+fn show_raw_data_value(ui: &mut egui::Ui, data: &MonitoredData, timestamps: &TimestampFormat, lang: Language) {
+    let timestamp = |ts: &Option<opcua::types::DateTime>| {
+        ts.map(|t| timestamps.format(t.as_chrono())).unwrap_or_else(|| "---".to_string())
+    };
+    let value = data.value.clone().unwrap_or(Variant::Empty);
+
+    egui::Grid::new("raw_data_value_grid")
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label(i18n::t(T::VariantType, lang));
+            ui.monospace(variant_type_name(&value));
+            ui.end_row();
+
+            ui.label(i18n::t(T::RawValue, lang));
+            ui.add(egui::Label::new(egui::RichText::new(format!("{:?}", value)).monospace()).wrap());
+            ui.end_row();
+
+            ui.label(i18n::t(T::SourceTimestamp, lang));
+            ui.monospace(timestamp(&data.source_timestamp));
+            ui.end_row();
+
+            ui.label(i18n::t(T::ServerTimestamp, lang));
+            ui.monospace(timestamp(&data.server_timestamp));
+            ui.end_row();
+
+            ui.label(i18n::t(T::Status, lang));
+            ui.monospace(format!("0x{:08X} {}", data.status.bits(), data.status));
+            ui.end_row();
+
+            ui.label(i18n::t(T::BinaryEncoding, lang));
+            ui.add(egui::Label::new(egui::RichText::new(variant_to_binary_hex(&value)).monospace()).wrap());
+            ui.end_row();
+        });
+
+    let json = serde_json::to_string_pretty(&variant_to_ua_json(&value)).unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label(i18n::t(T::JsonEncoding, lang));
+        if ui.small_button("📋").on_hover_text("Copy JSON").clicked() {
+            ui.ctx().copy_text(json.clone());
+        }
+    });
+    ui.add(egui::Label::new(egui::RichText::new(json).monospace()).wrap());
 }
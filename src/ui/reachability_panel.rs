@@ -0,0 +1,192 @@
+
+
+
+
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+use crate::network::reachability::{parse_target, ProbeSample, ReachabilityHistory};
+use crate::utils::i18n::{self, T, Language};
+
+
+const INTERVALS: [u64; 4] = [1, 2, 5, 10];
+
+
+const SLOT_WIDTH: f32 = 3.0;
+
+
+pub enum ReachabilityAction {
+    Start { host: String, port: u16, interval: Duration },
+    Stop,
+}
+
+
+pub struct ReachabilityPanel {
+    
+    pub target: String,
+    
+    pub interval_secs: u64,
+    
+    pub running: Option<String>,
+    
+    pub history: ReachabilityHistory,
+    
+    pub error: Option<String>,
+}
+
+impl Default for ReachabilityPanel {
+    fn default() -> Self {
+        Self {
+            target: String::new(),
+            interval_secs: 2,
+            running: None,
+            history: ReachabilityHistory::default(),
+            error: None,
+        }
+    }
+}
+
+impl ReachabilityPanel {
+    
+    pub fn record(&mut self, sample: ProbeSample) {
+        if self.running.is_some() {
+            self.history.push(sample);
+        }
+    }
+
+    
+    pub fn show(&mut self, ui: &mut egui::Ui, default_target: Option<&str>, lang: Language) -> Option<ReachabilityAction> {
+        let mut action = None;
+
+        if self.target.is_empty() {
+            if let Some(target) = default_target {
+                self.target = target.to_string();
+            }
+        }
+
+        ui.heading(format!("📶 {}", i18n::t(T::ConnectivityMonitor, lang)));
+        ui.label(i18n::t(T::ConnectivityHint, lang));
+        ui.separator();
+
+        let running = self.running.is_some();
+        ui.add_enabled_ui(!running, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(i18n::t(T::Target, lang));
+                ui.text_edit_singleline(&mut self.target);
+            });
+            egui::ComboBox::from_label(i18n::t(T::Interval, lang))
+                .selected_text(format!("{}s", self.interval_secs))
+                .show_ui(ui, |ui| {
+                    for secs in INTERVALS {
+                        ui.selectable_value(&mut self.interval_secs, secs, format!("{}s", secs));
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            if running {
+                ui.spinner();
+                if ui.button(format!("⏹ {}", i18n::t(T::Stop, lang))).clicked() {
+                    self.running = None;
+                    action = Some(ReachabilityAction::Stop);
+                }
+            } else if ui.button(format!("▶ {}", i18n::t(T::StartMonitor, lang))).clicked() {
+                match parse_target(&self.target) {
+                    Ok((host, port)) => {
+                        self.error = None;
+                        self.history.clear();
+                        self.running = Some(format!("{}:{}", host, port));
+                        action = Some(ReachabilityAction::Start {
+                            host,
+                            port,
+                            interval: Duration::from_secs(self.interval_secs),
+                        });
+                    }
+                    Err(e) => self.error = Some(e),
+                }
+            }
+            if let Some(label) = &self.running {
+                ui.label(label);
+            }
+        });
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), error);
+        }
+
+        ui.add_space(5.0);
+        self.show_timeline(ui, lang);
+        ui.add_space(5.0);
+
+        egui::Grid::new("reachability_stats")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(i18n::t(T::Availability, lang));
+                match self.history.uptime_ratio() {
+                    Some(ratio) => ui.label(format!("{:.1}%", ratio * 100.0)),
+                    None => ui.weak(i18n::t(T::NotAvailable, lang)),
+                };
+                ui.end_row();
+
+                ui.label(i18n::t(T::Drops, lang));
+                let drops = self.history.drop_count();
+                if drops > 0 {
+                    ui.colored_label(egui::Color32::from_rgb(255, 165, 0), drops.to_string());
+                } else {
+                    ui.label("0");
+                }
+                ui.end_row();
+
+                ui.label(i18n::t(T::AvgLatency, lang));
+                match self.history.average_latency() {
+                    Some(latency) => ui.label(format!("{} ms", latency.as_millis())),
+                    None => ui.weak(i18n::t(T::NotAvailable, lang)),
+                };
+                ui.end_row();
+            });
+
+        action
+    }
+
+    fn show_timeline(&self, ui: &mut egui::Ui, lang: Language) {
+        let desired = egui::vec2(ui.available_width(), 24.0);
+        let (rect, response) = ui.allocate_exact_size(desired, egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, egui::Color32::from_gray(40));
+
+        let capacity = (rect.width() / SLOT_WIDTH).floor().max(1.0) as usize;
+        let visible: Vec<&ProbeSample> = self.history.samples.iter().rev().take(capacity).collect();
+
+        
+        for (i, sample) in visible.iter().enumerate() {
+            let right = rect.right() - i as f32 * SLOT_WIDTH;
+            let slot = egui::Rect::from_min_max(
+                egui::pos2(right - SLOT_WIDTH + 0.5, rect.top()),
+                egui::pos2(right, rect.bottom()),
+            );
+            let color = if sample.is_up() {
+                egui::Color32::from_rgb(60, 180, 75)
+            } else {
+                egui::Color32::from_rgb(220, 50, 50)
+            };
+            painter.rect_filled(slot, 0.0, color);
+        }
+
+        if let Some(pos) = response.hover_pos() {
+            let index = ((rect.right() - pos.x) / SLOT_WIDTH).floor() as usize;
+            if let Some(sample) = visible.get(index) {
+                let age = Instant::now().saturating_duration_since(sample.at).as_secs();
+                let state = match sample.latency {
+                    Some(latency) => format!("{} ({} ms)", i18n::t(T::Reachable, lang), latency.as_millis()),
+                    None => i18n::t(T::Unreachable, lang).to_string(),
+                };
+                response.on_hover_text(format!(
+                    "{} - {}",
+                    state,
+                    i18n::t_fmt(T::SecondsAgo, lang, &[&age.to_string()])
+                ));
+            }
+        }
+    }
+}
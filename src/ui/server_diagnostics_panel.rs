@@ -0,0 +1,123 @@
+
+
+
+
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+use crate::opcua::server_diagnostics::{ServerDiagnostics, REFRESH_INTERVAL_SECS};
+use crate::utils::i18n::{self, T, Language};
+
+
+const LOAD_WARNING_RATIO: f32 = 0.8;
+
+
+pub enum ServerDiagnosticsAction {
+    Refresh,
+}
+
+
+#[derive(Default)]
+pub struct ServerDiagnosticsPanel {
+    
+    pub result: Option<Result<ServerDiagnostics, String>>,
+    
+    pub loading: bool,
+    
+    pub last_refresh: Option<Instant>,
+}
+
+impl ServerDiagnosticsPanel {
+    
+    pub fn needs_refresh(&self) -> bool {
+        !self.loading
+            && self.last_refresh
+                .map_or(true, |t| t.elapsed() >= Duration::from_secs(REFRESH_INTERVAL_SECS))
+    }
+
+    
+    pub fn begin_refresh(&mut self) {
+        self.loading = true;
+        self.last_refresh = Some(Instant::now());
+    }
+
+    
+    pub fn set_result(&mut self, result: Result<ServerDiagnostics, String>) {
+        self.loading = false;
+        self.result = Some(result);
+    }
+
+    
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    
+    pub fn show(&self, ui: &mut egui::Ui, lang: Language) -> Option<ServerDiagnosticsAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(format!("🩺 {}", i18n::t(T::ServerDiagnostics, lang)));
+            if self.loading {
+                ui.spinner();
+            } else if ui.button("🔄").on_hover_text(i18n::t(T::Refresh, lang)).clicked() {
+                action = Some(ServerDiagnosticsAction::Refresh);
+            }
+        });
+        ui.label(i18n::t_fmt(T::ServerDiagnosticsHint, lang, &[&REFRESH_INTERVAL_SECS.to_string()]));
+        ui.separator();
+
+        let diagnostics = match &self.result {
+            None => return action,
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::from_rgb(255, 100, 100), e);
+                return action;
+            }
+            Some(Ok(diagnostics)) => diagnostics,
+        };
+
+        if diagnostics.enabled == Some(false) {
+            ui.colored_label(egui::Color32::from_rgb(255, 165, 0), i18n::t(T::DiagnosticsDisabled, lang));
+            ui.add_space(5.0);
+        }
+
+        let session_usage = diagnostics.session_usage();
+        let subscription_usage = diagnostics.subscription_usage();
+        for (label, usage) in [(T::SessionLoad, session_usage), (T::SubscriptionLoad, subscription_usage)] {
+            if let Some(usage) = usage {
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t(label, lang));
+                    ui.add(egui::ProgressBar::new(usage.min(1.0)).show_percentage());
+                });
+            }
+        }
+        if [session_usage, subscription_usage].into_iter().flatten().any(|u| u >= LOAD_WARNING_RATIO) {
+            ui.colored_label(egui::Color32::from_rgb(255, 165, 0), i18n::t(T::ServerOverloadHint, lang));
+        }
+        ui.add_space(5.0);
+
+        egui::Grid::new("server_diagnostics_grid")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for (name, value) in &diagnostics.counters {
+                    ui.label(*name);
+                    match value {
+                        Some(v) if *v > 0 && ServerDiagnostics::is_rejection_counter(name) => {
+                            ui.colored_label(egui::Color32::from_rgb(255, 165, 0), v.to_string());
+                        }
+                        Some(v) => {
+                            ui.label(v.to_string());
+                        }
+                        None => {
+                            ui.weak(i18n::t(T::NotAvailable, lang));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        action
+    }
+}
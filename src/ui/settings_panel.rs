@@ -0,0 +1,70 @@
+use eframe::egui;
+
+use crate::config::settings::Settings;
+use crate::opcua::subscription::SubscriptionConfig;
+use crate::utils::i18n::{self, T, Language};
+
+
+pub enum SettingsAction {
+    Changed,
+    ApplyToSubscription,
+}
+
+
+pub fn show(ui: &mut egui::Ui, settings: &mut Settings, subscription_outdated: bool, lang: Language) -> Option<SettingsAction> {
+    let mut action = None;
+    let mut changed = false;
+    egui::Grid::new("settings_grid").num_columns(2).spacing([10.0, 6.0]).show(ui, |ui| {
+        ui.label(i18n::t(T::MaxWatchlistItems, lang));
+        changed |= ui.add(egui::DragValue::new(&mut settings.max_watchlist_items).range(1..=100_000).speed(10)).changed();
+        ui.end_row();
+
+        ui.label(i18n::t(T::MaxTrendSeries, lang));
+        changed |= ui.add(egui::DragValue::new(&mut settings.max_trend_series).range(1..=50)).changed();
+        ui.end_row();
+    });
+
+    ui.add_space(5.0);
+    ui.label(egui::RichText::new(i18n::t(T::Subscription, lang)).strong());
+    egui::Grid::new("subscription_settings_grid").num_columns(2).spacing([10.0, 6.0]).show(ui, |ui| {
+        ui.label(i18n::t(T::PublishingInterval, lang))
+            .on_hover_text(i18n::t(T::PublishingIntervalHint, lang));
+        changed |= ui.add(egui::DragValue::new(&mut settings.subscription_interval_ms).range(50..=60_000).speed(50).suffix(" ms")).changed();
+        ui.end_row();
+
+        let min_lifetime = SubscriptionConfig::min_lifetime_count(settings.subscription_keepalive_count);
+        ui.label(i18n::t(T::LifetimeCount, lang));
+        changed |= ui.add(egui::DragValue::new(&mut settings.subscription_lifetime_count).range(min_lifetime..=100_000)).changed();
+        ui.end_row();
+
+        ui.label(i18n::t(T::KeepAliveCount, lang));
+        if ui.add(egui::DragValue::new(&mut settings.subscription_keepalive_count).range(1..=10_000)).changed() {
+            let min_lifetime = SubscriptionConfig::min_lifetime_count(settings.subscription_keepalive_count);
+            settings.subscription_lifetime_count = settings.subscription_lifetime_count.max(min_lifetime);
+            changed = true;
+        }
+        ui.end_row();
+
+        ui.label(i18n::t(T::MaxNotifications, lang))
+            .on_hover_text(i18n::t(T::MaxNotificationsHint, lang));
+        changed |= ui.add(egui::DragValue::new(&mut settings.subscription_max_notifications).range(0..=100_000)).changed();
+        ui.end_row();
+    });
+    let min_lifetime = SubscriptionConfig::min_lifetime_count(settings.subscription_keepalive_count);
+    ui.label(egui::RichText::new(i18n::t_fmt(T::LifetimeCountHint, lang, &[&min_lifetime.to_string()])).small().weak());
+    if changed {
+        action = Some(SettingsAction::Changed);
+    }
+
+    if subscription_outdated {
+        ui.add_space(5.0);
+        ui.colored_label(egui::Color32::from_rgb(255, 200, 100), i18n::t(T::SubscriptionOutdated, lang));
+        if ui.button(format!("🔄 {}", i18n::t(T::ApplyToSubscription, lang))).clicked() {
+            action = Some(SettingsAction::ApplyToSubscription);
+        }
+    }
+
+    ui.add_space(5.0);
+    ui.label(egui::RichText::new(i18n::t(T::SettingsSavedNextToExe, lang)).small().weak());
+    action
+}
@@ -0,0 +1,160 @@
+
+
+
+
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+
+use crate::opcua::tag_validation::{TagCheck, TagVerdict};
+use crate::utils::i18n::{self, T, Language};
+
+
+pub enum TagValidationAction {
+    OpenFile,
+    AddPassingToWatchlist,
+}
+
+
+#[derive(Default)]
+pub struct TagValidationPanel {
+    
+    pub checks: Vec<TagCheck>,
+    
+    pub invalid: Vec<(usize, String)>,
+    
+    pub running: bool,
+    
+    pub error: Option<String>,
+    
+    pub failures_only: bool,
+}
+
+impl TagValidationPanel {
+    
+    pub fn begin(&mut self, invalid: Vec<(usize, String)>) {
+        *self = Self {
+            invalid,
+            running: true,
+            failures_only: self.failures_only,
+            ..Self::default()
+        };
+    }
+
+    
+    pub fn set_result(&mut self, result: Result<Vec<TagCheck>, String>) {
+        self.running = false;
+        match result {
+            Ok(checks) => self.checks = checks,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    
+    pub fn passing(&self) -> impl Iterator<Item = &TagCheck> {
+        self.checks.iter().filter(|c| c.verdict.passed())
+    }
+
+    
+    pub fn failed_count(&self) -> usize {
+        self.checks.len() - self.passing().count() + self.invalid.len()
+    }
+
+    
+    pub fn show(&mut self, ui: &mut egui::Ui, lang: Language) -> Option<TagValidationAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(format!("✅ {}", i18n::t(T::ValidateTagList, lang)));
+            if self.running {
+                ui.spinner();
+            } else if ui.button("📂").on_hover_text(i18n::t(T::ValidateTagList, lang)).clicked() {
+                action = Some(TagValidationAction::OpenFile);
+            }
+        });
+        ui.separator();
+
+        if let Some(e) = &self.error {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), e);
+            return action;
+        }
+        if self.checks.is_empty() && self.invalid.is_empty() {
+            if !self.running {
+                ui.label(i18n::t(T::ValidateTagListHint, lang));
+            }
+            return action;
+        }
+
+        let passed = self.passing().count();
+        ui.horizontal(|ui| {
+            ui.label(
+                i18n::t_fmt(T::ValidationSummary, lang, &[&passed.to_string(), &self.failed_count().to_string()]),
+            );
+            ui.checkbox(&mut self.failures_only, i18n::t(T::FailuresOnly, lang));
+            if passed > 0 && ui.button(i18n::t(T::AddPassingToWatchlist, lang)).clicked() {
+                action = Some(TagValidationAction::AddPassingToWatchlist);
+            }
+        });
+        ui.add_space(5.0);
+
+        for (line, text) in &self.invalid {
+            ui.colored_label(
+                egui::Color32::RED,
+                i18n::t_fmt(T::InvalidTagLine, lang, &[&line.to_string(), text]),
+            );
+        }
+
+        let rows: Vec<&TagCheck> = self.checks
+            .iter()
+            .filter(|c| !self.failures_only || !c.verdict.passed())
+            .collect();
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .column(Column::auto().at_least(50.0))
+            .column(Column::initial(160.0).at_least(80.0))
+            .column(Column::initial(200.0).at_least(80.0))
+            .column(Column::initial(100.0).at_least(60.0))
+            .column(Column::remainder())
+            .header(20.0, |mut header| {
+                header.col(|ui| { ui.strong(i18n::t(T::Result, lang)); });
+                header.col(|ui| { ui.strong(i18n::t(T::Name, lang)); });
+                header.col(|ui| { ui.strong("NodeId"); });
+                header.col(|ui| { ui.strong(i18n::t(T::DataType, lang)); });
+                header.col(|ui| { ui.strong(i18n::t(T::Status, lang)); });
+            })
+            .body(|body| {
+                body.rows(20.0, rows.len(), |mut row| {
+                    let check = rows[row.index()];
+                    row.col(|ui| {
+                        if check.verdict.passed() {
+                            ui.colored_label(egui::Color32::GREEN, i18n::t(T::Pass, lang));
+                        } else {
+                            ui.colored_label(egui::Color32::RED, i18n::t(T::Fail, lang));
+                        }
+                    });
+                    row.col(|ui| { ui.label(&check.name); });
+                    row.col(|ui| { ui.label(check.node_id.to_string()); });
+                    row.col(|ui| {
+                        match (&check.data_type_name, &check.data_type) {
+                            (Some(name), _) => ui.label(name),
+                            (None, Some(id)) => ui.label(id.to_string()),
+                            (None, None) => ui.weak("---"),
+                        };
+                    });
+                    row.col(|ui| {
+                        let reason = match check.verdict {
+                            TagVerdict::Pass => i18n::t(T::Readable, lang),
+                            TagVerdict::Missing => i18n::t(T::NodeMissing, lang),
+                            TagVerdict::NotVariable => i18n::t(T::NotAVariable, lang),
+                            TagVerdict::NotReadable => i18n::t(T::NotReadable, lang),
+                        };
+                        ui.label(reason)
+                            .on_hover_text(crate::opcua::status_codes::translate_status_code(check.status));
+                    });
+                });
+            });
+
+        action
+    }
+}
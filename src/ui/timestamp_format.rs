@@ -0,0 +1,59 @@
+use eframe::egui;
+
+use crate::utils::i18n::{self, T, Language};
+use crate::utils::timestamps::TimestampFormat;
+
+
+#[derive(Default)]
+pub struct TimestampFormatPicker {
+    
+    custom_input: String,
+    
+    error: Option<String>,
+}
+
+impl TimestampFormatPicker {
+    
+    pub fn show(&mut self, ui: &mut egui::Ui, format: &mut TimestampFormat, lang: Language) -> bool {
+        let before = format.clone();
+        if let TimestampFormat::Custom(pattern) = format {
+            if self.custom_input.is_empty() {
+                self.custom_input = pattern.clone();
+            }
+        }
+
+        ui.radio_value(format, TimestampFormat::Iso8601Millis, i18n::t(T::TimestampIso8601, lang));
+        ui.radio_value(format, TimestampFormat::LocalShort, i18n::t(T::TimestampLocalShort, lang));
+        ui.radio_value(format, TimestampFormat::LocalLong, i18n::t(T::TimestampLocalLong, lang));
+        ui.horizontal(|ui| {
+            let is_custom = matches!(format, TimestampFormat::Custom(_));
+            if ui.radio(is_custom, i18n::t(T::TimestampCustom, lang)).clicked() {
+                self.apply_custom(format);
+            }
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.custom_input)
+                    .hint_text("%d/%m/%Y %H:%M:%S%.3f")
+                    .desired_width(160.0),
+            );
+            if response.changed() {
+                self.apply_custom(format);
+            }
+        });
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), i18n::t_fmt(T::InvalidTimestampFormat, lang, &[error]));
+        }
+
+        *format != before
+    }
+
+    
+    fn apply_custom(&mut self, format: &mut TimestampFormat) {
+        match TimestampFormat::parse_custom(&self.custom_input) {
+            Ok(custom) => {
+                self.error = None;
+                *format = custom;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use opcua::types::NodeId;
 use std::collections::HashMap;
 use std::cell::RefCell;
 
-use crate::opcua::browser::BrowsedNode;
+use crate::opcua::browser::{BrowseFailure, BrowsedNode};
 use crate::utils::i18n::{self, T, Language};
 use crate::opcua::browser::NodeClass;
 
@@ -11,9 +11,108 @@ use crate::opcua::browser::NodeClass;
 pub enum TreeViewAction {
     Select(BrowsedNode),
     Expand(NodeId),
+    RetryBrowse(NodeId),
     ExportJson(BrowsedNode),
     ExportCsv(BrowsedNode),
     AddToWatchlist(BrowsedNode),
+    AddAllVariables(BrowsedNode),
+    Revealed(NodeId),
+    CopyStructure(BrowsedNode),
+    ToggleFavorite(BrowsedNode),
+    ExportNodeDetails(BrowsedNode),
+}
+
+
+
+pub fn show_view_selector(
+    ui: &mut egui::Ui,
+    views: &[BrowsedNode],
+    selected: Option<&NodeId>,
+    lang: Language,
+) -> Option<Option<NodeId>> {
+    let mut choice = None;
+    let full = i18n::t(T::FullAddressSpace, lang);
+    let current = selected
+        .and_then(|id| views.iter().find(|v| &v.node_id == id))
+        .map(|v| v.display_name.as_str())
+        .unwrap_or(full);
+
+    ui.horizontal(|ui| {
+        ui.label(format!("👁 {}", i18n::t(T::ServerView, lang)));
+        egui::ComboBox::from_id_salt("server_view_selector")
+            .selected_text(current)
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(selected.is_none(), full).clicked() && selected.is_some() {
+                    choice = Some(None);
+                }
+                for view in views {
+                    let is_selected = selected == Some(&view.node_id);
+                    if ui.selectable_label(is_selected, &view.display_name)
+                        .on_hover_text(view.node_id.to_string())
+                        .clicked()
+                        && !is_selected
+                    {
+                        choice = Some(Some(view.node_id.clone()));
+                    }
+                }
+            });
+    });
+
+    choice
+}
+
+
+pub const OUTLINE_MAX_BYTES: usize = 1024 * 1024;
+
+
+#[derive(Debug, Default)]
+pub struct SubtreeOutline {
+    
+    pub text: String,
+    
+    pub nodes: usize,
+    
+    pub truncated: bool,
+    
+    pub missing: Vec<(NodeId, usize)>,
+}
+
+
+
+pub fn subtree_outline(
+    root: &BrowsedNode,
+    node_cache: &HashMap<NodeId, Vec<BrowsedNode>>,
+    max_depth: usize,
+    max_bytes: usize,
+) -> SubtreeOutline {
+    let mut outline = SubtreeOutline::default();
+    let mut stack = vec![(root, 0usize)];
+
+    while let Some((node, depth)) = stack.pop() {
+        let line = format!(
+            "{}{} ({}, {})\n",
+            "  ".repeat(depth),
+            node.display_name,
+            node.node_class,
+            node.node_id
+        );
+        if outline.text.len() + line.len() > max_bytes {
+            outline.truncated = true;
+            break;
+        }
+        outline.text.push_str(&line);
+        outline.nodes += 1;
+
+        if !node.has_children || depth >= max_depth {
+            continue;
+        }
+        match node_cache.get(&node.node_id) {
+            Some(children) => stack.extend(children.iter().rev().map(|c| (c, depth + 1))),
+            None => outline.missing.push((node.node_id.clone(), depth)),
+        }
+    }
+
+    outline
 }
 
 
@@ -22,6 +121,16 @@ pub struct TreeView<'a> {
     node_cache: &'a HashMap<NodeId, Vec<BrowsedNode>>,
     
     selected_node_id: &'a Option<NodeId>,
+    
+    reveal_path: &'a [NodeId],
+    
+    reveal_target: Option<&'a NodeId>,
+    
+    favorites: &'a [NodeId],
+    
+    data_types: Option<&'a HashMap<NodeId, String>>,
+    
+    browse_errors: Option<&'a HashMap<NodeId, BrowseFailure>>,
 }
 
 impl<'a> TreeView<'a> {
@@ -32,6 +141,43 @@ impl<'a> TreeView<'a> {
         Self {
             node_cache,
             selected_node_id,
+            reveal_path: &[],
+            reveal_target: None,
+            favorites: &[],
+            data_types: None,
+            browse_errors: None,
+        }
+    }
+
+    
+    pub fn with_favorites(mut self, favorites: &'a [NodeId]) -> Self {
+        self.favorites = favorites;
+        self
+    }
+
+    
+    pub fn with_data_types(mut self, data_types: &'a HashMap<NodeId, String>) -> Self {
+        self.data_types = Some(data_types);
+        self
+    }
+
+    
+    pub fn with_browse_errors(mut self, browse_errors: &'a HashMap<NodeId, BrowseFailure>) -> Self {
+        self.browse_errors = Some(browse_errors);
+        self
+    }
+
+    
+    pub fn with_reveal(mut self, path: &'a [NodeId], target: &'a NodeId) -> Self {
+        self.reveal_path = path;
+        self.reveal_target = Some(target);
+        self
+    }
+
+    fn reveal_if_target(&self, response: &egui::Response, node_id: &NodeId, actions: &RefCell<Vec<TreeViewAction>>) {
+        if self.reveal_target == Some(node_id) {
+            response.scroll_to_me(Some(egui::Align::Center));
+            actions.borrow_mut().push(TreeViewAction::Revealed(node_id.clone()));
         }
     }
 
@@ -64,7 +210,19 @@ impl<'a> TreeView<'a> {
 
         
         let icon = node.node_class.icon();
-        let text = format!("{} {}", icon, node.display_name);
+        let is_favorite = self.favorites.contains(&node.node_id);
+        let mut text = if is_favorite {
+            format!("{} {} ⭐", icon, node.display_name)
+        } else {
+            format!("{} {}", icon, node.display_name)
+        };
+        if let Some(data_type) = self.data_types.and_then(|types| types.get(&node.node_id)) {
+            text.push_str(&format!("  [{}]", data_type));
+        }
+        let browse_error = self.browse_errors.and_then(|errors| errors.get(&node.node_id));
+        if browse_error == Some(&BrowseFailure::AccessDenied) {
+            text = format!("🔒 {} ({})", text, i18n::t(T::AccessDenied, lang));
+        }
         
         
         let id = ui.make_persistent_id(node.node_id.to_string());
@@ -83,6 +241,14 @@ impl<'a> TreeView<'a> {
                     actions.borrow_mut().push(TreeViewAction::ExportCsv(node.clone()));
                     ui.close_menu();
                 }
+                if ui.button(format!("📋 {}", i18n::t(T::CopyStructure, lang))).clicked() {
+                    actions.borrow_mut().push(TreeViewAction::CopyStructure(node.clone()));
+                    ui.close_menu();
+                }
+                if node.node_class == NodeClass::Object && ui.button(format!("📊 {}", i18n::t(T::AddAllVariables, lang))).clicked() {
+                    actions.borrow_mut().push(TreeViewAction::AddAllVariables(node.clone()));
+                    ui.close_menu();
+                }
             }
             
             if node.node_class == NodeClass::Variable {
@@ -94,27 +260,56 @@ impl<'a> TreeView<'a> {
                     ui.close_menu();
                 }
             }
+
+            let favorite_label = if is_favorite {
+                format!("☆ {}", i18n::t(T::RemoveFromFavorites, lang))
+            } else {
+                format!("⭐ {}", i18n::t(T::AddToFavorites, lang))
+            };
+            if ui.button(favorite_label).clicked() {
+                actions.borrow_mut().push(TreeViewAction::ToggleFavorite(node.clone()));
+                ui.close_menu();
+            }
+            if ui.button(format!("🧾 {}", i18n::t(T::ExportNodeDetails, lang))).clicked() {
+                actions.borrow_mut().push(TreeViewAction::ExportNodeDetails(node.clone()));
+                ui.close_menu();
+            }
         };
 
         
         if node.has_children {
-            let state = egui::collapsing_header::CollapsingState::load_with_default_open(
+            let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
                 ui.ctx(),
                 id,
                 false,
             );
+            if self.reveal_path.contains(&node.node_id) && !state.is_open() {
+                state.set_open(true);
+                state.store(ui.ctx());
+            }
 
             let header_response = state.show_header(ui, |ui| {
                 let response = ui.selectable_label(is_selected, text);
                 if response.clicked() {
                      actions.borrow_mut().push(TreeViewAction::Select(node.clone()));
                 }
+                self.reveal_if_target(&response, &node.node_id, &actions);
                 response.context_menu(context_menu);
             });
             
             header_response.body(|ui| {
                 if let Some(children) = self.node_cache.get(&node.node_id) {
                     actions.borrow_mut().extend(self.show(ui, children, lang));
+                } else if let Some(error) = browse_error {
+                    ui.horizontal(|ui| {
+                        match error {
+                            BrowseFailure::AccessDenied => ui.weak(format!("🔒 {}", i18n::t(T::AccessDenied, lang))),
+                            BrowseFailure::Error(e) => ui.colored_label(egui::Color32::from_rgb(255, 100, 100), format!("⚠ {}", e)),
+                        };
+                        if ui.small_button("↻").on_hover_text(i18n::t(T::RetryBrowse, lang)).clicked() {
+                            actions.borrow_mut().push(TreeViewAction::RetryBrowse(node.node_id.clone()));
+                        }
+                    });
                 } else {
                     ui.horizontal(|ui| {
                         ui.spinner();
@@ -133,9 +328,62 @@ impl<'a> TreeView<'a> {
             if response.double_clicked() && node.node_class == NodeClass::Variable {
                 actions.borrow_mut().push(TreeViewAction::AddToWatchlist(node.clone()));
             }
+            self.reveal_if_target(&response, &node.node_id, &actions);
             response.context_menu(context_menu);
         }
 
         actions.into_inner()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_nodes::TestNode;
+
+    fn node(id: &str, class: NodeClass) -> BrowsedNode {
+        TestNode::new(NodeId::new(2, id), id.rsplit('.').next().unwrap_or(id)).class(class).build()
+    }
+
+    fn synthetic_cache() -> (BrowsedNode, HashMap<NodeId, Vec<BrowsedNode>>) {
+        let line = node("Line1", NodeClass::Object);
+        let motor = node("Line1.Motor", NodeClass::Object);
+        let valve = node("Line1.Valve", NodeClass::Object);
+        let mut cache = HashMap::new();
+        cache.insert(line.node_id.clone(), vec![motor.clone(), valve, node("Line1.State", NodeClass::Variable)]);
+        cache.insert(motor.node_id.clone(), vec![node("Line1.Motor.Speed", NodeClass::Variable)]);
+        (line, cache)
+    }
+
+    #[test]
+    fn test_outline_indents_cached_levels() {
+        let (root, cache) = synthetic_cache();
+        let outline = subtree_outline(&root, &cache, 10, OUTLINE_MAX_BYTES);
+
+        let expected = "\
+Line1 (Object, ns=2;s=Line1)
+  Motor (Object, ns=2;s=Line1.Motor)
+    Speed (Variable, ns=2;s=Line1.Motor.Speed)
+  Valve (Object, ns=2;s=Line1.Valve)
+  State (Variable, ns=2;s=Line1.State)
+";
+        assert_eq!(outline.text, expected);
+        assert_eq!(outline.nodes, 5);
+        assert!(!outline.truncated);
+        assert_eq!(outline.missing, vec![(NodeId::new(2, "Line1.Valve"), 1)]);
+    }
+
+    #[test]
+    fn test_outline_respects_depth_and_size_caps() {
+        let (root, cache) = synthetic_cache();
+
+        let shallow = subtree_outline(&root, &cache, 1, OUTLINE_MAX_BYTES);
+        assert_eq!(shallow.nodes, 4);
+        assert!(shallow.missing.is_empty());
+
+        let capped = subtree_outline(&root, &cache, 10, 70);
+        assert!(capped.truncated);
+        assert_eq!(capped.nodes, 2);
+        assert!(capped.text.len() <= 70);
+    }
+}
@@ -3,26 +3,141 @@
 
 
 use eframe::egui;
-use egui_plot::{Line, Legend, Plot, PlotPoints, AxisHints};
+use egui_plot::{Line, LineStyle, MarkerShape, Plot, PlotPoints, Points, AxisHints};
 use opcua::types::NodeId;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
 
-use crate::opcua::subscription::MonitoredData;
+use crate::opcua::subscription::{LineDash, MonitoredData, TrendStyle};
+use crate::utils::i18n::{self, T, Language};
 
 
 const TIME_WINDOWS: [u64; 4] = [30, 60, 300, 600];
 
 
+const DISTINCT_PALETTE: [[u8; 3]; 10] = [
+    [31, 119, 180],
+    [255, 127, 14],
+    [44, 160, 44],
+    [214, 39, 40],
+    [148, 103, 189],
+    [140, 86, 75],
+    [227, 119, 194],
+    [127, 127, 127],
+    [188, 189, 34],
+    [23, 190, 207],
+];
+
+
+const PICKER_PALETTE: [[u8; 3]; 12] = [
+    [255, 0, 0],
+    [0, 255, 0],
+    [0, 0, 255],
+    [255, 255, 0],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 128, 0],
+    [128, 0, 255],
+    [0, 255, 128],
+    [255, 128, 128],
+    [128, 255, 128],
+    [128, 128, 255],
+];
+
+
+pub enum TrendingAction {
+    
+    AutoAssignColors,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesSource {
+    
+    Captured,
+    
+    History,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct DetachedSeries {
+    
+    pub source: SeriesSource,
+    
+    pub node_id: NodeId,
+    
+    pub display_name: String,
+    
+    pub history: VecDeque<(f64, f64)>,
+    
+    pub color: [u8; 3],
+    
+    pub unit: Option<String>,
+}
+
+impl DetachedSeries {
+    
+    pub fn from_monitored(item: &MonitoredData) -> Self {
+        let color = item.trend_color.unwrap_or_else(|| {
+            let color = color_for_node_id(&item.node_id);
+            [color.r(), color.g(), color.b()]
+        });
+        Self {
+            source: SeriesSource::Captured,
+            node_id: item.node_id.clone(),
+            display_name: item.display_name.clone(),
+            history: item.history.clone(),
+            color,
+            unit: item.unit.clone(),
+        }
+    }
+
+    
+    pub fn from_history(node_id: NodeId, display_name: String, points: Vec<(f64, f64)>) -> Self {
+        let color = color_for_node_id(&node_id);
+        Self {
+            source: SeriesSource::History,
+            node_id,
+            display_name,
+            history: points.into(),
+            color: [color.r(), color.g(), color.b()],
+            unit: None,
+        }
+    }
+
+    
+    pub fn label(&self, lang: Language) -> String {
+        match self.source {
+            SeriesSource::Captured => i18n::t_fmt(T::CapturedSeriesLabel, lang, &[&self.display_name]),
+            SeriesSource::History => i18n::t_fmt(T::HistorySeriesLabel, lang, &[&self.display_name]),
+        }
+    }
+
+    
+    fn time_range(&self) -> Option<(f64, f64)> {
+        let first = self.history.front()?.0;
+        let last = self.history.back()?.0;
+        Some((first.min(last), first.max(last)))
+    }
+}
+
+
 pub struct TrendingPanel {
     
     time_window: u64,
+    
+    hidden_series: HashSet<NodeId>,
+    
+    detached_series: Vec<DetachedSeries>,
 }
 
 impl Default for TrendingPanel {
     fn default() -> Self {
         Self {
             time_window: 60,
+            hidden_series: HashSet::new(),
+            detached_series: Vec::new(),
         }
     }
 }
@@ -44,6 +159,106 @@ pub fn color_for_node_id(node_id: &NodeId) -> egui::Color32 {
 }
 
 
+
+pub fn distinct_color(index: usize) -> [u8; 3] {
+    if let Some(rgb) = DISTINCT_PALETTE.get(index) {
+        return *rgb;
+    }
+    let hue = ((index - DISTINCT_PALETTE.len()) as f32 * 0.618_034).fract();
+    let color = egui::Color32::from(egui::ecolor::Hsva::new(hue, 0.75, 0.95, 1.0));
+    [color.r(), color.g(), color.b()]
+}
+
+
+
+pub fn assign_distinct_colors(monitored_items: &mut HashMap<NodeId, MonitoredData>) -> usize {
+    let mut trending: Vec<&mut MonitoredData> = monitored_items
+        .values_mut()
+        .filter(|item| item.show_in_trend)
+        .collect();
+    trending.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+
+    for (index, item) in trending.iter_mut().enumerate() {
+        item.trend_color = Some(distinct_color(index));
+    }
+    trending.len()
+}
+
+
+pub fn color_palette(ui: &mut egui::Ui) -> Option<[u8; 3]> {
+    let mut picked = None;
+    ui.horizontal_wrapped(|ui| {
+        for rgb in &PICKER_PALETTE {
+            let color = egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
+            if ui.add(egui::Button::new("  ").fill(color)).clicked() {
+                picked = Some(*rgb);
+            }
+        }
+    });
+    picked
+}
+
+
+pub fn trend_style_editor(ui: &mut egui::Ui, style: &mut TrendStyle, lang: Language) -> bool {
+    let before = *style;
+    ui.horizontal(|ui| {
+        ui.label(i18n::t(T::LineWidth, lang));
+        ui.add(egui::Slider::new(&mut style.width, TrendStyle::MIN_WIDTH..=TrendStyle::MAX_WIDTH).step_by(0.5).suffix(" px"));
+    });
+    ui.horizontal(|ui| {
+        ui.label(i18n::t(T::DashPattern, lang));
+        for dash in LineDash::ALL {
+            ui.selectable_value(&mut style.dash, dash, format!("{} {}", dash_glyph(dash), i18n::t(dash_label(dash), lang)));
+        }
+    });
+    ui.checkbox(&mut style.markers, i18n::t(T::PointMarkers, lang));
+    *style = style.clamped();
+    *style != before
+}
+
+
+fn dash_label(dash: LineDash) -> T {
+    match dash {
+        LineDash::Solid => T::DashSolid,
+        LineDash::Dashed => T::DashDashed,
+        LineDash::Dotted => T::DashDotted,
+    }
+}
+
+
+pub fn dash_glyph(dash: LineDash) -> &'static str {
+    match dash {
+        LineDash::Solid => "━",
+        LineDash::Dashed => "╍",
+        LineDash::Dotted => "┈",
+    }
+}
+
+
+fn line_style(dash: LineDash) -> LineStyle {
+    match dash {
+        LineDash::Solid => LineStyle::Solid,
+        LineDash::Dashed => LineStyle::dashed_dense(),
+        LineDash::Dotted => LineStyle::dotted_dense(),
+    }
+}
+
+
+pub fn shared_unit<'a>(units: impl IntoIterator<Item = Option<&'a str>>) -> Option<String> {
+    let mut units = units.into_iter();
+    let first = units.next()??;
+    units.all(|unit| unit == Some(first)).then(|| first.to_string())
+}
+
+
+fn with_unit(text: &str, unit: Option<&str>) -> String {
+    match unit {
+        Some(unit) => format!("{} [{}]", text, unit),
+        None => text.to_string(),
+    }
+}
+
+
 fn format_time(timestamp: f64) -> String {
     use std::time::{UNIX_EPOCH, Duration};
     
@@ -62,11 +277,141 @@ fn format_time(timestamp: f64) -> String {
 
 impl TrendingPanel {
     
+    pub fn is_series_visible(&self, node_id: &NodeId) -> bool {
+        !self.hidden_series.contains(node_id)
+    }
+
+    
+    pub fn toggle_series(&mut self, node_id: &NodeId) {
+        if !self.hidden_series.remove(node_id) {
+            self.hidden_series.insert(node_id.clone());
+        }
+    }
+
+    
+    pub fn solo_series<'a>(&mut self, node_id: &NodeId, series: impl IntoIterator<Item = &'a NodeId>) {
+        let others: HashSet<NodeId> = series.into_iter().filter(|id| *id != node_id).cloned().collect();
+        let already_solo = self.is_series_visible(node_id) && others.iter().all(|id| self.hidden_series.contains(id));
+        if already_solo {
+            self.hidden_series.clear();
+        } else {
+            self.hidden_series = others;
+        }
+    }
+
+    
+    pub fn show_all_series(&mut self) {
+        self.hidden_series.clear();
+    }
+
+    
+    pub fn detach(&mut self, item: &MonitoredData) -> bool {
+        if !item.is_trendable() || item.history.is_empty() {
+            return false;
+        }
+        self.detached_series.push(DetachedSeries::from_monitored(item));
+        true
+    }
+
+    
+    pub fn add_history(&mut self, series: DetachedSeries) {
+        self.detached_series.retain(|existing| !(existing.source == SeriesSource::History && existing.node_id == series.node_id));
+        self.detached_series.push(series);
+    }
+
+    
+    pub fn detached_series(&self) -> &[DetachedSeries] {
+        &self.detached_series
+    }
+
+    
+    pub fn discard_detached(&mut self, index: usize) {
+        if index < self.detached_series.len() {
+            self.detached_series.remove(index);
+        }
+    }
+
+    
+    pub fn clear_detached(&mut self) {
+        self.detached_series.clear();
+    }
+
+    fn show_detached_legend(&mut self, ui: &mut egui::Ui, lang: Language) {
+        let mut discard = None;
+        ui.horizontal_wrapped(|ui| {
+            for (index, series) in self.detached_series.iter().enumerate() {
+                let [r, g, b] = series.color;
+                let glyph = match series.source {
+                    SeriesSource::Captured => "┅",
+                    SeriesSource::History => "━",
+                };
+                ui.label(egui::RichText::new(format!("{} {}", glyph, series.label(lang))).color(egui::Color32::from_rgb(r, g, b)));
+                if ui.small_button("✖").on_hover_text(i18n::t(T::DiscardDetachedSeries, lang)).clicked() {
+                    discard = Some(index);
+                }
+            }
+        });
+        if let Some(index) = discard {
+            self.discard_detached(index);
+        }
+    }
+
+    
+    fn common_unit(&self, series: &[(&NodeId, &MonitoredData, egui::Color32)]) -> Option<String> {
+        let live = series.iter()
+            .filter(|(node_id, _, _)| self.is_series_visible(node_id))
+            .map(|(_, item, _)| item.unit.as_deref());
+        shared_unit(live.chain(self.detached_series.iter().map(|series| series.unit.as_deref())))
+    }
+
+    fn show_legend(
+        &mut self,
+        ui: &mut egui::Ui,
+        series: &[(&NodeId, &MonitoredData, egui::Color32)],
+        per_series_units: bool,
+        default_style: TrendStyle,
+        lang: Language,
+    ) {
+        let mut clicked = None;
+        ui.horizontal_wrapped(|ui| {
+            for (node_id, item, color) in series {
+                let visible = self.is_series_visible(node_id);
+                let swatch = if visible { *color } else { color.gamma_multiply(0.3) };
+                let unit = item.unit.as_deref().filter(|_| per_series_units);
+                let dash = item.trend_style.unwrap_or(default_style).dash;
+                let mut text = egui::RichText::new(format!("{} {}", dash_glyph(dash), with_unit(&item.display_name, unit))).color(swatch);
+                if !visible {
+                    text = text.strikethrough();
+                }
+                let response = ui.selectable_label(false, text)
+                    .on_hover_text(i18n::t(T::TrendLegendHint, lang));
+                if response.clicked() {
+                    clicked = Some(((*node_id).clone(), ui.input(|i| i.modifiers.command)));
+                }
+            }
+
+            if !self.hidden_series.is_empty() && ui.small_button(format!("👁 {}", i18n::t(T::ShowAllSeries, lang))).clicked() {
+                self.show_all_series();
+            }
+        });
+
+        match clicked {
+            Some((node_id, true)) => self.solo_series(&node_id, series.iter().map(|(id, _, _)| *id)),
+            Some((node_id, false)) => self.toggle_series(&node_id),
+            None => {}
+        }
+    }
+
+    
     pub fn show(
         &mut self,
         ui: &mut egui::Ui,
         monitored_items: &HashMap<NodeId, MonitoredData>,
-    ) {
+        default_style: TrendStyle,
+        lang: Language,
+    ) -> Option<TrendingAction> {
+        let mut action = None;
+
         ui.horizontal(|ui| {
             ui.heading("📈 Live Trend");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -78,6 +423,13 @@ impl TrendingPanel {
                             ui.selectable_value(&mut self.time_window, window, format!("{}s", window));
                         }
                     });
+
+                if ui.button(format!("🎨 {}", i18n::t(T::AutoColors, lang)))
+                    .on_hover_text(i18n::t(T::AutoColorsHint, lang))
+                    .clicked()
+                {
+                    action = Some(TrendingAction::AutoAssignColors);
+                }
             });
         });
         
@@ -97,49 +449,253 @@ impl TrendingPanel {
         };
         
         
-        let trending_items: Vec<_> = monitored_items.iter()
+        let mut trending_items: Vec<_> = monitored_items.iter()
             .filter(|(_, item)| item.show_in_trend && item.is_trendable() && !item.history.is_empty())
+            .map(|(node_id, item)| {
+                let color = if let Some(rgb) = item.trend_color {
+                    egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2])
+                } else {
+                    color_for_node_id(node_id)
+                };
+                (node_id, item, color)
+            })
+            .collect();
+        trending_items.sort_by(|a, b| a.1.display_name.cmp(&b.1.display_name));
+
+        let common_unit = self.common_unit(&trending_items);
+        if !trending_items.is_empty() {
+            self.show_legend(ui, &trending_items, common_unit.is_none(), default_style, lang);
+        }
+        if !self.detached_series.is_empty() {
+            self.show_detached_legend(ui, lang);
+        }
+
+        let series_units: HashMap<String, String> = trending_items.iter()
+            .filter_map(|(_, item, _)| item.unit.clone().map(|unit| (item.display_name.clone(), unit)))
+            .chain(self.detached_series.iter().filter_map(|series| series.unit.clone().map(|unit| (series.label(lang), unit))))
             .collect();
+        let readout_unit = common_unit.clone();
+        let readout = move |name: &str, point: &egui_plot::PlotPoint| {
+            let unit = series_units.get(name).map(String::as_str).or(readout_unit.as_deref());
+            let value = match unit {
+                Some(unit) => format!("{:.3} {}", point.y, unit),
+                None => format!("{:.3}", point.y),
+            };
+            if name.is_empty() {
+                format!("{}\n{}", format_time(point.x), value)
+            } else {
+                format!("{}\n{}\n{}", name, format_time(point.x), value)
+            }
+        };
         
         
-        Plot::new("trend_plot")
-            .legend(Legend::default())
+        let mut plot = Plot::new("trend_plot")
             .x_axis_label("Time")
-            .y_axis_label("Value")
-            .custom_x_axes(vec![AxisHints::new_x().formatter(x_fmt)])
-            .include_x(current_time)
-            .include_x(min_time)
-            .show(ui, |plot_ui| {
-                for (node_id, item) in &trending_items {
+            .y_axis_label(common_unit.unwrap_or_else(|| "Value".to_string()))
+            .label_formatter(readout)
+            .custom_x_axes(vec![AxisHints::new_x().formatter(x_fmt)]);
+        let history_ranges: Vec<(f64, f64)> = self.detached_series.iter()
+            .filter(|series| series.source == SeriesSource::History)
+            .filter_map(DetachedSeries::time_range)
+            .collect();
+        if history_ranges.is_empty() || !trending_items.is_empty() {
+            plot = plot.include_x(current_time).include_x(min_time);
+        }
+        for (first, last) in history_ranges {
+            plot = plot.include_x(first).include_x(last);
+        }
+        plot.show(ui, |plot_ui| {
+                for (node_id, item, color) in &trending_items {
+                    if !self.is_series_visible(node_id) {
+                        continue;
+                    }
                     
-                    let points: PlotPoints = item.history
+                    let style = item.trend_style.unwrap_or(default_style).clamped();
+                    let points: Vec<[f64; 2]> = item.history
                         .iter()
                         .filter(|(t, _)| *t >= min_time)
                         .map(|(t, v)| [*t, *v])
                         .collect();
 
-                    
-                    let color = if let Some(rgb) = item.trend_color {
-                        egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2])
-                    } else {
-                        color_for_node_id(node_id)
+                    if style.markers {
+                        plot_ui.points(
+                            Points::new(PlotPoints::from(points.clone()))
+                                .name(&item.display_name)
+                                .color(*color)
+                                .shape(MarkerShape::Circle)
+                                .radius(style.width + 1.0)
+                        );
+                    }
+                    plot_ui.line(
+                        Line::new(PlotPoints::from(points))
+                            .name(&item.display_name)
+                            .color(*color)
+                            .style(line_style(style.dash))
+                            .width(style.width)
+                    );
+                }
+
+                for series in &self.detached_series {
+                    let (style, in_window) = match series.source {
+                        SeriesSource::Captured => (LineStyle::dashed_loose(), min_time),
+                        SeriesSource::History => (LineStyle::Solid, f64::NEG_INFINITY),
                     };
+                    let points: PlotPoints = series.history
+                        .iter()
+                        .filter(|(t, _)| *t >= in_window)
+                        .map(|(t, v)| [*t, *v])
+                        .collect();
+                    let [r, g, b] = series.color;
 
                     plot_ui.line(
                         Line::new(points)
-                            .name(&item.display_name)
-                            .color(color)
+                            .name(series.label(lang))
+                            .color(egui::Color32::from_rgb(r, g, b))
+                            .style(style)
                             .width(2.0)
                     );
                 }
             });
             
         
-        if trending_items.is_empty() {
+        if trending_items.is_empty() && self.detached_series.is_empty() {
             ui.centered_and_justified(|ui| {
                 ui.label("Select numeric items in the Watchlist (📈) to visualize them here.\nNote: Dates and strings cannot be graphed.");
             });
         }
+
+        action
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_distinct_colors_only_touches_trended_items() {
+        let mut items = HashMap::new();
+        for i in 0..15 {
+            let node_id = NodeId::new(2, i);
+            let mut item = MonitoredData::new(node_id.clone(), format!("Tag{:02}", i));
+            item.show_in_trend = i != 0;
+            items.insert(node_id, item);
+        }
+
+        assert_eq!(assign_distinct_colors(&mut items), 14);
+        assert!(items[&NodeId::new(2, 0)].trend_color.is_none());
+
+        let mut colors: Vec<[u8; 3]> = items.values().filter_map(|i| i.trend_color).collect();
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors.len(), 14);
+        assert_eq!(items[&NodeId::new(2, 1)].trend_color, Some(DISTINCT_PALETTE[0]));
+    }
+
+    #[test]
+    fn test_toggle_and_solo_series() {
+        let ids: Vec<NodeId> = (0..3).map(|i| NodeId::new(2, i)).collect();
+        let mut panel = TrendingPanel::default();
+
+        panel.toggle_series(&ids[1]);
+        assert!(!panel.is_series_visible(&ids[1]));
+        panel.toggle_series(&ids[1]);
+        assert!(panel.is_series_visible(&ids[1]));
+
+        panel.solo_series(&ids[2], &ids);
+        assert!(panel.is_series_visible(&ids[2]));
+        assert!(!panel.is_series_visible(&ids[0]) && !panel.is_series_visible(&ids[1]));
+
+        panel.solo_series(&ids[2], &ids);
+        assert!(ids.iter().all(|id| panel.is_series_visible(id)));
+
+        panel.solo_series(&ids[0], &ids);
+        panel.show_all_series();
+        assert!(ids.iter().all(|id| panel.is_series_visible(id)));
+    }
+
+    #[test]
+    fn test_shared_unit_requires_every_series_to_agree() {
+        assert_eq!(shared_unit([Some("°C"), Some("°C")]), Some("°C".to_string()));
+        assert_eq!(shared_unit([Some("°C"), Some("bar")]), None);
+        assert_eq!(shared_unit([Some("°C"), None]), None);
+        assert_eq!(shared_unit([None, None]), None);
+        assert_eq!(shared_unit(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_common_unit_is_recomputed_when_visible_units_change() {
+        let temp = NodeId::new(2, "Temp");
+        let pressure = NodeId::new(2, "Pressure");
+        let mut temp_item = MonitoredData::new(temp.clone(), "Temp".into());
+        temp_item.unit = Some("°C".into());
+        let mut pressure_item = MonitoredData::new(pressure.clone(), "Pressure".into());
+        pressure_item.unit = Some("bar".into());
+
+        let mut panel = TrendingPanel::default();
+        let color = egui::Color32::WHITE;
+        let series = [(&temp, &temp_item, color), (&pressure, &pressure_item, color)];
+        assert_eq!(panel.common_unit(&series), None);
+
+        panel.toggle_series(&pressure);
+        assert_eq!(panel.common_unit(&series), Some("°C".to_string()));
+
+        pressure_item.unit = Some("°C".into());
+        panel.show_all_series();
+        let series = [(&temp, &temp_item, color), (&pressure, &pressure_item, color)];
+        assert_eq!(panel.common_unit(&series), Some("°C".to_string()));
+
+        let mut captured = DetachedSeries::from_history(NodeId::new(2, "Line"), "Line".into(), vec![(1.0, 2.0)]);
+        captured.unit = Some("bar".into());
+        panel.add_history(captured);
+        assert_eq!(panel.common_unit(&series), None);
+    }
+
+    #[test]
+    fn test_detached_series_keep_history_until_discarded() {
+        let node_id = NodeId::new(2, "Speed");
+        let mut item = MonitoredData::new(node_id.clone(), "Speed".into());
+        item.trend_color = Some([1, 2, 3]);
+        let mut panel = TrendingPanel::default();
+        assert!(!panel.detach(&item));
+
+        item.update(&opcua::types::DataValue::value_only(12.5f64));
+        item.update(&opcua::types::DataValue::value_only(13.0f64));
+        assert!(panel.detach(&item));
+        assert!(panel.detach(&item));
+        drop(item);
+
+        let detached = panel.detached_series();
+        assert_eq!(detached.len(), 2);
+        assert_eq!(detached[0].history.len(), 2);
+        assert_eq!(detached[0].color, [1, 2, 3]);
+        assert_eq!(detached[0].label(Language::English), "Speed (captured)");
+        assert_eq!(detached[0].label(Language::Spanish), "Speed (capturada)");
+
+        panel.discard_detached(5);
+        panel.discard_detached(0);
+        assert_eq!(panel.detached_series().len(), 1);
+        panel.clear_detached();
+        assert!(panel.detached_series().is_empty());
+    }
+
+    #[test]
+    fn test_history_series_replace_previous_read_of_same_node() {
+        let node_id = NodeId::new(2, "Speed");
+        let mut panel = TrendingPanel::default();
+        let mut item = MonitoredData::new(node_id.clone(), "Speed".into());
+        item.update(&opcua::types::DataValue::value_only(10.0f64));
+        assert!(panel.detach(&item));
+
+        panel.add_history(DetachedSeries::from_history(node_id.clone(), "Speed".into(), vec![(100.0, 1.0), (200.0, 2.0)]));
+        panel.add_history(DetachedSeries::from_history(node_id.clone(), "Speed".into(), vec![(300.0, 3.0), (50.0, 4.0)]));
+
+        let series = panel.detached_series();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].source, SeriesSource::Captured);
+        assert_eq!(series[1].label(Language::English), "Speed (history)");
+        assert_eq!(series[1].label(Language::Spanish), "Speed (historial)");
+        assert_eq!(series[1].time_range(), Some((50.0, 300.0)));
+    }
+}
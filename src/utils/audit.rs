@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, SecondsFormat};
+
+
+pub const MAX_AUDIT_ENTRIES: usize = 2000;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditEvent {
+    Connect,
+    Disconnect,
+    Browse,
+    WatchlistAdd,
+    WatchlistRemove,
+    Export,
+    Crawl,
+}
+
+impl AuditEvent {
+    
+    pub fn name(self) -> &'static str {
+        match self {
+            AuditEvent::Connect => "CONNECT",
+            AuditEvent::Disconnect => "DISCONNECT",
+            AuditEvent::Browse => "BROWSE",
+            AuditEvent::WatchlistAdd => "WATCHLIST_ADD",
+            AuditEvent::WatchlistRemove => "WATCHLIST_REMOVE",
+            AuditEvent::Export => "EXPORT",
+            AuditEvent::Crawl => "CRAWL",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    
+    pub timestamp: DateTime<Local>,
+    
+    pub event: AuditEvent,
+    
+    pub endpoint: Option<String>,
+    
+    pub details: String,
+}
+
+impl AuditEntry {
+    
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}",
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, false),
+            self.event.name(),
+            self.endpoint.as_deref().unwrap_or("-"),
+            self.details.replace(['\t', '\n', '\r'], " "),
+        )
+    }
+}
+
+
+pub fn audit_file_name(date: NaiveDate) -> String {
+    format!("audit_{}.log", date.format("%Y%m%d"))
+}
+
+
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    dir: PathBuf,
+    entries: VecDeque<AuditEntry>,
+}
+
+impl AuditLog {
+    pub fn new(enabled: bool, dir: impl Into<PathBuf>) -> Self {
+        Self { enabled, dir: dir.into(), entries: VecDeque::new() }
+    }
+
+    
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &AuditEntry> {
+        self.entries.iter()
+    }
+
+    
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    
+    pub fn file_path(&self, date: NaiveDate) -> PathBuf {
+        self.dir.join(audit_file_name(date))
+    }
+
+    
+    pub fn record(&mut self, event: AuditEvent, endpoint: Option<&str>, details: String) {
+        if !self.enabled {
+            return;
+        }
+        let entry = AuditEntry { timestamp: Local::now(), event, endpoint: endpoint.map(str::to_string), details };
+        let path = self.file_path(entry.timestamp.date_naive());
+        if let Err(e) = append_line(&path, &entry.to_line()) {
+            tracing::warn!("Failed to write audit entry to {:?}: {:#}", path, e);
+        }
+        if self.entries.len() >= MAX_AUDIT_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+
+fn append_line(path: &Path, line: &str) -> Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context("Failed to open audit log")?;
+    writeln!(file, "{}", line).context("Failed to append audit entry")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    #[test]
+    fn test_disabled_log_records_nothing() {
+        let dir = TestDir::new("audit_off");
+        let mut log = AuditLog::new(false, dir.path());
+        log.record(AuditEvent::Connect, Some("opc.tcp://plc:4840"), "Connected".into());
+        assert!(log.is_empty());
+        assert!(!log.file_path(Local::now().date_naive()).exists());
+    }
+
+    #[test]
+    fn test_entries_are_appended_to_the_daily_file() {
+        let dir = TestDir::new("audit_on");
+        let mut log = AuditLog::new(true, dir.path());
+        log.record(AuditEvent::Browse, Some("opc.tcp://plc:4840"), "ns=2;s=Line1".into());
+        log.record(AuditEvent::Export, None, "watchlist CSV:\tC:\\out.csv\n".into());
+
+        let path = log.file_path(Local::now().date_naive());
+        assert!(path.file_name().unwrap().to_string_lossy().starts_with("audit_"));
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("\tBROWSE\topc.tcp://plc:4840\tns=2;s=Line1"), "{}", lines[0]);
+        assert!(lines[1].ends_with("\tEXPORT\t-\twatchlist CSV: C:\\out.csv "), "{}", lines[1]);
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_memory_list_is_bounded() {
+        let dir = TestDir::new("audit_bound");
+        let mut log = AuditLog::new(true, dir.path());
+        for i in 0..MAX_AUDIT_ENTRIES + 5 {
+            log.record(AuditEvent::Browse, None, i.to_string());
+        }
+        assert_eq!(log.len(), MAX_AUDIT_ENTRIES);
+        assert_eq!(log.entries().next().unwrap().details, "5");
+    }
+
+    #[test]
+    fn test_file_name_uses_date() {
+        assert_eq!(audit_file_name(NaiveDate::from_ymd_opt(2026, 3, 7).unwrap()), "audit_20260307.log");
+    }
+}
@@ -1 +1,67 @@
 
+
+
+
+use anyhow::{Context, Result};
+use eframe::egui::{self, ColorImage};
+use std::path::Path;
+
+
+pub fn crop_screenshot(image: &ColorImage, rect: Option<egui::Rect>, pixels_per_point: f32) -> ColorImage {
+    let Some(rect) = rect else {
+        return image.clone();
+    };
+    let bounds = egui::Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(image.size[0] as f32, image.size[1] as f32) / pixels_per_point,
+    );
+    let clamped = rect.intersect(bounds);
+    if !clamped.is_positive() {
+        return image.clone();
+    }
+    image.region(&clamped, Some(pixels_per_point))
+}
+
+
+pub fn copy_image(image: &ColorImage) -> Result<()> {
+    let mut clipboard = arboard::Clipboard::new().context("Clipboard unavailable")?;
+    clipboard
+        .set_image(arboard::ImageData {
+            width: image.size[0],
+            height: image.size[1],
+            bytes: std::borrow::Cow::Borrowed(image.as_raw()),
+        })
+        .context("Failed to copy image to clipboard")
+}
+
+
+pub fn save_png(image: &ColorImage, path: &Path) -> Result<()> {
+    image::save_buffer(
+        path,
+        image.as_raw(),
+        image.size[0] as u32,
+        image.size[1] as u32,
+        image::ColorType::Rgba8,
+    )
+    .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crop_is_clamped_to_image() {
+        let image = ColorImage::new([200, 100], egui::Color32::WHITE);
+
+        let inside = crop_screenshot(&image, Some(egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(20.0, 20.0))), 2.0);
+        assert_eq!(inside.size, [40, 40]);
+
+        let overflowing = crop_screenshot(&image, Some(egui::Rect::from_min_max(egui::pos2(50.0, 0.0), egui::pos2(500.0, 500.0))), 2.0);
+        assert_eq!(overflowing.size, [100, 100]);
+
+        let outside = crop_screenshot(&image, Some(egui::Rect::from_min_size(egui::pos2(300.0, 300.0), egui::vec2(5.0, 5.0))), 1.0);
+        assert_eq!(outside.size, image.size);
+        assert_eq!(crop_screenshot(&image, None, 1.0).size, image.size);
+    }
+}
@@ -81,7 +81,10 @@ pub enum T {
     MaxDepth,
     MaxNodes,
     CrawlComplete,
+    CrawlProgress,
     CrawlFailed,
+    CrawlTruncated,
+    CrawlCancelled,
     NodeId,
     DisplayName,
     CrawlerDescription,
@@ -89,6 +92,83 @@ pub enum T {
     Configuration,
     Class,
     Results,
+    IncludeStartNode,
+    StartNodes,
+    UseSelectedTreeNode,
+    CrawlConflicts,
+    CrawlConflictsHint,
+    ExportConflicts,
+    FirstSeen,
+    SeenAgain,
+    ConflictsExported,
+    RemoveOrDetachHint,
+    DetachKeepCurve,
+    DiscardCapturedCurves,
+    DiscardCapturedCurvesPrompt,
+    Discard,
+    Keep,
+    RequestedEndpoint,
+    NegotiatedEndpoint,
+    EndpointMismatch,
+    EndpointUrlField,
+    SecurityPolicyField,
+    SecurityModeField,
+    AnonymizeExports,
+    AnonymizeExportsHint,
+    RefreshValueHint,
+    ReadingValue,
+    InputArguments,
+    OutputArguments,
+    ArgumentName,
+    NoArguments,
+    ReadingMethodArguments,
+    MethodArgumentsFailed,
+    ReadHistory,
+    ReadHistoryHint,
+    HistoryRange,
+    HistoryLastHour,
+    HistoryLast8Hours,
+    HistoryLastDay,
+    HistoryLastWeek,
+    HistoryCustomRange,
+    HistoryStart,
+    HistoryEnd,
+    HistoryMaxValues,
+    HistorySeriesLabel,
+    CapturedSeriesLabel,
+    DiscardDetachedSeries,
+    InvalidHistoryTime,
+    HistoryRangeInverted,
+    ReadingHistory,
+    HistoryLoaded,
+    HistoryEmpty,
+    HistoryReadFailed,
+    NarrowLayoutWidth,
+    NarrowLayoutWidthHint,
+    TrendLineStyle,
+    TrendLineStyleHint,
+    UseDefaultTrendStyle,
+    TrendSwatchHint,
+    TrendLegendHint,
+    AutoColorsHint,
+    AutoColors,
+    ShowAllSeries,
+    LineWidth,
+    DashPattern,
+    DashSolid,
+    DashDashed,
+    DashDotted,
+    PointMarkers,
+    AuditLog,
+    EnableAuditLog,
+    EnableAuditLogHint,
+    AuditLogFile,
+    AuditLogDisabled,
+    NoAuditEntries,
+    AuditTimeColumn,
+    AuditEventColumn,
+    AuditEndpointColumn,
+    AuditDetailsColumn,
     
     
     Node,
@@ -128,6 +208,276 @@ pub enum T {
     Anonymous,
     DiscoveryFailed,
     NoItems,
+    FilterAll,
+    FilterProblems,
+    FilterStale,
+    FilterName,
+    ShowingItems,
+    NumericOnly,
+    ExportFilteredOnly,
+    TrendLimitReached,
+    BookmarkWatchlistHint,
+    WatchlistLoadedFromBookmark,
+    SaveWatchlistToBookmark,
+    WatchlistSavedToBookmark,
+    MonitoredItemFailed,
+    GoToTypeDefinition,
+    NavigateFailed,
+    ServerDiagnostics,
+    ServerDiagnosticsHint,
+    DiagnosticsDisabled,
+    SessionLoad,
+    SubscriptionLoad,
+    ServerOverloadHint,
+    Refresh,
+    NotAvailable,
+    ExportOptions,
+    NumberFormatLabel,
+    NumberFullPrecision,
+    NumberFixed,
+    NumberShortest,
+    ConnectivityMonitor,
+    ConnectivityHint,
+    Target,
+    Interval,
+    StartMonitor,
+    Availability,
+    Drops,
+    AvgLatency,
+    Reachable,
+    Unreachable,
+    SecondsAgo,
+    CopyStructure,
+    StructureCopied,
+    StructureTruncated,
+    BrowseMissingLevels,
+    BrowseAndCopy,
+    CopyLoadedOnly,
+    ImportTagList,
+    InvalidTagLine,
+    TagListImported,
+    Overflow,
+    OverflowCount,
+    QueueOverflowWarning,
+    LimitLow,
+    LimitHigh,
+    LimitConstant,
+    ValidateTagList,
+    ValidateTagListHint,
+    ValidationSummary,
+    FailuresOnly,
+    AddPassingToWatchlist,
+    Result,
+    DataType,
+    Status,
+    Pass,
+    Fail,
+    Readable,
+    NodeMissing,
+    NotAVariable,
+    NotReadable,
+    SkipEndpointMatching,
+    SkipEndpointMatchingHint,
+    EndpointFallback,
+    EndpointFallbackHint,
+    TryingFallbackEndpoint,
+    ConnectedViaFallback,
+    GoToNodeId,
+    GoToPath,
+    GoToPathHint,
+    PathNotFound,
+    InvalidNodeId,
+    QuickAddFailed,
+    ServiceTimings,
+    Service,
+    SlowServiceCall,
+    RetryStep,
+    Add,
+    TagFilter,
+    Untagged,
+    ServerView,
+    FullAddressSpace,
+    EditBookmark,
+    Tags,
+    TagsHint,
+    PinConnectionPanel,
+    PinConnectionPanelHint,
+    ExportCertificateList,
+    ExportServerProfile,
+    SaveScreenshot,
+    ScreenshotHint,
+    ScreenshotCopied,
+    ScreenshotSaved,
+    OutboundRequests,
+    InboundNotifications,
+    OpenErrorPanel,
+    ServerProfileExported,
+    ExportNodeDetails,
+    NodeDetailsExported,
+    RawDataValue,
+    VariantType,
+    RawValue,
+    MaxUiUpdateRate,
+    MaxUiUpdateRateHint,
+    ReadInitialValue,
+    ReadInitialValueHint,
+    Favorites,
+    AddToFavorites,
+    RemoveFromFavorites,
+    FavoriteAdded,
+    FavoriteRemoved,
+    InputEmpty,
+    OnlyOpcTcpScheme,
+    InvalidPort,
+    InvalidIpv6,
+    InvalidHostPort,
+    HostEmpty,
+    HostPortDetail,
+    PortNotSpecified,
+    ResolvingHost,
+    TestedPorts,
+    EndpointsFoundAt,
+    InitializingDiagnostic,
+    DiagnoseHint,
+    ConnectHint,
+    DisconnectHint,
+    SaveBookmarkHint,
+    DefaultBookmarkName,
+    TimingMin,
+    TimingAvg,
+    BrowseError,
+    BrowseAccessDenied,
+    AccessDenied,
+    ErrorPrefix,
+    ConnectionFailed,
+    NotConnected,
+    SettingsSaveFailed,
+    Settings,
+    PublishingInterval,
+    PublishingIntervalHint,
+    MaxWatchlistItems,
+    MaxTrendSeries,
+    SettingsSavedNextToExe,
+    WatchlistLimitReached,
+    Subscription,
+    LifetimeCount,
+    LifetimeCountHint,
+    KeepAliveCount,
+    MaxNotifications,
+    MaxNotificationsHint,
+    SubscriptionOutdated,
+    ApplyToSubscription,
+    SubscriptionUpdated,
+    SubscriptionUpdateFailed,
+    ChangingPublishingInterval,
+    StreamToJson,
+    StreamToJsonHint,
+    CrawlStreamed,
+    CrawlStreamFailed,
+    WriteExportManifest,
+    WriteExportManifestHint,
+    ClearConnectionForm,
+    ClearConnectionFormHint,
+    Renderer,
+    ReadOnly,
+    ReadOnlyMode,
+    ReadOnlyModeDetail,
+    RendererDegraded,
+    MesaSuggestion,
+    OpenMesaDownload,
+    CopyMesaInstructions,
+    MesaInstructions,
+    UnlockSecurity,
+    SecurityLockedHint,
+    Unlimited,
+    SourceTimestamp,
+    ServerTimestamp,
+    BinaryEncoding,
+    JsonEncoding,
+    AccessLevel,
+    UserAccessLevel,
+    Description,
+    ValueRank,
+    MinimumSamplingInterval,
+    Historizing,
+    ArrayDimensions,
+    Attribute,
+    AttributeRefused,
+    AuthUsernamePassword,
+    AuthCertificate,
+    UserCertificate,
+    UserPrivateKey,
+    BrowseFile,
+    TimestampFormatLabel,
+    ExportTimestampFormat,
+    TimestampIso8601,
+    TimestampLocalShort,
+    TimestampLocalLong,
+    TimestampCustom,
+    InvalidTimestampFormat,
+    MonitoredItemQueue,
+    MonitoredItemQueueHint,
+    QueueSize,
+    DiscardOldest,
+    DiscardOldestHint,
+    TimestampsToReturn,
+    TimestampsToReturnHint,
+    TimestampsSource,
+    TimestampsServer,
+    TimestampsBoth,
+    TimestampsNeither,
+    RetryBrowse,
+    CrawlSummary,
+    CrawledNodeCount,
+    ItemDetails,
+    TimestampSkew,
+    InfoBits,
+    MonitoredItemId,
+    ClientHandle,
+    RevisedSamplingInterval,
+    RevisedQueueSize,
+    MonitoringParameters,
+    MonitoringParametersHint,
+    SamplingInterval,
+    Deadband,
+    DeadbandNone,
+    DeadbandAbsolute,
+    DeadbandPercent,
+    Apply,
+    MonitoredItemModifyFailed,
+    DeadbandRejected,
+    Events,
+    EventNotifier,
+    SubscribeEvents,
+    UnsubscribeEvents,
+    ReceivingEvents,
+    MinSeverity,
+    NoEvents,
+    Severity,
+    EventSource,
+    EventType,
+    EventMessage,
+    EventSubscriptionFailed,
+    AddAllVariables,
+    IncludeSubfolders,
+    CollectVariablesFailed,
+    NoVariablesFound,
+    FolderVariablesAdded,
+    FolderVariablesOverLimit,
+    FolderVariablesTruncated,
+    RecentSample,
+    CopyAllAsText,
+    StartupBookmarksCorrupt,
+    Recreate,
+    MonitoredItemInvalidated,
+    MonitoredItemRepairing,
+    MonitoredItemRepairFailed,
+    MonitoredItemLimitReached,
+    CreateAdditionalSubscription,
+    AdditionalSubscriptionFailed,
+    StartupBookmarksCorruptNoBackup,
+    StartupPkiNotWritable,
+    StartupLogNotWritable,
     
     
     Diagnose,
@@ -139,6 +489,8 @@ pub enum T {
     DiagnosticComplete,
     DiagnosticFailed,
     PortsOpen,
+    PortsChecked,
+    QueryingEndpointUrl,
     NoOpenPorts,
     DnsResolved,
     DnsFailed,
@@ -165,12 +517,36 @@ pub enum T {
     
     
     ErrorPanel,
+    MoreInErrorPanel,
     ClearAll,
     CommonErrors,
     ErrorCode,
     ErrorDescription,
     NoErrors,
     ServerDisconnected,
+    ConnectionLost,
+    ConnectionLostAfter,
+    Reconnect,
+    SessionInfo,
+    Uptime,
+    Reconnects,
+    Downtime,
+    Reconnecting,
+    ReconnectingAttempt,
+    ReconnectScheduled,
+    ReconnectGaveUp,
+    StopReconnecting,
+    AutoReconnect,
+    AutoReconnectHint,
+    ReconnectMaxAttempts,
+    ReconnectMaxAttemptsHint,
+    WatchlistRestored,
+    UnstableLinkHint,
+    DemoServer,
+    DemoServerHint,
+    DemoModeActive,
+    DemoStopped,
+    NotAvailableInDemo,
 }
 
 
@@ -181,6 +557,33 @@ pub fn t(key: T, lang: Language) -> &'static str {
     }
 }
 
+
+pub fn t_fmt(key: T, lang: Language, args: &[&str]) -> String {
+    let template = t(key, lang);
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let tail = &rest[open..];
+        let arg = tail.find('}').and_then(|close| {
+            let index: usize = tail[1..close].parse().ok()?;
+            Some((args.get(index)?, close))
+        });
+        match arg {
+            Some((value, close)) => {
+                out.push_str(value);
+                rest = &tail[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
 fn match_en(key: T) -> &'static str {
     match key {
         T::File => "File",
@@ -217,14 +620,14 @@ fn match_en(key: T) -> &'static str {
         T::Stop => "Stop",
         T::Success => "Success",
         T::Failed => "Failed",
-        T::FoundEndpoints => "Found {} endpoints:",
+        T::FoundEndpoints => "Found {0} endpoints:",
         T::Save => "Save",
         T::Cancel => "Cancel",
         T::Name => "Name:",
         T::ReadyNotConnected => "Ready - Not connected",
-        T::ConnectedTo => "Connected to {}",
+        T::ConnectedTo => "Connected to {0}",
         T::Disconnected => "Disconnected",
-        T::TaskCancelled => "Task '{}' cancelled",
+        T::TaskCancelled => "Task '{0}' cancelled",
         T::EstablishingConnection => "Establishing connection...",
         T::Watchlist => "Watchlist",
         T::Crawler => "Crawler",
@@ -233,8 +636,11 @@ fn match_en(key: T) -> &'static str {
         T::StartCrawl => "Start Crawl",
         T::MaxDepth => "Max Depth:",
         T::MaxNodes => "Max Nodes:",
-        T::CrawlComplete => "Crawl complete. Found {} nodes.",
-        T::CrawlFailed => "Crawl failed: {}",
+        T::CrawlComplete => "Crawl complete. Found {0} nodes.",
+        T::CrawlProgress => "{0} nodes found, depth {1}: {2}",
+        T::CrawlFailed => "Crawl failed: {0}",
+        T::CrawlTruncated => "Node limit reached, results truncated.",
+        T::CrawlCancelled => "Cancelled - {0} nodes collected",
         T::NodeId => "NodeId",
         T::DisplayName => "Display Name",
         T::CrawlerDescription => "Recursively discover nodes in the address space.",
@@ -242,6 +648,83 @@ fn match_en(key: T) -> &'static str {
         T::Configuration => "Configuration",
         T::Class => "Class",
         T::Results => "Results",
+        T::IncludeStartNode => "Include start node in results",
+        T::StartNodes => "Start nodes",
+        T::UseSelectedTreeNode => "Use selected tree node",
+        T::CrawlConflicts => "Conflicts ({0})",
+        T::CrawlConflictsHint => "These NodeIds were reached again with a different display name, browse name or node class",
+        T::ExportConflicts => "Export conflicts CSV",
+        T::FirstSeen => "First seen",
+        T::SeenAgain => "Seen again",
+        T::ConflictsExported => "Exported {0} conflicts to {1}",
+        T::RemoveOrDetachHint => "Remove (right-click to keep the captured curve)",
+        T::DetachKeepCurve => "Detach (keep curve)",
+        T::DiscardCapturedCurves => "Captured curves",
+        T::DiscardCapturedCurvesPrompt => "The trend still holds {0} captured curve(s) from the previous session. Discard them?",
+        T::Discard => "Discard",
+        T::Keep => "Keep",
+        T::RequestedEndpoint => "Requested",
+        T::NegotiatedEndpoint => "Negotiated",
+        T::EndpointMismatch => "The server negotiated a different endpoint than requested ({0})",
+        T::EndpointUrlField => "endpoint URL",
+        T::SecurityPolicyField => "security policy",
+        T::SecurityModeField => "security mode",
+        T::AnonymizeExports => "Anonymize exports",
+        T::AnonymizeExportsHint => "Replace names, string NodeIds, namespace URIs and endpoint hostnames with stable tokens. A .anonymization.csv mapping file is written next to each export; keep it on this machine.",
+        T::RefreshValueHint => "Read the current value again without adding it to the watchlist",
+        T::ReadingValue => "Reading value...",
+        T::InputArguments => "Input arguments",
+        T::OutputArguments => "Output arguments",
+        T::ArgumentName => "Name",
+        T::NoArguments => "None",
+        T::ReadingMethodArguments => "Reading method arguments...",
+        T::MethodArgumentsFailed => "Could not read the method arguments: {0}",
+        T::ReadHistory => "Read History",
+        T::ReadHistoryHint => "Read the values stored by the server's historian over a time range and plot them in the trend",
+        T::HistoryRange => "Range",
+        T::HistoryLastHour => "Last hour",
+        T::HistoryLast8Hours => "Last 8 hours",
+        T::HistoryLastDay => "Last 24 hours",
+        T::HistoryLastWeek => "Last 7 days",
+        T::HistoryCustomRange => "Custom range",
+        T::HistoryStart => "Start",
+        T::HistoryEnd => "End",
+        T::HistoryMaxValues => "Max. values",
+        T::HistorySeriesLabel => "{0} (history)",
+        T::CapturedSeriesLabel => "{0} (captured)",
+        T::DiscardDetachedSeries => "Discard captured curve",
+        T::InvalidHistoryTime => "'{0}' is not a valid time, expected YYYY-MM-DD HH:MM[:SS]",
+        T::HistoryRangeInverted => "The end of the range must be after its start",
+        T::ReadingHistory => "Reading history of {0}...",
+        T::HistoryLoaded => "Loaded {0} historical values for {1}",
+        T::HistoryEmpty => "No historical values for {0} in the selected range",
+        T::HistoryReadFailed => "History read of {0} failed",
+        T::NarrowLayoutWidth => "Tabbed side panels below",
+        T::NarrowLayoutWidthHint => "When the window is narrower than this width, the properties, crawler, certificates and error panels share one tabbed side panel. Set to 0 to always show them side by side.",
+        T::TrendLineStyle => "Trend line style",
+        T::TrendLineStyleHint => "Default line width, dash pattern and point markers for trend series without their own style",
+        T::UseDefaultTrendStyle => "Use default style",
+        T::TrendSwatchHint => "Right-click to change color and line style",
+        T::TrendLegendHint => "Click to hide/show, Ctrl+click to show only this series",
+        T::AutoColorsHint => "Assign distinct colors to all trended items",
+        T::AutoColors => "Auto colors",
+        T::ShowAllSeries => "Show all",
+        T::LineWidth => "Width",
+        T::DashPattern => "Pattern",
+        T::DashSolid => "Solid",
+        T::DashDashed => "Dashed",
+        T::DashDotted => "Dotted",
+        T::PointMarkers => "Point markers",
+        T::AuditLog => "Audit trail",
+        T::EnableAuditLog => "Record audit trail",
+        T::EnableAuditLogHint => "Record connects, disconnects, browsed nodes, watchlist changes, exports and crawls with timestamps and the connected endpoint. Values are never recorded.",
+        T::AuditLogFile => "Appending to {0}",
+        T::AuditLogDisabled => "The audit trail is off. Nothing is being recorded.",
+        T::NoAuditEntries => "No audit entries in this session.",
+        T::AuditTimeColumn => "Time",
+        T::AuditEventColumn => "Action",
+        T::AuditEndpointColumn => "Endpoint",
+        T::AuditDetailsColumn => "Details",
         T::Node => "Node",
         T::Value => "Value",
         T::Quality => "Quality",
@@ -273,6 +756,276 @@ fn match_en(key: T) -> &'static str {
         T::Anonymous => "Anonymous",
         T::DiscoveryFailed => "Discovery failed",
         T::NoItems => "No items to show.",
+        T::FilterAll => "All",
+        T::FilterProblems => "Bad or Uncertain only",
+        T::FilterStale => "Stale only",
+        T::FilterName => "Filter",
+        T::ShowingItems => "showing {0} of {1}",
+        T::NumericOnly => "Numeric only",
+        T::ExportFilteredOnly => "Export filtered only",
+        T::TrendLimitReached => "Trend limit reached ({0} series). Remove items from the trend before adding more.",
+        T::BookmarkWatchlistHint => "Default watchlist: {0} items",
+        T::WatchlistLoadedFromBookmark => "Loaded {0} items from bookmark",
+        T::SaveWatchlistToBookmark => "Save to bookmark",
+        T::WatchlistSavedToBookmark => "Saved {0} items to bookmark",
+        T::MonitoredItemFailed => "Could not monitor {0}: {1}",
+        T::GoToTypeDefinition => "Go to type definition",
+        T::NavigateFailed => "Could not locate {0} in the address space",
+        T::ServerDiagnostics => "Server diagnostics",
+        T::ServerDiagnosticsHint => "Standard counters from Server/ServerDiagnostics, refreshed every {0} s.",
+        T::DiagnosticsDisabled => "The server reports diagnostics as disabled; counters may be zero or missing.",
+        T::SessionLoad => "Session load",
+        T::SubscriptionLoad => "Subscription load",
+        T::ServerOverloadHint => "The server is close to its session or subscription limit, which can cause connection failures.",
+        T::Refresh => "Refresh",
+        T::NotAvailable => "n/a",
+        T::ExportOptions => "Export options",
+        T::NumberFormatLabel => "Number format",
+        T::NumberFullPrecision => "Full precision",
+        T::NumberFixed => "Fixed decimals",
+        T::NumberShortest => "Shortest round-trip",
+        T::ConnectivityMonitor => "Connectivity monitor",
+        T::ConnectivityHint => "Repeated TCP connect checks against the target, shown as an up/down strip.",
+        T::Target => "Target",
+        T::Interval => "Interval",
+        T::StartMonitor => "Start",
+        T::Availability => "Availability",
+        T::Drops => "Drops",
+        T::AvgLatency => "Avg latency",
+        T::Reachable => "Reachable",
+        T::Unreachable => "Unreachable",
+        T::SecondsAgo => "{0}s ago",
+        T::CopyStructure => "Copy structure as text",
+        T::StructureCopied => "Copied {0} nodes to the clipboard",
+        T::StructureTruncated => "(truncated at 1 MB)",
+        T::BrowseMissingLevels => "{0} folders in this subtree have not been loaded yet. Browse them (up to {1} levels deep) before copying?",
+        T::BrowseAndCopy => "Browse and copy",
+        T::CopyLoadedOnly => "Copy loaded only",
+        T::ImportTagList => "Import tag list...",
+        T::InvalidTagLine => "Line {0}: '{1}' is not a valid NodeId",
+        T::TagListImported => "Imported {0} tags ({1} invalid lines)",
+        T::Overflow => "overflow",
+        T::OverflowCount => "Server queue overflowed {0} times; values were dropped",
+        T::QueueOverflowWarning => "Queue overflow on '{0}': values are being dropped. Increase the queue size or use a faster publishing interval.",
+        T::LimitLow => "Limit: low",
+        T::LimitHigh => "Limit: high",
+        T::LimitConstant => "Limit: constant",
+        T::ValidateTagList => "Validate tag list",
+        T::ValidateTagListHint => "Pick a tag list file to check each NodeId against the server without subscribing.",
+        T::ValidationSummary => "{0} passed, {1} failed",
+        T::FailuresOnly => "Failures only",
+        T::AddPassingToWatchlist => "Add passing to watchlist",
+        T::Result => "Result",
+        T::DataType => "Data type",
+        T::Status => "Status",
+        T::Pass => "PASS",
+        T::Fail => "FAIL",
+        T::Readable => "Readable",
+        T::NodeMissing => "Node does not exist",
+        T::NotAVariable => "Not a variable",
+        T::NotReadable => "Not readable",
+        T::SkipEndpointMatching => "Advanced: connect without endpoint matching",
+        T::SkipEndpointMatchingHint => "Connect to the exact URL typed with the selected policy and mode, without asking the server for its endpoint list. Useful for servers that report inconsistent endpoints.",
+        T::EndpointFallback => "Fall back to the next endpoint on failure",
+        T::EndpointFallbackHint => "If the selected endpoint fails, retry with the next endpoint found by the diagnostic (possibly less secure). You will be notified which endpoint was used.",
+        T::TryingFallbackEndpoint => "Preferred endpoint failed, trying {0}",
+        T::ConnectedViaFallback => "Connected via fallback endpoint {0}",
+        T::GoToNodeId => "Go to NodeId:",
+        T::GoToPath => "Go to path:",
+        T::GoToPathHint => "Browse path from the Root folder, e.g. /Objects/2:MyDevice/2:Temperature. Prefix a segment with its namespace index for non-standard namespaces.",
+        T::PathNotFound => "Could not resolve path {0}",
+        T::InvalidNodeId => "'{0}' is not a valid NodeId",
+        T::QuickAddFailed => "Could not add {0} to the watchlist: {1}",
+        T::ServiceTimings => "Service round-trip times",
+        T::Service => "Service",
+        T::SlowServiceCall => "Last slow call: {0} took {1} ms (threshold {2} ms)",
+        T::RetryStep => "Retry from this step",
+        T::Add => "Add",
+        T::TagFilter => "Filter tags:",
+        T::Untagged => "Untagged",
+        T::ServerView => "View:",
+        T::FullAddressSpace => "Full address space",
+        T::EditBookmark => "Edit bookmark",
+        T::Tags => "Tags:",
+        T::TagsHint => "Plant A, Line 3",
+        T::PinConnectionPanel => "Pin connection panel",
+        T::PinConnectionPanelHint => "Keep the connection panel open after connecting",
+        T::ExportCertificateList => "Export list…",
+        T::ExportServerProfile => "Export server profile…",
+        T::SaveScreenshot => "Save screenshot…",
+        T::ScreenshotHint => "F12 copies the panel under the mouse to the clipboard; Shift+F12 saves it to a file",
+        T::ScreenshotCopied => "Screenshot copied to clipboard",
+        T::ScreenshotSaved => "Screenshot saved to {0}",
+        T::OutboundRequests => "Outbound requests (last second)",
+        T::InboundNotifications => "Inbound notifications (last second)",
+        T::OpenErrorPanel => "Click to open the error panel",
+        T::ServerProfileExported => "Server profile saved to {0}",
+        T::ExportNodeDetails => "Export node details…",
+        T::NodeDetailsExported => "Node details saved to {0}",
+        T::RawDataValue => "Raw DataValue",
+        T::VariantType => "Variant type",
+        T::RawValue => "Raw value",
+        T::MaxUiUpdateRate => "Max UI update rate",
+        T::MaxUiUpdateRateHint => "Limits how often displayed values refresh. Every sample is still recorded for trends.",
+        T::ReadInitialValue => "Read value when adding to watchlist",
+        T::ReadInitialValueHint => "Issue a one-shot Read so new watchlist items show a value before the first publish",
+        T::Favorites => "Favorites",
+        T::AddToFavorites => "Add to favorites",
+        T::RemoveFromFavorites => "Remove from favorites",
+        T::FavoriteAdded => "Added {0} to favorites",
+        T::FavoriteRemoved => "Removed {0} from favorites",
+        T::InputEmpty => "Input cannot be empty",
+        T::OnlyOpcTcpScheme => "Only opc.tcp:// scheme is supported",
+        T::InvalidPort => "Invalid port: {0}",
+        T::InvalidIpv6 => "Invalid IPv6 address format",
+        T::InvalidHostPort => "Invalid host:port format",
+        T::HostEmpty => "Host cannot be empty",
+        T::HostPortDetail => "Host: {0}, Port: {1}",
+        T::PortNotSpecified => "not specified (common ports)",
+        T::ResolvingHost => "Resolving {0}...",
+        T::TestedPorts => "tested",
+        T::EndpointsFoundAt => "{0} endpoints found at {1}",
+        T::InitializingDiagnostic => "Initializing diagnostic...",
+        T::DiagnoseHint => "Validates input, resolves DNS, scans ports, and discovers endpoints",
+        T::ConnectHint => "Establishes a secure OPC UA session",
+        T::DisconnectHint => "Terminates the current OPC UA session",
+        T::SaveBookmarkHint => "Save this server configuration",
+        T::DefaultBookmarkName => "Server {0}",
+        T::TimingMin => "min",
+        T::TimingAvg => "avg",
+        T::BrowseError => "Browse error: {0}",
+        T::BrowseAccessDenied => "Access denied browsing {0}",
+        T::AccessDenied => "access denied",
+        T::ErrorPrefix => "Error: {0}",
+        T::ConnectionFailed => "Connection failed: {0}",
+        T::NotConnected => "Not connected",
+        T::SettingsSaveFailed => "Failed to save settings: {0}",
+        T::Settings => "Settings",
+        T::PublishingInterval => "Publishing interval:",
+        T::PublishingIntervalHint => "Requested publishing interval for new subscriptions. The server may revise it.",
+        T::MaxWatchlistItems => "Max watchlist items:",
+        T::MaxTrendSeries => "Max trend series:",
+        T::SettingsSavedNextToExe => "Saved to settings.json next to the executable.",
+        T::WatchlistLimitReached => "Watchlist limit of {0} items reached, {1} items were not added.",
+        T::Subscription => "Subscription",
+        T::LifetimeCount => "Lifetime count:",
+        T::LifetimeCountHint => "OPC UA requires at least 3 × the keep-alive count (currently {0})",
+        T::KeepAliveCount => "Keep-alive count:",
+        T::MaxNotifications => "Max notifications per publish:",
+        T::MaxNotificationsHint => "0 means no limit",
+        T::SubscriptionOutdated => "The active subscription still uses the previous parameters.",
+        T::ApplyToSubscription => "Apply to active subscription",
+        T::SubscriptionUpdated => "Subscription parameters updated",
+        T::SubscriptionUpdateFailed => "Failed to update the subscription: {0}",
+        T::ChangingPublishingInterval => "Changing the publishing interval to {0} ms",
+        T::StreamToJson => "Stream to JSON",
+        T::StreamToJsonHint => "Crawl and write each node to a JSON array file as it is discovered",
+        T::CrawlStreamed => "Streamed {0} nodes to {1}",
+        T::CrawlStreamFailed => "Streaming crawl export failed: {0}",
+        T::WriteExportManifest => "Write export manifests",
+        T::WriteExportManifestHint => "Write a <file>.manifest.json next to each CSV/JSON export describing its columns, row count and options",
+        T::ClearConnectionForm => "Clear",
+        T::ClearConnectionFormHint => "Start a new connection: reset the server address, security, authentication and diagnostic results to their defaults",
+        T::Renderer => "Renderer",
+        T::ReadOnly => "Read-only",
+        T::ReadOnlyMode => "Read-only mode — no write services are ever invoked",
+        T::ReadOnlyModeDetail => "The application only has access to the Read, HistoryRead, Browse, BrowseNext and TranslateBrowsePaths services plus its own subscriptions. Write, Call and node management services are not reachable from any part of the tool, so it cannot change setpoints or other values on the server.",
+        T::RendererDegraded => "Slow graphics rendering detected: {0}",
+        T::MesaSuggestion => "Placing Mesa3D's opengl32.dll next to the executable usually fixes this.",
+        T::OpenMesaDownload => "Open download page",
+        T::CopyMesaInstructions => "Copy instructions",
+        T::MesaInstructions => "Mesa3D software rendering setup:\n1. Download the latest mesa3d x64 release (release-msvc) from {0}\n2. Extract opengl32.dll (and libgallium_wgl.dll if present) from the x64 folder.\n3. Copy the file(s) next to the diagnostic tool executable.\n4. Restart the application.",
+        T::UnlockSecurity => "Unlock / choose manually",
+        T::SecurityLockedHint => "Security policy and mode are taken from the endpoint selected in the discovery results. Unlock to pick them manually; the current values are kept as a starting point.",
+        T::Unlimited => "Unlimited",
+        T::SourceTimestamp => "Source timestamp",
+        T::ServerTimestamp => "Server timestamp",
+        T::BinaryEncoding => "Binary encoding",
+        T::JsonEncoding => "JSON encoding",
+        T::AccessLevel => "Access level",
+        T::UserAccessLevel => "User access level",
+        T::Description => "Description",
+        T::ValueRank => "Value rank",
+        T::MinimumSamplingInterval => "Min. sampling interval",
+        T::Historizing => "Historizing",
+        T::ArrayDimensions => "Array dimensions",
+        T::Attribute => "Attribute",
+        T::AttributeRefused => "The server refused to return this attribute (status {0})",
+        T::AuthUsernamePassword => "Username / password",
+        T::AuthCertificate => "User certificate",
+        T::UserCertificate => "Certificate:",
+        T::UserPrivateKey => "Private key:",
+        T::BrowseFile => "Browse for file",
+        T::TimestampFormatLabel => "Timestamp format",
+        T::ExportTimestampFormat => "Exported timestamps:",
+        T::TimestampIso8601 => "ISO 8601 (UTC, ms)",
+        T::TimestampLocalShort => "Local short",
+        T::TimestampLocalLong => "Local long (ms, offset)",
+        T::TimestampCustom => "Custom:",
+        T::InvalidTimestampFormat => "Invalid format: {0}",
+        T::MonitoredItemQueue => "Monitored item queue",
+        T::MonitoredItemQueueHint => "Server-side queue for values sampled between publishes. Applies to items added afterwards.",
+        T::QueueSize => "Queue size:",
+        T::DiscardOldest => "Discard oldest when full",
+        T::RetryBrowse => "Retry browse",
+        T::CrawlSummary => "{0} nodes in {1}s",
+        T::CrawledNodeCount => "{0} nodes",
+        T::ItemDetails => "Item details",
+        T::TimestampSkew => "Timestamp skew",
+        T::InfoBits => "Info bits",
+        T::MonitoredItemId => "Monitored item ID",
+        T::ClientHandle => "Client handle",
+        T::RevisedSamplingInterval => "Revised sampling interval",
+        T::RevisedQueueSize => "Revised queue size",
+        T::MonitoringParameters => "Monitoring parameters",
+        T::MonitoringParametersHint => "Unchecked values use the watchlist defaults. The server may revise them.",
+        T::SamplingInterval => "Sampling interval (ms):",
+        T::Deadband => "Deadband:",
+        T::DeadbandNone => "None",
+        T::DeadbandAbsolute => "Absolute",
+        T::DeadbandPercent => "Percent of range",
+        T::Apply => "Apply",
+        T::MonitoredItemModifyFailed => "Could not change the monitoring parameters of {0}: {1}",
+        T::DeadbandRejected => "{0}: the server rejected the deadband filter, monitoring without it",
+        T::Events => "Events",
+        T::EventNotifier => "Notifier:",
+        T::SubscribeEvents => "Subscribe",
+        T::UnsubscribeEvents => "Unsubscribe",
+        T::ReceivingEvents => "Receiving events from {0}",
+        T::MinSeverity => "Min. severity:",
+        T::NoEvents => "No events received",
+        T::Severity => "Severity",
+        T::EventSource => "Source",
+        T::EventType => "Event type",
+        T::EventMessage => "Message",
+        T::EventSubscriptionFailed => "Could not subscribe to events: {0}",
+        T::AddAllVariables => "Add all variables to watchlist",
+        T::IncludeSubfolders => "Include subfolders",
+        T::CollectVariablesFailed => "Could not browse the variables of {0}: {1}",
+        T::NoVariablesFound => "{0} contains no variables",
+        T::FolderVariablesAdded => "Adding {0} variables to the watchlist",
+        T::FolderVariablesOverLimit => "{0} variables were found, but the watchlist is limited to {1} items. Only {2} will be added.",
+        T::FolderVariablesTruncated => "Stopped searching {0} after {1} browse calls, some variables were not collected",
+        T::RecentSample => "Recent sample",
+        T::CopyAllAsText => "Copy all as text",
+        T::Recreate => "Recreate",
+        T::MonitoredItemInvalidated => "invalidated",
+        T::MonitoredItemRepairing => "The server invalidated this item; it is being re-created automatically",
+        T::MonitoredItemRepairFailed => "Automatic re-creation gave up after {0} attempts. Right-click the name and choose Recreate to try again",
+        T::MonitoredItemLimitReached => "The server refused {0} item(s): too many monitored items in one subscription",
+        T::CreateAdditionalSubscription => "Create additional subscription",
+        T::AdditionalSubscriptionFailed => "Could not create an additional subscription: {0}",
+        T::StartupBookmarksCorrupt => "Bookmarks file {0} could not be read ({1}). It was kept as {2}; repair or restore it before saving new bookmarks.",
+        T::StartupBookmarksCorruptNoBackup => "Bookmarks file {0} could not be read ({1}) and no backup could be made. Copy it somewhere safe before saving new bookmarks.",
+        T::StartupPkiNotWritable => "Certificate folder {0} is not writable ({1}). Secure connections will fail; move the tool to a writable folder or fix the folder permissions.",
+        T::StartupLogNotWritable => "Log folder {0} is not writable ({1}). No diagnostic log will be kept; start the tool from a writable working directory.",
+        T::DiscardOldestHint => "Untick to keep the oldest queued values, so the first spike of a burst is never lost",
+        T::TimestampsToReturn => "Timestamps to return",
+        T::TimestampsToReturnHint => "Which timestamps the server sends with each value. Applies to items added from now on. Values without a timestamp show the local receive time, marked \"(local)\"",
+        T::TimestampsSource => "Source",
+        T::TimestampsServer => "Server",
+        T::TimestampsBoth => "Both",
+        T::TimestampsNeither => "Neither",
         
         
         T::Diagnose => "Diagnose",
@@ -284,6 +1037,8 @@ fn match_en(key: T) -> &'static str {
         T::DiagnosticComplete => "Diagnostic complete",
         T::DiagnosticFailed => "Diagnostic failed",
         T::PortsOpen => "Open ports",
+        T::PortsChecked => "{0}/{1} ports checked, {2} open so far",
+        T::QueryingEndpointUrl => "Querying {0} ({1}/{2})",
         T::NoOpenPorts => "No open ports found",
         T::DnsResolved => "DNS resolved",
         T::DnsFailed => "DNS resolution failed",
@@ -310,12 +1065,36 @@ fn match_en(key: T) -> &'static str {
         
         
         T::ErrorPanel => "Errors",
+        T::MoreInErrorPanel => "+{0} more in error panel",
         T::ClearAll => "Clear All",
         T::CommonErrors => "Common OPC-UA Errors",
         T::ErrorCode => "Error Code",
         T::ErrorDescription => "Description",
         T::NoErrors => "No errors.",
         T::ServerDisconnected => "Server disconnected",
+        T::ConnectionLost => "Connection lost",
+        T::ConnectionLostAfter => "Lost the connection to {0} after {1} connected",
+        T::Reconnect => "Reconnect",
+        T::SessionInfo => "Session Info",
+        T::Uptime => "Uptime:",
+        T::Reconnects => "Reconnects:",
+        T::Downtime => "Downtime:",
+        T::Reconnecting => "Reconnecting...",
+        T::ReconnectingAttempt => "Reconnecting (attempt {0}/{1})...",
+        T::ReconnectScheduled => "Connection lost, retrying in {0}s (attempt {1}/{2})",
+        T::ReconnectGaveUp => "Reconnection failed after {0} attempts: {1}",
+        T::StopReconnecting => "Stop reconnecting",
+        T::AutoReconnect => "Auto-reconnect",
+        T::AutoReconnectHint => "Reconnect automatically when the connection drops unexpectedly, retrying until it succeeds or you stop it, and restore the watchlist",
+        T::ReconnectMaxAttempts => "Max attempts:",
+        T::ReconnectMaxAttemptsHint => "0 (∞) keeps retrying until the connection is restored or you stop it",
+        T::WatchlistRestored => "Reconnected, {0} watchlist items restored",
+        T::UnstableLinkHint => "The session has reconnected during this connection. Frequent reconnects indicate an unstable link or an overloaded server.",
+        T::DemoServer => "Demo server",
+        T::DemoServerHint => "Explore the tool with a simulated address space, no network required",
+        T::DemoModeActive => "DEMO MODE - simulated data",
+        T::DemoStopped => "Demo mode stopped",
+        T::NotAvailableInDemo => "not available in demo mode",
     }
 }
 
@@ -355,14 +1134,14 @@ fn match_es(key: T) -> &'static str {
         T::Stop => "Detener",
         T::Success => "Éxito",
         T::Failed => "Falló",
-        T::FoundEndpoints => "Encontrados {} endpoints:",
+        T::FoundEndpoints => "Encontrados {0} endpoints:",
         T::Save => "Guardar",
         T::Cancel => "Cancelar",
         T::Name => "Nombre:",
         T::ReadyNotConnected => "Listo - Sin conexión",
-        T::ConnectedTo => "Conectado a {}",
+        T::ConnectedTo => "Conectado a {0}",
         T::Disconnected => "Desconectado",
-        T::TaskCancelled => "Tarea '{}' cancelada",
+        T::TaskCancelled => "Tarea '{0}' cancelada",
         T::EstablishingConnection => "Estableciendo conexión...",
         T::Watchlist => "Lista de Seguimiento",
         T::Crawler => "Rastreador",
@@ -371,8 +1150,11 @@ fn match_es(key: T) -> &'static str {
         T::StartCrawl => "Iniciar Rastreo",
         T::MaxDepth => "Profundidad Máxima:",
         T::MaxNodes => "Máximo de Nodos:",
-        T::CrawlComplete => "Rastreo completado. Encontrados {} nodos.",
-        T::CrawlFailed => "Rastreo fallido: {}",
+        T::CrawlComplete => "Rastreo completado. Encontrados {0} nodos.",
+        T::CrawlProgress => "{0} nodos encontrados, profundidad {1}: {2}",
+        T::CrawlFailed => "Rastreo fallido: {0}",
+        T::CrawlTruncated => "Límite de nodos alcanzado, resultados truncados.",
+        T::CrawlCancelled => "Cancelado - {0} nodos recopilados",
         T::NodeId => "NodeId",
         T::DisplayName => "Nombre",
         T::CrawlerDescription => "Descubra nodos recursivamente en el espacio de direcciones.",
@@ -380,6 +1162,83 @@ fn match_es(key: T) -> &'static str {
         T::Configuration => "Configuración",
         T::Class => "Clase",
         T::Results => "Resultados",
+        T::IncludeStartNode => "Incluir nodo inicial en resultados",
+        T::StartNodes => "Nodos iniciales",
+        T::UseSelectedTreeNode => "Usar nodo seleccionado del árbol",
+        T::CrawlConflicts => "Conflictos ({0})",
+        T::CrawlConflictsHint => "Estos NodeIds se alcanzaron de nuevo con otro nombre visible, nombre de exploración o clase de nodo",
+        T::ExportConflicts => "Exportar conflictos CSV",
+        T::FirstSeen => "Primera aparición",
+        T::SeenAgain => "Vista de nuevo",
+        T::ConflictsExported => "{0} conflictos exportados a {1}",
+        T::RemoveOrDetachHint => "Eliminar (clic derecho para conservar la curva capturada)",
+        T::DetachKeepCurve => "Desacoplar (conservar curva)",
+        T::DiscardCapturedCurves => "Curvas capturadas",
+        T::DiscardCapturedCurvesPrompt => "La tendencia aún contiene {0} curva(s) capturada(s) de la sesión anterior. ¿Descartarlas?",
+        T::Discard => "Descartar",
+        T::Keep => "Conservar",
+        T::RequestedEndpoint => "Solicitado",
+        T::NegotiatedEndpoint => "Negociado",
+        T::EndpointMismatch => "El servidor negoció un endpoint distinto al solicitado ({0})",
+        T::EndpointUrlField => "URL del endpoint",
+        T::SecurityPolicyField => "política de seguridad",
+        T::SecurityModeField => "modo de seguridad",
+        T::AnonymizeExports => "Anonimizar exportaciones",
+        T::AnonymizeExportsHint => "Sustituye nombres, NodeIds de texto, URIs de namespace y hosts del endpoint por tokens estables. Junto a cada exportación se escribe un archivo de correspondencia .anonymization.csv; consérvelo en este equipo.",
+        T::RefreshValueHint => "Vuelve a leer el valor actual sin añadirlo a la lista de seguimiento",
+        T::ReadingValue => "Leyendo valor...",
+        T::InputArguments => "Argumentos de entrada",
+        T::OutputArguments => "Argumentos de salida",
+        T::ArgumentName => "Nombre",
+        T::NoArguments => "Ninguno",
+        T::ReadingMethodArguments => "Leyendo argumentos del método...",
+        T::MethodArgumentsFailed => "No se pudieron leer los argumentos del método: {0}",
+        T::ReadHistory => "Leer histórico",
+        T::ReadHistoryHint => "Lee los valores guardados por el histórico del servidor en un rango de tiempo y los representa en la tendencia",
+        T::HistoryRange => "Rango",
+        T::HistoryLastHour => "Última hora",
+        T::HistoryLast8Hours => "Últimas 8 horas",
+        T::HistoryLastDay => "Últimas 24 horas",
+        T::HistoryLastWeek => "Últimos 7 días",
+        T::HistoryCustomRange => "Rango personalizado",
+        T::HistoryStart => "Inicio",
+        T::HistoryEnd => "Fin",
+        T::HistoryMaxValues => "Valores máx.",
+        T::HistorySeriesLabel => "{0} (historial)",
+        T::CapturedSeriesLabel => "{0} (capturada)",
+        T::DiscardDetachedSeries => "Descartar curva capturada",
+        T::InvalidHistoryTime => "'{0}' no es una hora válida, se esperaba AAAA-MM-DD HH:MM[:SS]",
+        T::HistoryRangeInverted => "El fin del rango debe ser posterior a su inicio",
+        T::ReadingHistory => "Leyendo histórico de {0}...",
+        T::HistoryLoaded => "Cargados {0} valores históricos de {1}",
+        T::HistoryEmpty => "No hay valores históricos de {0} en el rango seleccionado",
+        T::HistoryReadFailed => "Falló la lectura del histórico de {0}",
+        T::NarrowLayoutWidth => "Paneles laterales en pestañas por debajo de",
+        T::NarrowLayoutWidthHint => "Cuando la ventana es más estrecha que este ancho, los paneles de propiedades, rastreador, certificados y errores comparten un único panel lateral con pestañas. Use 0 para mostrarlos siempre uno al lado del otro.",
+        T::TrendLineStyle => "Estilo de línea de tendencia",
+        T::TrendLineStyleHint => "Grosor, patrón de trazo y marcadores de punto predeterminados para las series sin estilo propio",
+        T::UseDefaultTrendStyle => "Usar estilo predeterminado",
+        T::TrendSwatchHint => "Clic derecho para cambiar el color y el estilo de línea",
+        T::TrendLegendHint => "Clic para ocultar/mostrar, Ctrl+clic para mostrar solo esta serie",
+        T::AutoColorsHint => "Asignar colores distintos a todos los elementos en tendencia",
+        T::AutoColors => "Colores automáticos",
+        T::ShowAllSeries => "Mostrar todas",
+        T::LineWidth => "Grosor",
+        T::DashPattern => "Patrón",
+        T::DashSolid => "Continua",
+        T::DashDashed => "Discontinua",
+        T::DashDotted => "Punteada",
+        T::PointMarkers => "Marcadores de punto",
+        T::AuditLog => "Registro de auditoría",
+        T::EnableAuditLog => "Registrar auditoría",
+        T::EnableAuditLogHint => "Registra conexiones, desconexiones, nodos explorados, cambios en la lista de seguimiento, exportaciones y rastreos con marca de tiempo y el endpoint conectado. Nunca se registran valores.",
+        T::AuditLogFile => "Añadiendo a {0}",
+        T::AuditLogDisabled => "El registro de auditoría está desactivado. No se registra nada.",
+        T::NoAuditEntries => "No hay entradas de auditoría en esta sesión.",
+        T::AuditTimeColumn => "Hora",
+        T::AuditEventColumn => "Acción",
+        T::AuditEndpointColumn => "Endpoint",
+        T::AuditDetailsColumn => "Detalles",
         T::Node => "Nodo",
         T::Value => "Valor",
         T::Quality => "Calidad",
@@ -411,6 +1270,276 @@ fn match_es(key: T) -> &'static str {
         T::Anonymous => "Anónimo",
         T::DiscoveryFailed => "La detección falló",
         T::NoItems => "No hay elementos para mostrar.",
+        T::FilterAll => "Todos",
+        T::FilterProblems => "Solo malos o inciertos",
+        T::FilterStale => "Solo obsoletos",
+        T::FilterName => "Filtrar",
+        T::ShowingItems => "mostrando {0} de {1}",
+        T::NumericOnly => "Solo numéricos",
+        T::ExportFilteredOnly => "Exportar solo filtrados",
+        T::TrendLimitReached => "Límite de tendencias alcanzado ({0} series). Quite elementos de la tendencia antes de añadir más.",
+        T::BookmarkWatchlistHint => "Lista de vigilancia predeterminada: {0} elementos",
+        T::WatchlistLoadedFromBookmark => "Cargados {0} elementos desde el marcador",
+        T::SaveWatchlistToBookmark => "Guardar en marcador",
+        T::WatchlistSavedToBookmark => "Guardados {0} elementos en el marcador",
+        T::MonitoredItemFailed => "No se pudo monitorizar {0}: {1}",
+        T::GoToTypeDefinition => "Ir a la definición de tipo",
+        T::NavigateFailed => "No se pudo localizar {0} en el espacio de direcciones",
+        T::ServerDiagnostics => "Diagnóstico del servidor",
+        T::ServerDiagnosticsHint => "Contadores estándar de Server/ServerDiagnostics, actualizados cada {0} s.",
+        T::DiagnosticsDisabled => "El servidor indica que el diagnóstico está deshabilitado; los contadores pueden ser cero o faltar.",
+        T::SessionLoad => "Carga de sesiones",
+        T::SubscriptionLoad => "Carga de suscripciones",
+        T::ServerOverloadHint => "El servidor está cerca de su límite de sesiones o suscripciones, lo que puede causar fallos de conexión.",
+        T::Refresh => "Actualizar",
+        T::NotAvailable => "n/d",
+        T::ExportOptions => "Opciones de exportación",
+        T::NumberFormatLabel => "Formato numérico",
+        T::NumberFullPrecision => "Precisión completa",
+        T::NumberFixed => "Decimales fijos",
+        T::NumberShortest => "Más corto reversible",
+        T::ConnectivityMonitor => "Monitor de conectividad",
+        T::ConnectivityHint => "Comprobaciones TCP repetidas contra el destino, mostradas como una franja de disponibilidad.",
+        T::Target => "Destino",
+        T::Interval => "Intervalo",
+        T::StartMonitor => "Iniciar",
+        T::Availability => "Disponibilidad",
+        T::Drops => "Caídas",
+        T::AvgLatency => "Latencia media",
+        T::Reachable => "Accesible",
+        T::Unreachable => "Inaccesible",
+        T::SecondsAgo => "hace {0}s",
+        T::CopyStructure => "Copiar estructura como texto",
+        T::StructureCopied => "Copiados {0} nodos al portapapeles",
+        T::StructureTruncated => "(truncado a 1 MB)",
+        T::BrowseMissingLevels => "{0} carpetas de este subárbol aún no se han cargado. ¿Explorarlas (hasta {1} niveles) antes de copiar?",
+        T::BrowseAndCopy => "Explorar y copiar",
+        T::CopyLoadedOnly => "Copiar solo lo cargado",
+        T::ImportTagList => "Importar lista de etiquetas...",
+        T::InvalidTagLine => "Línea {0}: '{1}' no es un NodeId válido",
+        T::TagListImported => "Importadas {0} etiquetas ({1} líneas no válidas)",
+        T::Overflow => "desbordamiento",
+        T::OverflowCount => "La cola del servidor se desbordó {0} veces; se perdieron valores",
+        T::QueueOverflowWarning => "Desbordamiento de cola en '{0}': se están perdiendo valores. Aumente el tamaño de cola o use un intervalo de publicación más rápido.",
+        T::LimitLow => "Límite: bajo",
+        T::LimitHigh => "Límite: alto",
+        T::LimitConstant => "Límite: constante",
+        T::ValidateTagList => "Validar lista de etiquetas",
+        T::ValidateTagListHint => "Elija un archivo de etiquetas para comprobar cada NodeId en el servidor sin suscribirse.",
+        T::ValidationSummary => "{0} correctas, {1} fallidas",
+        T::FailuresOnly => "Solo fallos",
+        T::AddPassingToWatchlist => "Añadir correctas a la lista",
+        T::Result => "Resultado",
+        T::DataType => "Tipo de dato",
+        T::Status => "Estado",
+        T::Pass => "OK",
+        T::Fail => "FALLO",
+        T::Readable => "Legible",
+        T::NodeMissing => "El nodo no existe",
+        T::NotAVariable => "No es una variable",
+        T::NotReadable => "No legible",
+        T::SkipEndpointMatching => "Avanzado: conectar sin emparejar endpoints",
+        T::SkipEndpointMatchingHint => "Conecta a la URL exacta escrita con la política y modo seleccionados, sin pedir al servidor su lista de endpoints. Útil para servidores que informan endpoints inconsistentes.",
+        T::EndpointFallback => "Usar el siguiente endpoint si falla",
+        T::EndpointFallbackHint => "Si el endpoint seleccionado falla, reintenta con el siguiente endpoint encontrado por el diagnóstico (posiblemente menos seguro). Se notificará qué endpoint se usó.",
+        T::TryingFallbackEndpoint => "El endpoint preferido falló, probando {0}",
+        T::ConnectedViaFallback => "Conectado mediante endpoint alternativo {0}",
+        T::GoToNodeId => "Ir a NodeId:",
+        T::GoToPath => "Ir a ruta:",
+        T::GoToPathHint => "Ruta desde la carpeta Root, p. ej. /Objects/2:MyDevice/2:Temperature. Anteponga el índice de namespace a los segmentos de namespaces no estándar.",
+        T::PathNotFound => "No se pudo resolver la ruta {0}",
+        T::InvalidNodeId => "'{0}' no es un NodeId válido",
+        T::QuickAddFailed => "No se pudo añadir {0} a la lista de seguimiento: {1}",
+        T::ServiceTimings => "Tiempos de ida y vuelta de servicios",
+        T::Service => "Servicio",
+        T::SlowServiceCall => "Última llamada lenta: {0} tardó {1} ms (umbral {2} ms)",
+        T::RetryStep => "Reintentar desde este paso",
+        T::Add => "Añadir",
+        T::TagFilter => "Filtrar etiquetas:",
+        T::Untagged => "Sin etiqueta",
+        T::ServerView => "Vista:",
+        T::FullAddressSpace => "Espacio de direcciones completo",
+        T::EditBookmark => "Editar marcador",
+        T::Tags => "Etiquetas:",
+        T::TagsHint => "Planta A, Línea 3",
+        T::PinConnectionPanel => "Fijar panel de conexión",
+        T::PinConnectionPanelHint => "Mantener abierto el panel de conexión tras conectar",
+        T::ExportCertificateList => "Exportar lista…",
+        T::ExportServerProfile => "Exportar perfil del servidor…",
+        T::SaveScreenshot => "Guardar captura…",
+        T::ScreenshotHint => "F12 copia el panel bajo el ratón al portapapeles; Shift+F12 lo guarda en un archivo",
+        T::ScreenshotCopied => "Captura copiada al portapapeles",
+        T::ScreenshotSaved => "Captura guardada en {0}",
+        T::OutboundRequests => "Peticiones salientes (último segundo)",
+        T::InboundNotifications => "Notificaciones entrantes (último segundo)",
+        T::OpenErrorPanel => "Clic para abrir el panel de errores",
+        T::ServerProfileExported => "Perfil del servidor guardado en {0}",
+        T::ExportNodeDetails => "Exportar detalles del nodo…",
+        T::NodeDetailsExported => "Detalles del nodo guardados en {0}",
+        T::RawDataValue => "DataValue sin procesar",
+        T::VariantType => "Tipo de Variant",
+        T::RawValue => "Valor sin procesar",
+        T::MaxUiUpdateRate => "Frecuencia máx. de actualización",
+        T::MaxUiUpdateRateHint => "Limita la frecuencia de refresco de los valores mostrados. Todas las muestras se siguen registrando para las tendencias.",
+        T::ReadInitialValue => "Leer valor al añadir a la lista",
+        T::ReadInitialValueHint => "Realiza una lectura inmediata para que los nuevos elementos muestren un valor antes de la primera publicación",
+        T::Favorites => "Favoritos",
+        T::AddToFavorites => "Añadir a favoritos",
+        T::RemoveFromFavorites => "Quitar de favoritos",
+        T::FavoriteAdded => "{0} añadido a favoritos",
+        T::FavoriteRemoved => "{0} quitado de favoritos",
+        T::InputEmpty => "La entrada no puede estar vacía",
+        T::OnlyOpcTcpScheme => "Solo se admite el esquema opc.tcp://",
+        T::InvalidPort => "Puerto inválido: {0}",
+        T::InvalidIpv6 => "Formato de dirección IPv6 inválido",
+        T::InvalidHostPort => "Formato host:puerto inválido",
+        T::HostEmpty => "El host no puede estar vacío",
+        T::HostPortDetail => "Host: {0}, Puerto: {1}",
+        T::PortNotSpecified => "no especificado (puertos comunes)",
+        T::ResolvingHost => "Resolviendo {0}...",
+        T::TestedPorts => "probados",
+        T::EndpointsFoundAt => "{0} endpoints encontrados en {1}",
+        T::InitializingDiagnostic => "Inicializando diagnóstico...",
+        T::DiagnoseHint => "Valida la entrada, resuelve DNS, escanea puertos y descubre endpoints",
+        T::ConnectHint => "Establece una sesión OPC UA segura",
+        T::DisconnectHint => "Termina la sesión OPC UA actual",
+        T::SaveBookmarkHint => "Guardar esta configuración de servidor",
+        T::DefaultBookmarkName => "Servidor {0}",
+        T::TimingMin => "mín",
+        T::TimingAvg => "prom",
+        T::BrowseError => "Error de exploración: {0}",
+        T::BrowseAccessDenied => "Acceso denegado al explorar {0}",
+        T::AccessDenied => "acceso denegado",
+        T::ErrorPrefix => "Error: {0}",
+        T::ConnectionFailed => "Conexión fallida: {0}",
+        T::NotConnected => "No conectado",
+        T::SettingsSaveFailed => "No se pudo guardar la configuración: {0}",
+        T::Settings => "Configuración",
+        T::PublishingInterval => "Intervalo de publicación:",
+        T::PublishingIntervalHint => "Intervalo de publicación solicitado para nuevas suscripciones. El servidor puede ajustarlo.",
+        T::MaxWatchlistItems => "Máx. elementos en seguimiento:",
+        T::MaxTrendSeries => "Máx. series de tendencia:",
+        T::SettingsSavedNextToExe => "Guardado en settings.json junto al ejecutable.",
+        T::WatchlistLimitReached => "Límite de {0} elementos en seguimiento alcanzado, {1} elementos no se añadieron.",
+        T::Subscription => "Suscripción",
+        T::LifetimeCount => "Contador de vida:",
+        T::LifetimeCountHint => "OPC UA exige al menos 3 × el contador de keep-alive (actualmente {0})",
+        T::KeepAliveCount => "Contador de keep-alive:",
+        T::MaxNotifications => "Máx. notificaciones por publicación:",
+        T::MaxNotificationsHint => "0 significa sin límite",
+        T::SubscriptionOutdated => "La suscripción activa aún usa los parámetros anteriores.",
+        T::ApplyToSubscription => "Aplicar a la suscripción activa",
+        T::SubscriptionUpdated => "Parámetros de suscripción actualizados",
+        T::SubscriptionUpdateFailed => "No se pudo actualizar la suscripción: {0}",
+        T::ChangingPublishingInterval => "Cambiando el intervalo de publicación a {0} ms",
+        T::StreamToJson => "Transmitir a JSON",
+        T::StreamToJsonHint => "Explorar y escribir cada nodo en un archivo de matriz JSON a medida que se descubre",
+        T::CrawlStreamed => "Se transmitieron {0} nodos a {1}",
+        T::CrawlStreamFailed => "Falló la exportación en streaming del rastreo: {0}",
+        T::WriteExportManifest => "Escribir manifiestos de exportación",
+        T::WriteExportManifestHint => "Escribir un <archivo>.manifest.json junto a cada exportación CSV/JSON con sus columnas, número de filas y opciones",
+        T::ClearConnectionForm => "Limpiar",
+        T::ClearConnectionFormHint => "Nueva conexión: restablecer la dirección del servidor, la seguridad, la autenticación y los resultados del diagnóstico a sus valores predeterminados",
+        T::Renderer => "Renderizador",
+        T::ReadOnly => "Solo lectura",
+        T::ReadOnlyMode => "Modo de solo lectura — nunca se invocan servicios de escritura",
+        T::ReadOnlyModeDetail => "La aplicación solo tiene acceso a los servicios Read, HistoryRead, Browse, BrowseNext y TranslateBrowsePaths además de sus propias suscripciones. Los servicios Write, Call y de gestión de nodos no son accesibles desde ninguna parte de la herramienta, por lo que no puede cambiar consignas ni otros valores en el servidor.",
+        T::RendererDegraded => "Se detectó un renderizado gráfico lento: {0}",
+        T::MesaSuggestion => "Colocar opengl32.dll de Mesa3D junto al ejecutable normalmente lo soluciona.",
+        T::OpenMesaDownload => "Abrir página de descarga",
+        T::CopyMesaInstructions => "Copiar instrucciones",
+        T::MesaInstructions => "Configuración del renderizado por software Mesa3D:\n1. Descargue la última versión x64 de mesa3d (release-msvc) desde {0}\n2. Extraiga opengl32.dll (y libgallium_wgl.dll si existe) de la carpeta x64.\n3. Copie el/los archivo(s) junto al ejecutable de la herramienta de diagnóstico.\n4. Reinicie la aplicación.",
+        T::UnlockSecurity => "Desbloquear / elegir manualmente",
+        T::SecurityLockedHint => "La política y el modo de seguridad se toman del endpoint seleccionado en los resultados del descubrimiento. Desbloquee para elegirlos manualmente; los valores actuales se conservan como punto de partida.",
+        T::Unlimited => "Sin límite",
+        T::SourceTimestamp => "Marca de tiempo de origen",
+        T::ServerTimestamp => "Marca de tiempo del servidor",
+        T::BinaryEncoding => "Codificación binaria",
+        T::JsonEncoding => "Codificación JSON",
+        T::AccessLevel => "Nivel de acceso",
+        T::UserAccessLevel => "Nivel de acceso del usuario",
+        T::Description => "Descripción",
+        T::ValueRank => "Rango de valor",
+        T::MinimumSamplingInterval => "Intervalo de muestreo mín.",
+        T::Historizing => "Historizando",
+        T::ArrayDimensions => "Dimensiones del array",
+        T::Attribute => "Atributo",
+        T::AttributeRefused => "El servidor se negó a devolver este atributo (estado {0})",
+        T::AuthUsernamePassword => "Usuario / contraseña",
+        T::AuthCertificate => "Certificado de usuario",
+        T::UserCertificate => "Certificado:",
+        T::UserPrivateKey => "Clave privada:",
+        T::BrowseFile => "Buscar archivo",
+        T::TimestampFormatLabel => "Formato de marca de tiempo",
+        T::ExportTimestampFormat => "Marcas de tiempo exportadas:",
+        T::TimestampIso8601 => "ISO 8601 (UTC, ms)",
+        T::TimestampLocalShort => "Local corto",
+        T::TimestampLocalLong => "Local largo (ms, desfase)",
+        T::TimestampCustom => "Personalizado:",
+        T::InvalidTimestampFormat => "Formato inválido: {0}",
+        T::MonitoredItemQueue => "Cola de elementos monitoreados",
+        T::MonitoredItemQueueHint => "Cola en el servidor para valores muestreados entre publicaciones. Se aplica a los elementos añadidos después.",
+        T::QueueSize => "Tamaño de cola:",
+        T::DiscardOldest => "Descartar los más antiguos al llenarse",
+        T::RetryBrowse => "Reintentar exploración",
+        T::CrawlSummary => "{0} nodos en {1}s",
+        T::CrawledNodeCount => "{0} nodos",
+        T::ItemDetails => "Detalles del elemento",
+        T::TimestampSkew => "Desfase de marcas de tiempo",
+        T::InfoBits => "Bits de información",
+        T::MonitoredItemId => "ID del elemento monitorizado",
+        T::ClientHandle => "Handle de cliente",
+        T::RevisedSamplingInterval => "Intervalo de muestreo revisado",
+        T::RevisedQueueSize => "Tamaño de cola revisado",
+        T::MonitoringParameters => "Parámetros de monitorización",
+        T::MonitoringParametersHint => "Los valores sin marcar usan los predeterminados de la lista de seguimiento. El servidor puede ajustarlos.",
+        T::SamplingInterval => "Intervalo de muestreo (ms):",
+        T::Deadband => "Banda muerta:",
+        T::DeadbandNone => "Ninguna",
+        T::DeadbandAbsolute => "Absoluta",
+        T::DeadbandPercent => "Porcentaje del rango",
+        T::Apply => "Aplicar",
+        T::MonitoredItemModifyFailed => "No se pudieron cambiar los parámetros de monitorización de {0}: {1}",
+        T::DeadbandRejected => "{0}: el servidor rechazó el filtro de banda muerta, se monitoriza sin él",
+        T::Events => "Eventos",
+        T::EventNotifier => "Notificador:",
+        T::SubscribeEvents => "Suscribirse",
+        T::UnsubscribeEvents => "Cancelar suscripción",
+        T::ReceivingEvents => "Recibiendo eventos de {0}",
+        T::MinSeverity => "Severidad mín.:",
+        T::NoEvents => "No se han recibido eventos",
+        T::Severity => "Severidad",
+        T::EventSource => "Origen",
+        T::EventType => "Tipo de evento",
+        T::EventMessage => "Mensaje",
+        T::EventSubscriptionFailed => "No se pudo suscribir a eventos: {0}",
+        T::AddAllVariables => "Añadir todas las variables a la lista de seguimiento",
+        T::IncludeSubfolders => "Incluir subcarpetas",
+        T::CollectVariablesFailed => "No se pudieron explorar las variables de {0}: {1}",
+        T::NoVariablesFound => "{0} no contiene variables",
+        T::FolderVariablesAdded => "Añadiendo {0} variables a la lista de seguimiento",
+        T::FolderVariablesOverLimit => "Se encontraron {0} variables, pero la lista de seguimiento está limitada a {1} elementos. Solo se añadirán {2}.",
+        T::FolderVariablesTruncated => "Se detuvo la búsqueda en {0} tras {1} llamadas Browse, algunas variables no se recogieron",
+        T::RecentSample => "Muestra reciente",
+        T::CopyAllAsText => "Copiar todo como texto",
+        T::Recreate => "Recrear",
+        T::MonitoredItemInvalidated => "invalidado",
+        T::MonitoredItemRepairing => "El servidor invalidó este elemento; se está recreando automáticamente",
+        T::MonitoredItemRepairFailed => "La recreación automática se abandonó tras {0} intentos. Haga clic derecho en el nombre y elija Recrear para reintentarlo",
+        T::MonitoredItemLimitReached => "El servidor rechazó {0} elemento(s): demasiados elementos monitorizados en una suscripción",
+        T::CreateAdditionalSubscription => "Crear suscripción adicional",
+        T::AdditionalSubscriptionFailed => "No se pudo crear una suscripción adicional: {0}",
+        T::StartupBookmarksCorrupt => "No se pudo leer el archivo de marcadores {0} ({1}). Se conservó como {2}; repárelo o restáurelo antes de guardar nuevos marcadores.",
+        T::StartupBookmarksCorruptNoBackup => "No se pudo leer el archivo de marcadores {0} ({1}) ni crear una copia de seguridad. Cópielo a un lugar seguro antes de guardar nuevos marcadores.",
+        T::StartupPkiNotWritable => "La carpeta de certificados {0} no tiene permisos de escritura ({1}). Las conexiones seguras fallarán; mueva la herramienta a una carpeta con escritura o corrija los permisos.",
+        T::StartupLogNotWritable => "La carpeta de registro {0} no tiene permisos de escritura ({1}). No se guardará el registro de diagnóstico; inicie la herramienta desde un directorio de trabajo con escritura.",
+        T::DiscardOldestHint => "Desmarque para conservar los valores más antiguos en cola, así nunca se pierde el primer pico de una ráfaga",
+        T::TimestampsToReturn => "Marcas de tiempo a devolver",
+        T::TimestampsToReturnHint => "Qué marcas de tiempo envía el servidor con cada valor. Se aplica a los elementos añadidos a partir de ahora. Los valores sin marca de tiempo muestran la hora local de recepción, marcada \"(local)\"",
+        T::TimestampsSource => "Origen",
+        T::TimestampsServer => "Servidor",
+        T::TimestampsBoth => "Ambas",
+        T::TimestampsNeither => "Ninguna",
         
         // Diagnósticos
         T::Diagnose => "Diagnosticar",
@@ -422,6 +1551,8 @@ fn match_es(key: T) -> &'static str {
         T::DiagnosticComplete => "Diagnóstico completo",
         T::DiagnosticFailed => "Diagnóstico fallido",
         T::PortsOpen => "Puertos abiertos",
+        T::PortsChecked => "{0}/{1} puertos comprobados, {2} abiertos hasta ahora",
+        T::QueryingEndpointUrl => "Consultando {0} ({1}/{2})",
         T::NoOpenPorts => "No se encontraron puertos abiertos",
         T::DnsResolved => "DNS resuelto",
         T::DnsFailed => "Resolución DNS fallida",
@@ -448,11 +1579,35 @@ fn match_es(key: T) -> &'static str {
         
         // Panel de Errores
         T::ErrorPanel => "Errores",
+        T::MoreInErrorPanel => "+{0} más en el panel de errores",
         T::ClearAll => "Limpiar Todo",
         T::CommonErrors => "Errores OPC-UA Comunes",
         T::ErrorCode => "Código de Error",
         T::ErrorDescription => "Descripción",
         T::NoErrors => "Sin errores.",
         T::ServerDisconnected => "Servidor desconectado",
+        T::ConnectionLost => "Conexión perdida",
+        T::ConnectionLostAfter => "Se perdió la conexión con {0} tras {1} conectado",
+        T::Reconnect => "Reconectar",
+        T::SessionInfo => "Información de Sesión",
+        T::Uptime => "Tiempo activo:",
+        T::Reconnects => "Reconexiones:",
+        T::Downtime => "Tiempo caído:",
+        T::Reconnecting => "Reconectando...",
+        T::ReconnectingAttempt => "Reconectando (intento {0}/{1})...",
+        T::ReconnectScheduled => "Conexión perdida, reintentando en {0}s (intento {1}/{2})",
+        T::ReconnectGaveUp => "La reconexión falló tras {0} intentos: {1}",
+        T::StopReconnecting => "Detener reconexión",
+        T::AutoReconnect => "Reconexión automática",
+        T::AutoReconnectHint => "Reconectar automáticamente cuando la conexión se pierde inesperadamente, reintentando hasta lograrlo o hasta que lo detenga, y restaurar la lista de seguimiento",
+        T::ReconnectMaxAttempts => "Intentos máximos:",
+        T::ReconnectMaxAttemptsHint => "0 (∞) sigue reintentando hasta restablecer la conexión o hasta que lo detenga",
+        T::WatchlistRestored => "Reconectado, {0} elementos de la lista de seguimiento restaurados",
+        T::UnstableLinkHint => "La sesión se ha reconectado durante esta conexión. Las reconexiones frecuentes indican un enlace inestable o un servidor sobrecargado.",
+        T::DemoServer => "Servidor de demostración",
+        T::DemoServerHint => "Explore la herramienta con un espacio de direcciones simulado, sin red",
+        T::DemoModeActive => "MODO DEMO - datos simulados",
+        T::DemoStopped => "Modo demo detenido",
+        T::NotAvailableInDemo => "no disponible en modo demo",
     }
 }
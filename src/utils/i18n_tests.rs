@@ -21,4 +21,70 @@ mod tests {
         assert_eq!(i18n::t(T::SecurityNone, Language::English), "None (No Security)");
         assert_eq!(i18n::t(T::SecurityNone, Language::Spanish), "Ninguna (Sin seguridad)");
     }
+
+    const DIAGNOSTIC_KEYS: [T; 24] = [
+        T::InputEmpty,
+        T::OnlyOpcTcpScheme,
+        T::InvalidPort,
+        T::InvalidIpv6,
+        T::InvalidHostPort,
+        T::HostEmpty,
+        T::HostPortDetail,
+        T::PortNotSpecified,
+        T::ResolvingHost,
+        T::TestedPorts,
+        T::EndpointsFoundAt,
+        T::InitializingDiagnostic,
+        T::DiagnoseHint,
+        T::ConnectHint,
+        T::DisconnectHint,
+        T::SaveBookmarkHint,
+        T::DefaultBookmarkName,
+        T::TimingMin,
+        T::TimingAvg,
+        T::BrowseError,
+        T::ErrorPrefix,
+        T::ConnectionFailed,
+        T::NotConnected,
+        T::SettingsSaveFailed,
+    ];
+
+    #[test]
+    fn test_diagnostic_keys_in_every_language() {
+        for key in DIAGNOSTIC_KEYS {
+            let english = i18n::t(key, Language::English);
+            let spanish = i18n::t(key, Language::Spanish);
+            assert!(!english.is_empty() && !spanish.is_empty());
+            for placeholder in ["{0}", "{1}", "{2}"] {
+                assert_eq!(english.contains(placeholder), spanish.contains(placeholder), "{} in {:?}", placeholder, english);
+            }
+        }
+        assert_eq!(i18n::t(T::ResolvingHost, Language::Spanish), "Resolviendo {0}...");
+    }
+
+    #[test]
+    fn test_t_fmt_substitutes_positional_placeholders() {
+        assert_eq!(i18n::t_fmt(T::CrawlComplete, Language::English, &["42"]), "Crawl complete. Found 42 nodes.");
+        assert_eq!(
+            i18n::t_fmt(T::HostPortDetail, Language::Spanish, &["plc01", "4840"]),
+            i18n::t(T::HostPortDetail, Language::Spanish).replace("{0}", "plc01").replace("{1}", "4840"),
+        );
+        assert_eq!(
+            i18n::t_fmt(T::EndpointsFoundAt, Language::English, &["3", "opc.tcp://plc01:4840"]),
+            "3 endpoints found at opc.tcp://plc01:4840",
+        );
+        for lang in [Language::English, Language::Spanish] {
+            let text = i18n::t_fmt(T::BrowseMissingLevels, lang, &["7", "4"]);
+            assert!(text.contains('7') && text.contains('4') && !text.contains('{'), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_t_fmt_tolerates_argument_mismatches() {
+        assert_eq!(i18n::t_fmt(T::HostPortDetail, Language::English, &["plc01"]), "Host: plc01, Port: {1}");
+        assert_eq!(i18n::t_fmt(T::HostPortDetail, Language::English, &[]), "Host: {0}, Port: {1}");
+        assert_eq!(i18n::t_fmt(T::ResolvingHost, Language::English, &["plc01", "unused"]), "Resolving plc01...");
+        assert_eq!(i18n::t_fmt(T::File, Language::Spanish, &["unused"]), "Archivo");
+        assert_eq!(i18n::t_fmt(T::ErrorPrefix, Language::English, &["{0} {"]), "Error: {0} {");
+    }
 }
@@ -1,7 +1,14 @@
 
 
+pub mod audit;
 pub mod clipboard;
 pub mod status_codes;
 pub mod i18n;
+pub mod renderer;
+pub mod timestamps;
 #[cfg(test)]
 pub mod i18n_tests;
+#[cfg(test)]
+pub mod test_dir;
+#[cfg(test)]
+pub mod test_nodes;
@@ -0,0 +1,145 @@
+
+
+
+
+use std::time::{Duration, Instant};
+
+
+pub const MESA_DOWNLOAD_URL: &str = "https://github.com/pal1000/mesa-dist-win/releases";
+
+
+pub const PROBE_WINDOW: Duration = Duration::from_secs(3);
+
+
+const PROBE_MIN_FRAMES: u32 = 10;
+
+
+pub const DEGRADED_FPS: f32 = 15.0;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RendererBackend {
+    Wgpu,
+    Glow,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RendererInfo {
+    
+    pub backend: RendererBackend,
+    
+    pub mesa_detected: bool,
+    
+    pub wgpu_error: Option<String>,
+}
+
+impl RendererInfo {
+    
+    pub fn new(backend: RendererBackend, mesa_detected: bool) -> Self {
+        Self { backend, mesa_detected, wgpu_error: None }
+    }
+
+    
+    pub fn with_wgpu_error(mut self, error: impl Into<String>) -> Self {
+        self.wgpu_error = Some(error.into());
+        self
+    }
+
+    
+    pub fn is_fallback(&self) -> bool {
+        self.wgpu_error.is_some()
+    }
+
+    
+    pub fn summary(&self) -> String {
+        match (self.backend, self.mesa_detected, self.is_fallback()) {
+            (RendererBackend::Wgpu, _, _) => "wgpu (DirectX 12 / Vulkan)".to_string(),
+            (RendererBackend::Glow, true, _) => "glow (Mesa3D software OpenGL)".to_string(),
+            (RendererBackend::Glow, false, true) => "glow (OpenGL fallback after wgpu failed)".to_string(),
+            (RendererBackend::Glow, false, false) => "glow (OpenGL)".to_string(),
+        }
+    }
+
+    
+    pub fn suggest_mesa(&self) -> bool {
+        self.backend == RendererBackend::Glow && !self.mesa_detected
+    }
+
+    
+    pub fn is_degraded(&self, measured_fps: Option<f32>) -> bool {
+        let slow = measured_fps.is_some_and(|fps| fps < DEGRADED_FPS);
+        slow || (self.is_fallback() && !self.mesa_detected)
+    }
+}
+
+impl Default for RendererInfo {
+    fn default() -> Self {
+        Self::new(RendererBackend::Wgpu, false)
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct FrameTimeProbe {
+    
+    started: Option<Instant>,
+    
+    frames: u32,
+    
+    total_frame_time: f32,
+    
+    result: Option<f32>,
+}
+
+impl FrameTimeProbe {
+    
+    pub fn observe(&mut self, frame_time: Option<f32>, now: Instant) -> Option<f32> {
+        if self.result.is_some() {
+            return self.result;
+        }
+        let started = *self.started.get_or_insert(now);
+        if let Some(frame_time) = frame_time.filter(|t| *t > 0.0) {
+            self.frames += 1;
+            self.total_frame_time += frame_time;
+        }
+        if now.duration_since(started) >= PROBE_WINDOW && self.frames >= PROBE_MIN_FRAMES {
+            let average = self.total_frame_time / self.frames as f32;
+            self.result = Some(if average > 0.0 { 1.0 / average } else { f32::INFINITY });
+        }
+        self.result
+    }
+
+    
+    pub fn is_running(&self) -> bool {
+        self.result.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_reports_average_fps_after_window() {
+        let start = Instant::now();
+        let mut probe = FrameTimeProbe::default();
+        for i in 0..20 {
+            let now = start + Duration::from_millis(i * 100);
+            assert_eq!(probe.observe(Some(0.2), now), None);
+        }
+        assert!(probe.is_running());
+
+        let fps = probe.observe(Some(0.2), start + PROBE_WINDOW).unwrap();
+        assert!((fps - 5.0).abs() < 0.01);
+        assert!(!probe.is_running());
+        assert_eq!(probe.observe(Some(0.001), start + PROBE_WINDOW * 2), Some(fps));
+
+        let fallback = RendererInfo::new(RendererBackend::Glow, false).with_wgpu_error("no adapter");
+        assert!(fallback.is_degraded(None) && fallback.suggest_mesa());
+        let mesa = RendererInfo::new(RendererBackend::Glow, true);
+        assert!(!mesa.is_degraded(Some(60.0)) && mesa.is_degraded(Some(fps)));
+        assert!(!mesa.suggest_mesa());
+        assert!(!RendererInfo::default().is_degraded(Some(60.0)));
+    }
+}
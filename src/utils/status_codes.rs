@@ -148,7 +148,56 @@ pub fn translate_status_code(code: u32) -> String {
     format!("{} (0x{:08X})", severity, code)
 }
 
-#[allow(dead_code)]
+
+const INFO_TYPE_MASK: u32 = 0x0000_0C00;
+
+
+const INFO_TYPE_DATA_VALUE: u32 = 0x0000_0400;
+
+
+const LIMIT_BITS_MASK: u32 = 0x0000_0300;
+
+
+const OVERFLOW_BIT: u32 = 0x0000_0080;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitBits {
+    #[default]
+    None,
+    Low,
+    High,
+    Constant,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InfoBits {
+    
+    pub limit: LimitBits,
+    
+    pub overflow: bool,
+}
+
+
+pub fn info_bits(code: u32) -> InfoBits {
+    if code & INFO_TYPE_MASK != INFO_TYPE_DATA_VALUE {
+        return InfoBits::default();
+    }
+
+    let limit = match (code & LIMIT_BITS_MASK) >> 8 {
+        1 => LimitBits::Low,
+        2 => LimitBits::High,
+        3 => LimitBits::Constant,
+        _ => LimitBits::None,
+    };
+
+    InfoBits {
+        limit,
+        overflow: code & OVERFLOW_BIT != 0,
+    }
+}
+
 pub fn status_code_color(code: u32) -> [u8; 3] {
     match code >> 30 {
         0 => [0, 200, 0],       
@@ -171,6 +220,21 @@ mod tests {
         assert_eq!(translate_status_code(0x801C0000), "Bad - Certificate Untrusted");
     }
 
+    #[test]
+    fn test_info_bits_data_value() {
+        assert_eq!(info_bits(0x0000_0000), InfoBits::default());
+        assert_eq!(info_bits(0x0000_0480), InfoBits { limit: LimitBits::None, overflow: true });
+        assert_eq!(info_bits(0x4000_0580), InfoBits { limit: LimitBits::Low, overflow: true });
+        assert_eq!(info_bits(0x0000_0600), InfoBits { limit: LimitBits::High, overflow: false });
+        assert_eq!(info_bits(0x0000_0700).limit, LimitBits::Constant);
+    }
+
+    #[test]
+    fn test_info_bits_ignored_without_data_value_info_type() {
+        assert_eq!(info_bits(0x0000_0080), InfoBits::default());
+        assert_eq!(info_bits(0x0000_0B80), InfoBits::default());
+    }
+
     #[test]
     fn test_translate_unknown() {
         let result = translate_status_code(0x80FF0000);
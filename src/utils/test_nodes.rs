@@ -0,0 +1,58 @@
+use opcua::types::NodeId;
+
+use crate::opcua::browser::{BrowsedNode, NodeClass};
+
+
+pub struct TestNode {
+    
+    node: BrowsedNode,
+}
+
+impl TestNode {
+    
+    pub fn new(node_id: NodeId, display_name: &str) -> Self {
+        let browse_name = format!("{}:{}", node_id.namespace, display_name);
+        Self {
+            node: BrowsedNode {
+                node_id,
+                browse_name,
+                display_name: display_name.to_string(),
+                node_class: NodeClass::Variable,
+                type_definition: None,
+                has_children: false,
+                crawl_root: None,
+                parent_id: None,
+            },
+        }
+    }
+
+    
+    pub fn class(mut self, node_class: NodeClass) -> Self {
+        self.node.node_class = node_class;
+        self.node.has_children = node_class.has_children();
+        self
+    }
+
+    
+    pub fn browse_name(mut self, browse_name: &str) -> Self {
+        self.node.browse_name = browse_name.to_string();
+        self
+    }
+
+    
+    pub fn type_definition(mut self, type_definition: NodeId) -> Self {
+        self.node.type_definition = Some(type_definition);
+        self
+    }
+
+    
+    pub fn crawl_root(mut self, crawl_root: NodeId) -> Self {
+        self.node.crawl_root = Some(crawl_root);
+        self
+    }
+
+    
+    pub fn build(self) -> BrowsedNode {
+        self.node
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{Display, Write};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+
+const LOCAL_SHORT: &str = "%Y-%m-%d %H:%M:%S";
+const LOCAL_LONG: &str = "%Y-%m-%d %H:%M:%S%.3f %:z";
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimestampFormat {
+    
+    #[default]
+    Iso8601Millis,
+    
+    LocalShort,
+    
+    LocalLong,
+    
+    Custom(String),
+}
+
+impl TimestampFormat {
+    
+    pub fn parse_custom(pattern: &str) -> Result<Self, String> {
+        if pattern.trim().is_empty() {
+            return Err("empty format".to_string());
+        }
+        if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+            return Err(format!("unsupported specifier in \"{}\"", pattern));
+        }
+        let mut probe = String::new();
+        write!(probe, "{}", Local::now().format(pattern)).map_err(|_| format!("cannot format \"{}\"", pattern))?;
+        Ok(Self::Custom(pattern.to_string()))
+    }
+
+    
+    pub fn format(&self, dt: DateTime<Utc>) -> String {
+        match self {
+            Self::Iso8601Millis => dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+            _ => self.format_in(dt.with_timezone(&Local)),
+        }
+    }
+
+    
+    pub fn format_in<Tz: TimeZone>(&self, dt: DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let pattern = match self {
+            Self::Iso8601Millis => return dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+            Self::LocalShort => LOCAL_SHORT,
+            Self::LocalLong => LOCAL_LONG,
+            Self::Custom(pattern) => pattern,
+        };
+        let mut out = String::new();
+        match write!(out, "{}", dt.format(pattern)) {
+            Ok(()) => out,
+            Err(_) => dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+        }
+    }
+
+    
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Iso8601Millis => "ISO 8601",
+            Self::LocalShort => LOCAL_SHORT,
+            Self::LocalLong => LOCAL_LONG,
+            Self::Custom(pattern) => pattern,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn sample() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 7, 14, 5, 9).unwrap() + chrono::Duration::milliseconds(42)
+    }
+
+    #[test]
+    fn test_iso8601_preset_keeps_milliseconds_in_utc() {
+        assert_eq!(TimestampFormat::Iso8601Millis.format(sample()), "2024-03-07T14:05:09.042Z");
+    }
+
+    #[test]
+    fn test_local_presets() {
+        let plus_one = sample().with_timezone(&FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(TimestampFormat::LocalShort.format_in(plus_one), "2024-03-07 15:05:09");
+        assert_eq!(TimestampFormat::LocalLong.format_in(plus_one), "2024-03-07 15:05:09.042 +01:00");
+    }
+
+    #[test]
+    fn test_custom_format_is_validated() {
+        let custom = TimestampFormat::parse_custom("%m/%d/%Y %I:%M:%S%.3f %p").unwrap();
+        assert_eq!(custom.format_in(sample()), "03/07/2024 02:05:09.042 PM");
+
+        assert!(TimestampFormat::parse_custom("%Y-%m-%d %Q").is_err());
+        assert!(TimestampFormat::parse_custom("%").is_err());
+        assert!(TimestampFormat::parse_custom("  ").is_err());
+    }
+
+    #[test]
+    fn test_invalid_custom_format_falls_back_instead_of_panicking() {
+        let invalid = TimestampFormat::Custom("%Q".to_string());
+        assert_eq!(invalid.format_in(sample()), "2024-03-07T14:05:09.042Z");
+    }
+}